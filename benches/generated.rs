@@ -0,0 +1,113 @@
+//! Benchmarks of the generated validation, conversion and builder code
+//!
+//! Run with `cargo bench`; pass a filter to run one group, e.g.
+//! `cargo bench -- from_df`.
+
+#![allow(non_upper_case_globals)]
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use polars_tools::*;
+
+#[derive(Clone, PolarsSchema)]
+#[allow(dead_code)]
+struct Order {
+    id: i64,
+    customer: String,
+    amount: f64,
+    #[polars(alias = "qty")]
+    quantity: i32,
+    note: Option<String>,
+}
+
+const ROW_COUNTS: [usize; 3] = [100, 10_000, 100_000];
+
+fn orders(rows: usize) -> Vec<Order> {
+    (0..rows)
+        .map(|i| Order {
+            id: i as i64,
+            customer: format!("customer-{}", i % 100),
+            amount: i as f64 * 0.5,
+            quantity: (i % 7) as i32,
+            note: (i % 3 == 0).then(|| "gift".to_string()),
+        })
+        .collect()
+}
+
+fn validate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("validate");
+    for rows in ROW_COUNTS {
+        let df = Order::to_df(&orders(rows)).unwrap();
+        group.bench_with_input(BenchmarkId::new("validate", rows), &df, |b, df| {
+            b.iter(|| Order::validate(df).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("validate_cached", rows), &df, |b, df| {
+            b.iter(|| Order::validate_cached(df).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn conform(c: &mut Criterion) {
+    let mut group = c.benchmark_group("conform");
+    for rows in ROW_COUNTS {
+        let df = Order::to_df(&orders(rows))
+            .unwrap()
+            .lazy()
+            .rename(["quantity"], ["qty"], true)
+            .collect()
+            .unwrap();
+        group.bench_with_input(BenchmarkId::from_parameter(rows), &df, |b, df| {
+            b.iter(|| Order::conform(df).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn to_df(c: &mut Criterion) {
+    let mut group = c.benchmark_group("to_df");
+    group.sample_size(20);
+    for rows in ROW_COUNTS {
+        let orders = orders(rows);
+        group.throughput(Throughput::Elements(rows as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(rows), &orders, |b, orders| {
+            b.iter(|| Order::to_df(orders).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn from_df(c: &mut Criterion) {
+    let mut group = c.benchmark_group("from_df");
+    group.sample_size(20);
+    for rows in ROW_COUNTS {
+        let df = Order::to_df(&orders(rows)).unwrap();
+        group.throughput(Throughput::Elements(rows as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(rows), &df, |b, df| {
+            b.iter(|| Order::from_df(df).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn builders(c: &mut Criterion) {
+    let mut group = c.benchmark_group("builders");
+    group.bench_function("filter", |b| {
+        b.iter(|| {
+            Order::filter()
+                .amount()
+                .gt(10.0)
+                .customer()
+                .eq("customer-1")
+                .or()
+                .note()
+                .is_null()
+                .build()
+        })
+    });
+    group.bench_function("all_cols", |b| b.iter(Order::all_cols));
+    group.bench_function("polars_schema", |b| b.iter(Order::polars_schema));
+    group.finish();
+}
+
+criterion_group!(benches, validate, conform, to_df, from_df, builders);
+criterion_main!(benches);
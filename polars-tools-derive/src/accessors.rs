@@ -0,0 +1,168 @@
+//! Generation of typed per-field column accessors
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::Field;
+
+use crate::attrs::FieldAttrs;
+use crate::types;
+
+/// Type whose `ChunkedColumn` impl describes the field's column, or `None`
+/// when the column type is only known at runtime (`#[polars(dtype = ...)]`)
+pub(crate) fn chunked_source(field: &Field, attrs: &FieldAttrs) -> Option<TokenStream> {
+    let ty = &field.ty;
+    if attrs.dtype.is_some() {
+        None
+    } else if attrs.as_string {
+        Some(quote!(::polars_tools::__private::StringColumn))
+    } else if attrs.binary || types::is_byte_buffer(ty) {
+        Some(quote!(::polars_tools::__private::BinaryColumn))
+    } else if attrs.struct_keys.is_some() {
+        Some(quote!(::polars_tools::__private::StructColumn))
+    } else if attrs.transparent {
+        let wrapper = types::option_inner(ty).unwrap_or(ty);
+        Some(quote!(<#wrapper as ::std::ops::Deref>::Target))
+    } else {
+        Some(quote!(#ty))
+    }
+}
+
+/// `Struct::field_series(&df)` getters returning the downcast chunked array
+///
+/// Fields whose type has no `ChunkedColumn` impl still get a getter, but its
+/// `where` clause makes it uncallable instead of failing the derive.
+pub(crate) fn series_getters(
+    fields: &[&Field],
+    attrs: &[FieldAttrs],
+    dtypes: &[TokenStream],
+) -> Vec<TokenStream> {
+    fields
+        .iter()
+        .zip(attrs)
+        .zip(dtypes)
+        .filter_map(|((field, attrs), dtype)| {
+            let source = chunked_source(field, attrs)?;
+            let ident = field.ident.as_ref().unwrap();
+            let name = &attrs.column;
+            let getter = format_ident!("{}_series", ident);
+            let doc = format!("Get the `{}` column downcast to its chunked array type", name);
+
+            Some(quote! {
+                #[doc = #doc]
+                pub fn #getter(
+                    df: &::polars::prelude::DataFrame,
+                ) -> ::polars_tools::Result<&<#source as ::polars_tools::ChunkedColumn>::Chunked>
+                where
+                    for<'__polars> #source: ::polars_tools::ChunkedColumn,
+                {
+                    ::polars_tools::__private::typed_column::<#source>(df, #name, Self::COLUMN_ALIASES, &#dtype)
+                }
+            })
+        })
+        .collect()
+}
+
+/// `Struct::field_at(&df, row)` accessors reading a single cell
+///
+/// Like the series getters, fields whose type has no `CellValue` impl get an
+/// accessor that cannot be called.
+pub(crate) fn cell_accessors(fields: &[&Field], attrs: &[FieldAttrs]) -> Vec<TokenStream> {
+    fields
+        .iter()
+        .zip(attrs)
+        .filter_map(|(field, attrs)| {
+            let source = chunked_source(field, attrs)?;
+            let ident = field.ident.as_ref().unwrap();
+            let name = &attrs.column;
+            let accessor = format_ident!("{}_at", ident);
+            let doc = format!(
+                "Get the `{}` value at `row`, or `None` if the cell is null",
+                name
+            );
+
+            Some(quote! {
+                #[doc = #doc]
+                pub fn #accessor(
+                    df: &::polars::prelude::DataFrame,
+                    row: usize,
+                ) -> ::polars_tools::Result<Option<<#source as ::polars_tools::CellValue>::Value>>
+                where
+                    for<'__polars> #source: ::polars_tools::CellValue,
+                {
+                    ::polars_tools::__private::typed_cell::<#source>(df, #name, Self::COLUMN_ALIASES, row)
+                }
+            })
+        })
+        .collect()
+}
+
+/// `Struct::with_field(df, values)` helpers replacing a column after checking
+/// its dtype against the field's schema type
+pub(crate) fn column_setters(
+    fields: &[&Field],
+    attrs: &[FieldAttrs],
+    dtypes: &[TokenStream],
+) -> Vec<TokenStream> {
+    fields
+        .iter()
+        .zip(attrs)
+        .zip(dtypes)
+        .map(|((field, attrs), dtype)| {
+            let ident = field.ident.as_ref().unwrap();
+            let name = &attrs.column;
+            let setter = format_ident!("with_{}", ident);
+            let any_time_unit = attrs.any_time_unit;
+            let doc = format!(
+                "Replace or add the `{}` column, rejecting values of the wrong dtype",
+                name
+            );
+
+            quote! {
+                #[doc = #doc]
+                pub fn #setter(
+                    df: ::polars::prelude::DataFrame,
+                    values: impl Into<::polars::prelude::Series>,
+                ) -> ::polars_tools::Result<::polars::prelude::DataFrame> {
+                    ::polars_tools::__private::with_typed_column(
+                        df,
+                        #name,
+                        values.into(),
+                        &#dtype,
+                        #any_time_unit,
+                    )
+                }
+            }
+        })
+        .collect()
+}
+
+/// `Struct::lit_field(value)` helpers building a literal cast to the field's
+/// dtype
+///
+/// Fields whose type has no `IntoLiteral` impl get a helper that cannot be
+/// called.
+pub(crate) fn literal_helpers(fields: &[&Field], dtypes: &[TokenStream]) -> Vec<TokenStream> {
+    fields
+        .iter()
+        .zip(dtypes)
+        .map(|(field, dtype)| {
+            let ty = &field.ty;
+            let ident = field.ident.as_ref().unwrap();
+            let helper = format_ident!("lit_{}", ident);
+            let doc = format!(
+                "Literal expression for a `{}` value, cast to the column dtype",
+                ident
+            );
+
+            quote! {
+                #[doc = #doc]
+                pub fn #helper(value: impl Into<#ty>) -> ::polars::prelude::Expr
+                where
+                    for<'__polars> #ty: ::polars_tools::IntoLiteral,
+                {
+                    ::polars_tools::IntoLiteral::into_literal(value.into()).cast(#dtype)
+                }
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,574 @@
+//! Parsing of `#[polars(...)]` attributes
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{DeriveInput, Expr, Field, Ident, LitInt, LitStr, Path, Token, Type};
+
+/// Options set on the struct with `#[polars(...)]`
+#[derive(Default)]
+pub(crate) struct ContainerAttrs {
+    /// Reject field types without a `PolarsDType` impl instead of storing
+    /// them as strings (`#[polars(strict_types)]`)
+    pub strict_types: bool,
+    /// Register the schema in `polars_tools::registry`, under the struct name
+    /// unless given (`#[polars(register)]`, `#[polars(register = "users")]`)
+    pub register: Option<Option<LitStr>>,
+    /// Table name in a `SQLContext`, the snake_case struct name unless given
+    /// (`#[polars(table = "users")]`)
+    pub table: Option<LitStr>,
+    /// Module of the column name constants instead of the struct's impl,
+    /// `struct_name::<consts_mod>` (`#[polars(consts_mod = "cols")]`)
+    pub consts_mod: Option<Ident>,
+    /// Path of polars-tools in the generated code, e.g. when re-exported
+    /// from a facade crate (`#[polars(crate = "facade::polars_tools")]`)
+    pub krate: Option<Path>,
+    /// Parent schema of a `PolarsProjection`, whose fields the projection's
+    /// fields must be (`#[polars(of = "UserProfile")]`)
+    pub of: Option<Type>,
+    /// Name columns after the fields' `#[serde(rename = "...")]`
+    /// (`#[polars(use_serde_rename)]`)
+    pub use_serde_rename: bool,
+    /// Prepended to every column name, after any serde rename; the column
+    /// constants keep the field names (`#[polars(prefix = "user_")]`)
+    pub prefix: Option<LitStr>,
+    /// Leave out `ExprFor<Struct>`, `Struct::expr` and `Struct::exprs()`
+    /// (`#[polars(skip_expr)]`)
+    pub skip_expr: bool,
+    /// Leave out `Struct::df()`, `empty_lazy()` and `lazy()`
+    /// (`#[polars(skip_df)]`)
+    pub skip_df: bool,
+    /// Leave out the `<field>_type` and `<field>_idx` constants
+    /// (`#[polars(skip_consts)]`)
+    pub skip_consts: bool,
+    /// Leave out the `<field>_col()` functions (`#[polars(skip_col_fns)]`)
+    pub skip_col_fns: bool,
+}
+
+impl ContainerAttrs {
+    pub(crate) fn from_input(input: &DeriveInput) -> syn::Result<Self> {
+        let mut attrs = ContainerAttrs::default();
+
+        for attr in input.attrs.iter().filter(|a| a.path().is_ident("polars")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("strict_types") {
+                    attrs.strict_types = true;
+                    Ok(())
+                } else if meta.path.is_ident("register") {
+                    let name = if meta.input.peek(Token![=]) {
+                        Some(meta.value()?.parse()?)
+                    } else {
+                        None
+                    };
+                    attrs.register = Some(name);
+                    Ok(())
+                } else if meta.path.is_ident("table") {
+                    attrs.table = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("crate") {
+                    let krate: LitStr = meta.value()?.parse()?;
+                    attrs.krate = Some(krate.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("consts_mod") {
+                    let consts_mod: LitStr = meta.value()?.parse()?;
+                    attrs.consts_mod = Some(consts_mod.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("of") {
+                    let parent: LitStr = meta.value()?.parse()?;
+                    attrs.of = Some(parent.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("use_serde_rename") {
+                    attrs.use_serde_rename = true;
+                    Ok(())
+                } else if meta.path.is_ident("prefix") {
+                    attrs.prefix = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("skip_expr") {
+                    attrs.skip_expr = true;
+                    Ok(())
+                } else if meta.path.is_ident("skip_df") {
+                    attrs.skip_df = true;
+                    Ok(())
+                } else if meta.path.is_ident("skip_consts") {
+                    attrs.skip_consts = true;
+                    Ok(())
+                } else if meta.path.is_ident("skip_col_fns") {
+                    attrs.skip_col_fns = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported polars attribute"))
+                }
+            })?;
+        }
+
+        Ok(attrs)
+    }
+}
+
+/// Options set on a field with `#[polars(...)]`
+#[derive(Default)]
+pub(crate) struct FieldAttrs {
+    /// Column name, the field name unless renamed with `use_serde_rename`,
+    /// behind the container's `prefix`
+    pub column: String,
+    /// Decimal precision (`#[polars(precision = 38)]`)
+    pub precision: Option<usize>,
+    /// Decimal scale (`#[polars(scale = 2)]`)
+    pub scale: Option<usize>,
+    /// Store the field as `DataType::Binary` (`#[polars(binary)]`)
+    pub binary: bool,
+    /// Time unit of temporal columns (`#[polars(time_unit = "ms")]`), as a
+    /// `TimeUnit` expression
+    pub time_unit: Option<TokenStream>,
+    /// Accept any time unit during validation (`#[polars(any_time_unit)]`)
+    pub any_time_unit: bool,
+    /// Time zone of datetime columns (`#[polars(tz = "Europe/Berlin")]`)
+    pub tz: Option<LitStr>,
+    /// Map a `Deref` wrapper to its target's dtype (`#[polars(transparent)]`)
+    pub transparent: bool,
+    /// Require a `PolarsDType` impl for this field (`#[polars(not_enum)]`)
+    pub not_enum: bool,
+    /// Store the field as `DataType::String` (`#[polars(as_string)]`)
+    pub as_string: bool,
+    /// Explicit column dtype (`#[polars(dtype = DataType::Int32)]`)
+    pub dtype: Option<Expr>,
+    /// Store a map field as a struct with fixed keys
+    /// (`#[polars(struct_keys("open", "close"))]`)
+    pub struct_keys: Option<Vec<LitStr>>,
+    /// Quality rule: non-null values are distinct (`#[polars(unique)]`)
+    pub unique: bool,
+    /// Quality rule: largest allowed share of nulls
+    /// (`#[polars(max_null_fraction = 0.1)]`)
+    pub max_null_fraction: Option<Expr>,
+    /// Quality rule: inclusive lower bound (`#[polars(min = 0)]`)
+    pub min: Option<Expr>,
+    /// Quality rule: inclusive upper bound (`#[polars(max = 150)]`)
+    pub max: Option<Expr>,
+    /// Schema version that added the column (`#[polars(since = "2")]`)
+    pub since: Option<u32>,
+    /// Column no longer required, from the given schema version on or in
+    /// every version (`#[polars(deprecated = "3")]`, `#[polars(deprecated)]`)
+    pub deprecated: Option<Option<u32>>,
+    /// Former column names matched by `migrate_from`
+    /// (`#[polars(renamed_from = "old_name")]`, repeatable)
+    pub renamed_from: Vec<LitStr>,
+    /// Legacy column names accepted in place of the column by `validate` and
+    /// renamed by `conform` (`#[polars(alias = "old_name")]`, repeatable)
+    pub aliases: Vec<LitStr>,
+    /// Value used for missing columns and in generated rows
+    /// (`#[polars(default = 0)]`)
+    pub default: Option<Expr>,
+    /// Function returning the default value
+    /// (`#[polars(default_fn = "path::to::fn")]`)
+    pub default_fn: Option<syn::Path>,
+    /// How `fill_nulls` fills the nulls of the column: a strategy such as
+    /// `"zero"` or `"mean"`, or a value (`#[polars(fill_null = "mean")]`,
+    /// `#[polars(fill_null = -1)]`)
+    pub fill_null: Option<FillNull>,
+    /// Bounds `clean` clips the values to instead of rejecting them
+    /// (`#[polars(clip(0, 150))]`, `#[polars(clip(max = 150))]`)
+    pub clip: Option<(Option<Expr>, Option<Expr>)>,
+    /// `clean` and `conform` strip surrounding whitespace (`#[polars(trim)]`
+    /// or `#[polars(strip)]`)
+    pub trim: bool,
+    /// `clean` and `conform` lowercase the strings (`#[polars(lowercase)]`)
+    pub lowercase: bool,
+    /// `clean` and `conform` uppercase the strings (`#[polars(uppercase)]`)
+    pub uppercase: bool,
+    /// Values `clean` and `conform` replace, after trimming and case
+    /// normalization (`#[polars(map_values("hi" => "high", "h" => "high"))]`)
+    pub map_values: Vec<(LitStr, LitStr)>,
+    /// strftime format of a temporal column in JSON rows
+    /// (`#[polars(format = "%d/%m/%Y")]`)
+    pub format: Option<LitStr>,
+    /// Formats `conform` and `parse_dates` read a string column of the
+    /// temporal field with, tried in order: strftime formats, `epoch_s` or
+    /// `epoch_ms` (`#[polars(parse_date = "%Y-%m-%d")]`, repeatable)
+    pub parse_date: Vec<LitStr>,
+    /// Column description, from `#[polars(doc = "...")]` or else the field's
+    /// rustdoc comment
+    pub doc: Option<String>,
+    /// Semantic tags such as `"pii"` (`#[polars(tag = "pii")]`, repeatable)
+    pub tags: Vec<LitStr>,
+    /// Named column groups the field belongs to, each generating
+    /// `<group>_columns()` / `<group>_cols()` (`#[polars(group = "keys")]`,
+    /// repeatable)
+    pub groups: Vec<LitStr>,
+    /// Time column of the rolling and `group_by_dynamic` helpers, on at most
+    /// one field (`#[polars(time_index)]`)
+    pub time_index: bool,
+    /// Part of the primary key (`#[polars(key)]`), checked and deduplicated
+    /// on by the generated key helpers
+    pub key: bool,
+    /// Column of another schema the values must appear in, as its constant
+    /// (`#[polars(references(Customer::id))]`)
+    pub references: Option<syn::Path>,
+}
+
+impl FieldAttrs {
+    pub(crate) fn from_field(field: &Field, container: &ContainerAttrs) -> syn::Result<Self> {
+        let mut attrs = FieldAttrs {
+            column: field.ident.as_ref().unwrap().to_string(),
+            ..Default::default()
+        };
+        if container.use_serde_rename {
+            if let Some(rename) = serde_rename(field)? {
+                attrs.column = rename.value();
+            }
+        }
+        if let Some(prefix) = &container.prefix {
+            attrs.column.insert_str(0, &prefix.value());
+        }
+
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("polars")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("precision") {
+                    attrs.precision = Some(meta.value()?.parse::<LitInt>()?.base10_parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("scale") {
+                    attrs.scale = Some(meta.value()?.parse::<LitInt>()?.base10_parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("binary") {
+                    attrs.binary = true;
+                    Ok(())
+                } else if meta.path.is_ident("time_unit") {
+                    let unit: LitStr = meta.value()?.parse()?;
+                    attrs.time_unit = Some(parse_time_unit(&unit)?);
+                    Ok(())
+                } else if meta.path.is_ident("any_time_unit") {
+                    attrs.any_time_unit = true;
+                    Ok(())
+                } else if meta.path.is_ident("tz") {
+                    attrs.tz = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("transparent") {
+                    attrs.transparent = true;
+                    Ok(())
+                } else if meta.path.is_ident("not_enum") {
+                    attrs.not_enum = true;
+                    Ok(())
+                } else if meta.path.is_ident("as_string") {
+                    attrs.as_string = true;
+                    Ok(())
+                } else if meta.path.is_ident("dtype") {
+                    attrs.dtype = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("struct_keys") {
+                    let content;
+                    syn::parenthesized!(content in meta.input);
+                    let keys = Punctuated::<LitStr, Token![,]>::parse_terminated(&content)?;
+                    attrs.struct_keys = Some(keys.into_iter().collect());
+                    Ok(())
+                } else if meta.path.is_ident("unique") {
+                    attrs.unique = true;
+                    Ok(())
+                } else if meta.path.is_ident("max_null_fraction") {
+                    attrs.max_null_fraction = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("min") {
+                    attrs.min = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("max") {
+                    attrs.max = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("default") {
+                    attrs.default = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("default_fn") {
+                    let path: LitStr = meta.value()?.parse()?;
+                    attrs.default_fn = Some(path.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("fill_null") {
+                    attrs.fill_null = Some(FillNull::parse(meta.value()?.parse()?));
+                    Ok(())
+                } else if meta.path.is_ident("clip") {
+                    let content;
+                    syn::parenthesized!(content in meta.input);
+                    let bounds = Punctuated::<Expr, Token![,]>::parse_terminated(&content)?;
+                    attrs.clip = Some(parse_clip(&meta.path, bounds)?);
+                    Ok(())
+                } else if meta.path.is_ident("trim") || meta.path.is_ident("strip") {
+                    attrs.trim = true;
+                    Ok(())
+                } else if meta.path.is_ident("references") {
+                    let content;
+                    syn::parenthesized!(content in meta.input);
+                    let path: syn::Path = content.parse()?;
+                    if path.segments.len() < 2 {
+                        return Err(syn::Error::new_spanned(
+                            path,
+                            "expected references(Schema::field)",
+                        ));
+                    }
+                    attrs.references = Some(path);
+                    Ok(())
+                } else if meta.path.is_ident("key") {
+                    attrs.key = true;
+                    Ok(())
+                } else if meta.path.is_ident("time_index") {
+                    attrs.time_index = true;
+                    Ok(())
+                } else if meta.path.is_ident("lowercase") {
+                    attrs.lowercase = true;
+                    Ok(())
+                } else if meta.path.is_ident("uppercase") {
+                    attrs.uppercase = true;
+                    Ok(())
+                } else if meta.path.is_ident("map_values") {
+                    let content;
+                    syn::parenthesized!(content in meta.input);
+                    let pairs = Punctuated::<ValueMapping, Token![,]>::parse_terminated(&content)?;
+                    if pairs.is_empty() {
+                        return Err(meta.error("expected map_values(\"from\" => \"to\", ...)"));
+                    }
+                    attrs.map_values.extend(pairs.into_iter().map(|pair| (pair.from, pair.to)));
+                    Ok(())
+                } else if meta.path.is_ident("format") {
+                    attrs.format = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("parse_date") {
+                    attrs.parse_date.push(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("doc") {
+                    attrs.doc = Some(meta.value()?.parse::<LitStr>()?.value());
+                    Ok(())
+                } else if meta.path.is_ident("tag") {
+                    attrs.tags.push(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("group") {
+                    let group: LitStr = meta.value()?.parse()?;
+                    if syn::parse_str::<syn::Ident>(&group.value()).is_err()
+                        || group.value() == "all"
+                    {
+                        return Err(syn::Error::new(
+                            group.span(),
+                            "group must be an identifier other than \"all\"",
+                        ));
+                    }
+                    attrs.groups.push(group);
+                    Ok(())
+                } else if meta.path.is_ident("alias") {
+                    attrs.aliases.push(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("renamed_from") {
+                    attrs.renamed_from.push(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("since") {
+                    attrs.since = Some(parse_version(&meta.value()?.parse()?)?);
+                    Ok(())
+                } else if meta.path.is_ident("deprecated") {
+                    let version = if meta.input.peek(Token![=]) {
+                        Some(parse_version(&meta.value()?.parse()?)?)
+                    } else {
+                        None
+                    };
+                    attrs.deprecated = Some(version);
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported polars attribute"))
+                }
+            })?;
+        }
+
+        if attrs.doc.is_none() {
+            attrs.doc = rustdoc(field);
+        }
+
+        if attrs.lowercase && attrs.uppercase {
+            return Err(syn::Error::new_spanned(
+                field,
+                "lowercase and uppercase cannot be combined",
+            ));
+        }
+
+        if let (Some(default), Some(_)) = (&attrs.default, &attrs.default_fn) {
+            return Err(syn::Error::new_spanned(
+                default,
+                "default and default_fn cannot be combined",
+            ));
+        }
+
+        Ok(attrs)
+    }
+}
+
+/// Null filling of `#[polars(fill_null = ...)]`
+pub(crate) enum FillNull {
+    /// A `FillNullStrategy` expression, from its name
+    Strategy(TokenStream),
+    /// A value typed as the field
+    Value(Expr),
+}
+
+impl FillNull {
+    /// The strategy a string literal names, any other expression as a value
+    fn parse(value: Expr) -> Self {
+        let strategy = match &value {
+            Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(name),
+                ..
+            }) => match name.value().as_str() {
+                "zero" => Some(quote!(Zero)),
+                "one" => Some(quote!(One)),
+                "mean" => Some(quote!(Mean)),
+                "min" => Some(quote!(Min)),
+                "max" => Some(quote!(Max)),
+                "forward" => Some(quote!(Forward(None))),
+                "backward" => Some(quote!(Backward(None))),
+                _ => None,
+            },
+            _ => None,
+        };
+        match strategy {
+            Some(strategy) => {
+                FillNull::Strategy(quote!(::polars::prelude::FillNullStrategy::#strategy))
+            }
+            None => FillNull::Value(value),
+        }
+    }
+}
+
+/// Name given with `#[serde(rename = "...")]`, ignoring the other serde
+/// attributes
+///
+/// A rename differing between serialization and deserialization
+/// (`rename(serialize = "...", deserialize = "...")`) has no single column
+/// name and is rejected.
+fn serde_rename(field: &Field) -> syn::Result<Option<LitStr>> {
+    let mut rename = None;
+    for attr in field.attrs.iter().filter(|a| a.path().is_ident("serde")) {
+        let metas = attr.parse_args_with(Punctuated::<syn::Meta, Token![,]>::parse_terminated)?;
+        for meta in metas.iter().filter(|meta| meta.path().is_ident("rename")) {
+            match meta {
+                syn::Meta::NameValue(syn::MetaNameValue {
+                    value:
+                        Expr::Lit(syn::ExprLit {
+                            lit: syn::Lit::Str(name),
+                            ..
+                        }),
+                    ..
+                }) => rename = Some(name.clone()),
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        meta,
+                        "use_serde_rename supports #[serde(rename = \"...\")] only",
+                    ))
+                }
+            }
+        }
+    }
+    Ok(rename)
+}
+
+/// Text of the `///` comments of a field, with wrapped lines joined and
+/// paragraphs separated by a blank line
+fn rustdoc(field: &Field) -> Option<String> {
+    let lines = field.attrs.iter().filter_map(|attr| match &attr.meta {
+        syn::Meta::NameValue(doc) if doc.path.is_ident("doc") => match &doc.value {
+            Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(line),
+                ..
+            }) => Some(line.value()),
+            _ => None,
+        },
+        _ => None,
+    });
+    let mut paragraphs = vec![String::new()];
+    for line in lines.flat_map(|line| line.split('\n').map(str::to_string).collect::<Vec<_>>()) {
+        let line = line.trim();
+        let paragraph = paragraphs.last_mut().unwrap();
+        if line.is_empty() {
+            if !paragraph.is_empty() {
+                paragraphs.push(String::new());
+            }
+        } else {
+            if !paragraph.is_empty() {
+                paragraph.push(' ');
+            }
+            paragraph.push_str(line);
+        }
+    }
+    paragraphs.retain(|paragraph| !paragraph.is_empty());
+    (!paragraphs.is_empty()).then(|| paragraphs.join("\n\n"))
+}
+
+/// A `"from" => "to"` pair of `map_values(...)`
+struct ValueMapping {
+    from: LitStr,
+    to: LitStr,
+}
+
+impl Parse for ValueMapping {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let from = input.parse()?;
+        input.parse::<Token![=>]>()?;
+        let to = input.parse()?;
+        Ok(Self { from, to })
+    }
+}
+
+/// Bounds of `clip(min, max)`, or of `clip(min = ...)` / `clip(max = ...)`
+/// for a single one
+fn parse_clip(
+    clip: &Path,
+    bounds: Punctuated<Expr, Token![,]>,
+) -> syn::Result<(Option<Expr>, Option<Expr>)> {
+    let named = |bound: &Expr| match bound {
+        Expr::Assign(assign) => match &*assign.left {
+            Expr::Path(path) if path.path.is_ident("min") => Some((true, (*assign.right).clone())),
+            Expr::Path(path) if path.path.is_ident("max") => Some((false, (*assign.right).clone())),
+            _ => None,
+        },
+        _ => None,
+    };
+    let bounds: Vec<Expr> = bounds.into_iter().collect();
+    if bounds.iter().all(|bound| named(bound).is_some()) && !bounds.is_empty() {
+        let (mut min, mut max) = (None, None);
+        for bound in &bounds {
+            match named(bound) {
+                Some((true, value)) if min.is_none() => min = Some(value),
+                Some((false, value)) if max.is_none() => max = Some(value),
+                _ => return Err(syn::Error::new_spanned(bound, "duplicate clip bound")),
+            }
+        }
+        return Ok((min, max));
+    }
+    match <[Expr; 2]>::try_from(bounds) {
+        Ok([min, max]) if named(&min).is_none() && named(&max).is_none() => {
+            Ok((Some(min), Some(max)))
+        }
+        _ => Err(syn::Error::new_spanned(
+            clip,
+            "expected clip(min, max), clip(min = ...) or clip(max = ...)",
+        )),
+    }
+}
+
+/// Schema version given as a string or integer literal (`"2"` or `2`)
+fn parse_version(lit: &syn::Lit) -> syn::Result<u32> {
+    match lit {
+        syn::Lit::Str(version) => version
+            .value()
+            .parse()
+            .map_err(|_| syn::Error::new(version.span(), "version must be a positive integer")),
+        syn::Lit::Int(version) => version.base10_parse(),
+        _ => Err(syn::Error::new_spanned(
+            lit,
+            "version must be a positive integer",
+        )),
+    }
+}
+
+/// Map a time unit literal (`"ns"`, `"us"`, `"ms"`) to a `TimeUnit` expression
+fn parse_time_unit(unit: &LitStr) -> syn::Result<TokenStream> {
+    match unit.value().as_str() {
+        "ns" => Ok(quote!(::polars::prelude::TimeUnit::Nanoseconds)),
+        "us" => Ok(quote!(::polars::prelude::TimeUnit::Microseconds)),
+        "ms" => Ok(quote!(::polars::prelude::TimeUnit::Milliseconds)),
+        _ => Err(syn::Error::new(
+            unit.span(),
+            "time_unit must be one of \"ns\", \"us\" or \"ms\"",
+        )),
+    }
+}
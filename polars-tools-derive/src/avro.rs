@@ -0,0 +1,62 @@
+//! Generation of the `avro` feature's schema and object container file IO
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Field, Ident};
+
+use crate::types;
+
+/// `Struct::avro_schema()`, `Struct::read_avro(reader)` and
+/// `Struct::write_avro(writer, &df)`, with `Option` fields as nullable unions
+pub(crate) fn avro_io(name: &Ident, fields: &[&Field]) -> TokenStream {
+    let record_name = name.to_string();
+    let nullable = fields
+        .iter()
+        .map(|field| types::option_inner(&field.ty).is_some());
+    quote! {
+        const AVRO_NULLABLE: &'static [bool] = &[#(#nullable),*];
+
+        /// Avro record schema of the struct, as JSON text, with the column
+        /// docs as field `doc`s
+        pub fn avro_schema() -> ::polars_tools::Result<String> {
+            ::polars_tools::__private::avro_schema(
+                #record_name,
+                Self::polars_schema(),
+                Self::AVRO_NULLABLE,
+                Self::COLUMN_DOCS,
+            )
+            .map(|schema| schema.to_string())
+        }
+
+        /// Read an Avro object container file written with `avro_schema()`
+        /// and validate the frame
+        pub fn read_avro<R: ::std::io::Read>(
+            reader: R,
+        ) -> ::polars_tools::Result<::polars::prelude::DataFrame> {
+            let df = ::polars_tools::__private::read_avro(
+                reader,
+                #record_name,
+                Self::polars_schema(),
+                Self::AVRO_NULLABLE,
+            )?;
+            Self::validate(&df)?;
+            Ok(df)
+        }
+
+        /// Validate `df` and write its schema columns as an Avro object
+        /// container file
+        pub fn write_avro<W: ::std::io::Write>(
+            writer: W,
+            df: &::polars::prelude::DataFrame,
+        ) -> ::polars_tools::Result<()> {
+            Self::validate(df)?;
+            ::polars_tools::__private::write_avro(
+                writer,
+                df,
+                #record_name,
+                Self::polars_schema(),
+                Self::AVRO_NULLABLE,
+            )
+        }
+    }
+}
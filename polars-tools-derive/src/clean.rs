@@ -0,0 +1,114 @@
+//! Generation of `clean` and `normalize_exprs` from `#[polars(clip(...))]`,
+//! `#[polars(trim)]` / `#[polars(strip)]`, `#[polars(lowercase)]`,
+//! `#[polars(uppercase)]` and `#[polars(map_values(...))]`
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::Field;
+
+use crate::attrs::FieldAttrs;
+use crate::defaults;
+
+/// Expression normalizing the strings of a column: trimmed, case-normalized
+/// and then mapped through `map_values`, `None` without any of them
+fn normalize_expr(column: &str, attrs: &FieldAttrs) -> Option<TokenStream> {
+    if !attrs.trim && !attrs.lowercase && !attrs.uppercase && attrs.map_values.is_empty() {
+        return None;
+    }
+    let mut expr = quote!(::polars_tools::__private::column_expr(#column));
+    if attrs.trim {
+        expr = quote!(#expr.str().strip_chars(::polars::prelude::lit(::polars::prelude::NULL)));
+    }
+    if attrs.lowercase {
+        expr = quote!(#expr.str().to_lowercase());
+    }
+    if attrs.uppercase {
+        expr = quote!(#expr.str().to_uppercase());
+    }
+    if let Some(((first_from, first_to), rest)) = attrs.map_values.split_first() {
+        let (from, to): (Vec<_>, Vec<_>) = rest.iter().cloned().unzip();
+        expr = quote! {{
+            let value = #expr;
+            ::polars::prelude::when(value.clone().eq(::polars::prelude::lit(#first_from)))
+                .then(::polars::prelude::lit(#first_to))
+                #(
+                    .when(value.clone().eq(::polars::prelude::lit(#from)))
+                    .then(::polars::prelude::lit(#to))
+                )*
+                .otherwise(value)
+                .alias(#column)
+        }};
+    }
+    Some(expr)
+}
+
+/// `Struct::normalize_exprs()` of the string normalization `conform` applies,
+/// `Struct::clean_exprs()` with one expression per cleaned column applying
+/// its normalization and then its clip bounds, and the eager and lazy
+/// `clean` applying them in a single `with_columns`
+pub(crate) fn clean(fields: &[&Field], attrs: &[FieldAttrs]) -> TokenStream {
+    let normalize_exprs = attrs
+        .iter()
+        .filter_map(|attrs| normalize_expr(&attrs.column, attrs));
+    let clean_exprs = fields.iter().zip(attrs).filter_map(|(field, attrs)| {
+        let ident = field.ident.as_ref().unwrap();
+        let column = &attrs.column;
+        let normalized = normalize_expr(column, attrs);
+        let Some((min, max)) = &attrs.clip else {
+            return normalized;
+        };
+        let expr =
+            normalized.unwrap_or_else(|| quote!(::polars_tools::__private::column_expr(#column)));
+        let helper = format_ident!("lit_{}", ident);
+        let bound = |bound: &syn::Expr| {
+            let value = defaults::typed_value(field, bound);
+            quote!(Self::#helper(#value))
+        };
+        Some(match (min, max) {
+            (Some(min), Some(max)) => {
+                let (min, max) = (bound(min), bound(max));
+                quote!((#expr).clip(#min, #max))
+            }
+            (Some(min), None) => {
+                let min = bound(min);
+                quote!((#expr).clip_min(#min))
+            }
+            (None, Some(max)) => {
+                let max = bound(max);
+                quote!((#expr).clip_max(#max))
+            }
+            (None, None) => unreachable!("clip without bounds is rejected when parsing"),
+        })
+    });
+
+    quote! {
+        /// Expressions normalizing the string columns declared with `trim`,
+        /// `lowercase`, `uppercase` or `map_values`, as applied by `conform`
+        pub fn normalize_exprs() -> Vec<::polars::prelude::Expr> {
+            vec![#(#normalize_exprs),*]
+        }
+
+        /// Expressions cleaning the columns declared with `clip`, `trim`,
+        /// `lowercase`, `uppercase` or `map_values`, for `with_columns`
+        pub fn clean_exprs() -> Vec<::polars::prelude::Expr> {
+            vec![#(#clean_exprs),*]
+        }
+
+        /// `lf` with the declared cleaning steps applied
+        pub fn clean_lazy(lf: ::polars::prelude::LazyFrame) -> ::polars::prelude::LazyFrame {
+            let exprs = Self::clean_exprs();
+            if exprs.is_empty() {
+                return lf;
+            }
+            lf.with_columns(exprs)
+        }
+
+        /// `df` with values clipped to their `clip` bounds and strings
+        /// trimmed, case-normalized and mapped as declared, in one pass
+        pub fn clean(
+            df: &::polars::prelude::DataFrame,
+        ) -> ::polars_tools::Result<::polars::prelude::DataFrame> {
+            Ok(Self::clean_lazy(::polars::prelude::IntoLazy::lazy(df.clone())).collect()?)
+        }
+    }
+}
@@ -0,0 +1,224 @@
+//! Parsing and expansion of `combine!(Left, Right)`, a schema type for the
+//! horizontal concatenation or join of two derived schemas
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, quote_spanned};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{bracketed, Ident, Path, Token, Visibility};
+
+/// `[vis Name =] Left, Right [, on = [key, ...]]`
+pub(crate) struct CombineInput {
+    vis: Visibility,
+    name: Option<Ident>,
+    left: Path,
+    right: Path,
+    keys: Vec<Ident>,
+}
+
+impl Parse for CombineInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let vis = input.parse()?;
+        let name = if input.peek(Ident) && input.peek2(Token![=]) {
+            let name = input.parse()?;
+            input.parse::<Token![=]>()?;
+            Some(name)
+        } else {
+            None
+        };
+        let left = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let right = input.parse()?;
+
+        let mut keys = Vec::new();
+        if input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+            let on: Ident = input.parse()?;
+            if on != "on" {
+                return Err(syn::Error::new(on.span(), "expected `on = [...]`"));
+            }
+            input.parse::<Token![=]>()?;
+            let content;
+            bracketed!(content in input);
+            let parsed = Punctuated::<Ident, Token![,]>::parse_terminated(&content)?;
+            for key in parsed {
+                if keys.contains(&key) {
+                    return Err(syn::Error::new(key.span(), "duplicate join key"));
+                }
+                keys.push(key);
+            }
+            input.parse::<Option<Token![,]>>()?;
+        }
+        Ok(CombineInput {
+            vis,
+            name,
+            left,
+            right,
+            keys,
+        })
+    }
+}
+
+fn type_name(path: &Path) -> &Ident {
+    &path.segments.last().unwrap().ident
+}
+
+/// A unit struct standing for the columns of `Left` followed by those of
+/// `Right`, the join keys only once, with a const check rejecting any other
+/// shared column
+pub(crate) fn combine(input: CombineInput) -> TokenStream {
+    let CombineInput {
+        vis,
+        name,
+        left,
+        right,
+        keys,
+    } = input;
+    let left_name = type_name(&left).to_string();
+    let right_name = type_name(&right).to_string();
+    let name = name.unwrap_or_else(|| format_ident!("{}{}", left_name, right_name));
+    let keys: Vec<String> = keys.iter().map(|key| key.to_string()).collect();
+    let key_count = keys.len();
+    let doc = format!(
+        " Columns of [`{left_name}`] followed by those of [`{right_name}`], generated by `combine!`"
+    );
+    let check = quote_spanned! {right.span()=>
+        if let Some(error) = ::polars_tools::__private::combine_error(
+            #left_name,
+            #left::COLUMNS,
+            #right_name,
+            #right::COLUMNS,
+            &[#(#keys),*],
+        ) {
+            panic!("{}", error.as_str())
+        }
+    };
+
+    quote! {
+        #[doc = #doc]
+        #[derive(Clone, Copy, Debug, Default)]
+        #vis struct #name;
+
+        const _: () = #check;
+
+        #[allow(dead_code)]
+        impl #name {
+            /// Join key columns, present once and with the dtype of the left
+            /// schema
+            pub const KEYS: &'static [&'static str] = &[#(#keys),*];
+
+            /// Number of columns
+            pub const WIDTH: usize = #left::WIDTH + #right::WIDTH - #key_count;
+
+            /// Column names in schema order, usable in const contexts
+            pub const COLUMNS: &'static [&'static str] =
+                &::polars_tools::__private::combined_columns::<{ #name::WIDTH }>(
+                    #left::COLUMNS,
+                    #right::COLUMNS,
+                    Self::KEYS,
+                );
+
+            /// Position of the column `name`
+            pub fn field_index(name: &str) -> Option<usize> {
+                Self::COLUMNS.iter().position(|column| *column == name)
+            }
+
+            /// Get all column names as Vec<&str> for use with df.select()
+            pub fn all_columns() -> Vec<&'static str> {
+                Self::COLUMNS.to_vec()
+            }
+
+            /// Get all column types as Vec<DataType>
+            pub fn all_types() -> Vec<::polars::prelude::DataType> {
+                Self::polars_schema().iter_values().cloned().collect()
+            }
+
+            /// Get all column names as expressions for lazy operations
+            pub fn all_cols() -> Vec<::polars::prelude::Expr> {
+                Self::COLUMNS
+                    .iter()
+                    .map(|column| ::polars_tools::__private::column_expr(column))
+                    .collect()
+            }
+
+            /// Expressions casting every column to its schema dtype
+            pub fn cast_exprs() -> Vec<::polars::prelude::Expr> {
+                Self::polars_schema()
+                    .iter()
+                    .map(|(column, dtype)| {
+                        ::polars_tools::__private::column_expr(column).cast(dtype.clone())
+                    })
+                    .collect()
+            }
+
+            /// Column names without those in `except`
+            pub fn all_columns_except(except: &[&str]) -> Vec<&'static str> {
+                Self::COLUMNS
+                    .iter()
+                    .copied()
+                    .filter(|column| !except.contains(column))
+                    .collect()
+            }
+
+            /// Column expressions of `all_columns_except(except)`
+            pub fn all_cols_except(except: &[&str]) -> Vec<::polars::prelude::Expr> {
+                Self::all_columns_except(except)
+                    .into_iter()
+                    .map(::polars_tools::__private::column_expr)
+                    .collect()
+            }
+
+            /// Create an empty DataFrame with the correct schema
+            pub fn df() -> ::polars::prelude::DataFrame {
+                ::polars::prelude::DataFrame::empty_with_schema(Self::polars_schema())
+            }
+
+            /// Create an empty LazyFrame with the correct schema
+            pub fn empty_lazy() -> ::polars::prelude::LazyFrame {
+                ::polars::prelude::IntoLazy::lazy(Self::df())
+            }
+
+            /// Expected schema of the frame, built on first use
+            pub fn polars_schema() -> &'static ::polars::prelude::Schema {
+                static SCHEMA: ::std::sync::OnceLock<::polars::prelude::Schema> =
+                    ::std::sync::OnceLock::new();
+                SCHEMA.get_or_init(|| {
+                    ::polars_tools::__private::combined_schema(
+                        <#left as ::polars_tools::PolarsSchemaInfo>::polars_schema(),
+                        <#right as ::polars_tools::PolarsSchemaInfo>::polars_schema(),
+                        Self::KEYS,
+                    )
+                })
+            }
+
+            /// Validate `df` against both schemas, so each side keeps its own
+            /// aliases and time unit options
+            pub fn validate(df: &::polars::prelude::DataFrame) -> ::polars_tools::Result<()> {
+                <#left as ::polars_tools::PolarsSchemaInfo>::validate(df)?;
+                <#right as ::polars_tools::PolarsSchemaInfo>::validate(df)
+            }
+
+            pub fn validate_strict(df: &::polars::prelude::DataFrame) -> ::polars_tools::Result<()> {
+                Self::validate(df)?;
+                ::polars_tools::__private::validate_column_set(df, Self::polars_schema())
+            }
+
+            /// Validate `df` and wrap it as a frame known to match this schema
+            pub fn typed(
+                df: &::polars::prelude::DataFrame,
+            ) -> ::polars_tools::Result<::polars_tools::TypedFrame<Self>> {
+                ::polars_tools::TypedFrame::new(df.clone())
+            }
+        }
+
+        impl ::polars_tools::PolarsSchemaInfo for #name {
+            fn polars_schema() -> &'static ::polars::prelude::Schema {
+                #name::polars_schema()
+            }
+
+            fn validate(df: &::polars::prelude::DataFrame) -> ::polars_tools::Result<()> {
+                #name::validate(df)
+            }
+        }
+    }
+}
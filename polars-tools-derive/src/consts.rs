@@ -0,0 +1,240 @@
+//! Generation of the column name constants, `Struct::field` or, with
+//! `#[polars(consts_mod = "...")]`, a `struct_name::<consts_mod>` module
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Field, Ident};
+
+use crate::attrs::{ContainerAttrs, FieldAttrs};
+
+/// Items of the generated impls that are not named after a field, a field of
+/// the same name would get a colliding `Struct::field` constant
+const COLUMNS_ITEMS: &[&str] = &[
+    "agg_counts",
+    "agg_numeric_means",
+    "agg_numeric_sums",
+    "all_cols",
+    "all_cols_except",
+    "all_columns",
+    "all_columns_except",
+    "all_types",
+    "anti_validate",
+    "anti_validate_with",
+    "cast_exprs",
+    "clean",
+    "clean_exprs",
+    "clean_lazy",
+    "col_expr",
+    "column_name_at",
+    "column_names",
+    "columns",
+    "debug_validate",
+    "df",
+    "diff_rows",
+    "empty_lazy",
+    "expr",
+    "exprs",
+    "field_index",
+    "fields",
+    "fill_null_exprs",
+    "fill_nulls",
+    "fill_nulls_lazy",
+    "filter",
+    "group_by",
+    "group_by_dynamic",
+    "group_by_dynamic_with",
+    "lazy",
+    "normalize_exprs",
+    "polars_schema",
+    "profile",
+    "quality_checks",
+    "rolling_max",
+    "rolling_mean",
+    "rolling_means",
+    "rolling_min",
+    "rolling_sum",
+    "sort_by",
+    "time_index",
+    "type_at",
+    "validate",
+    "validate_cached",
+    "validate_lenient",
+    "validate_strict",
+    "validate_values",
+    "validate_values_lazy",
+    "validate_values_with",
+    "validate_with",
+];
+
+/// Further items of `#[derive(PolarsSchema)]`
+const SCHEMA_ITEMS: &[&str] = &[
+    "assert_unique_keys",
+    "cols_tagged",
+    "cols_untagged",
+    "column_docs",
+    "columns_for_version",
+    "columns_tagged",
+    "concat",
+    "concat_with",
+    "conform",
+    "conform_lenient",
+    "conform_with",
+    "describe_schema",
+    "dedupe",
+    "dedupe_keep_last",
+    "drop_tagged",
+    "expectation_suite",
+    "from_any_values",
+    "from_df",
+    "insert_sql",
+    "join_on_key",
+    "key_columns",
+    "map_rows",
+    "melt",
+    "melt_columns",
+    "melt_schema",
+    "migrate_from",
+    "migrate_to_version",
+    "parse_dates",
+    "preview",
+    "python_schema",
+    "rename_to_camel",
+    "rename_to_snake",
+    "schema_description",
+    "schema_fingerprint",
+    "schema_hash",
+    "to_any_values",
+    "to_df",
+    "typed",
+    "validate_references",
+    "validate_streaming",
+    "validate_version",
+];
+
+/// Further items of `#[derive(PolarsProjection)]`
+const PROJECTION_ITEMS: &[&str] = &["from_parent", "select_from", "select_from_lazy"];
+
+/// Items of feature-gated generated code
+fn feature_items(schema: bool) -> Vec<&'static str> {
+    let mut items = Vec::new();
+    if cfg!(feature = "fake") {
+        items.extend(["fake_df", "fake_df_with", "fake_rows", "fake_rows_with"]);
+    }
+    if cfg!(feature = "proptest") {
+        items.extend(["df_strategy", "df_strategy_with_rows"]);
+    }
+    if schema {
+        if cfg!(feature = "serde") {
+            items.extend(["describe_schema_json", "from_json_rows", "to_json_rows"]);
+        }
+        if cfg!(feature = "avro") {
+            items.extend(["avro_schema", "read_avro", "write_avro"]);
+        }
+        if cfg!(feature = "sql") {
+            items.extend(["query", "register"]);
+        }
+        if cfg!(feature = "delta") {
+            items.push("scan_delta");
+        }
+        if cfg!(feature = "flight") {
+            items.extend(["do_get", "do_put"]);
+        }
+        if cfg!(feature = "rayon") {
+            items.push("par_map_rows");
+        }
+        if cfg!(feature = "parquet") {
+            items.extend([
+                "check_parquet_fingerprint",
+                "compare_parquet",
+                "parquet_schema_metadata",
+                "read_parquet",
+                "write_parquet",
+            ]);
+        }
+    }
+    items
+}
+
+/// `FooBar` -> `foo_bar`
+pub(crate) fn snake_case(name: &str) -> String {
+    let mut snake = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
+
+/// The column name constants, as items of the struct's impl and as a module
+/// next to the struct (only one of them non-empty)
+///
+/// Without `consts_mod`, a field named like another generated item is
+/// rejected, pointing at `consts_mod` as the way out.
+pub(crate) fn column_consts(
+    name: &Ident,
+    fields: &[&Field],
+    attrs: &[FieldAttrs],
+    container: &ContainerAttrs,
+    schema: bool,
+) -> syn::Result<(TokenStream, TokenStream)> {
+    let idents: Vec<&Ident> = fields.iter().map(|f| f.ident.as_ref().unwrap()).collect();
+    let columns: Vec<&String> = attrs.iter().map(|attrs| &attrs.column).collect();
+
+    let Some(consts_mod) = &container.consts_mod else {
+        let mut reserved = COLUMNS_ITEMS.to_vec();
+        if schema {
+            reserved.extend(SCHEMA_ITEMS);
+        }
+        if container.of.is_some() {
+            reserved.extend(PROJECTION_ITEMS);
+        }
+        reserved.extend(feature_items(schema));
+        if container.skip_expr {
+            reserved.retain(|item| !["expr", "exprs"].contains(item));
+        }
+        if container.skip_df {
+            reserved.retain(|item| !["df", "empty_lazy", "lazy"].contains(item));
+        }
+        for ident in &idents {
+            if reserved.contains(&ident.to_string().as_str()) {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    format!(
+                        "field `{ident}` collides with the generated `{name}::{ident}`; \
+                         use #[polars(consts_mod = \"cols\")] to move the column name \
+                         constants into a module"
+                    ),
+                ));
+            }
+        }
+        let consts = quote! {
+            #(
+                #[allow(non_upper_case_globals)]
+                pub const #idents: &'static str = #columns;
+            )*
+        };
+        return Ok((consts, TokenStream::new()));
+    };
+
+    let outer = format_ident!("{}", snake_case(&name.to_string()));
+    let doc = format!(" Column names of `{name}`, one constant per field");
+    let module = quote! {
+        #[allow(non_snake_case)]
+        pub mod #outer {
+            #[doc = #doc]
+            pub mod #consts_mod {
+                #(
+                    #[allow(non_upper_case_globals)]
+                    pub const #idents: &'static str = #columns;
+                )*
+            }
+        }
+    };
+    Ok((TokenStream::new(), module))
+}
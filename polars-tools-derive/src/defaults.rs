@@ -0,0 +1,56 @@
+//! Default values declared with `#[polars(default = ...)]` and
+//! `#[polars(default_fn = "...")]`
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Expr, Field, Lit};
+
+use crate::attrs::FieldAttrs;
+use crate::types;
+
+/// Expression of the field's type holding its declared default, if any
+///
+/// Literals are typed as the field (or the `Option` inner) type, so
+/// `default = 0` works for every integer width and `default = "n/a"` for
+/// `String` fields. `default_fn` may return anything convertible into the
+/// field type.
+pub(crate) fn default_value(field: &Field, attrs: &FieldAttrs) -> Option<TokenStream> {
+    let ty = &field.ty;
+    if let Some(path) = &attrs.default_fn {
+        return Some(quote!(::std::convert::Into::<#ty>::into(#path())));
+    }
+
+    attrs.default.as_ref().map(|default| typed_value(field, default))
+}
+
+/// `value` typed as the field, through the `Option` inner type, so that
+/// integer and string literals work as in `default`
+pub(crate) fn typed_value(field: &Field, value: &Expr) -> TokenStream {
+    let ty = &field.ty;
+    let inner = types::option_inner(ty).unwrap_or(ty);
+    let value = match value {
+        Expr::Lit(lit) if matches!(lit.lit, Lit::Str(_)) => {
+            quote!(::std::convert::Into::<#inner>::into(#value))
+        }
+        _ => quote!({
+            let value: #inner = #value;
+            value
+        }),
+    };
+    quote!(::std::convert::Into::<#ty>::into(#value))
+}
+
+/// Body of `PolarsSchemaInfo::column_defaults`: each defaulted column with
+/// its value as a literal expression of the column dtype, which requires the
+/// field type to implement `IntoLiteral`
+pub(crate) fn column_defaults(fields: &[&Field], attrs: &[FieldAttrs]) -> TokenStream {
+    let defaults = fields.iter().zip(attrs).filter_map(|(field, attrs)| {
+        let value = default_value(field, attrs)?;
+        let ident = field.ident.as_ref().unwrap();
+        let column = &attrs.column;
+        let helper = quote::format_ident!("lit_{}", ident);
+        Some(quote!((#column, Self::#helper(#value))))
+    });
+
+    quote!(vec![#(#defaults),*])
+}
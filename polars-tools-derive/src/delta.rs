@@ -0,0 +1,33 @@
+//! Generation of the `delta` feature's table scan
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// `Struct::scan_delta(uri, scan)`
+pub(crate) fn delta_scan() -> TokenStream {
+    quote! {
+        /// Open the Delta table at `uri`, check its schema against this one
+        /// and scan its data files with `scan`, see
+        /// `polars_tools::delta::DeltaTable`
+        ///
+        /// Fails with `ValidationError::SchemaMismatch` when a column is
+        /// missing from the table or has another dtype. Extra table columns
+        /// are dropped from the returned frame.
+        pub fn scan_delta<F>(uri: &str, scan: F) -> ::polars_tools::Result<::polars::prelude::LazyFrame>
+        where
+            F: FnMut(&::std::path::Path) -> ::polars::prelude::PolarsResult<::polars::prelude::LazyFrame>,
+        {
+            let table = ::polars_tools::delta::DeltaTable::open(uri)?;
+            let schema = Self::polars_schema();
+            let diff = ::polars_tools::SchemaDiff::between(schema, table.schema());
+            if !diff.is_compatible() {
+                return Err(::polars_tools::ValidationError::SchemaMismatch(diff));
+            }
+            let columns: Vec<::polars::prelude::Expr> = schema
+                .iter_names()
+                .map(|name| ::polars::prelude::col(name.clone()))
+                .collect();
+            Ok(table.scan(scan)?.select(columns))
+        }
+    }
+}
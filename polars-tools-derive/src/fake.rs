@@ -0,0 +1,109 @@
+//! Generation of the `fake` feature's random row and frame constructors
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Field;
+
+use crate::attrs::FieldAttrs;
+use crate::defaults::default_value;
+
+/// `Struct::fake_rows(n)` / `Struct::fake_df(n)` and their `_with(rng, n)`
+/// variants
+///
+/// Fields with a `default` always take that value; every other field type
+/// must implement `Fake`, otherwise the constructors cannot be called.
+pub(crate) fn fake_constructors(
+    fields: &[&Field],
+    attrs: &[FieldAttrs],
+    dtypes: &[TokenStream],
+) -> TokenStream {
+    let fake_types: Vec<_> = fields
+        .iter()
+        .zip(attrs)
+        .filter(|(field, attrs)| default_value(field, attrs).is_none())
+        .map(|(field, _)| &field.ty)
+        .collect();
+    let idents: Vec<_> = fields.iter().map(|f| f.ident.as_ref().unwrap()).collect();
+    let names: Vec<_> = attrs.iter().map(|attrs| &attrs.column).collect();
+    let values = fields.iter().zip(attrs).map(|(field, attrs)| {
+        if let Some(default) = default_value(field, attrs) {
+            return default;
+        }
+        let ty = &field.ty;
+        let bound = |bound: &Option<syn::Expr>| match bound {
+            Some(bound) => quote!(Some((#bound) as f64)),
+            None => quote!(None),
+        };
+        let (min, max) = (bound(&attrs.min), bound(&attrs.max));
+        quote! {
+            <#ty as ::polars_tools::fake::Fake>::fake(
+                rng,
+                &::polars_tools::fake::FakeOptions {
+                    min: #min,
+                    max: #max,
+                    ..::std::default::Default::default()
+                },
+            )
+        }
+    });
+
+    quote! {
+        /// Random rows respecting the field types, enum values, `Option`
+        /// nullability and `min`/`max` attributes; fields with a `default`
+        /// take that value
+        pub fn fake_rows_with<R: ::polars_tools::fake::rand::Rng + ?Sized>(
+            rng: &mut R,
+            n: usize,
+        ) -> Vec<Self>
+        where
+            #(for<'__polars> #fake_types: ::polars_tools::fake::Fake,)*
+        {
+            (0..n)
+                .map(|_| Self {
+                    #(#idents: #values,)*
+                })
+                .collect()
+        }
+
+        /// `fake_rows_with` using the thread-local random generator
+        pub fn fake_rows(n: usize) -> Vec<Self>
+        where
+            #(for<'__polars> #fake_types: ::polars_tools::fake::Fake,)*
+        {
+            Self::fake_rows_with(&mut ::polars_tools::fake::rand::thread_rng(), n)
+        }
+
+        /// DataFrame of `n` random rows matching the schema
+        pub fn fake_df_with<R: ::polars_tools::fake::rand::Rng + ?Sized>(
+            rng: &mut R,
+            n: usize,
+        ) -> std::result::Result<::polars::prelude::DataFrame, ::polars::prelude::PolarsError>
+        where
+            #(for<'__polars> #fake_types: ::polars_tools::fake::Fake,)*
+        {
+            let rows = Self::fake_rows_with(rng, n);
+            ::polars::prelude::DataFrame::new(vec![
+                #(
+                    ::polars_tools::__private::column_from_values(
+                        #names,
+                        &rows
+                            .iter()
+                            .map(|row| ::polars_tools::ToAnyValue::to_any_value(&row.#idents))
+                            .collect::<Vec<_>>(),
+                        &(#dtypes),
+                    )?
+                ),*
+            ])
+        }
+
+        /// `fake_df_with` using the thread-local random generator
+        pub fn fake_df(
+            n: usize,
+        ) -> std::result::Result<::polars::prelude::DataFrame, ::polars::prelude::PolarsError>
+        where
+            #(for<'__polars> #fake_types: ::polars_tools::fake::Fake,)*
+        {
+            Self::fake_df_with(&mut ::polars_tools::fake::rand::thread_rng(), n)
+        }
+    }
+}
@@ -0,0 +1,53 @@
+//! Generation of `fill_nulls` from `#[polars(fill_null = ...)]`
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::Field;
+
+use crate::attrs::{FieldAttrs, FillNull};
+use crate::defaults;
+
+/// `Struct::fill_null_exprs()` and the eager and lazy `fill_nulls` applying
+/// them in a single `with_columns`
+///
+/// Values go through the `lit_<field>` helper, so they are cast to the
+/// column dtype like `default` values.
+pub(crate) fn fill_nulls(fields: &[&Field], attrs: &[FieldAttrs]) -> TokenStream {
+    let exprs = fields.iter().zip(attrs).filter_map(|(field, attrs)| {
+        let ident = field.ident.as_ref().unwrap();
+        let column = &attrs.column;
+        let fill = match attrs.fill_null.as_ref()? {
+            FillNull::Strategy(strategy) => quote!(fill_null_with_strategy(#strategy)),
+            FillNull::Value(value) => {
+                let value = defaults::typed_value(field, value);
+                let helper = format_ident!("lit_{}", ident);
+                quote!(fill_null(Self::#helper(#value)))
+            }
+        };
+        Some(quote!(::polars_tools::__private::column_expr(#column).#fill))
+    });
+
+    quote! {
+        /// Expressions filling the nulls of the `#[polars(fill_null = ...)]`
+        /// columns, for `with_columns`
+        pub fn fill_null_exprs() -> Vec<::polars::prelude::Expr> {
+            vec![#(#exprs),*]
+        }
+
+        /// `lf` with the nulls of the `fill_null` columns filled
+        pub fn fill_nulls_lazy(lf: ::polars::prelude::LazyFrame) -> ::polars::prelude::LazyFrame {
+            let exprs = Self::fill_null_exprs();
+            if exprs.is_empty() {
+                return lf;
+            }
+            lf.with_columns(exprs)
+        }
+
+        /// `df` with the nulls of the `fill_null` columns filled, in one pass
+        pub fn fill_nulls(
+            df: &::polars::prelude::DataFrame,
+        ) -> ::polars_tools::Result<::polars::prelude::DataFrame> {
+            Ok(Self::fill_nulls_lazy(::polars::prelude::IntoLazy::lazy(df.clone())).collect()?)
+        }
+    }
+}
@@ -0,0 +1,74 @@
+//! Generation of the fluent `FilterFor{Struct}` predicate builder
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use syn::{Field, Ident};
+
+use crate::attrs::FieldAttrs;
+use crate::types::static_lifetimes;
+
+/// `FilterFor{Struct}` type with one comparison entry point per field, plus the
+/// `Struct::filter()` constructor to include in the struct's impl block
+pub(crate) fn filter_builder(
+    name: &Ident,
+    fields: &[&Field],
+    attrs: &[FieldAttrs],
+    dtypes: &[TokenStream],
+) -> (TokenStream, TokenStream) {
+    let builder = format_ident!("FilterFor{}", name);
+
+    // The builder has no lifetime parameters to name borrowed field types with
+    let field_methods = fields.iter().zip(attrs).zip(dtypes).map(|((field, attrs), dtype)| {
+        let ty = static_lifetimes(field.ty.to_token_stream());
+        let dtype = static_lifetimes(dtype.clone());
+        let ident = field.ident.as_ref().unwrap();
+        let column = &attrs.column;
+        let doc = format!("Add a condition on the `{}` column", column);
+
+        quote! {
+            #[doc = #doc]
+            pub fn #ident(self) -> ::polars_tools::FieldFilter<Self, #ty> {
+                ::polars_tools::FieldFilter::new(self, #column, #dtype)
+            }
+        }
+    });
+
+    let definition = quote! {
+        #[derive(Clone, Default)]
+        pub struct #builder(::polars_tools::FilterBuilder);
+
+        impl #builder {
+            #(#field_methods)*
+
+            /// Join the next condition with OR instead of AND
+            pub fn or(self) -> Self {
+                Self(self.0.or())
+            }
+
+            /// Join the next condition with AND (the default)
+            pub fn and(self) -> Self {
+                Self(self.0.and())
+            }
+
+            /// Combine the conditions into a single predicate
+            pub fn build(self) -> ::polars::prelude::Expr {
+                self.0.build()
+            }
+        }
+
+        impl ::polars_tools::FilterChain for #builder {
+            fn push(self, condition: ::polars::prelude::Expr) -> Self {
+                Self(self.0.push(condition))
+            }
+        }
+    };
+
+    let constructor = quote! {
+        /// Start a typed filter predicate over this struct's columns
+        pub fn filter() -> #builder {
+            #builder::default()
+        }
+    };
+
+    (definition, constructor)
+}
@@ -0,0 +1,30 @@
+//! Generation of the `flight` feature's Flight client helpers
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// `Struct::do_get(client, ticket)` and `Struct::do_put(client, &df)`
+pub(crate) fn flight_io() -> TokenStream {
+    quote! {
+        /// `DoGet` of `ticket` through `client`, validating the frame
+        pub fn do_get<C: ::polars_tools::flight::FlightClient>(
+            client: &mut C,
+            ticket: &[u8],
+        ) -> ::polars_tools::Result<::polars::prelude::DataFrame> {
+            let data = client.do_get(ticket)?;
+            let df = ::polars_tools::flight::decode_flight(&data)?;
+            Self::validate(&df)?;
+            Ok(df)
+        }
+
+        /// Validate `df` and `DoPut` its schema columns through `client`
+        pub fn do_put<C: ::polars_tools::flight::FlightClient>(
+            client: &mut C,
+            df: &::polars::prelude::DataFrame,
+        ) -> ::polars_tools::Result<()> {
+            Self::validate(df)?;
+            let data = ::polars_tools::flight::encode_flight(df, Self::polars_schema())?;
+            client.do_put(data)
+        }
+    }
+}
@@ -0,0 +1,102 @@
+//! Generation of the accessors of `#[polars(group = "...")]` column groups
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::attrs::FieldAttrs;
+
+/// `Struct::<group>_columns()`, `Struct::<group>_cols()` and
+/// `Struct::<group>_cast_exprs()` for every group, listing its columns in
+/// field order
+pub(crate) fn column_groups(attrs: &[FieldAttrs], dtypes: &[TokenStream]) -> TokenStream {
+    let mut groups: Vec<(String, Vec<(&String, &TokenStream)>)> = Vec::new();
+    for (attrs, dtype) in attrs.iter().zip(dtypes) {
+        let column = &attrs.column;
+        for group in &attrs.groups {
+            let group = group.value();
+            match groups.iter_mut().find(|(name, _)| *name == group) {
+                Some((_, columns)) if columns.iter().any(|(name, _)| *name == column) => {}
+                Some((_, columns)) => columns.push((column, dtype)),
+                None => groups.push((group, vec![(column, dtype)])),
+            }
+        }
+    }
+
+    let accessors = groups.iter().map(|(group, columns)| {
+        let (columns, dtypes): (Vec<_>, Vec<_>) = columns.iter().copied().unzip();
+        let columns_fn = format_ident!("{}_columns", group);
+        let cols_fn = format_ident!("{}_cols", group);
+        let cast_fn = format_ident!("{}_cast_exprs", group);
+        let columns_doc = format!("Names of the `{}` group columns, in field order", group);
+        let cols_doc = format!(
+            "Expressions of the `{}` group columns, in field order",
+            group
+        );
+        let cast_doc = format!(
+            "Expressions casting the `{}` group columns to their schema dtypes",
+            group
+        );
+        quote! {
+            #[doc = #columns_doc]
+            pub fn #columns_fn() -> Vec<&'static str> {
+                vec![#(#columns),*]
+            }
+
+            #[doc = #cols_doc]
+            pub fn #cols_fn() -> Vec<::polars::prelude::Expr> {
+                vec![#(::polars_tools::__private::column_expr(#columns)),*]
+            }
+
+            #[doc = #cast_doc]
+            pub fn #cast_fn() -> Vec<::polars::prelude::Expr> {
+                vec![#(::polars_tools::__private::column_expr(#columns).cast(#dtypes)),*]
+            }
+        }
+    });
+    quote!(#(#accessors)*)
+}
+
+/// `Struct::melt(&df, id_group, value_group)` unpivoting the columns of one
+/// group with those of another as the index, plus `melt_columns` taking the
+/// columns themselves and `melt_schema` giving the long-format schema
+pub(crate) fn melt(attrs: &[FieldAttrs]) -> TokenStream {
+    let pairs = attrs.iter().flat_map(|attrs| {
+        let column = &attrs.column;
+        attrs.groups.iter().map(move |group| quote!((#group, #column)))
+    });
+    quote! {
+        const COLUMN_GROUPS: &'static [(&'static str, &'static str)] = &[#(#pairs),*];
+
+        /// Long-format schema of `melt(df, id_group, value_group)`: the
+        /// `id_group` columns, `variable` and `value`
+        pub fn melt_schema(
+            id_group: &str,
+            value_group: &str,
+        ) -> ::polars_tools::Result<::polars::prelude::Schema> {
+            let ids = ::polars_tools::__private::group_columns(Self::COLUMN_GROUPS, id_group)?;
+            let values = ::polars_tools::__private::group_columns(Self::COLUMN_GROUPS, value_group)?;
+            ::polars_tools::melted_schema(Self::polars_schema(), &ids, &values)
+        }
+
+        /// `df` unpivoted to one row per `value_group` column and row, keyed
+        /// by the `id_group` columns, and checked against `melt_schema`
+        pub fn melt(
+            df: &::polars::prelude::DataFrame,
+            id_group: &str,
+            value_group: &str,
+        ) -> ::polars_tools::Result<::polars::prelude::DataFrame> {
+            let ids = ::polars_tools::__private::group_columns(Self::COLUMN_GROUPS, id_group)?;
+            let values = ::polars_tools::__private::group_columns(Self::COLUMN_GROUPS, value_group)?;
+            Self::melt_columns(df, &ids, &values)
+        }
+
+        /// `melt` by columns instead of groups, e.g. by `columns_tagged(...)`
+        pub fn melt_columns(
+            df: &::polars::prelude::DataFrame,
+            ids: &[&str],
+            values: &[&str],
+        ) -> ::polars_tools::Result<::polars::prelude::DataFrame> {
+            ::polars_tools::__private::melt_columns(df, Self::polars_schema(), ids, values)
+        }
+    }
+}
@@ -0,0 +1,55 @@
+//! Generation of the `serde` feature's JSON row conversions
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::attrs::FieldAttrs;
+
+/// `Struct::from_json_rows(json)` / `Struct::to_json_rows(&df)`, passing the
+/// `#[polars(format = "...")]` of every temporal column, and
+/// `Struct::describe_schema_json()`
+pub(crate) fn json_rows(attrs: &[FieldAttrs]) -> TokenStream {
+    let formats = attrs.iter().filter_map(|attrs| {
+        let column = &attrs.column;
+        attrs
+            .format
+            .as_ref()
+            .map(|format| quote!((#column, #format)))
+    });
+    quote! {
+        const JSON_FORMATS: &'static [(&'static str, &'static str)] = &[#(#formats),*];
+
+        /// Load a JSON array of row objects into a frame of this schema and
+        /// validate it
+        pub fn from_json_rows(json: &str) -> ::polars_tools::Result<::polars::prelude::DataFrame> {
+            let df = ::polars_tools::__private::from_json_rows(
+                json,
+                Self::polars_schema(),
+                Self::JSON_FORMATS,
+            )?;
+            Self::validate(&df)?;
+            Ok(df)
+        }
+
+        /// Validate `df` and write its schema columns as a JSON array of row
+        /// objects
+        pub fn to_json_rows(df: &::polars::prelude::DataFrame) -> ::polars_tools::Result<String> {
+            Self::validate(df)?;
+            ::polars_tools::__private::to_json_rows(df, Self::polars_schema(), Self::JSON_FORMATS)
+        }
+
+        /// `describe_schema` as pretty-printed JSON
+        pub fn describe_schema_json() -> ::polars_tools::Result<String> {
+            Self::schema_description().to_json()
+        }
+    }
+}
+
+/// `PolarsSchemaInfo::json_formats`, for code generic over schemas
+pub(crate) fn json_formats() -> TokenStream {
+    quote! {
+        fn json_formats() -> &'static [(&'static str, &'static str)] {
+            Self::JSON_FORMATS
+        }
+    }
+}
@@ -0,0 +1,69 @@
+//! Generation of the primary-key helpers of `#[polars(key)]` fields
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::attrs::FieldAttrs;
+
+/// `Struct::key_columns()`, `assert_unique_keys`, `dedupe`,
+/// `dedupe_keep_last` and `join_on_key` over the key columns in field order,
+/// nothing without a `key` field
+pub(crate) fn key_helpers(attrs: &[FieldAttrs]) -> TokenStream {
+    let keys: Vec<_> = attrs
+        .iter()
+        .filter(|attrs| attrs.key)
+        .map(|attrs| &attrs.column)
+        .collect();
+    if keys.is_empty() {
+        return TokenStream::new();
+    }
+
+    quote! {
+        /// Columns of the `#[polars(key)]` fields, in field order
+        pub fn key_columns() -> Vec<&'static str> {
+            vec![#(#keys),*]
+        }
+
+        /// Check that no two rows of `df` share a key, reporting the number
+        /// of repeated keys and a sample of them
+        pub fn assert_unique_keys(
+            df: &::polars::prelude::DataFrame,
+        ) -> ::polars_tools::Result<()> {
+            ::polars_tools::__private::assert_unique_keys(df, &[#(#keys),*])
+        }
+
+        /// `df` with one row per key, keeping the first of each in row order
+        pub fn dedupe(
+            df: &::polars::prelude::DataFrame,
+        ) -> ::polars_tools::Result<::polars::prelude::DataFrame> {
+            ::polars_tools::__private::dedupe(df, &[#(#keys),*])
+        }
+
+        /// `df` with the latest record of every key: the last one when sorted
+        /// by the `order_by` columns, e.g. `&[Self::updated_at]`
+        pub fn dedupe_keep_last(
+            df: &::polars::prelude::DataFrame,
+            order_by: &[&str],
+        ) -> ::polars_tools::Result<::polars::prelude::DataFrame> {
+            ::polars_tools::__private::dedupe_keep_last(df, &[#(#keys),*], order_by)
+        }
+
+        /// `df`, valid under this schema, joined with `other`, valid under
+        /// the `Other` schema, on the key columns, which `Other` must declare
+        /// with the same dtypes
+        pub fn join_on_key<Other: ::polars_tools::PolarsSchemaInfo>(
+            df: &::polars::prelude::DataFrame,
+            other: &::polars::prelude::DataFrame,
+            how: ::polars::prelude::JoinType,
+        ) -> ::polars_tools::Result<::polars::prelude::DataFrame> {
+            ::polars_tools::__private::join_on_key(
+                df,
+                Self::polars_schema(),
+                other,
+                Other::polars_schema(),
+                &[#(#keys),*],
+                how,
+            )
+        }
+    }
+}
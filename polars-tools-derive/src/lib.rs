@@ -3,47 +3,336 @@
 //! This crate provides the `PolarsColumns` derive macro for generating column access helpers
 //! for Polars DataFrames.
 
+mod accessors;
+mod attrs;
+mod clean;
+mod combine;
+mod consts;
+#[cfg(feature = "avro")]
+mod avro;
+mod defaults;
+#[cfg(feature = "delta")]
+mod delta;
+#[cfg(feature = "fake")]
+mod fake;
+mod fill;
+mod filter;
+mod groups;
+mod keys;
+mod nested;
+mod parse;
+mod paths;
+mod projection;
+#[cfg(feature = "flight")]
+mod flight;
+#[cfg(feature = "serde")]
+mod json;
+#[cfg(feature = "parquet")]
+mod parquet;
+mod quality;
+mod references;
+mod rows;
+mod select;
+#[cfg(feature = "sql")]
+mod sql;
+#[cfg(feature = "proptest")]
+mod strategy;
+mod tables;
+mod types;
+mod validate;
+mod window;
+
+use attrs::{ContainerAttrs, FieldAttrs};
 use proc_macro::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote, quote_spanned};
+use syn::spanned::Spanned;
 use syn::{parse_macro_input, Data, DeriveInput, Fields};
 
-/// Helper function to determine if a type is likely an enum (not a known primitive)
-fn is_likely_enum_type(type_str: &str) -> bool {
-    // Known primitive types that should NOT be treated as enums
-    let primitives = [
-        // Integers
-        "i8", "i16", "i32", "i64", "i128", "isize",
-        "u8", "u16", "u32", "u64", "u128", "usize",
-        // Floats  
-        "f32", "f64",
-        // Other primitives
-        "bool", "String", "str", "&str",
-        // Option wrapped primitives
-        "Option < i8 >", "Option < i16 >", "Option < i32 >", "Option < i64 >",
-        "Option < u8 >", "Option < u16 >", "Option < u32 >", "Option < u64 >",
-        "Option < f32 >", "Option < f64 >", "Option < bool >", "Option < String >",
-        // Chrono types (both with and without chrono:: prefix)
-        "chrono :: NaiveDate", "chrono :: NaiveDateTime", "chrono :: NaiveTime",
-        "chrono :: DateTime < chrono :: Utc >",
-        "NaiveDate", "NaiveDateTime", "NaiveTime", "DateTime < Utc >",
-    ];
-    
-    // Check if it's a known primitive
-    if primitives.contains(&type_str) {
-        return false;
+/// Expression resolving a field type to its Polars dtype through `PolarsDType`.
+///
+/// Types without a `PolarsDType` impl fall back to `DataType::String`, which is
+/// how enum fields end up stored as strings.
+fn polars_dtype(ty: &syn::Type) -> proc_macro2::TokenStream {
+    quote! {
+        {
+            #[allow(unused_imports)]
+            use ::polars_tools::__private::{ViaFallback as _, ViaPolarsDType as _};
+            (&::polars_tools::__private::DTypeProbe::<#ty>::new()).probe_dtype()
+        }
+    }
+}
+
+/// Expression resolving a field type through its `PolarsDType` impl, failing to
+/// compile (pointing at the type) when there is none
+fn strict_polars_dtype(ty: &syn::Type) -> proc_macro2::TokenStream {
+    quote_spanned! {ty.span()=>
+        <#ty as ::polars_tools::PolarsDType>::dtype()
+    }
+}
+
+/// Polars dtype expression for a field, honouring dtype attributes before
+/// falling back to the field type's `PolarsDType` mapping.
+fn field_dtype(
+    field: &syn::Field,
+    attrs: &FieldAttrs,
+    container: &ContainerAttrs,
+) -> proc_macro2::TokenStream {
+    if let Some(dtype) = &attrs.dtype {
+        return quote!(#dtype);
+    }
+
+    if attrs.as_string {
+        return quote!(::polars::prelude::DataType::String);
+    }
+
+    // `Vec<u8>` would otherwise resolve to `List(UInt8)` through the blanket `Vec<T>` impl
+    if attrs.binary || types::is_byte_buffer(&field.ty) {
+        return quote!(::polars::prelude::DataType::Binary);
+    }
+
+    // Read through their `seconds` / `nanos` fields, see `rows.rs`
+    match types::proto_well_known(&field.ty) {
+        Some(types::WellKnown::Timestamp) => {
+            return quote! {
+                ::polars::prelude::DataType::Datetime(::polars::prelude::TimeUnit::Nanoseconds, None)
+            }
+        }
+        Some(types::WellKnown::Duration) => {
+            return quote! {
+                ::polars::prelude::DataType::Duration(::polars::prelude::TimeUnit::Nanoseconds)
+            }
+        }
+        None => {}
+    }
+
+    if attrs.precision.is_some() || attrs.scale.is_some() {
+        let precision = match attrs.precision {
+            Some(precision) => quote!(#precision),
+            None => quote!(::polars_tools::DEFAULT_DECIMAL_PRECISION),
+        };
+        let scale = match attrs.scale {
+            Some(scale) => quote!(#scale),
+            None => quote!(::polars_tools::DEFAULT_DECIMAL_SCALE),
+        };
+        return quote!(::polars::prelude::DataType::Decimal(Some(#precision), Some(#scale)));
+    }
+
+    let ty = &field.ty;
+    let resolve = if container.strict_types || attrs.not_enum {
+        strict_polars_dtype
+    } else {
+        polars_dtype
+    };
+
+    if let Some(keys) = &attrs.struct_keys {
+        let map = types::option_inner(ty).unwrap_or(ty);
+        let Some(value) = types::map_value(map) else {
+            return syn::Error::new_spanned(ty, "struct_keys requires a HashMap or BTreeMap field")
+                .to_compile_error();
+        };
+        let value_dtype = resolve(value);
+        return quote!(::polars_tools::map_struct_dtype(&[#(#keys),*], #value_dtype));
+    }
+    let mut dtype = if attrs.transparent {
+        let wrapper = types::option_inner(ty).unwrap_or(ty);
+        resolve(&syn::parse_quote!(<#wrapper as ::std::ops::Deref>::Target))
+    } else {
+        resolve(ty)
+    };
+    if let Some(unit) = &attrs.time_unit {
+        dtype = quote!(::polars_tools::__private::with_time_unit(#dtype, #unit));
+    }
+    if let Some(tz) = &attrs.tz {
+        dtype = quote! {
+            ::polars_tools::__private::with_time_zone(
+                #dtype,
+                ::polars::prelude::PlSmallStr::from_static(#tz),
+            )
+        };
+    }
+    dtype
+}
+
+/// Random data constructors, only emitted with the `fake` feature
+#[cfg(feature = "fake")]
+fn fake_constructors(
+    fields: &[&syn::Field],
+    attrs: &[FieldAttrs],
+    dtypes: &[proc_macro2::TokenStream],
+) -> proc_macro2::TokenStream {
+    fake::fake_constructors(fields, attrs, dtypes)
+}
+
+#[cfg(not(feature = "fake"))]
+fn fake_constructors(
+    _fields: &[&syn::Field],
+    _attrs: &[FieldAttrs],
+    _dtypes: &[proc_macro2::TokenStream],
+) -> proc_macro2::TokenStream {
+    proc_macro2::TokenStream::new()
+}
+
+/// Proptest frame strategies, only emitted with the `proptest` feature
+#[cfg(feature = "proptest")]
+fn df_strategies(
+    fields: &[&syn::Field],
+    attrs: &[FieldAttrs],
+    dtypes: &[proc_macro2::TokenStream],
+) -> proc_macro2::TokenStream {
+    strategy::df_strategies(fields, attrs, dtypes)
+}
+
+#[cfg(not(feature = "proptest"))]
+fn df_strategies(
+    _fields: &[&syn::Field],
+    _attrs: &[FieldAttrs],
+    _dtypes: &[proc_macro2::TokenStream],
+) -> proc_macro2::TokenStream {
+    proc_macro2::TokenStream::new()
+}
+
+/// JSON row conversions, only emitted with the `serde` feature
+#[cfg(feature = "serde")]
+fn json_rows(attrs: &[FieldAttrs]) -> proc_macro2::TokenStream {
+    json::json_rows(attrs)
+}
+
+#[cfg(not(feature = "serde"))]
+fn json_rows(_attrs: &[FieldAttrs]) -> proc_macro2::TokenStream {
+    proc_macro2::TokenStream::new()
+}
+
+#[cfg(feature = "serde")]
+fn json_formats() -> proc_macro2::TokenStream {
+    json::json_formats()
+}
+
+#[cfg(not(feature = "serde"))]
+fn json_formats() -> proc_macro2::TokenStream {
+    proc_macro2::TokenStream::new()
+}
+
+/// Avro schema and IO, only emitted with the `avro` feature
+#[cfg(feature = "avro")]
+fn avro_io(name: &syn::Ident, fields: &[&syn::Field]) -> proc_macro2::TokenStream {
+    avro::avro_io(name, fields)
+}
+
+#[cfg(not(feature = "avro"))]
+fn avro_io(_name: &syn::Ident, _fields: &[&syn::Field]) -> proc_macro2::TokenStream {
+    proc_macro2::TokenStream::new()
+}
+
+/// `SQLContext` helpers, only emitted with the `sql` feature
+#[cfg(feature = "sql")]
+fn sql_context(name: &syn::Ident, container: &ContainerAttrs) -> proc_macro2::TokenStream {
+    sql::sql_context(name, container)
+}
+
+#[cfg(not(feature = "sql"))]
+fn sql_context(_name: &syn::Ident, _container: &ContainerAttrs) -> proc_macro2::TokenStream {
+    proc_macro2::TokenStream::new()
+}
+
+/// Delta table scan, only emitted with the `delta` feature
+#[cfg(feature = "delta")]
+fn delta_scan() -> proc_macro2::TokenStream {
+    delta::delta_scan()
+}
+
+#[cfg(not(feature = "delta"))]
+fn delta_scan() -> proc_macro2::TokenStream {
+    proc_macro2::TokenStream::new()
+}
+
+/// Arrow Flight helpers, only emitted with the `flight` feature
+#[cfg(feature = "flight")]
+fn flight_io() -> proc_macro2::TokenStream {
+    flight::flight_io()
+}
+
+#[cfg(not(feature = "flight"))]
+fn flight_io() -> proc_macro2::TokenStream {
+    proc_macro2::TokenStream::new()
+}
+
+/// Self-describing Parquet IO, only emitted with the `parquet` feature
+#[cfg(feature = "parquet")]
+fn parquet_io(name: &syn::Ident) -> proc_macro2::TokenStream {
+    parquet::parquet_io(name)
+}
+
+#[cfg(not(feature = "parquet"))]
+fn parquet_io(_name: &syn::Ident) -> proc_macro2::TokenStream {
+    proc_macro2::TokenStream::new()
+}
+
+/// Parse the `#[polars(...)]` attributes of every field, rejecting field types
+/// without a column representation unless an explicit dtype is given
+fn parse_field_attrs<'a>(
+    fields: impl IntoIterator<Item = &'a syn::Field>,
+    container: &ContainerAttrs,
+) -> syn::Result<Vec<FieldAttrs>> {
+    let fields: Vec<_> = fields.into_iter().collect();
+    let attrs = fields
+        .iter()
+        .map(|field| {
+            let attrs = FieldAttrs::from_field(field, container)?;
+            if attrs.dtype.is_none() {
+                types::check_field_type(field)?;
+            }
+            Ok(attrs)
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    check_duplicate_columns(&fields, &attrs)?;
+    check_time_index(&fields, &attrs)?;
+    Ok(attrs)
+}
+
+/// Reject `#[polars(time_index)]` on more than one field
+fn check_time_index(fields: &[&syn::Field], attrs: &[FieldAttrs]) -> syn::Result<()> {
+    let mut time_indexes = fields
+        .iter()
+        .zip(attrs)
+        .filter(|(_, attrs)| attrs.time_index)
+        .map(|(field, _)| field.ident.as_ref().unwrap());
+    if let (Some(first), Some(second)) = (time_indexes.next(), time_indexes.next()) {
+        return Err(syn::Error::new(
+            second.span(),
+            format!("field `{first}` is already the time_index"),
+        ));
     }
-    
-    // Check if it's an Option<SomeCustomType> - extract inner type
-    if type_str.contains("Option") && type_str.contains("<") && type_str.contains(">") {
-        let start = type_str.find('<').unwrap_or(0) + 1;
-        let end = type_str.rfind('>').unwrap_or(type_str.len());
-        let inner = type_str[start..end].trim();
-        // If inner type is not primitive, then it's likely an enum
-        return !primitives.iter().any(|p| p == &inner);
+    Ok(())
+}
+
+/// Reject two fields ending up with the same column name, or with a name the
+/// one's `alias` gives to the other, naming both fields
+fn check_duplicate_columns(fields: &[&syn::Field], attrs: &[FieldAttrs]) -> syn::Result<()> {
+    let mut names: Vec<(String, &syn::Ident)> = Vec::new();
+    for (field, attrs) in fields.iter().zip(attrs) {
+        let ident = field.ident.as_ref().unwrap();
+        let own = std::iter::once((attrs.column.clone(), ident.span(), "column"));
+        let aliases = attrs
+            .aliases
+            .iter()
+            .map(|alias| (alias.value(), alias.span(), "alias"));
+        for (name, span, kind) in own.chain(aliases) {
+            match names.iter().find(|(taken, _)| *taken == name) {
+                Some((_, first)) if *first != ident => {
+                    return Err(syn::Error::new(
+                        span,
+                        format!(
+                            "{kind} `{name}` of field `{ident}` is already a name of \
+                             field `{first}`"
+                        ),
+                    ))
+                }
+                Some(_) => {}
+                None => names.push((name, ident)),
+            }
+        }
     }
-    
-    // If it's not a primitive and not an option of a primitive, likely enum
-    true
+    Ok(())
 }
 
 /// Derive macro for generating Polars column access helpers.
@@ -51,10 +340,34 @@ fn is_likely_enum_type(type_str: &str) -> bool {
 /// This macro generates:
 /// - `StructName::field_name` constants for column names
 /// - `StructName::expr.field_name()` methods for column expressions
+/// - `StructName::validate(&df)` and `validate_strict(&df)` schema checks
 /// - Implementations of `PolarsColumns` and `PolarsColumnsExt` traits
-#[proc_macro_derive(PolarsColumns)]
+#[proc_macro_derive(PolarsColumns, attributes(polars))]
 pub fn polars_columns_derive(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
+    let container_attrs = match ContainerAttrs::from_input(&input) {
+        Ok(attrs) => attrs,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    if container_attrs.register.is_some() {
+        return syn::Error::new_spanned(&input.ident, "register requires #[derive(PolarsSchema)]")
+            .to_compile_error()
+            .into();
+    }
+    if container_attrs.table.is_some() {
+        return syn::Error::new_spanned(&input.ident, "table requires #[derive(PolarsSchema)]")
+            .to_compile_error()
+            .into();
+    }
+    if container_attrs.of.is_some() {
+        return syn::Error::new_spanned(&input.ident, "of requires #[derive(PolarsProjection)]")
+            .to_compile_error()
+            .into();
+    }
+    if let Err(err) = types::check_generics(&input.generics, "PolarsColumns") {
+        return err.to_compile_error().into();
+    }
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
     let name = input.ident;
 
     let fields = match input.data {
@@ -66,246 +379,142 @@ pub fn polars_columns_derive(input: TokenStream) -> TokenStream {
     };
 
     let field_names: Vec<_> = fields.iter().map(|f| &f.ident).collect();
-    let field_name_strs: Vec<_> = field_names
+    let field_count = field_names.len();
+    let field_indices: Vec<_> = (0..field_count).collect();
+
+    let field_attrs = match parse_field_attrs(&fields, &container_attrs) {
+        Ok(attrs) => attrs,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let field_name_strs: Vec<_> = field_attrs.iter().map(|attrs| &attrs.column).collect();
+
+    // Resolve the polars data type of every field
+    let polars_types: Vec<_> = fields
         .iter()
-        .map(|f| f.as_ref().unwrap().to_string())
-        .collect();
-    let _field_names_count = field_names.len();
-
-    // Collect enum field information for validation generation
-    let _enum_fields: Vec<_> = fields.iter()
-        .filter_map(|f| {
-            let field_type = &f.ty;
-            let type_str = quote!(#field_type).to_string();
-            let field_name = f.ident.as_ref().unwrap();
-            
-            if is_likely_enum_type(&type_str) {
-                Some((field_name.clone(), field_type.clone()))
-            } else {
-                None
-            }
-        })
+        .zip(&field_attrs)
+        .map(|(f, attrs)| field_dtype(f, attrs, &container_attrs))
         .collect();
 
-    // Generate polars data types for empty DataFrame creation
-    let polars_types: Vec<_> = fields.iter().map(|f| {
-        let field_type = &f.ty;
-        let type_str = quote!(#field_type).to_string();
-        
-        // If it's likely an enum, map it to String
-        if is_likely_enum_type(&type_str) {
-            return quote!(polars::prelude::DataType::String);
-        }
-        
-        match type_str.as_str() {
-            // Handle Option<T> types - exact match for all supported types
-            "Option < i8 >" => quote!(polars::prelude::DataType::Int8),
-            "Option < i16 >" => quote!(polars::prelude::DataType::Int16),
-            "Option < i32 >" => quote!(polars::prelude::DataType::Int32),
-            "Option < i64 >" => quote!(polars::prelude::DataType::Int64),
-            "Option < u8 >" => quote!(polars::prelude::DataType::UInt8),
-            "Option < u16 >" => quote!(polars::prelude::DataType::UInt16),
-            "Option < u32 >" => quote!(polars::prelude::DataType::UInt32),
-            "Option < u64 >" => quote!(polars::prelude::DataType::UInt64),
-            "Option < f32 >" => quote!(polars::prelude::DataType::Float32),
-            "Option < f64 >" => quote!(polars::prelude::DataType::Float64),
-            "Option < bool >" => quote!(polars::prelude::DataType::Boolean),
-            "Option < String >" => quote!(polars::prelude::DataType::String),
-            // Signed integers
-            "i8" => quote!(polars::prelude::DataType::Int8),
-            "i16" => quote!(polars::prelude::DataType::Int16),
-            "i32" => quote!(polars::prelude::DataType::Int32),
-            "i64" => quote!(polars::prelude::DataType::Int64),
-            // Unsigned integers
-            "u8" => quote!(polars::prelude::DataType::UInt8),
-            "u16" => quote!(polars::prelude::DataType::UInt16),
-            "u32" => quote!(polars::prelude::DataType::UInt32),
-            "u64" => quote!(polars::prelude::DataType::UInt64),
-            // Floats
-            "f32" => quote!(polars::prelude::DataType::Float32),
-            "f64" => quote!(polars::prelude::DataType::Float64),
-            // Boolean and String
-            "bool" => quote!(polars::prelude::DataType::Boolean),
-            "String" => quote!(polars::prelude::DataType::String),
-            // Handle Option<T> types - fallback pattern
-            s if s.contains("Option") && s.contains("<") && s.contains(">") => {
-                // Extract everything between < and >
-                let start = s.find('<').unwrap_or(0) + 1;
-                let end = s.rfind('>').unwrap_or(s.len());
-                let inner = s[start..end].trim();
-                match inner {
-                    "i8" => quote!(polars::prelude::DataType::Int8),
-                    "i16" => quote!(polars::prelude::DataType::Int16),
-                    "i32" => quote!(polars::prelude::DataType::Int32),
-                    "i64" => quote!(polars::prelude::DataType::Int64),
-                    "u8" => quote!(polars::prelude::DataType::UInt8),
-                    "u16" => quote!(polars::prelude::DataType::UInt16),
-                    "u32" => quote!(polars::prelude::DataType::UInt32),
-                    "u64" => quote!(polars::prelude::DataType::UInt64),
-                    "f32" => quote!(polars::prelude::DataType::Float32),
-                    "f64" => quote!(polars::prelude::DataType::Float64),
-                    "bool" => quote!(polars::prelude::DataType::Boolean),
-                    "String" => quote!(polars::prelude::DataType::String),
-                    _ => quote!(polars::prelude::DataType::String),
-                }
-            }
-            // Chrono temporal types (with chrono:: prefix)
-            "chrono :: NaiveDate" => quote!(polars::prelude::DataType::Date),
-            "chrono :: NaiveDateTime" => quote!(polars::prelude::DataType::Datetime(
-                polars::prelude::TimeUnit::Microseconds,
-                None
-            )),
-            "chrono :: NaiveTime" => quote!(polars::prelude::DataType::Time),
-            "chrono :: DateTime < chrono :: Utc >" => quote!(polars::prelude::DataType::Datetime(
-                polars::prelude::TimeUnit::Microseconds,
-                Some(polars::prelude::PlSmallStr::from_static("UTC"))
-            )),
-            // Chrono temporal types (imported without prefix)
-            "NaiveDate" => quote!(polars::prelude::DataType::Date),
-            "NaiveDateTime" => quote!(polars::prelude::DataType::Datetime(
-                polars::prelude::TimeUnit::Microseconds,
-                None
-            )),
-            "NaiveTime" => quote!(polars::prelude::DataType::Time),
-            "DateTime < Utc >" => quote!(polars::prelude::DataType::Datetime(
-                polars::prelude::TimeUnit::Microseconds,
-                Some(polars::prelude::PlSmallStr::from_static("UTC"))
-            )),
-            _ => quote!(polars::prelude::DataType::String), // Default fallback
-        }
-    }).collect();
+    let field_refs: Vec<_> = fields.iter().collect();
+    let series_getters = accessors::series_getters(&field_refs, &field_attrs, &polars_types);
+    let cell_accessors = accessors::cell_accessors(&field_refs, &field_attrs);
+    let column_setters = accessors::column_setters(&field_refs, &field_attrs, &polars_types);
+    let literal_helpers = accessors::literal_helpers(&field_refs, &polars_types);
+    let (filter_builder, filter_constructor) =
+        filter::filter_builder(&name, &field_refs, &field_attrs, &polars_types);
+    let quality_checks = quality::quality_checks(&field_refs, &field_attrs);
+    let table_helpers = tables::table_helpers();
+    let validators = validate::validators(&name, &field_refs, &field_attrs);
+    let column_lookups = tables::column_lookups();
+    let column_groups = groups::column_groups(&field_attrs, &polars_types);
+    let window_helpers = window::window_helpers(&field_attrs);
+    let fill_nulls = fill::fill_nulls(&field_refs, &field_attrs);
+    let clean = clean::clean(&field_refs, &field_attrs);
+    let fake_constructors = fake_constructors(&field_refs, &field_attrs, &polars_types);
+    let df_strategies = df_strategies(&field_refs, &field_attrs, &polars_types);
 
-    let const_impls = fields.iter().map(|f| {
-        let field_name = &f.ident;
-        let field_name_str = field_name.as_ref().unwrap().to_string();
-        quote! {
-            #[allow(non_upper_case_globals)]
-            pub const #field_name: &'static str = #field_name_str;
-        }
-    });
+    let (const_impls, consts_module) =
+        match consts::column_consts(&name, &field_refs, &field_attrs, &container_attrs, false) {
+            Ok(consts) => consts,
+            Err(err) => return err.to_compile_error().into(),
+        };
 
-    let type_const_impls = fields.iter().zip(polars_types.clone()).map(|(f, polars_type)| {
-        let field_name = &f.ident;
-        let type_const_name = syn::Ident::new(
-            &format!("{}_type", field_name.as_ref().unwrap()),
-            proc_macro2::Span::call_site(),
-        );
-        quote! {
-            #[allow(non_upper_case_globals)]
-            pub const #type_const_name: polars::prelude::DataType = #polars_type;
-        }
-    });
-
-    let col_func_impls = fields.iter().map(|f| {
-        let field_name = &f.ident;
-        let func_name = syn::Ident::new(
-            &format!("{}_col", field_name.as_ref().unwrap()),
-            proc_macro2::Span::call_site(),
-        );
-        let field_name_str = field_name.as_ref().unwrap().to_string();
-        quote! {
-            pub fn #func_name() -> polars::prelude::Expr {
-                polars::prelude::col(#field_name_str)
-            }
-        }
-    });
-
-    // Generate expr helper struct name
-    let expr_struct_name =
-        syn::Ident::new(&format!("ExprFor{}", name), proc_macro2::Span::call_site());
+    let field_items = tables::field_items(&field_refs, &field_attrs, &polars_types, &container_attrs);
+    let expr_helper = tables::expr_helper(
+        &name,
+        &input.generics,
+        &field_refs,
+        &field_attrs,
+        &container_attrs,
+    );
+    let frame_constructors = tables::frame_constructors(&container_attrs);
 
     let expanded = quote! {
-        impl #name {
-            #(#const_impls)*
-            #(#type_const_impls)*
-            #(#col_func_impls)*
+        #consts_module
 
-            /// Get all column names as Vec<&str> for use with df.select()
-            pub fn all_columns() -> Vec<&'static str> {
-                vec![#(#field_name_strs),*]
-            }
+        impl #impl_generics #name #ty_generics #where_clause {
+            #const_impls
 
-            /// Get all column types as Vec<DataType>
-            pub fn all_types() -> Vec<polars::prelude::DataType> {
-                vec![#(#polars_types),*]
-            }
+            /// Column names in field order, usable in const contexts
+            pub const COLUMNS: &'static [&'static str] = &[#(#field_name_strs),*];
 
-            /// Get column type at specific index
-            pub fn type_at(index: usize) -> Option<polars::prelude::DataType> {
-                let types = [#(#polars_types),*];
-                types.get(index).cloned()
-            }
+            /// Number of columns
+            pub const WIDTH: usize = #field_count;
 
-            /// Get all column names as expressions for lazy operations
-            pub fn all_cols() -> Vec<polars::prelude::Expr> {
-                vec![#(polars::prelude::col(#field_name_strs)),*]
-            }
-
-            /// Create an empty DataFrame with the correct schema
-            pub fn df() -> std::result::Result<polars::prelude::DataFrame, polars::prelude::PolarsError> {
-                let columns = vec![
-                    #(
-                        polars::prelude::Column::new(#field_name_strs.into(), polars::prelude::Series::new_empty(#field_name_strs.into(), &#polars_types))
-                    ),*
-                ];
-                polars::prelude::DataFrame::new(columns)
-            }
-        }
+            #field_items
 
-        pub struct #expr_struct_name;
-
-        impl #expr_struct_name {
-            #(
-                pub fn #field_names(&self) -> polars::prelude::Expr {
-                    polars::prelude::col(#field_name_strs)
+            /// Position of the column `name`, e.g. for `df.get_row` values
+            pub fn field_index(name: &str) -> Option<usize> {
+                match name {
+                    #(#field_name_strs => Some(#field_indices),)*
+                    _ => None,
                 }
-            )*
-            
-            /// Get all column expressions as Vec<Expr> for lazy operations
-            pub fn all_cols(&self) -> Vec<polars::prelude::Expr> {
-                vec![#(polars::prelude::col(#field_name_strs)),*]
             }
-        }
+            #(#series_getters)*
+            #(#cell_accessors)*
+            #(#column_setters)*
+            #(#literal_helpers)*
+            #filter_constructor
+            #quality_checks
+            #column_groups
+            #window_helpers
+            #fill_nulls
+            #clean
+            #fake_constructors
+            #df_strategies
 
-        impl #name {
-            pub const expr: #expr_struct_name = #expr_struct_name;
-        }
+            #table_helpers
+            #frame_constructors
+            #validators
 
-        // Implement the trait methods directly without trait bounds to avoid import issues
-        impl #name {
-            /// Implementation of PolarsColumnsExt::columns() 
-            pub fn columns() -> Vec<&'static str> {
-                vec![#(#field_name_strs),*]
+            /// `validate`, also rejecting columns that are not fields
+            pub fn validate_strict(df: &::polars::prelude::DataFrame) -> ::polars_tools::Result<()> {
+                let (df, _) = ::polars_tools::__private::match_columns(
+                    df,
+                    Self::polars_schema(),
+                    Self::COLUMN_ALIASES,
+                    &::polars_tools::ValidateOptions::default(),
+                )?;
+                Self::validate(&df)?;
+                ::polars_tools::__private::validate_column_set(&df, Self::polars_schema())
             }
+        }
 
-            /// Implementation of PolarsColumns::column_names()
-            pub fn column_names() -> Vec<&'static str> {
-                vec![#(#field_name_strs),*]
-            }
+        #expr_helper
 
-            /// Implementation of PolarsColumns::column_name_at()
-            pub fn column_name_at(index: usize) -> Option<&'static str> {
-                let names = [#(#field_name_strs),*];
-                names.get(index).copied()
-            }
+        #filter_builder
 
-            /// Implementation of PolarsColumns::col_expr()
-            pub fn col_expr(field_name: &str) -> Option<polars::prelude::Expr> {
-                match field_name {
-                    #(#field_name_strs => Some(polars::prelude::col(#field_name_strs)),)*
-                    _ => None,
-                }
-            }
+        // Implement the trait methods directly without trait bounds to avoid import issues
+        impl #impl_generics #name #ty_generics #where_clause {
+            #column_lookups
         }
     };
 
-    TokenStream::from(expanded)
+    TokenStream::from(paths::with_crate_paths(expanded, &container_attrs))
 }
 
 /// Derive macro for generating schema validation using a struct definition
-#[proc_macro_derive(PolarsSchema)]
+#[proc_macro_derive(PolarsSchema, attributes(polars))]
 pub fn polars_schema_derive(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
+    let container_attrs = match ContainerAttrs::from_input(&input) {
+        Ok(attrs) => attrs,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    if container_attrs.of.is_some() {
+        return syn::Error::new_spanned(&input.ident, "of requires #[derive(PolarsProjection)]")
+            .to_compile_error()
+            .into();
+    }
+    TokenStream::from(schema_derive(input, &container_attrs))
+}
+
+/// Expansion of `#[derive(PolarsSchema)]`, shared with `PolarsProjection`
+fn schema_derive(input: DeriveInput, container_attrs: &ContainerAttrs) -> proc_macro2::TokenStream {
+    if let Err(err) = types::check_generics(&input.generics, "PolarsSchema") {
+        return err.to_compile_error();
+    }
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
     let name = input.ident;
 
     let fields = match input.data {
@@ -316,365 +525,677 @@ pub fn polars_schema_derive(input: TokenStream) -> TokenStream {
         _ => panic!("PolarsSchema only supports structs"),
     };
 
-    // Collect enum field information for validation generation
-    let _enum_fields_for_schema: Vec<_> = fields.iter()
-        .filter_map(|f| {
-            let field_type = &f.ty;
-            let type_str = quote!(#field_type).to_string();
-            let field_name = f.ident.as_ref().unwrap();
-            
-            if is_likely_enum_type(&type_str) {
-                Some((field_name.clone(), field_type.clone()))
-            } else {
-                None
-            }
-        })
-        .collect();
+    let field_attrs = match parse_field_attrs(&fields, container_attrs) {
+        Ok(attrs) => attrs,
+        Err(err) => return err.to_compile_error(),
+    };
 
-    // Collect the polars types for DataFrame creation
+    // Resolve the polars data type of every field
     let polars_types_for_df: Vec<_> = fields
         .iter()
-        .map(|f| {
-            let field_type = &f.ty;
-            let type_str = quote!(#field_type).to_string();
-            
-            // If it's likely an enum, map it to String
-            if is_likely_enum_type(&type_str) {
-                return quote!(polars::prelude::DataType::String);
-            }
-            
-            match type_str.as_str() {
-                // Handle Option<T> types - exact match for all supported types
-                "Option < i8 >" => quote!(polars::prelude::DataType::Int8),
-                "Option < i16 >" => quote!(polars::prelude::DataType::Int16),
-                "Option < i32 >" => quote!(polars::prelude::DataType::Int32),
-                "Option < i64 >" => quote!(polars::prelude::DataType::Int64),
-                "Option < u8 >" => quote!(polars::prelude::DataType::UInt8),
-                "Option < u16 >" => quote!(polars::prelude::DataType::UInt16),
-                "Option < u32 >" => quote!(polars::prelude::DataType::UInt32),
-                "Option < u64 >" => quote!(polars::prelude::DataType::UInt64),
-                "Option < f32 >" => quote!(polars::prelude::DataType::Float32),
-                "Option < f64 >" => quote!(polars::prelude::DataType::Float64),
-                "Option < bool >" => quote!(polars::prelude::DataType::Boolean),
-                "Option < String >" => quote!(polars::prelude::DataType::String),
-                // Signed integers
-                "i8" => quote!(polars::prelude::DataType::Int8),
-                "i16" => quote!(polars::prelude::DataType::Int16),
-                "i32" => quote!(polars::prelude::DataType::Int32),
-                "i64" => quote!(polars::prelude::DataType::Int64),
-                // Unsigned integers
-                "u8" => quote!(polars::prelude::DataType::UInt8),
-                "u16" => quote!(polars::prelude::DataType::UInt16),
-                "u32" => quote!(polars::prelude::DataType::UInt32),
-                "u64" => quote!(polars::prelude::DataType::UInt64),
-                // Floats
-                "f32" => quote!(polars::prelude::DataType::Float32),
-                "f64" => quote!(polars::prelude::DataType::Float64),
-                // Boolean and String
-                "bool" => quote!(polars::prelude::DataType::Boolean),
-                "String" => quote!(polars::prelude::DataType::String),
-                // Handle Option<T> types
-                s if s.starts_with("Option <") || s.starts_with("std :: option :: Option <") => {
-                    let inner = if s.starts_with("Option <") {
-                        s.trim_start_matches("Option <").trim_end_matches(">")
-                    } else {
-                        s.trim_start_matches("std :: option :: Option <")
-                            .trim_end_matches(">")
-                    };
-                    match inner {
-                        "i8" => quote!(polars::prelude::DataType::Int8),
-                        "i16" => quote!(polars::prelude::DataType::Int16),
-                        "i32" => quote!(polars::prelude::DataType::Int32),
-                        "i64" => quote!(polars::prelude::DataType::Int64),
-                        "u8" => quote!(polars::prelude::DataType::UInt8),
-                        "u16" => quote!(polars::prelude::DataType::UInt16),
-                        "u32" => quote!(polars::prelude::DataType::UInt32),
-                        "u64" => quote!(polars::prelude::DataType::UInt64),
-                        "f32" => quote!(polars::prelude::DataType::Float32),
-                        "f64" => quote!(polars::prelude::DataType::Float64),
-                        "bool" => quote!(polars::prelude::DataType::Boolean),
-                        "String" => quote!(polars::prelude::DataType::String),
-                        _ => quote!(polars::prelude::DataType::String),
-                    }
-                }
-                // Chrono temporal types (with chrono:: prefix)
-                "chrono :: NaiveDate" => quote!(polars::prelude::DataType::Date),
-                "chrono :: NaiveDateTime" => quote!(polars::prelude::DataType::Datetime(
-                    polars::prelude::TimeUnit::Microseconds,
-                    None
-                )),
-                "chrono :: NaiveTime" => quote!(polars::prelude::DataType::Time),
-                "chrono :: DateTime < chrono :: Utc >" => {
-                    quote!(polars::prelude::DataType::Datetime(
-                        polars::prelude::TimeUnit::Microseconds,
-                        Some(polars::prelude::PlSmallStr::from_static("UTC"))
-                    ))
-                }
-                // Chrono temporal types (imported without prefix)
-                "NaiveDate" => quote!(polars::prelude::DataType::Date),
-                "NaiveDateTime" => quote!(polars::prelude::DataType::Datetime(
-                    polars::prelude::TimeUnit::Microseconds,
-                    None
-                )),
-                "NaiveTime" => quote!(polars::prelude::DataType::Time),
-                "DateTime < Utc >" => quote!(polars::prelude::DataType::Datetime(
-                    polars::prelude::TimeUnit::Microseconds,
-                    Some(polars::prelude::PlSmallStr::from_static("UTC"))
-                )),
-                _ => quote!(polars::prelude::DataType::String), // Default fallback
-            }
-        })
+        .zip(&field_attrs)
+        .map(|(f, attrs)| field_dtype(f, attrs, container_attrs))
         .collect();
 
-    let field_validations =
-        fields
-            .iter()
-            .zip(polars_types_for_df.iter())
-            .map(|(f, _polars_type)| {
-                let field_name = f.ident.as_ref().unwrap().to_string();
-                let field_type = &f.ty;
-
-                // Map Rust types to Polars DataTypes
-                let type_str = quote!(#field_type).to_string();
-                let polars_type = match type_str.as_str() {
-                    // Signed integers
-                    "i8" => quote!(polars::prelude::DataType::Int8),
-                    "i16" => quote!(polars::prelude::DataType::Int16),
-                    "i32" => quote!(polars::prelude::DataType::Int32),
-                    "i64" => quote!(polars::prelude::DataType::Int64),
-                    // Unsigned integers
-                    "u8" => quote!(polars::prelude::DataType::UInt8),
-                    "u16" => quote!(polars::prelude::DataType::UInt16),
-                    "u32" => quote!(polars::prelude::DataType::UInt32),
-                    "u64" => quote!(polars::prelude::DataType::UInt64),
-                    // Floats
-                    "f32" => quote!(polars::prelude::DataType::Float32),
-                    "f64" => quote!(polars::prelude::DataType::Float64),
-                    // Handle Option<T> types - simplified exact match FIRST to ensure priority
-                    "Option < i32 >" => quote!(polars::prelude::DataType::Int32),
-                    "Option < String >" => quote!(polars::prelude::DataType::String),
-                    // Boolean and String
-                    "bool" => quote!(polars::prelude::DataType::Boolean),
-                    "String" => quote!(polars::prelude::DataType::String),
-                    // Handle Option<T> types (nullable columns) - fallback pattern
-                    s if s.contains("Option") && s.contains("<") && s.contains(">") => {
-                        // Extract everything between < and >
-                        let start = s.find('<').unwrap_or(0) + 1;
-                        let end = s.rfind('>').unwrap_or(s.len());
-                        let inner = s[start..end].trim();
-                        match inner {
-                            "i8" => quote!(polars::prelude::DataType::Int8),
-                            "i16" => quote!(polars::prelude::DataType::Int16),
-                            "i32" => quote!(polars::prelude::DataType::Int32),
-                            "i64" => quote!(polars::prelude::DataType::Int64),
-                            "u8" => quote!(polars::prelude::DataType::UInt8),
-                            "u16" => quote!(polars::prelude::DataType::UInt16),
-                            "u32" => quote!(polars::prelude::DataType::UInt32),
-                            "u64" => quote!(polars::prelude::DataType::UInt64),
-                            "f32" => quote!(polars::prelude::DataType::Float32),
-                            "f64" => quote!(polars::prelude::DataType::Float64),
-                            "bool" => quote!(polars::prelude::DataType::Boolean),
-                            "String" => quote!(polars::prelude::DataType::String),
-                            _ => quote!(polars::prelude::DataType::String),
-                        }
-                    }
-                    // Chrono temporal types (with chrono:: prefix)
-                    "chrono :: NaiveDate" => quote!(polars::prelude::DataType::Date),
-                    "chrono :: NaiveDateTime" => quote!(polars::prelude::DataType::Datetime(
-                        polars::prelude::TimeUnit::Microseconds,
-                        None
-                    )),
-                    "chrono :: NaiveTime" => quote!(polars::prelude::DataType::Time),
-                    "chrono :: DateTime < chrono :: Utc >" => {
-                        quote!(polars::prelude::DataType::Datetime(
-                            polars::prelude::TimeUnit::Microseconds,
-                            Some(polars::prelude::PlSmallStr::from_static("UTC"))
-                        ))
-                    }
-                    // Chrono temporal types (imported without prefix)
-                    "NaiveDate" => quote!(polars::prelude::DataType::Date),
-                    "NaiveDateTime" => quote!(polars::prelude::DataType::Datetime(
-                        polars::prelude::TimeUnit::Microseconds,
-                        None
-                    )),
-                    "NaiveTime" => quote!(polars::prelude::DataType::Time),
-                    "DateTime < Utc >" => quote!(polars::prelude::DataType::Datetime(
-                        polars::prelude::TimeUnit::Microseconds,
-                        Some(polars::prelude::PlSmallStr::from_static("UTC"))
-                    )),
-                    _ => quote!(polars::prelude::DataType::String), // Default fallback
-                };
-
-                quote! {
-                    let col = df.column(#field_name)
-                        .map_err(|_| ::polars_tools::ValidationError::MissingColumn {
-                            column_name: #field_name.to_string()
-                        })?;
-
-                    if col.dtype() != &#polars_type {
-                        return Err(::polars_tools::ValidationError::TypeMismatch {
-                            column_name: #field_name.to_string(),
-                            actual_type: format!("{:?}", col.dtype()),
-                            expected_type: format!("{:?}", #polars_type),
-                        });
-                    }
-                }
-            });
+    // Columns whose time unit is not checked by `validate`
+    let any_time_unit_columns: Vec<_> = fields
+        .iter()
+        .zip(&field_attrs)
+        .filter(|(_, attrs)| attrs.any_time_unit)
+        .map(|(_, attrs)| &attrs.column)
+        .collect();
 
     let field_names: Vec<_> = fields.iter().map(|f| &f.ident).collect();
-    let field_name_strs: Vec<_> = fields
+    let field_name_strs: Vec<_> = field_attrs.iter().map(|attrs| &attrs.column).collect();
+    let field_count = field_names.len();
+    let field_indices: Vec<_> = (0..field_count).collect();
+    // Rust type of every field, checked against by `PolarsProjection`
+    let field_type_fns: Vec<_> = field_names
         .iter()
-        .map(|f| f.ident.as_ref().unwrap().to_string())
+        .map(|f| format_ident!("__polars_field_{}", f.as_ref().unwrap()))
         .collect();
-    let _field_names_count = field_names.len();
+    let field_tys: Vec<_> = fields.iter().map(|f| &f.ty).collect();
 
-    // Generate const impls and expr helper (same as PolarsColumns macro)
-    let const_impls = fields.iter().map(|f| {
-        let field_name = &f.ident;
-        let field_name_str = field_name.as_ref().unwrap().to_string();
+    let field_refs: Vec<_> = fields.iter().collect();
+    let series_getters =
+        accessors::series_getters(&field_refs, &field_attrs, &polars_types_for_df);
+    let cell_accessors = accessors::cell_accessors(&field_refs, &field_attrs);
+    let column_setters =
+        accessors::column_setters(&field_refs, &field_attrs, &polars_types_for_df);
+    let literal_helpers = accessors::literal_helpers(&field_refs, &polars_types_for_df);
+    let (filter_builder, filter_constructor) =
+        filter::filter_builder(&name, &field_refs, &field_attrs, &polars_types_for_df);
+    let quality_checks = quality::quality_checks(&field_refs, &field_attrs);
+    let table_helpers = tables::table_helpers();
+    let validators = validate::validators(&name, &field_refs, &field_attrs);
+    let column_lookups = tables::column_lookups();
+    let column_groups = groups::column_groups(&field_attrs, &polars_types_for_df);
+    let window_helpers = window::window_helpers(&field_attrs);
+    let fill_nulls = fill::fill_nulls(&field_refs, &field_attrs);
+    let date_parsing = parse::date_parsing(&field_attrs);
+    let clean = clean::clean(&field_refs, &field_attrs);
+    let fake_constructors = fake_constructors(&field_refs, &field_attrs, &polars_types_for_df);
+    let df_strategies = df_strategies(&field_refs, &field_attrs, &polars_types_for_df);
+    let json_rows = json_rows(&field_attrs);
+    let json_formats = json_formats();
+    let (row_conversions, rows_impl) =
+        rows::row_conversions(&name, &input.generics, &field_refs, &field_attrs);
+    let nested_helpers = nested::nested_helpers(&field_refs, &field_attrs);
+    let melt = groups::melt(&field_attrs);
+    let key_helpers = keys::key_helpers(&field_attrs);
+    let reference_checks = references::reference_checks(&field_refs, &field_attrs);
+    let avro_io = avro_io(&name, &field_refs);
+    let parquet_io = parquet_io(&name);
+    let sql_context = sql_context(&name, container_attrs);
+    let delta_scan = delta_scan();
+    let flight_io = flight_io();
+
+    let versioned_columns = field_attrs.iter().map(|attrs| {
+        let column = &attrs.column;
+        let since = attrs.since.unwrap_or(1);
+        let until = match attrs.deprecated {
+            Some(Some(version)) => quote!(Some(#version)),
+            Some(None) => quote!(Some(0)),
+            None => quote!(None),
+        };
         quote! {
-            #[allow(non_upper_case_globals)]
-            pub const #field_name: &'static str = #field_name_str;
+            ::polars_tools::__private::VersionedColumn {
+                name: #column,
+                since: #since,
+                until: #until,
+            }
         }
     });
+    let latest_version = field_attrs
+        .iter()
+        .flat_map(|attrs| [attrs.since, attrs.deprecated.flatten()])
+        .flatten()
+        .max()
+        .unwrap_or(1);
+
+    let column_defaults = defaults::column_defaults(&field_refs, &field_attrs);
+    let renamed_columns = field_attrs.iter().flat_map(|attrs| {
+        let column = &attrs.column;
+        attrs
+            .renamed_from
+            .iter()
+            .map(move |old| quote!((#old, #column)))
+    });
 
-    let type_const_impls = fields.iter().zip(polars_types_for_df.clone()).map(|(f, polars_type)| {
-        let field_name = &f.ident;
-        let type_const_name = syn::Ident::new(
-            &format!("{}_type", field_name.as_ref().unwrap()),
-            proc_macro2::Span::call_site(),
-        );
-        quote! {
-            #[allow(non_upper_case_globals)]
-            pub const #type_const_name: polars::prelude::DataType = #polars_type;
-        }
+    let column_docs = field_attrs.iter().filter_map(|attrs| {
+        let column = &attrs.column;
+        attrs.doc.as_ref().map(|doc| quote!((#column, #doc)))
     });
 
-    let col_func_impls = fields.iter().map(|f| {
-        let field_name = &f.ident;
-        let func_name = syn::Ident::new(
-            &format!("{}_col", field_name.as_ref().unwrap()),
-            proc_macro2::Span::call_site(),
-        );
-        let field_name_str = field_name.as_ref().unwrap().to_string();
+    let schema_name = name.to_string();
+
+    let column_tags = field_attrs.iter().flat_map(|attrs| {
+        let column = &attrs.column;
+        attrs.tags.iter().map(move |tag| quote!((#column, #tag)))
+    });
+
+    let registration = container_attrs.register.as_ref().map(|register_name| {
+        let register_name = match register_name {
+            Some(register_name) => register_name.value(),
+            None => name.to_string(),
+        };
         quote! {
-            pub fn #func_name() -> polars::prelude::Expr {
-                polars::prelude::col(#field_name_str)
+            ::polars_tools::__private::inventory::submit! {
+                ::polars_tools::registry::RegisteredSchema::new(
+                    #register_name,
+                    #name::COLUMNS,
+                    #name::DTYPES,
+                    #name::polars_schema,
+                    #name::column_docs,
+                    #name::schema_description,
+                    #name::validate,
+                    #name::validate_strict,
+                )
             }
         }
     });
 
-    // Generate expr helper struct name
-    let expr_struct_name =
-        syn::Ident::new(&format!("ExprFor{}", name), proc_macro2::Span::call_site());
+    // Generate const impls and expr helper (same as PolarsColumns macro)
+    let (const_impls, consts_module) =
+        match consts::column_consts(&name, &field_refs, &field_attrs, container_attrs, true) {
+            Ok(consts) => consts,
+            Err(err) => return err.to_compile_error(),
+        };
+
+    let field_items = tables::field_items(&field_refs, &field_attrs, &polars_types_for_df, container_attrs);
+    let expr_helper = tables::expr_helper(
+        &name,
+        &input.generics,
+        &field_refs,
+        &field_attrs,
+        container_attrs,
+    );
+    let frame_constructors = tables::frame_constructors(container_attrs);
 
     let expanded = quote! {
-        impl #name {
-            #(#const_impls)*
-            #(#type_const_impls)*
-            #(#col_func_impls)*
+        #consts_module
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            #const_impls
+
+            /// Column names in field order, usable in const contexts
+            pub const COLUMNS: &'static [&'static str] = &[#(#field_name_strs),*];
+
+            /// Number of columns
+            pub const WIDTH: usize = #field_count;
 
-            /// Get all column names as Vec<&str> for use with df.select()
-            pub fn all_columns() -> Vec<&'static str> {
-                vec![#(#field_name_strs),*]
+            #field_items
+
+            const COLUMN_DOCS: &'static [(&'static str, &'static str)] = &[#(#column_docs),*];
+
+            /// Descriptions of the documented columns as `(column, doc)`, from
+            /// `#[polars(doc = "...")]` or the field's `///` comment
+            pub fn column_docs() -> &'static [(&'static str, &'static str)] {
+                Self::COLUMN_DOCS
             }
 
-            /// Get all column types as Vec<DataType>
-            pub fn all_types() -> Vec<polars::prelude::DataType> {
-                vec![#(#polars_types_for_df),*]
+            const COLUMN_TAGS: &'static [(&'static str, &'static str)] = &[#(#column_tags),*];
+
+            /// Columns with their dtype, nullability, doc and tags, see
+            /// `describe_schema`
+            pub fn schema_description() -> ::polars_tools::SchemaDescription {
+                ::polars_tools::SchemaDescription::new(
+                    #schema_name,
+                    Self::polars_schema(),
+                    Self::COLUMN_NULLABLE,
+                    Self::COLUMN_DOCS,
+                    Self::COLUMN_TAGS,
+                )
             }
 
-            /// Get column type at specific index
-            pub fn type_at(index: usize) -> Option<polars::prelude::DataType> {
-                let types = [#(#polars_types_for_df),*];
-                types.get(index).cloned()
+            /// Plain-text table of the columns with their dtype, nullability,
+            /// doc and tags, e.g. for a `--print-schema` flag
+            pub fn describe_schema() -> String {
+                Self::schema_description().to_string()
             }
 
-            /// Get all column names as expressions for lazy operations
-            pub fn all_cols() -> Vec<polars::prelude::Expr> {
-                vec![#(polars::prelude::col(#field_name_strs)),*]
+            /// Validate `df` and render the first and last `n` rows of the
+            /// schema columns under their names and dtypes, for logging at
+            /// pipeline checkpoints
+            pub fn preview(
+                df: &::polars::prelude::DataFrame,
+                n: usize,
+            ) -> ::polars_tools::Result<String> {
+                ::polars_tools::__private::preview(df, #schema_name, Self::polars_schema(), n)
             }
 
-            /// Create an empty DataFrame with the correct schema
-            pub fn df() -> std::result::Result<polars::prelude::DataFrame, polars::prelude::PolarsError> {
-                let columns = vec![
-                    #(
-                        polars::prelude::Column::new(#field_name_strs.into(), polars::prelude::Series::new_empty(#field_name_strs.into(), &#polars_types_for_df))
-                    ),*
-                ];
-                polars::prelude::DataFrame::new(columns)
+            /// `quality_checks()` as an expectation suite named after the
+            /// struct and versioned with `SCHEMA_VERSION`, to extend, store
+            /// and compare runs of
+            pub fn expectation_suite() -> ::polars_tools::expectations::ExpectationSuite {
+                ::polars_tools::expectations::ExpectationSuite::new(#schema_name, Self::SCHEMA_VERSION)
+                    .checks(Self::quality_checks())
             }
 
-            pub fn validate(df: &polars::prelude::DataFrame) -> ::polars_tools::Result<()> {
-                #(#field_validations)*
-                Ok(())
+            /// Columns tagged `tag` with `#[polars(tag = "...")]`, in field order
+            pub fn columns_tagged(tag: &str) -> Vec<&'static str> {
+                ::polars_tools::__private::tables::columns_tagged(Self::COLUMN_TAGS, tag)
             }
 
-            pub fn validate_strict(df: &polars::prelude::DataFrame) -> ::polars_tools::Result<()> {
-                Self::validate(df)?;
+            /// Column expressions of the columns tagged `tag`
+            pub fn cols_tagged(tag: &str) -> Vec<::polars::prelude::Expr> {
+                ::polars_tools::__private::tables::column_exprs(&Self::columns_tagged(tag))
+            }
 
-                let expected_columns: std::collections::HashSet<_> =
-                    Self::column_names().into_iter().collect();
-                let actual_columns: std::collections::HashSet<_> =
-                    df.get_column_names().into_iter().map(|s| s.as_str()).collect();
+            /// Column expressions of the columns not tagged `tag`
+            pub fn cols_untagged(tag: &str) -> Vec<::polars::prelude::Expr> {
+                Self::all_cols_except(&Self::columns_tagged(tag))
+            }
+
+            /// `df` without the columns tagged `tag`, e.g. before exporting a
+            /// frame without its `"pii"` columns
+            pub fn drop_tagged(
+                df: &::polars::prelude::DataFrame,
+                tag: &str,
+            ) -> ::polars::prelude::DataFrame {
+                df.drop_many(Self::columns_tagged(tag))
+            }
 
-                if expected_columns != actual_columns {
-                    return Err(::polars_tools::ValidationError::ColumnCountMismatch {
-                        expected: expected_columns.into_iter().map(|s| s.to_string()).collect(),
-                        actual: actual_columns.into_iter().map(|s| s.to_string()).collect(),
-                    });
+            #(
+                #[doc(hidden)]
+                pub fn #field_type_fns() -> ::std::marker::PhantomData<#field_tys> {
+                    ::std::marker::PhantomData
                 }
+            )*
 
-                Ok(())
+            /// Position of the column `name`, e.g. for `df.get_row` values
+            pub fn field_index(name: &str) -> Option<usize> {
+                match name {
+                    #(#field_name_strs => Some(#field_indices),)*
+                    _ => None,
+                }
             }
-        }
+            #(#series_getters)*
+            #(#cell_accessors)*
+            #(#column_setters)*
+            #(#literal_helpers)*
+            #filter_constructor
+            #quality_checks
+            #column_groups
+            #window_helpers
+            #date_parsing
+            #fill_nulls
+            #clean
+            #fake_constructors
+            #df_strategies
 
-        pub struct #expr_struct_name;
+            #table_helpers
+            #frame_constructors
 
-        impl #expr_struct_name {
-            #(
-                pub fn #field_names(&self) -> polars::prelude::Expr {
-                    polars::prelude::col(#field_name_strs)
+            #validators
+
+            pub fn validate_strict(df: &::polars::prelude::DataFrame) -> ::polars_tools::Result<()> {
+                let (df, _) = Self::conform(df)?;
+                ::polars_tools::__private::validate_column_set(&df, Self::polars_schema())
+            }
+
+            /// Validate `df` and wrap it as a frame known to match this schema
+            pub fn typed(
+                df: &::polars::prelude::DataFrame,
+            ) -> ::polars_tools::Result<::polars_tools::TypedFrame<Self>> {
+                ::polars_tools::TypedFrame::new(df.clone())
+            }
+
+            /// Conform each of `frames` and concatenate their schema columns;
+            /// an invalid frame fails with `InputFrame` giving its index
+            pub fn concat(
+                frames: impl IntoIterator<Item = ::polars::prelude::DataFrame>,
+            ) -> ::polars_tools::Result<::polars::prelude::DataFrame> {
+                Self::concat_with(frames, &::polars_tools::ConcatOptions::default())
+            }
+
+            /// `concat`, matching columns and rechunking the result as set in
+            /// `opts`
+            pub fn concat_with(
+                frames: impl IntoIterator<Item = ::polars::prelude::DataFrame>,
+                opts: &::polars_tools::ConcatOptions,
+            ) -> ::polars_tools::Result<::polars::prelude::DataFrame> {
+                ::polars_tools::__private::concat_frames::<Self>(frames, opts, Self::conform_with)
+            }
+
+            /// Rename the `alias` columns standing in for missing columns, parse
+            /// the string columns of `parse_date` fields, validate the result
+            /// and normalize its strings as declared with `trim`, `lowercase`,
+            /// `uppercase` or `map_values`, reporting the renames and parses
+            pub fn conform(
+                df: &::polars::prelude::DataFrame,
+            ) -> ::polars_tools::Result<(
+                ::polars::prelude::DataFrame,
+                ::polars_tools::MigrationReport,
+            )> {
+                Self::conform_with(df, &::polars_tools::ValidateOptions::default())
+            }
+
+            /// `conform`, also renaming columns matched as set in `opts`, e.g.
+            /// `User_ID` to `user_id` with `case_insensitive`, casting the
+            /// narrower columns accepted by `allow_widening` and adding the
+            /// optional columns missing under `allow_missing_optional` as nulls
+            pub fn conform_with(
+                df: &::polars::prelude::DataFrame,
+                opts: &::polars_tools::ValidateOptions,
+            ) -> ::polars_tools::Result<(
+                ::polars::prelude::DataFrame,
+                ::polars_tools::MigrationReport,
+            )> {
+                let (df, mut report) = ::polars_tools::__private::match_columns(
+                    df,
+                    Self::polars_schema(),
+                    Self::COLUMN_ALIASES,
+                    opts,
+                )?;
+                let df = ::polars_tools::__private::parse_dates(
+                    df.into_owned(),
+                    Self::polars_schema(),
+                    Self::PARSE_DATE_FORMATS,
+                    &mut report,
+                )?;
+                Self::validate_with(&df, opts)?;
+                let df = ::polars_tools::__private::conform_frame(
+                    df,
+                    Self::polars_schema(),
+                    Self::COLUMN_NULLABLE,
+                    opts,
+                    &mut report,
+                )?;
+                let exprs = Self::normalize_exprs();
+                if exprs.is_empty() {
+                    return Ok((df, report));
                 }
-            )*
-            
-            /// Get all column expressions as Vec<Expr> for lazy operations
-            pub fn all_cols(&self) -> Vec<polars::prelude::Expr> {
-                vec![#(polars::prelude::col(#field_name_strs)),*]
+                let df = ::polars::prelude::IntoLazy::lazy(df)
+                    .with_columns(exprs)
+                    .collect()?;
+                Ok((df, report))
+            }
+
+            /// `conform`, adding the columns of `Option` fields that are missing
+            /// from `df` as nulls of their dtype, see `validate_lenient`
+            pub fn conform_lenient(
+                df: &::polars::prelude::DataFrame,
+            ) -> ::polars_tools::Result<(
+                ::polars::prelude::DataFrame,
+                ::polars_tools::MigrationReport,
+            )> {
+                Self::conform_with(
+                    df,
+                    &::polars_tools::ValidateOptions::default().allow_missing_optional(),
+                )
+            }
+
+            /// `df` with columns named in another convention, e.g. `userId`,
+            /// `UserId`, `USER_ID` or `user-id`, renamed to the schema columns
+            /// (or aliases) they stand for, and the renames applied
+            pub fn rename_to_snake(
+                df: &::polars::prelude::DataFrame,
+            ) -> ::polars_tools::Result<(
+                ::polars::prelude::DataFrame,
+                ::polars_tools::MigrationReport,
+            )> {
+                let (df, report) = ::polars_tools::__private::match_columns(
+                    df,
+                    Self::polars_schema(),
+                    Self::COLUMN_ALIASES,
+                    &::polars_tools::ValidateOptions::default().ignore_naming_convention(),
+                )?;
+                Ok((df.into_owned(), report))
+            }
+
+            /// `df` with the schema columns renamed to camelCase, e.g. for a
+            /// camelCase consumer, and the renames applied
+            pub fn rename_to_camel(
+                df: &::polars::prelude::DataFrame,
+            ) -> ::polars_tools::Result<(
+                ::polars::prelude::DataFrame,
+                ::polars_tools::MigrationReport,
+            )> {
+                ::polars_tools::__private::rename_to_camel(df, Self::COLUMNS)
+            }
+
+            /// Validate every chunk of a frame that arrives in pieces and run
+            /// `quality_checks()` over the whole stream without collecting it
+            pub fn validate_streaming<I>(
+                chunks: I,
+            ) -> ::polars_tools::Result<::polars_tools::quality::QualityReport>
+            where
+                I: IntoIterator<Item = ::polars::prelude::DataFrame>,
+            {
+                let checks = Self::quality_checks();
+                let mut stream = checks.streaming();
+                for chunk in chunks {
+                    Self::validate(&chunk)?;
+                    stream.push(&chunk)?;
+                }
+                Ok(stream.finish())
+            }
+
+            /// Convert `df`, valid under the `Old` schema, into a frame with
+            /// exactly this schema's columns: renaming `renamed_from` columns,
+            /// casting changed dtypes, adding missing columns with their
+            /// `default` (or nulls) and dropping the rest
+            pub fn migrate_from<Old: ::polars_tools::PolarsSchemaInfo>(
+                df: &::polars::prelude::DataFrame,
+            ) -> ::polars_tools::Result<(
+                ::polars::prelude::DataFrame,
+                ::polars_tools::MigrationReport,
+            )> {
+                ::polars_tools::__private::migrate::<Old, Self>(df)
             }
-        }
 
-        impl #name {
-            pub const expr: #expr_struct_name = #expr_struct_name;
+            #row_conversions
+            #nested_helpers
+            #melt
+            #key_helpers
+            #reference_checks
+
+            /// Postgres `INSERT` of every row of bound column arrays into
+            /// `table`, see `polars_tools::sql::insert_sql`
+            pub fn insert_sql(table: &str) -> ::polars_tools::Result<String> {
+                ::polars_tools::sql::insert_sql(table, Self::polars_schema())
+            }
+
+            /// Stable 64-bit fingerprint of the column names and dtypes, see
+            /// `polars_tools::schema_hash`
+            pub fn schema_hash() -> u64 {
+                ::polars_tools::schema_hash(Self::polars_schema())
+            }
+
+            /// `schema_hash` as 16 hex digits, e.g. for file metadata
+            pub fn schema_fingerprint() -> String {
+                ::polars_tools::schema_fingerprint(Self::polars_schema())
+            }
+
+            /// `pl.Schema({...})` expression of this schema for Python polars,
+            /// see `polars_tools::python::python_schema`
+            pub fn python_schema() -> ::polars_tools::Result<String> {
+                ::polars_tools::python::python_schema(Self::polars_schema())
+            }
+            #json_rows
+            #avro_io
+            #parquet_io
+            #sql_context
+            #delta_scan
+            #flight_io
+
+            /// Newest schema version named by a `since` or `deprecated` attribute
+            pub const SCHEMA_VERSION: u32 = #latest_version;
+
+            const VERSIONED_COLUMNS: &'static [::polars_tools::__private::VersionedColumn] =
+                &[#(#versioned_columns),*];
+
+            /// Columns that frames of schema `version` must have
+            pub fn columns_for_version(version: u32) -> Vec<&'static str> {
+                ::polars_tools::__private::columns_for_version(Self::VERSIONED_COLUMNS, version)
+            }
+
+            /// Like `validate`, requiring only the columns of schema `version`
+            pub fn validate_version(
+                df: &::polars::prelude::DataFrame,
+                version: u32,
+            ) -> ::polars_tools::Result<()> {
+                ::polars_tools::__private::validate_version(
+                    df,
+                    Self::polars_schema(),
+                    Self::VERSIONED_COLUMNS,
+                    version,
+                    &[#(#any_time_unit_columns),*],
+                )
+            }
+
+            /// Add the columns of schema `version` missing from `df`, filled
+            /// with their `default` or nulls of their declared dtype
+            pub fn migrate_to_version(
+                df: &::polars::prelude::DataFrame,
+                version: u32,
+            ) -> ::polars_tools::Result<::polars::prelude::DataFrame> {
+                ::polars_tools::__private::migrate_to_version(
+                    df,
+                    Self::polars_schema(),
+                    Self::VERSIONED_COLUMNS,
+                    version,
+                    &<Self as ::polars_tools::PolarsSchemaInfo>::column_defaults(),
+                )
+            }
         }
 
+        #expr_helper
+
+        #filter_builder
+
         // Implement the trait methods directly without trait bounds to avoid import issues
-        impl #name {
-            /// Implementation of PolarsColumnsExt::columns() 
-            pub fn columns() -> Vec<&'static str> {
-                vec![#(#field_name_strs),*]
+        impl #impl_generics #name #ty_generics #where_clause {
+            #column_lookups
+        }
+
+        impl #impl_generics ::polars_tools::PolarsSchemaInfo for #name #ty_generics #where_clause {
+            fn polars_schema() -> &'static ::polars::prelude::Schema {
+                #name::polars_schema()
             }
 
-            /// Implementation of PolarsColumns::column_names()
-            pub fn column_names() -> Vec<&'static str> {
-                vec![#(#field_name_strs),*]
+            fn validate(df: &::polars::prelude::DataFrame) -> ::polars_tools::Result<()> {
+                #name::validate(df)
             }
 
-            /// Implementation of PolarsColumns::column_name_at()
-            pub fn column_name_at(index: usize) -> Option<&'static str> {
-                let names = [#(#field_name_strs),*];
-                names.get(index).copied()
+            fn renamed_columns() -> &'static [(&'static str, &'static str)] {
+                &[#(#renamed_columns),*]
             }
 
-            /// Implementation of PolarsColumns::col_expr()
-            pub fn col_expr(field_name: &str) -> Option<polars::prelude::Expr> {
-                match field_name {
-                    #(#field_name_strs => Some(polars::prelude::col(#field_name_strs)),)*
-                    _ => None,
-                }
+            fn column_defaults() -> Vec<(&'static str, ::polars::prelude::Expr)> {
+                #column_defaults
             }
+
+            #json_formats
         }
+
+        #rows_impl
+
+        #registration
     };
 
+    paths::with_crate_paths(expanded, container_attrs)
+}
+
+/// Derive macro for a schema that is a projection of a wider parent schema.
+///
+/// Generates everything `PolarsSchema` does, fails to compile when a field is
+/// not a field of the parent named by `#[polars(of = "Parent")]` with the
+/// same type, and adds `select_from(&df)`, `select_from_lazy(lf)` and
+/// `from_parent(&typed)` reading the projection from parent frames.
+#[proc_macro_derive(PolarsProjection, attributes(polars))]
+pub fn polars_projection_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let container_attrs = match ContainerAttrs::from_input(&input) {
+        Ok(attrs) => attrs,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let Some(parent) = &container_attrs.of else {
+        return syn::Error::new_spanned(
+            &input.ident,
+            "PolarsProjection requires #[polars(of = \"Parent\")]",
+        )
+        .to_compile_error()
+        .into();
+    };
+    let projection = match projection::projection(&input, parent) {
+        Ok(projection) => projection,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    let mut expanded = schema_derive(input, &container_attrs);
+    expanded.extend(paths::with_crate_paths(projection, &container_attrs));
     TokenStream::from(expanded)
 }
+
+/// Column expressions of fields of a `PolarsColumns` / `PolarsSchema` struct.
+///
+/// `cols!(User: user_id, email)` expands to
+/// `[col("user_id"), col("email")]`, and fails to compile when `User` has no
+/// such field.
+#[proc_macro]
+pub fn cols(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as select::ColsInput);
+    TokenStream::from(select::cols(input))
+}
+
+/// Schema type of the horizontal concatenation or join of two
+/// `PolarsSchema` structs.
+///
+/// `combine!(Order, Customer, on = [customer_id])` generates
+/// `struct OrderCustomer` with the columns of `Order` followed by those of
+/// `Customer`, `customer_id` only once, and fails to compile when the two
+/// share any other column. `combine!(pub OrderRow = Order, Customer)` names
+/// the type.
+#[proc_macro]
+pub fn combine(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as combine::CombineInput);
+    TokenStream::from(combine::combine(input))
+}
+
+/// Derive macro mapping a single-field wrapper to the dtype of the wrapped type.
+///
+/// Generates `PolarsDType`, `ChunkedColumn`, `CellValue` and `IntoLiteral` impls
+/// delegating to the inner type, so a `struct UserId(i64)` field is stored as
+/// `Int64` instead of `String` and works with the typed accessors and literals.
+#[proc_macro_derive(PolarsNewtype, attributes(polars))]
+pub fn polars_newtype_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let container_attrs = match ContainerAttrs::from_input(&input) {
+        Ok(attrs) => attrs,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let inner_field = match &input.data {
+        Data::Struct(data_struct) if data_struct.fields.len() == 1 => {
+            data_struct.fields.iter().next().unwrap()
+        }
+        _ => panic!("PolarsNewtype only supports structs with exactly one field"),
+    };
+    let inner = &inner_field.ty;
+    let construct = match &inner_field.ident {
+        Some(field) => quote!(#name { #field: value }),
+        None => quote!(#name(value)),
+    };
+    let access = match &inner_field.ident {
+        Some(field) => quote!(self.#field),
+        None => quote!(self.0),
+    };
+    let predicates = where_clause.map(|w| &w.predicates);
+
+    let expanded = quote! {
+        impl #impl_generics ::polars_tools::PolarsDType for #name #ty_generics #where_clause {
+            fn dtype() -> ::polars::prelude::DataType {
+                <#inner as ::polars_tools::PolarsDType>::dtype()
+            }
+
+            const NULLABLE: bool = <#inner as ::polars_tools::PolarsDType>::NULLABLE;
+        }
+
+        impl #impl_generics ::polars_tools::ChunkedColumn for #name #ty_generics
+        where
+            for<'__polars> #inner: ::polars_tools::ChunkedColumn,
+            #predicates
+        {
+            type Chunked = <#inner as ::polars_tools::ChunkedColumn>::Chunked;
+
+            fn downcast(
+                column: &::polars::prelude::Column,
+            ) -> ::polars::prelude::PolarsResult<&Self::Chunked> {
+                <#inner as ::polars_tools::ChunkedColumn>::downcast(column)
+            }
+        }
+
+        impl #impl_generics ::polars_tools::CellValue for #name #ty_generics
+        where
+            for<'__polars> #inner: ::polars_tools::CellValue<Value = #inner>,
+            #predicates
+        {
+            type Value = Self;
+
+            fn from_any_value(
+                value: &::polars::prelude::AnyValue<'_>,
+                column_name: &str,
+            ) -> ::polars_tools::Result<Self> {
+                let value =
+                    <#inner as ::polars_tools::CellValue>::from_any_value(value, column_name)?;
+                Ok(#construct)
+            }
+        }
+
+        impl #impl_generics ::polars_tools::IntoLiteral for #name #ty_generics
+        where
+            for<'__polars> #inner: ::polars_tools::IntoLiteral,
+            #predicates
+        {
+            fn into_literal(self) -> ::polars::prelude::Expr {
+                ::polars_tools::IntoLiteral::into_literal(#access)
+            }
+        }
+    };
+
+    TokenStream::from(paths::with_crate_paths(expanded, &container_attrs))
+}
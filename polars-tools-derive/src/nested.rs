@@ -0,0 +1,78 @@
+//! Generation of the `explode_<field>` and `unnest_<field>` helpers of list
+//! and struct columns
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Expr, Field};
+
+use crate::attrs::FieldAttrs;
+use crate::types;
+
+/// `DataType` variant named by an explicit `dtype`, e.g. `List` for
+/// `DataType::List(Box::new(DataType::Int64))`
+fn dtype_variant(dtype: &Expr) -> Option<String> {
+    let path = match dtype {
+        Expr::Call(call) => match &*call.func {
+            Expr::Path(path) => &path.path,
+            _ => return None,
+        },
+        Expr::Path(path) => &path.path,
+        _ => return None,
+    };
+    Some(path.segments.last()?.ident.to_string())
+}
+
+/// `Struct::explode_<field>(&df)` for the list fields (`Vec<T>` other than
+/// byte buffers, or an explicit `List` dtype) and `Struct::unnest_<field>(&df)`
+/// for the struct fields (`struct_keys`, or an explicit `Struct` dtype)
+pub(crate) fn nested_helpers(fields: &[&Field], attrs: &[FieldAttrs]) -> TokenStream {
+    let helpers = fields.iter().zip(attrs).filter_map(|(field, attrs)| {
+        let ident = field.ident.as_ref().unwrap();
+        let column = &attrs.column;
+        let variant = attrs.dtype.as_ref().and_then(dtype_variant);
+        let ty = types::option_inner(&field.ty).unwrap_or(&field.ty);
+        let is_list = match &variant {
+            Some(variant) => variant == "List",
+            None => {
+                attrs.dtype.is_none()
+                    && !attrs.binary
+                    && !types::is_byte_buffer(ty)
+                    && types::generic_arg(ty, "Vec").is_some()
+            }
+        };
+        let is_struct = attrs.struct_keys.is_some() || variant.as_deref() == Some("Struct");
+
+        if is_list {
+            let helper = format_ident!("explode_{}", ident);
+            let doc = format!(
+                "`df` with one row per element of the `{column}` list, checked against \
+                 the schema with `{column}` holding the element dtype"
+            );
+            Some(quote! {
+                #[doc = #doc]
+                pub fn #helper(
+                    df: &::polars::prelude::DataFrame,
+                ) -> ::polars_tools::Result<::polars::prelude::DataFrame> {
+                    ::polars_tools::__private::explode_column(df, Self::polars_schema(), #column)
+                }
+            })
+        } else if is_struct {
+            let helper = format_ident!("unnest_{}", ident);
+            let doc = format!(
+                "`df` with the `{column}` struct split into one column per struct \
+                 field, checked against the schema with `{column}` replaced by them"
+            );
+            Some(quote! {
+                #[doc = #doc]
+                pub fn #helper(
+                    df: &::polars::prelude::DataFrame,
+                ) -> ::polars_tools::Result<::polars::prelude::DataFrame> {
+                    ::polars_tools::__private::unnest_column(df, Self::polars_schema(), #column)
+                }
+            })
+        } else {
+            None
+        }
+    });
+    quote!(#(#helpers)*)
+}
@@ -0,0 +1,70 @@
+//! Generation of the `parquet` feature's self-describing file IO
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Ident;
+
+/// `Struct::write_parquet(writer, &df)` storing the schema metadata in the
+/// footer, `Struct::read_parquet(reader)` checking it, plus
+/// `Struct::parquet_schema_metadata()`, `Struct::compare_parquet(reader)`
+/// and `Struct::check_parquet_fingerprint(reader)`
+pub(crate) fn parquet_io(name: &Ident) -> TokenStream {
+    let schema_name = name.to_string();
+    quote! {
+        /// Footer metadata `write_parquet` stores: the schema name, version,
+        /// fingerprint and column docs
+        pub fn parquet_schema_metadata() -> ::polars_tools::parquet::SchemaMetadata {
+            ::polars_tools::parquet::SchemaMetadata {
+                name: Some(#schema_name.to_string()),
+                version: Some(Self::SCHEMA_VERSION),
+                fingerprint: Some(Self::schema_fingerprint()),
+                column_docs: Self::COLUMN_DOCS
+                    .iter()
+                    .map(|(column, doc)| (column.to_string(), doc.to_string()))
+                    .collect(),
+            }
+        }
+
+        /// Validate `df` and write its schema columns as a Parquet file with
+        /// `parquet_schema_metadata()` in its footer, returning the size of
+        /// the file
+        pub fn write_parquet<W: ::std::io::Write>(
+            writer: W,
+            df: &::polars::prelude::DataFrame,
+        ) -> ::polars_tools::Result<u64> {
+            Self::validate(df)?;
+            let df = df.select(Self::all_columns())?;
+            ::polars_tools::parquet::write_parquet(
+                writer,
+                &df,
+                &Self::parquet_schema_metadata().to_key_values(),
+            )
+        }
+
+        /// Read the schema columns of a Parquet file, failing with
+        /// `ValidationError::SchemaMismatch` when the file was not written
+        /// with this schema and lacks a column or has another dtype
+        pub fn read_parquet<R: ::polars::io::mmap::MmapBytesReader>(
+            reader: R,
+        ) -> ::polars_tools::Result<::polars::prelude::DataFrame> {
+            ::polars_tools::parquet::read_parquet(reader, Self::polars_schema())
+        }
+
+        /// Differences between this schema and the columns of a Parquet
+        /// file, reading only its footer
+        pub fn compare_parquet<R: ::polars::io::mmap::MmapBytesReader>(
+            reader: R,
+        ) -> ::polars_tools::Result<::polars_tools::SchemaDiff> {
+            let actual = ::polars_tools::parquet::parquet_schema(reader)?;
+            Ok(::polars_tools::SchemaDiff::between(Self::polars_schema(), &actual))
+        }
+
+        /// Check that a Parquet file was written with this schema's
+        /// fingerprint, reading only its footer
+        pub fn check_parquet_fingerprint<R: ::polars::io::mmap::MmapBytesReader>(
+            reader: R,
+        ) -> ::polars_tools::Result<()> {
+            ::polars_tools::parquet::check_parquet_fingerprint(reader, Self::polars_schema())
+        }
+    }
+}
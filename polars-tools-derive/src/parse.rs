@@ -0,0 +1,39 @@
+//! Generation of the parsing of string columns declared with
+//! `#[polars(parse_date = "...")]`
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::attrs::FieldAttrs;
+
+/// `Struct::parse_dates(df)`, parsing the string columns of the
+/// `parse_date` fields, also run by `conform`
+pub(crate) fn date_parsing(attrs: &[FieldAttrs]) -> TokenStream {
+    let formats = attrs
+        .iter()
+        .filter(|attrs| !attrs.parse_date.is_empty())
+        .map(|attrs| {
+            let column = &attrs.column;
+            let formats = &attrs.parse_date;
+            quote!((#column, &[#(#formats),*]))
+        });
+    quote! {
+        const PARSE_DATE_FORMATS: &'static [(&'static str, &'static [&'static str])] =
+            &[#(#formats),*];
+
+        /// `df` with the string columns of `#[polars(parse_date = "...")]`
+        /// fields parsed to their temporal dtype, each value with the first of
+        /// the field's formats it matches, failing with `UnparseableValues`
+        /// on strings that match none
+        pub fn parse_dates(
+            df: &::polars::prelude::DataFrame,
+        ) -> ::polars_tools::Result<::polars::prelude::DataFrame> {
+            ::polars_tools::__private::parse_dates(
+                df.clone(),
+                Self::polars_schema(),
+                Self::PARSE_DATE_FORMATS,
+                &mut ::polars_tools::MigrationReport::default(),
+            )
+        }
+    }
+}
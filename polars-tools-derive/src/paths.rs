@@ -0,0 +1,66 @@
+//! Crate paths of the generated code, overridden with
+//! `#[polars(crate = "...")]`
+//!
+//! Generators write `::polars_tools::...` and `::polars::...` paths. With a
+//! `crate` attribute the finished output is rewritten: `::polars_tools` becomes
+//! the given path, and `polars` paths, including those of attribute
+//! expressions such as `dtype = polars::prelude::DataType::Date`, go through
+//! the `polars` re-exported by polars-tools, so neither crate has to be a
+//! direct dependency under its usual name.
+
+use proc_macro2::{Group, Spacing, TokenStream, TokenTree};
+use quote::quote;
+use syn::Path;
+
+use crate::attrs::ContainerAttrs;
+
+/// `tokens` with the crate paths of `container` substituted
+pub(crate) fn with_crate_paths(tokens: TokenStream, container: &ContainerAttrs) -> TokenStream {
+    match &container.krate {
+        Some(krate) => rewrite(tokens, krate),
+        None => tokens,
+    }
+}
+
+fn rewrite(tokens: TokenStream, krate: &Path) -> TokenStream {
+    let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+    let mut out = TokenStream::new();
+    let mut i = 0;
+    while i < tokens.len() {
+        let path_sep_at = |at: usize| {
+            matches!(
+                (tokens.get(at), tokens.get(at + 1)),
+                (Some(TokenTree::Punct(a)), Some(TokenTree::Punct(b)))
+                    if a.as_char() == ':' && a.spacing() == Spacing::Joint && b.as_char() == ':'
+            )
+        };
+        let ident_at = |at: usize, name: &str| {
+            matches!(tokens.get(at), Some(TokenTree::Ident(ident)) if ident == name)
+        };
+        // a path segment, not the tail of a longer path such as `a::polars`
+        let starts_path = i < 2 || !path_sep_at(i - 2);
+
+        if path_sep_at(i) && ident_at(i + 2, "polars_tools") && starts_path {
+            out.extend(quote!(#krate));
+            i += 3;
+        } else if path_sep_at(i) && ident_at(i + 2, "polars") && starts_path {
+            out.extend(quote!(#krate::__private::polars));
+            i += 3;
+        } else if ident_at(i, "polars") && path_sep_at(i + 1) && starts_path {
+            out.extend(quote!(#krate::__private::polars));
+            i += 1;
+        } else {
+            match &tokens[i] {
+                TokenTree::Group(group) => {
+                    let mut rewritten =
+                        Group::new(group.delimiter(), rewrite(group.stream(), krate));
+                    rewritten.set_span(group.span());
+                    out.extend([TokenTree::Group(rewritten)]);
+                }
+                token => out.extend([token.clone()]),
+            }
+            i += 1;
+        }
+    }
+    out
+}
@@ -0,0 +1,80 @@
+//! Items of `#[derive(PolarsProjection)]` on top of the `PolarsSchema`
+//! expansion: the compile-time check of the fields against the parent schema
+//! and the select helpers from parent frames
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, quote_spanned};
+use syn::{Data, DeriveInput, Fields, Type};
+
+/// Check and helpers of a projection of `parent`
+///
+/// Every field reads the type of the parent field of the same name through
+/// the `__polars_field_<field>` function of the parent's derive, so a field
+/// missing from the parent or of another type fails to compile, pointing at
+/// the field.
+pub(crate) fn projection(input: &DeriveInput, parent: &Type) -> syn::Result<TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data_struct) => match &data_struct.fields {
+            Fields::Named(fields_named) => &fields_named.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "PolarsProjection only supports structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "PolarsProjection only supports structs",
+            ))
+        }
+    };
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let checks = fields.iter().map(|field| {
+        let ident = field.ident.as_ref().unwrap();
+        let ty = &field.ty;
+        let parent_field = format_ident!("__polars_field_{}", ident);
+        quote_spanned! {ident.span()=>
+            let _: ::std::marker::PhantomData<#ty> = <#parent>::#parent_field();
+        }
+    });
+
+    Ok(quote! {
+        const _: () = {
+            #[allow(dead_code)]
+            fn check_parent_fields #impl_generics () #where_clause {
+                #(#checks)*
+            }
+        };
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// The columns of this projection from a frame of the parent
+            /// schema, conformed and validated against the projection
+            pub fn select_from(
+                df: &::polars::prelude::DataFrame,
+            ) -> ::polars_tools::Result<::polars::prelude::DataFrame> {
+                let (df, _) = Self::conform(df)?;
+                Ok(df.select(Self::COLUMNS.iter().copied())?)
+            }
+
+            /// Select the columns of this projection from a query over
+            /// frames of the parent schema
+            pub fn select_from_lazy(
+                lf: ::polars::prelude::LazyFrame,
+            ) -> ::polars::prelude::LazyFrame {
+                lf.select(Self::all_cols())
+            }
+
+            /// The columns of this projection from a validated frame of the
+            /// parent schema
+            pub fn from_parent(
+                frame: &::polars_tools::TypedFrame<#parent>,
+            ) -> ::polars_tools::Result<::polars_tools::TypedFrame<Self>> {
+                frame.select()
+            }
+        }
+    })
+}
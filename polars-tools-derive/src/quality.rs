@@ -0,0 +1,88 @@
+//! Generation of `Struct::quality_checks()` from field attributes
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Field;
+
+use crate::attrs::FieldAttrs;
+use crate::types;
+
+/// `Struct::quality_checks()` collecting the rules declared with
+/// `#[polars(unique)]`, `#[polars(max_null_fraction = ...)]` and
+/// `#[polars(min = ..., max = ...)]` and the values of enum fields, plus the
+/// `validate_values*` runners
+pub(crate) fn quality_checks(fields: &[&Field], attrs: &[FieldAttrs]) -> TokenStream {
+    let checks = attrs.iter().flat_map(|attrs| {
+        let column = &attrs.column;
+        let mut checks = Vec::new();
+
+        if let Some(max) = &attrs.max_null_fraction {
+            checks.push(quote!(.max_null_fraction(#column, (#max) as f64)));
+        }
+        if attrs.unique {
+            checks.push(quote!(.unique(#column)));
+        }
+        if attrs.min.is_some() || attrs.max.is_some() {
+            let bound = |bound: &Option<syn::Expr>| match bound {
+                Some(bound) => quote!(Some((#bound) as f64)),
+                None => quote!(None),
+            };
+            let (min, max) = (bound(&attrs.min), bound(&attrs.max));
+            checks.push(quote!(.range(#column, #min, #max)));
+        }
+        checks
+    });
+
+    // Enum fields only restrict their values when the type turns out to
+    // implement `ValidatableEnum`, which is only known after expansion
+    let enum_checks = fields.iter().zip(attrs).map(|(field, attrs)| {
+        let column = &attrs.column;
+        let ty = types::option_inner(&field.ty).unwrap_or(&field.ty);
+        quote! {
+            #[allow(unused_imports)]
+            use ::polars_tools::__private::{ViaEnumValues as _, ViaNoEnumValues as _};
+            if let Some(values) =
+                (&::polars_tools::__private::DTypeProbe::<#ty>::new()).probe_enum_values()
+            {
+                checks = checks.one_of(#column, &values);
+            }
+        }
+    });
+
+    quote! {
+        /// Data-quality checks declared on the fields, plus the valid values
+        /// of enum fields; extend them with runtime rules before calling
+        /// `run(&df)`
+        pub fn quality_checks() -> ::polars_tools::quality::QualityChecks {
+            #[allow(unused_mut)]
+            let mut checks = ::polars_tools::quality::QualityChecks::new() #(#checks)*;
+            #({ #enum_checks })*
+            checks
+        }
+
+        /// Run `quality_checks()` against `df`
+        pub fn validate_values(
+            df: &::polars::prelude::DataFrame,
+        ) -> ::polars_tools::Result<::polars_tools::quality::QualityReport> {
+            Self::quality_checks().run(df)
+        }
+
+        /// Run `quality_checks()` against `df`, or a random sample of it
+        /// when `opts.sample_rows` is set
+        pub fn validate_values_with(
+            df: &::polars::prelude::DataFrame,
+            opts: &::polars_tools::ValidateOptions,
+        ) -> ::polars_tools::Result<::polars_tools::quality::QualityReport> {
+            Self::quality_checks().run_with(df, opts)
+        }
+
+        /// Lazy one-row frame counting the violations of `quality_checks()`
+        /// on `lf`, evaluated in the same query; read it back with
+        /// `quality_checks().report(&counts)`
+        pub fn validate_values_lazy(
+            lf: ::polars::prelude::LazyFrame,
+        ) -> ::polars_tools::Result<::polars::prelude::LazyFrame> {
+            Self::quality_checks().violations_lazy(lf)
+        }
+    }
+}
@@ -0,0 +1,91 @@
+//! Generation of the referential-integrity checks of
+//! `#[polars(references(Other::field))]` fields
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Field, Path};
+
+use crate::attrs::FieldAttrs;
+
+/// Schema type of a `references` path, `Customer` for `Customer::id`
+fn referenced_schema(path: &Path) -> Path {
+    let mut schema = path.clone();
+    schema.segments.pop();
+    schema.segments.pop_punct();
+    schema
+}
+
+/// `Struct::validate_<field>_references(&df, &other)` for every field with
+/// `references`, plus `Struct::validate_references(&df, &other)` running all
+/// of them when they reference a single schema
+pub(crate) fn reference_checks(fields: &[&Field], attrs: &[FieldAttrs]) -> TokenStream {
+    let references: Vec<_> = fields
+        .iter()
+        .zip(attrs)
+        .filter_map(|(field, attrs)| {
+            let path = attrs.references.as_ref()?;
+            Some((field.ident.as_ref().unwrap(), &attrs.column, path))
+        })
+        .collect();
+    let Some((_, _, first)) = references.first() else {
+        return TokenStream::new();
+    };
+
+    let checks = references.iter().map(|(ident, column, path)| {
+        let helper = format_ident!("validate_{}_references", ident);
+        let schema = referenced_schema(path);
+        let doc = format!(
+            "Check that every non-null `{column}` of `df` appears in `{}` of \
+             `other`, reporting the orphaned rows and a sample of their values",
+            quote!(#path).to_string().replace(' ', "")
+        );
+        quote! {
+            #[doc = #doc]
+            pub fn #helper(
+                df: &::polars::prelude::DataFrame,
+                other: &::polars::prelude::DataFrame,
+            ) -> ::polars_tools::Result<()> {
+                ::polars_tools::__private::validate_reference(
+                    df,
+                    Self::polars_schema(),
+                    #column,
+                    other,
+                    <#schema as ::polars_tools::PolarsSchemaInfo>::polars_schema(),
+                    #path,
+                )
+            }
+        }
+    });
+
+    let schema = referenced_schema(first);
+    let schema_name = quote!(#schema).to_string();
+    let single_schema = references.iter().all(|(_, _, path)| {
+        let other = referenced_schema(path);
+        quote!(#other).to_string() == schema_name
+    });
+    let validate_all = single_schema.then(|| {
+        let helpers = references
+            .iter()
+            .map(|(ident, _, _)| format_ident!("validate_{}_references", ident));
+        let doc = format!(
+            "Check every `references` column of `df` against `other`, valid \
+             under `{}`, failing on the first with orphaned values",
+            schema_name.replace(' ', "")
+        );
+        quote! {
+            #[doc = #doc]
+            pub fn validate_references(
+                df: &::polars::prelude::DataFrame,
+                other: &::polars::prelude::DataFrame,
+            ) -> ::polars_tools::Result<()> {
+                #(Self::#helpers(df, other)?;)*
+                Ok(())
+            }
+        }
+    });
+
+    quote! {
+        #(#checks)*
+        #validate_all
+    }
+}
@@ -0,0 +1,304 @@
+//! Generation of the `to_df` / `from_df` and `to_any_values` /
+//! `from_any_values` row conversions
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Field, Generics, Ident};
+
+use crate::accessors::chunked_source;
+use crate::attrs::FieldAttrs;
+use crate::types::{self, WellKnown};
+
+/// Where the generated conversions read a field's cell from
+#[derive(Clone, Copy)]
+enum Cells {
+    /// Row `row` of the column resolved beforehand, in `from_df`
+    Column,
+    /// Value at the field's position of `values`, in `from_any_values`
+    Row,
+}
+
+impl Cells {
+    /// Read of the cell as `source`, `Option` for nullable fields
+    fn read(
+        self,
+        source: &TokenStream,
+        optional: bool,
+        column: &Ident,
+        name: &str,
+        position: usize,
+    ) -> TokenStream {
+        match (self, optional) {
+            (Cells::Column, true) => {
+                quote!(::polars_tools::__private::column_cell::<#source>(&#column, #name, row)?)
+            }
+            (Cells::Column, false) => quote! {
+                ::polars_tools::__private::required_column_cell::<#source>(&#column, #name, row)?
+            },
+            (Cells::Row, true) => {
+                quote!(::polars_tools::__private::value_cell::<#source>(values, #name, #position)?)
+            }
+            (Cells::Row, false) => quote! {
+                ::polars_tools::__private::required_value_cell::<#source>(values, #name, #position)?
+            },
+        }
+    }
+}
+
+/// `Struct::to_df(&rows)` and `Struct::from_df(&df)`, their single-row
+/// counterparts `row.to_any_values()` and `Struct::from_any_values(&values)`
+/// and `Struct::map_rows(&df, f)`, along with the `PolarsRows` impl
+///
+/// Fields go through `ToAnyValue` and `CellValue`, except byte buffers and
+/// protobuf `Timestamp` / `Duration` messages, which are converted through
+/// their bytes and `seconds` / `nanos` fields. As with the accessors, field
+/// types without those impls leave the conversions uncallable.
+pub(crate) fn row_conversions(
+    name: &Ident,
+    generics: &Generics,
+    fields: &[&Field],
+    attrs: &[FieldAttrs],
+) -> (TokenStream, TokenStream) {
+    let mut to_bounds = Vec::new();
+    let mut from_bounds = Vec::new();
+    let mut names = Vec::new();
+    let mut columns = Vec::new();
+    let mut to_values = Vec::new();
+    let mut from_cells = Vec::new();
+    let mut from_values = Vec::new();
+
+    for (position, (field, attrs)) in fields.iter().zip(attrs).enumerate() {
+        let ident = field.ident.as_ref().unwrap();
+        let name = &attrs.column;
+        let ty = &field.ty;
+        let inner = types::option_inner(ty);
+        let value = inner.unwrap_or(ty);
+        let column = format_ident!("__column_{}", ident);
+
+        let (to_value, source) = if let Some(kind) = types::proto_well_known(ty) {
+            let (convert, source) = match kind {
+                WellKnown::Timestamp => (
+                    quote!(::polars_tools::__private::timestamp_value),
+                    quote!(::polars_tools::ProtoTimestamp),
+                ),
+                WellKnown::Duration => (
+                    quote!(::polars_tools::__private::duration_value),
+                    quote!(::polars_tools::ProtoDuration),
+                ),
+            };
+            let to_value = if inner.is_some() {
+                quote! {
+                    row.#ident.as_ref().map_or(::polars::prelude::AnyValue::Null, |value| {
+                        #convert(value.seconds, value.nanos)
+                    })
+                }
+            } else {
+                quote!(#convert(row.#ident.seconds, row.#ident.nanos))
+            };
+            let from_cell = |cells: Cells| {
+                let read = cells.read(&source, inner.is_some(), &column, name, position);
+                if inner.is_some() {
+                    quote!(#read.map(|(seconds, nanos)| #value { seconds, nanos }))
+                } else {
+                    quote! {{
+                        let (seconds, nanos) = #read;
+                        #value { seconds, nanos }
+                    }}
+                }
+            };
+            let (by_column, by_value) = (from_cell(Cells::Column), from_cell(Cells::Row));
+            from_cells.push(quote!(#ident: #by_column));
+            from_values.push(quote!(#ident: #by_value));
+            names.push(name);
+            columns.push(column);
+            to_values.push(to_value);
+            continue;
+        } else if attrs.binary || types::is_byte_buffer(ty) {
+            to_bounds.push(quote!(#value: ::std::convert::AsRef<[u8]>));
+            let bytes = quote! {
+                ::polars::prelude::AnyValue::BinaryOwned(
+                    <_ as ::std::convert::AsRef<[u8]>>::as_ref(value).to_vec(),
+                )
+            };
+            let to_value = if inner.is_some() {
+                quote!(row.#ident.as_ref().map_or(::polars::prelude::AnyValue::Null, |value| #bytes))
+            } else {
+                quote!({
+                    let value = &row.#ident;
+                    #bytes
+                })
+            };
+            (to_value, quote!(::polars_tools::__private::BinaryColumn))
+        } else {
+            to_bounds.push(quote!(#ty: ::polars_tools::ToAnyValue));
+            let source = chunked_source(field, attrs).unwrap_or_else(|| quote!(#ty));
+            (
+                quote!(::polars_tools::ToAnyValue::to_any_value(&row.#ident)),
+                source,
+            )
+        };
+
+        // Cells of the source type, when they differ from the field type
+        let cell = if attrs.as_string {
+            Some(quote!(::std::string::String))
+        } else if attrs.binary || types::is_byte_buffer(ty) {
+            Some(quote!(::std::vec::Vec<u8>))
+        } else if attrs.transparent {
+            Some(source.clone())
+        } else {
+            None
+        };
+        match &cell {
+            Some(cell) => {
+                from_bounds.push(quote!(#source: ::polars_tools::CellValue<Value = #cell>));
+                from_bounds.push(quote!(#value: ::std::convert::From<#cell>));
+            }
+            None => from_bounds.push(quote!(#source: ::polars_tools::CellValue<Value = #value>)),
+        }
+        let from_cell = |cells: Cells| {
+            let read = cells.read(&source, inner.is_some(), &column, name, position);
+            match &cell {
+                Some(cell) if inner.is_some() => {
+                    quote!(#read.map(<#value as ::std::convert::From<#cell>>::from))
+                }
+                Some(cell) => quote!(<#value as ::std::convert::From<#cell>>::from(#read)),
+                None => read,
+            }
+        };
+        let (by_column, by_value) = (from_cell(Cells::Column), from_cell(Cells::Row));
+        from_cells.push(quote!(#ident: #by_column));
+        from_values.push(quote!(#ident: #by_value));
+        names.push(name);
+        columns.push(column);
+        to_values.push(to_value);
+    }
+
+    let par_map_rows = par_map_rows(&from_bounds);
+    let width = names.len();
+    let indices: Vec<usize> = (0..width).collect();
+    let conversions = quote! {
+        /// DataFrame with one row per element of `rows`
+        pub fn to_df(rows: &[Self]) -> ::polars_tools::Result<::polars::prelude::DataFrame>
+        where
+            #(for<'__polars> #to_bounds,)*
+        {
+            Ok(::polars::prelude::DataFrame::new(vec![
+                #(
+                    ::polars_tools::__private::column_from_values(
+                        #names,
+                        &rows.iter().map(|row| #to_values).collect::<Vec<_>>(),
+                        Self::polars_schema().get_at_index(#indices).unwrap().1,
+                    )?
+                ),*
+            ])?)
+        }
+
+        /// Validate `df` and read every row back into the struct, failing on
+        /// nulls in non-`Option` fields
+        pub fn from_df(df: &::polars::prelude::DataFrame) -> ::polars_tools::Result<Vec<Self>>
+        where
+            #(for<'__polars> #from_bounds,)*
+        {
+            Self::validate(df)?;
+            // Resolved once, as single chunks, rather than for every cell
+            #(let #columns = ::polars_tools::__private::cell_column(df, #names, Self::COLUMN_ALIASES)?.rechunk();)*
+            (0..df.height())
+                .map(|row| Ok(Self { #(#from_cells),* }))
+                .collect()
+        }
+
+        /// The row's values in column order, in the dtypes of the schema, as
+        /// `DataFrame::get_row` returns them
+        pub fn to_any_values(&self) -> Vec<::polars::prelude::AnyValue<'static>>
+        where
+            #(for<'__polars> #to_bounds,)*
+        {
+            let row = self;
+            let schema = Self::polars_schema();
+            vec![
+                #(
+                    ::polars_tools::__private::row_value(
+                        #to_values,
+                        schema.get_at_index(#indices).unwrap().1,
+                    )
+                ),*
+            ]
+        }
+
+        /// Read one row from its values in column order, e.g. a `Row` of
+        /// `DataFrame::get_row` or the output of a UDF, failing with the
+        /// position of the first value of the wrong dtype
+        pub fn from_any_values(
+            values: &[::polars::prelude::AnyValue<'_>],
+        ) -> ::polars_tools::Result<Self>
+        where
+            #(for<'__polars> #from_bounds,)*
+        {
+            ::polars_tools::__private::row_width(values, #width)?;
+            Ok(Self { #(#from_values),* })
+        }
+
+        /// Validate `df`, apply `f` to each of its rows and build a frame of
+        /// the rows of another derived schema it returns, in the same order
+        pub fn map_rows<Out, F>(
+            df: &::polars::prelude::DataFrame,
+            f: F,
+        ) -> ::polars_tools::Result<::polars::prelude::DataFrame>
+        where
+            Out: ::polars_tools::PolarsRows,
+            F: FnMut(Self) -> Out,
+            #(for<'__polars> #from_bounds,)*
+        {
+            let rows: Vec<Out> = Self::from_df(df)?.into_iter().map(f).collect();
+            Out::to_df(&rows)
+        }
+
+        #par_map_rows
+    };
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let predicates = where_clause.into_iter().flat_map(|clause| &clause.predicates);
+    let rows_impl = quote! {
+        impl #impl_generics ::polars_tools::PolarsRows for #name #ty_generics
+        where
+            #(#predicates,)*
+            #(for<'__polars> #to_bounds,)*
+            #(for<'__polars> #from_bounds,)*
+        {
+            fn to_df(rows: &[Self]) -> ::polars_tools::Result<::polars::prelude::DataFrame> {
+                #name::to_df(rows)
+            }
+
+            fn from_df(df: &::polars::prelude::DataFrame) -> ::polars_tools::Result<Vec<Self>> {
+                #name::from_df(df)
+            }
+        }
+    };
+    (conversions, rows_impl)
+}
+
+/// `Struct::par_map_rows(&df, f)`, `map_rows` over slices of `df` in parallel
+#[cfg(feature = "rayon")]
+fn par_map_rows(from_bounds: &[TokenStream]) -> TokenStream {
+    quote! {
+        /// `map_rows` run on slices of `df` in parallel with rayon, the
+        /// output rows in the order of the input rows
+        pub fn par_map_rows<Out, F>(
+            df: &::polars::prelude::DataFrame,
+            f: F,
+        ) -> ::polars_tools::Result<::polars::prelude::DataFrame>
+        where
+            Out: ::polars_tools::PolarsRows,
+            F: Fn(Self) -> Out + Sync,
+            #(for<'__polars> #from_bounds,)*
+        {
+            Self::validate(df)?;
+            ::polars_tools::__private::par_map_chunks(df, |chunk| Self::map_rows(chunk, &f))
+        }
+    }
+}
+
+#[cfg(not(feature = "rayon"))]
+fn par_map_rows(_from_bounds: &[TokenStream]) -> TokenStream {
+    TokenStream::new()
+}
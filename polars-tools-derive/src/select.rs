@@ -0,0 +1,34 @@
+//! Parsing and expansion of `cols!(Struct: field, ...)`
+
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Ident, Path, Token};
+
+/// `Struct: field, field, ...`
+pub(crate) struct ColsInput {
+    schema: Path,
+    fields: Punctuated<Ident, Token![,]>,
+}
+
+impl Parse for ColsInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let schema = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let fields = Punctuated::parse_terminated(input)?;
+        Ok(ColsInput { schema, fields })
+    }
+}
+
+/// An array of column expressions, one per field, read through the `expr`
+/// helper of the derive so that unknown fields fail to compile
+pub(crate) fn cols(input: ColsInput) -> TokenStream {
+    let schema = &input.schema;
+    let exprs = input.fields.iter().map(|field| {
+        quote_spanned! {field.span()=>
+            #schema::expr.#field()
+        }
+    });
+    quote!([#(#exprs),*])
+}
@@ -0,0 +1,41 @@
+//! Generation of the `sql` feature's `SQLContext` helpers
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Ident;
+
+use crate::attrs::ContainerAttrs;
+use crate::consts::snake_case;
+
+/// `Struct::TABLE_NAME`, `Struct::register(&mut ctx, &df)` and
+/// `Struct::query(&mut ctx, sql)`
+pub(crate) fn sql_context(name: &Ident, container: &ContainerAttrs) -> TokenStream {
+    let table = match &container.table {
+        Some(table) => table.value(),
+        None => snake_case(&name.to_string()),
+    };
+    quote! {
+        /// Name under which `register` adds frames to a `SQLContext`
+        pub const TABLE_NAME: &'static str = #table;
+
+        /// Validate `df` and register it in `ctx` as `TABLE_NAME`
+        pub fn register(
+            ctx: &mut ::polars::sql::SQLContext,
+            df: &::polars::prelude::DataFrame,
+        ) -> ::polars_tools::Result<()> {
+            Self::validate(df)?;
+            ctx.register(Self::TABLE_NAME, ::polars::prelude::IntoLazy::lazy(df.clone()));
+            Ok(())
+        }
+
+        /// Run `sql` in `ctx` and validate the result against this schema
+        pub fn query(
+            ctx: &mut ::polars::sql::SQLContext,
+            sql: &str,
+        ) -> ::polars_tools::Result<::polars::prelude::DataFrame> {
+            let df = ctx.execute(sql)?.collect()?;
+            Self::validate(&df)?;
+            Ok(df)
+        }
+    }
+}
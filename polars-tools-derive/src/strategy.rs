@@ -0,0 +1,54 @@
+//! Generation of the `proptest` feature's frame strategies
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Field;
+
+use crate::attrs::FieldAttrs;
+
+/// `Struct::df_strategy()` and `Struct::df_strategy_with_rows(range)`
+///
+/// Like the other generated helpers, they cannot be called when a field type
+/// has no `ValueStrategy` impl.
+pub(crate) fn df_strategies(
+    fields: &[&Field],
+    attrs: &[FieldAttrs],
+    dtypes: &[TokenStream],
+) -> TokenStream {
+    let types: Vec<_> = fields.iter().map(|f| &f.ty).collect();
+    let names: Vec<_> = attrs.iter().map(|attrs| &attrs.column).collect();
+
+    quote! {
+        /// Proptest strategy for frames conforming to the schema
+        pub fn df_strategy() -> ::polars_tools::strategy::proptest::strategy::BoxedStrategy<
+            ::polars::prelude::DataFrame,
+        >
+        where
+            #(for<'__polars> #types: ::polars_tools::strategy::ValueStrategy,)*
+        {
+            Self::df_strategy_with_rows(::polars_tools::strategy::DEFAULT_ROWS)
+        }
+
+        /// `df_strategy` with a custom range of row counts
+        pub fn df_strategy_with_rows(
+            rows: ::std::ops::RangeInclusive<usize>,
+        ) -> ::polars_tools::strategy::proptest::strategy::BoxedStrategy<
+            ::polars::prelude::DataFrame,
+        >
+        where
+            #(for<'__polars> #types: ::polars_tools::strategy::ValueStrategy,)*
+        {
+            ::polars_tools::__private::frame_strategy(rows, |rows| {
+                vec![
+                    #(
+                        ::polars_tools::__private::column_strategy::<#types>(
+                            #names,
+                            #dtypes,
+                            rows,
+                        )
+                    ),*
+                ]
+            })
+        }
+    }
+}
@@ -0,0 +1,268 @@
+//! Helpers generated identically for every struct, as thin calls into
+//! `polars_tools::__private` over the `COLUMNS` / `DTYPES` tables rather than
+//! one expansion of every column per method
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Field, Generics, Ident};
+
+use crate::attrs::{ContainerAttrs, FieldAttrs};
+
+/// `DTYPES` and the per-field `<field>_type` / `<field>_idx` constants and
+/// `<field>_col()` functions, minus those skipped by `skip_consts` and
+/// `skip_col_fns`
+pub(crate) fn field_items(
+    fields: &[&Field],
+    attrs: &[FieldAttrs],
+    dtypes: &[TokenStream],
+    container: &ContainerAttrs,
+) -> TokenStream {
+    let idents: Vec<&Ident> = fields.iter().map(|f| f.ident.as_ref().unwrap()).collect();
+    let columns: Vec<&String> = attrs.iter().map(|attrs| &attrs.column).collect();
+    let indices: Vec<usize> = (0..fields.len()).collect();
+
+    let consts = (!container.skip_consts).then(|| {
+        let type_consts = idents.iter().map(|ident| format_ident!("{}_type", ident));
+        let index_consts = idents.iter().map(|ident| format_ident!("{}_idx", ident));
+        quote! {
+            #(
+                #[allow(non_upper_case_globals)]
+                pub const #type_consts: ::polars_tools::ColumnDType = Self::DTYPES[#indices];
+            )*
+            #(
+                #[allow(non_upper_case_globals)]
+                pub const #index_consts: usize = #indices;
+            )*
+        }
+    });
+    let col_fns = (!container.skip_col_fns).then(|| {
+        let fns = idents.iter().map(|ident| format_ident!("{}_col", ident));
+        quote! {
+            #(
+                pub fn #fns() -> ::polars::prelude::Expr {
+                    ::polars_tools::__private::column_expr(#columns)
+                }
+            )*
+        }
+    });
+
+    quote! {
+        /// Column dtypes in field order
+        pub const DTYPES: &'static [::polars_tools::ColumnDType] =
+            &[#(::polars_tools::ColumnDType::new(|| #dtypes)),*];
+
+        #consts
+        #col_fns
+    }
+}
+
+/// `ExprFor<Struct>` with one expression method per field, `Struct::expr`,
+/// `Struct::exprs()` and the `PolarsExprs` impl; nothing under `skip_expr`
+pub(crate) fn expr_helper(
+    name: &Ident,
+    generics: &Generics,
+    fields: &[&Field],
+    attrs: &[FieldAttrs],
+    container: &ContainerAttrs,
+) -> TokenStream {
+    if container.skip_expr {
+        return TokenStream::new();
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let helper = format_ident!("ExprFor{}", name);
+    let doc = format!(" Column expressions of [`{}`], one method per field", name);
+    let idents: Vec<&Ident> = fields.iter().map(|f| f.ident.as_ref().unwrap()).collect();
+    let columns: Vec<&String> = attrs.iter().map(|attrs| &attrs.column).collect();
+
+    quote! {
+        #[doc = #doc]
+        ///
+        /// The name `ExprFor<Struct>` is stable, so the helper can be named in
+        /// function signatures; generic code can use
+        /// `<Struct as PolarsExprs>::Exprs` instead.
+        #[derive(Clone, Copy, Debug, Default)]
+        pub struct #helper;
+
+        impl #helper {
+            #(
+                pub fn #idents(&self) -> ::polars::prelude::Expr {
+                    ::polars_tools::__private::column_expr(#columns)
+                }
+            )*
+
+            /// Get all column expressions as Vec<Expr> for lazy operations
+            pub fn all_cols(&self) -> Vec<::polars::prelude::Expr> {
+                vec![#(::polars_tools::__private::column_expr(#columns)),*]
+            }
+        }
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            pub const expr: #helper = #helper;
+
+            /// The column expression helper, same as `Self::expr`
+            pub fn exprs() -> #helper {
+                #helper
+            }
+        }
+
+        impl #impl_generics ::polars_tools::PolarsExprs for #name #ty_generics #where_clause {
+            type Exprs = #helper;
+        }
+    }
+}
+
+/// Column list, dtype, expression and empty-frame helpers plus the
+/// `group_by` and aggregation presets
+pub(crate) fn table_helpers() -> TokenStream {
+    quote! {
+        /// Sort specification from column constants, e.g.
+        /// `sort_by(&[Desc(Self::score), Asc(Self::name)])`
+        pub fn sort_by(keys: &[::polars_tools::SortKey]) -> ::polars_tools::SortSpec {
+            ::polars_tools::SortSpec::new(keys)
+        }
+
+        /// Group the frame by key columns, e.g. `group_by(df, [Self::country])`
+        pub fn group_by<'__key>(
+            df: ::polars::prelude::DataFrame,
+            keys: impl IntoIterator<Item = &'__key str>,
+        ) -> ::polars::prelude::LazyGroupBy {
+            ::polars_tools::__private::tables::group_by(df, keys)
+        }
+
+        /// `<field>_sum` aggregation for every numeric field
+        pub fn agg_numeric_sums() -> Vec<::polars::prelude::Expr> {
+            ::polars_tools::__private::numeric_aggregations(
+                Self::COLUMNS,
+                &Self::all_types(),
+                "sum",
+                ::polars::prelude::Expr::sum,
+            )
+        }
+
+        /// `<field>_mean` aggregation for every numeric field
+        pub fn agg_numeric_means() -> Vec<::polars::prelude::Expr> {
+            ::polars_tools::__private::numeric_aggregations(
+                Self::COLUMNS,
+                &Self::all_types(),
+                "mean",
+                ::polars::prelude::Expr::mean,
+            )
+        }
+
+        /// Compare two frames row by row on the schema columns, matching rows by `keys`
+        pub fn diff_rows(
+            left: &::polars::prelude::DataFrame,
+            right: &::polars::prelude::DataFrame,
+            keys: &[&str],
+        ) -> ::polars_tools::Result<::polars_tools::DiffResult> {
+            ::polars_tools::__private::diff_rows(left, right, Self::COLUMNS, keys)
+        }
+
+        /// Per-column statistics chosen by each field's declared dtype
+        pub fn profile(
+            df: &::polars::prelude::DataFrame,
+        ) -> ::polars_tools::Result<::polars_tools::ProfileReport> {
+            ::polars_tools::ProfileReport::from_schema(df, Self::COLUMNS, &Self::all_types())
+        }
+
+        /// `<field>_count` aggregation counting the non-null values of every field
+        pub fn agg_counts() -> Vec<::polars::prelude::Expr> {
+            ::polars_tools::__private::tables::count_exprs(Self::COLUMNS)
+        }
+
+        /// Get all column names as Vec<&str> for use with df.select()
+        pub fn all_columns() -> Vec<&'static str> {
+            Self::COLUMNS.to_vec()
+        }
+
+        /// Get all column types as Vec<DataType>
+        pub fn all_types() -> Vec<::polars::prelude::DataType> {
+            ::polars_tools::__private::tables::dtypes(Self::DTYPES)
+        }
+
+        /// Columns as Polars `Field`s, name and dtype, in field order, e.g.
+        /// for `DataType::Struct(Self::fields())`
+        pub fn fields() -> Vec<::polars::prelude::Field> {
+            ::polars_tools::__private::tables::fields(Self::COLUMNS, Self::DTYPES)
+        }
+
+        /// Get column type at specific index
+        pub fn type_at(index: usize) -> Option<::polars::prelude::DataType> {
+            Self::DTYPES.get(index).map(::polars_tools::ColumnDType::dtype)
+        }
+
+        /// Get all column names as expressions for lazy operations
+        pub fn all_cols() -> Vec<::polars::prelude::Expr> {
+            ::polars_tools::__private::tables::column_exprs(Self::COLUMNS)
+        }
+
+        /// Expressions casting every column to its schema dtype, e.g.
+        /// `lf.with_columns(Self::cast_exprs())` to coerce a frame to the
+        /// schema; values that do not convert become null
+        pub fn cast_exprs() -> Vec<::polars::prelude::Expr> {
+            ::polars_tools::__private::tables::cast_exprs(Self::COLUMNS, Self::DTYPES)
+        }
+
+        /// Column names in field order without those in `except`, e.g.
+        /// `all_columns_except(&[Self::email])`
+        pub fn all_columns_except(except: &[&str]) -> Vec<&'static str> {
+            ::polars_tools::__private::tables::columns_except(Self::COLUMNS, except)
+        }
+
+        /// Column expressions of `all_columns_except(except)`
+        pub fn all_cols_except(except: &[&str]) -> Vec<::polars::prelude::Expr> {
+            ::polars_tools::__private::tables::column_exprs(&Self::all_columns_except(except))
+        }
+    }
+}
+
+/// `df()`, `empty_lazy()` and `lazy()`, the empty frames of the schema;
+/// nothing under `skip_df`
+pub(crate) fn frame_constructors(container: &ContainerAttrs) -> TokenStream {
+    if container.skip_df {
+        return TokenStream::new();
+    }
+    quote! {
+        /// Create an empty DataFrame with the correct schema
+        pub fn df() -> std::result::Result<::polars::prelude::DataFrame, ::polars::prelude::PolarsError> {
+            ::polars_tools::__private::tables::empty_frame(Self::COLUMNS, Self::DTYPES)
+        }
+
+        /// Create an empty LazyFrame with the correct schema, e.g. as the
+        /// seed of a fold over `concat`
+        pub fn empty_lazy() -> ::polars::prelude::LazyFrame {
+            ::polars_tools::__private::tables::empty_lazy(Self::COLUMNS, Self::DTYPES)
+        }
+
+        /// Same as `empty_lazy`
+        pub fn lazy() -> ::polars::prelude::LazyFrame {
+            Self::empty_lazy()
+        }
+    }
+}
+
+/// `columns()`, `column_names()`, `column_name_at(index)` and
+/// `col_expr(name)`, the inherent versions of the `PolarsColumns` methods
+pub(crate) fn column_lookups() -> TokenStream {
+    quote! {
+        /// Implementation of PolarsColumnsExt::columns()
+        pub fn columns() -> Vec<&'static str> {
+            Self::COLUMNS.to_vec()
+        }
+
+        /// Implementation of PolarsColumns::column_names()
+        pub fn column_names() -> Vec<&'static str> {
+            Self::COLUMNS.to_vec()
+        }
+
+        /// Implementation of PolarsColumns::column_name_at()
+        pub fn column_name_at(index: usize) -> Option<&'static str> {
+            Self::COLUMNS.get(index).copied()
+        }
+
+        /// Implementation of PolarsColumns::col_expr()
+        pub fn col_expr(field_name: &str) -> Option<::polars::prelude::Expr> {
+            ::polars_tools::__private::tables::col_expr(Self::COLUMNS, field_name)
+        }
+    }
+}
@@ -0,0 +1,190 @@
+//! Syntactic inspection of field types
+
+use proc_macro2::{Group, Ident, Punct, Spacing, Span, TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::{Field, GenericArgument, Generics, PathArguments, Type};
+
+/// Single generic argument of a path type whose last segment is `wrapper`,
+/// e.g. `T` for `Option<T>` or `std::option::Option<T>`
+pub(crate) fn generic_arg<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    if type_path.qself.is_some() {
+        return None;
+    }
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != wrapper {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    let mut types = args.args.iter().filter_map(|arg| match arg {
+        GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    });
+    match (types.next(), types.next()) {
+        (Some(inner), None) => Some(inner),
+        _ => None,
+    }
+}
+
+/// Value type `V` of a `HashMap<K, V>` or `BTreeMap<K, V>`
+pub(crate) fn map_value(ty: &Type) -> Option<&Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "HashMap" && segment.ident != "BTreeMap" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    args.args
+        .iter()
+        .filter_map(|arg| match arg {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        })
+        .nth(1)
+}
+
+/// Inner type of `Option<T>`
+pub(crate) fn option_inner(ty: &Type) -> Option<&Type> {
+    generic_arg(ty, "Option")
+}
+
+/// Whether the type is a plain path ending in `ident` (no generic arguments)
+pub(crate) fn is_ident(ty: &Type, ident: &str) -> bool {
+    match ty {
+        Type::Path(type_path) if type_path.qself.is_none() => type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|s| s.ident == ident && s.arguments.is_empty()),
+        _ => false,
+    }
+}
+
+/// Whether the type is a byte buffer (`Vec<u8>`), optionally wrapped in `Option`
+pub(crate) fn is_byte_buffer(ty: &Type) -> bool {
+    let ty = option_inner(ty).unwrap_or(ty);
+    generic_arg(ty, "Vec").is_some_and(|inner| is_ident(inner, "u8"))
+}
+
+/// Protobuf well-known message types with a native column representation
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum WellKnown {
+    Timestamp,
+    Duration,
+}
+
+/// Well-known type of a `prost_types::Timestamp` / `prost_types::Duration`
+/// field (or the `pbjson_types` equivalents), optionally wrapped in `Option`
+pub(crate) fn proto_well_known(ty: &Type) -> Option<WellKnown> {
+    let ty = option_inner(ty).unwrap_or(ty);
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segments = &type_path.path.segments;
+    let first = segments.first()?;
+    if segments.len() < 2 || (first.ident != "prost_types" && first.ident != "pbjson_types") {
+        return None;
+    }
+    match segments.last()?.ident.to_string().as_str() {
+        "Timestamp" => Some(WellKnown::Timestamp),
+        "Duration" => Some(WellKnown::Duration),
+        _ => None,
+    }
+}
+
+/// Reject field types that have no sensible column representation, such as
+/// `Option<Option<T>>`, tuples or `Result`, naming the field and type
+pub(crate) fn check_field_type(field: &Field) -> syn::Result<()> {
+    match unsupported_reason(&field.ty) {
+        Some(reason) => Err(syn::Error::new_spanned(
+            &field.ty,
+            format!(
+                "field `{}` has unsupported type `{}`: {}; use #[polars(dtype = ...)] to choose the column type",
+                field.ident.as_ref().unwrap(),
+                field.ty.to_token_stream().to_string().replace(' ', ""),
+                reason,
+            ),
+        )),
+        None => Ok(()),
+    }
+}
+
+fn unsupported_reason(ty: &Type) -> Option<&'static str> {
+    match ty {
+        Type::Tuple(tuple) if !tuple.elems.is_empty() => {
+            Some("tuples cannot be stored in a single column")
+        }
+        Type::Group(group) => unsupported_reason(&group.elem),
+        Type::Paren(paren) => unsupported_reason(&paren.elem),
+        Type::Path(type_path) => {
+            let segment = type_path.path.segments.last()?;
+            if segment.ident == "Result" {
+                return Some("`Result` has no column representation");
+            }
+            if let Some(inner) = option_inner(ty) {
+                if option_inner(inner).is_some() {
+                    return Some("nested `Option` cannot be distinguished from a single null");
+                }
+            }
+            let PathArguments::AngleBracketed(args) = &segment.arguments else {
+                return None;
+            };
+            args.args.iter().find_map(|arg| match arg {
+                GenericArgument::Type(inner) => unsupported_reason(inner),
+                _ => None,
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Reject type and const parameters: field types must be concrete for their
+/// dtype to be resolved, while lifetimes of borrowed fields are fine
+pub(crate) fn check_generics(generics: &Generics, derive: &str) -> syn::Result<()> {
+    match generics.type_params().next().map(|p| &p.ident).or_else(|| {
+        generics.const_params().next().map(|p| &p.ident)
+    }) {
+        Some(param) => Err(syn::Error::new_spanned(
+            param,
+            format!("{derive} supports lifetime parameters only"),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// `tokens` with every lifetime replaced by `'static`, for naming a borrowed
+/// field type outside the struct's impl blocks, e.g. `&'static str` for
+/// `&'a str`
+pub(crate) fn static_lifetimes(tokens: TokenStream) -> TokenStream {
+    let mut out = Vec::new();
+    let mut tokens = tokens.into_iter();
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Punct(punct) if punct.as_char() == '\'' => {
+                let lifetime = tokens.next();
+                let mut apostrophe = Punct::new('\'', Spacing::Joint);
+                apostrophe.set_span(punct.span());
+                out.push(TokenTree::Punct(apostrophe));
+                out.push(TokenTree::Ident(Ident::new(
+                    "static",
+                    lifetime.map_or_else(Span::call_site, |lifetime| lifetime.span()),
+                )));
+            }
+            TokenTree::Group(group) => {
+                let mut replaced = Group::new(group.delimiter(), static_lifetimes(group.stream()));
+                replaced.set_span(group.span());
+                out.push(TokenTree::Group(replaced));
+            }
+            token => out.push(token),
+        }
+    }
+    out.into_iter().collect()
+}
@@ -0,0 +1,114 @@
+//! Generation of the schema checks shared by both derives
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use syn::{Field, Ident};
+
+use crate::attrs::FieldAttrs;
+use crate::types;
+
+/// `Struct::polars_schema()` plus `validate`, `validate_with`,
+/// `validate_lenient`, `debug_validate` and `validate_cached` checking a frame against it,
+/// matching `#[polars(alias = "...")]` columns and ignoring the time unit of
+/// `#[polars(any_time_unit)]` columns, and `anti_validate` / `anti_validate_with`
+/// checking that a frame holds none of the columns
+pub(crate) fn validators(name: &Ident, fields: &[&Field], attrs: &[FieldAttrs]) -> TokenStream {
+    let schema_name = name.to_string();
+    let nullable = fields
+        .iter()
+        .map(|field| types::option_inner(&field.ty).is_some());
+    let column_aliases = attrs.iter().flat_map(|attrs| {
+        let column = &attrs.column;
+        attrs.aliases.iter().map(move |alias| quote!((#alias, #column)))
+    });
+    // Columns whose time unit is not checked by `validate`
+    let any_time_unit_columns = attrs
+        .iter()
+        .filter(|attrs| attrs.any_time_unit)
+        .map(|attrs| &attrs.column);
+
+    quote! {
+        /// Expected schema of the frame, built on first use
+        pub fn polars_schema() -> &'static ::polars::prelude::Schema {
+            static SCHEMA: ::std::sync::OnceLock<::polars::prelude::Schema> =
+                ::std::sync::OnceLock::new();
+            SCHEMA.get_or_init(|| ::polars_tools::__private::tables::schema(Self::COLUMNS, Self::DTYPES))
+        }
+
+        const COLUMN_ALIASES: &'static [(&'static str, &'static str)] = &[#(#column_aliases),*];
+
+        const COLUMN_NULLABLE: &'static [bool] = &[#(#nullable),*];
+
+        pub fn validate(df: &::polars::prelude::DataFrame) -> ::polars_tools::Result<()> {
+            Self::validate_with(df, &::polars_tools::ValidateOptions::default())
+        }
+
+        /// `validate`, matching column names and dtypes as set in `opts`, e.g.
+        /// accepting `Int32` for `Int64` with `allow_widening`
+        pub fn validate_with(
+            df: &::polars::prelude::DataFrame,
+            opts: &::polars_tools::ValidateOptions,
+        ) -> ::polars_tools::Result<()> {
+            let (df, _) = ::polars_tools::__private::match_columns(
+                df,
+                Self::polars_schema(),
+                Self::COLUMN_ALIASES,
+                opts,
+            )?;
+            ::polars_tools::__private::validate_schema_with(
+                &df,
+                Self::polars_schema(),
+                &[#(#any_time_unit_columns),*],
+                Self::COLUMN_NULLABLE,
+                opts,
+            )
+        }
+
+        /// `validate`, letting the columns of `Option` fields be absent
+        pub fn validate_lenient(df: &::polars::prelude::DataFrame) -> ::polars_tools::Result<()> {
+            Self::validate_with(
+                df,
+                &::polars_tools::ValidateOptions::default().allow_missing_optional(),
+            )
+        }
+
+        /// `validate` in builds with debug assertions (or the
+        /// `release-checks` feature of polars-tools), a no-op otherwise
+        pub fn debug_validate(df: &::polars::prelude::DataFrame) -> ::polars_tools::Result<()> {
+            if ::polars_tools::DEBUG_CHECKS {
+                Self::validate(df)
+            } else {
+                Ok(())
+            }
+        }
+
+        /// `validate`, skipped when the same unchanged frame already passed
+        /// it, for frames validated again at every stage of a pipeline
+        pub fn validate_cached(df: &::polars::prelude::DataFrame) -> ::polars_tools::Result<()> {
+            ::polars_tools::__private::validate_cached(df, Self::validate)
+        }
+
+        /// Reject frames holding any column of this schema, under its name or
+        /// an alias, e.g. to keep sensitive columns out of an export; the
+        /// `ForbiddenColumns` error lists the columns found
+        pub fn anti_validate(df: &::polars::prelude::DataFrame) -> ::polars_tools::Result<()> {
+            Self::anti_validate_with(df, &::polars_tools::ValidateOptions::default())
+        }
+
+        /// `anti_validate`, matching column names as set in `opts`, e.g.
+        /// also rejecting `EMAIL` for an `email` field with `case_insensitive`
+        pub fn anti_validate_with(
+            df: &::polars::prelude::DataFrame,
+            opts: &::polars_tools::ValidateOptions,
+        ) -> ::polars_tools::Result<()> {
+            ::polars_tools::__private::anti_validate(
+                df,
+                #schema_name,
+                Self::COLUMNS,
+                Self::COLUMN_ALIASES,
+                opts,
+            )
+        }
+    }
+}
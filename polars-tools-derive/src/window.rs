@@ -0,0 +1,98 @@
+//! Generation of the rolling and `group_by_dynamic` helpers of a
+//! `#[polars(time_index)]` column
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::attrs::FieldAttrs;
+
+/// `Struct::time_index()`, `Struct::rolling_<agg>(column, window)` and
+/// `Struct::rolling_means(window)` by the time column, and
+/// `Struct::group_by_dynamic` / `group_by_dynamic_with` grouping on it,
+/// nothing without a `time_index` field
+pub(crate) fn window_helpers(attrs: &[FieldAttrs]) -> TokenStream {
+    let Some(time) = attrs.iter().find(|attrs| attrs.time_index) else {
+        return TokenStream::new();
+    };
+    let time = &time.column;
+
+    let rolling = ["mean", "sum", "min", "max"].iter().map(|agg| {
+        let helper = format_ident!("rolling_{}", agg);
+        let method = format_ident!("rolling_{}_by", agg);
+        let doc = format!(
+            "Rolling {agg} of `column` over the `window` duration (e.g. `\"7d\"`) \
+             before each row, by `{time}`"
+        );
+        quote! {
+            #[doc = #doc]
+            pub fn #helper(column: &str, window: &str) -> ::polars_tools::Result<::polars::prelude::Expr> {
+                ::polars_tools::__private::rolling_by(
+                    column,
+                    #time,
+                    window,
+                    ::polars::prelude::Expr::#method,
+                )
+            }
+        }
+    });
+    let means_doc = format!(
+        "`<field>_rolling_mean` over the `window` duration by `{time}` for every \
+         numeric field"
+    );
+    let time_doc = format!("Time column of the rolling and dynamic helpers, `{time}`");
+
+    quote! {
+        #[doc = #time_doc]
+        pub fn time_index() -> &'static str {
+            #time
+        }
+
+        #(#rolling)*
+
+        #[doc = #means_doc]
+        pub fn rolling_means(window: &str) -> ::polars_tools::Result<Vec<::polars::prelude::Expr>> {
+            ::polars_tools::__private::numeric_rolling(
+                &Self::all_columns(),
+                &Self::all_types(),
+                #time,
+                window,
+                "mean",
+                ::polars::prelude::Expr::rolling_mean_by,
+            )
+        }
+
+        /// Group the frame, sorted by the time column, into consecutive
+        /// windows of the `every` duration (e.g. `"1w"`) and by key columns
+        pub fn group_by_dynamic<'__key>(
+            df: ::polars::prelude::DataFrame,
+            keys: impl IntoIterator<Item = &'__key str>,
+            every: &str,
+        ) -> ::polars_tools::Result<::polars::prelude::LazyGroupBy> {
+            let keys: Vec<::polars::prelude::Expr> =
+                keys.into_iter().map(::polars::prelude::col).collect();
+            ::polars_tools::__private::group_by_every(
+                ::polars::prelude::IntoLazy::lazy(df),
+                #time,
+                keys,
+                every,
+            )
+        }
+
+        /// `group_by_dynamic` with explicit window options, e.g. overlapping
+        /// windows with a `period` longer than `every`
+        pub fn group_by_dynamic_with<'__key>(
+            df: ::polars::prelude::DataFrame,
+            keys: impl IntoIterator<Item = &'__key str>,
+            options: ::polars::prelude::DynamicGroupOptions,
+        ) -> ::polars::prelude::LazyGroupBy {
+            let keys: Vec<::polars::prelude::Expr> =
+                keys.into_iter().map(::polars::prelude::col).collect();
+            ::polars_tools::__private::group_by_dynamic(
+                ::polars::prelude::IntoLazy::lazy(df),
+                #time,
+                keys,
+                options,
+            )
+        }
+    }
+}
@@ -0,0 +1,739 @@
+//! Typed column access for derived schemas
+
+use polars::prelude::*;
+
+use crate::suggest::missing_column;
+use crate::{Result, ValidatableEnum, ValidationError};
+
+/// Trait for Rust types whose columns can be downcast to a concrete `ChunkedArray`
+///
+/// Used by the generated `Struct::field_series(&df)` getters.
+pub trait ChunkedColumn {
+    /// Chunked array type of the column, e.g. `Int32Chunked` for `i32`
+    type Chunked;
+
+    /// Downcast a column to its chunked array, failing on a dtype mismatch
+    fn downcast(column: &Column) -> PolarsResult<&Self::Chunked>;
+}
+
+macro_rules! impl_chunked_column {
+    ($($ty:ty => $chunked:ty, $method:ident);* $(;)?) => {
+        $(
+            impl ChunkedColumn for $ty {
+                type Chunked = $chunked;
+
+                fn downcast(column: &Column) -> PolarsResult<&$chunked> {
+                    column.as_materialized_series().$method()
+                }
+            }
+        )*
+    };
+}
+
+impl_chunked_column! {
+    i8 => Int8Chunked, i8;
+    i16 => Int16Chunked, i16;
+    i32 => Int32Chunked, i32;
+    i64 => Int64Chunked, i64;
+    i128 => Int128Chunked, i128;
+    u8 => UInt8Chunked, u8;
+    u16 => UInt16Chunked, u16;
+    u32 => UInt32Chunked, u32;
+    u64 => UInt64Chunked, u64;
+    f32 => Float32Chunked, f32;
+    f64 => Float64Chunked, f64;
+    bool => BooleanChunked, bool;
+    String => StringChunked, str;
+    str => StringChunked, str;
+    std::time::Duration => DurationChunked, duration;
+}
+
+impl<T: ChunkedColumn> ChunkedColumn for Option<T> {
+    type Chunked = T::Chunked;
+
+    fn downcast(column: &Column) -> PolarsResult<&Self::Chunked> {
+        T::downcast(column)
+    }
+}
+
+impl<T> ChunkedColumn for Vec<T> {
+    type Chunked = ListChunked;
+
+    fn downcast(column: &Column) -> PolarsResult<&ListChunked> {
+        column.as_materialized_series().list()
+    }
+}
+
+impl<K, V, S> ChunkedColumn for std::collections::HashMap<K, V, S> {
+    type Chunked = ListChunked;
+
+    fn downcast(column: &Column) -> PolarsResult<&ListChunked> {
+        column.as_materialized_series().list()
+    }
+}
+
+impl<K, V> ChunkedColumn for std::collections::BTreeMap<K, V> {
+    type Chunked = ListChunked;
+
+    fn downcast(column: &Column) -> PolarsResult<&ListChunked> {
+        column.as_materialized_series().list()
+    }
+}
+
+impl<T: ValidatableEnum> ChunkedColumn for T {
+    type Chunked = StringChunked;
+
+    fn downcast(column: &Column) -> PolarsResult<&StringChunked> {
+        column.as_materialized_series().str()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl_chunked_column! {
+    chrono::NaiveDate => DateChunked, date;
+    chrono::NaiveDateTime => DatetimeChunked, datetime;
+    chrono::NaiveTime => TimeChunked, time;
+    chrono::DateTime<chrono::Utc> => DatetimeChunked, datetime;
+    chrono::DateTime<chrono::FixedOffset> => DatetimeChunked, datetime;
+    chrono::Duration => DurationChunked, duration;
+}
+
+#[cfg(feature = "chrono-tz")]
+impl_chunked_column! {
+    chrono::DateTime<chrono_tz::Tz> => DatetimeChunked, datetime;
+}
+
+#[cfg(feature = "time")]
+impl_chunked_column! {
+    time::Date => DateChunked, date;
+    time::PrimitiveDateTime => DatetimeChunked, datetime;
+    time::OffsetDateTime => DatetimeChunked, datetime;
+    time::Time => TimeChunked, time;
+    time::Duration => DurationChunked, duration;
+}
+
+#[cfg(feature = "decimal")]
+impl_chunked_column! {
+    rust_decimal::Decimal => DecimalChunked, decimal;
+    u128 => DecimalChunked, decimal;
+}
+
+#[cfg(feature = "uuid")]
+impl_chunked_column! {
+    uuid::Uuid => StringChunked, str;
+}
+
+#[cfg(feature = "bytes")]
+impl_chunked_column! {
+    bytes::Bytes => BinaryChunked, binary;
+}
+
+/// Stand-in for fields stored as `Binary` regardless of their Rust type
+#[doc(hidden)]
+pub struct BinaryColumn;
+
+/// Stand-in for fields stored as `Struct` regardless of their Rust type
+#[doc(hidden)]
+pub struct StructColumn;
+
+/// Stand-in for fields stored as `String` regardless of their Rust type
+#[doc(hidden)]
+pub struct StringColumn;
+
+impl_chunked_column! {
+    BinaryColumn => BinaryChunked, binary;
+    StructColumn => StructChunked, struct_;
+    StringColumn => StringChunked, str;
+}
+
+/// Fetch a column, or an alias standing in for it, and downcast it, reporting
+/// a missing column or wrong dtype as a `ValidationError`
+#[doc(hidden)]
+pub fn typed_column<'a, T: ChunkedColumn + ?Sized>(
+    df: &'a DataFrame,
+    name: &str,
+    aliases: &[(&str, &str)],
+    expected_type: &DataType,
+) -> Result<&'a T::Chunked> {
+    let column = cell_column(df, name, aliases)?;
+
+    T::downcast(column).map_err(|_| ValidationError::TypeMismatch {
+        column_name: name.to_string(),
+        actual_type: format!("{:?}", column.dtype()),
+        expected_type: format!("{:?}", expected_type),
+    })
+}
+
+/// Trait for Rust types that can be read from a single non-null cell
+///
+/// Used by the generated `Struct::field_at(&df, row)` accessors.
+pub trait CellValue {
+    /// Value produced for a non-null cell, e.g. `i32` for both `i32` and `Option<i32>`
+    type Value;
+
+    /// Convert a non-null cell of the named column
+    fn from_any_value(value: &AnyValue<'_>, column_name: &str) -> Result<Self::Value>;
+}
+
+/// Error for a cell whose dtype does not match the expected one
+pub(crate) fn cell_type_mismatch(value: &AnyValue<'_>, column_name: &str, expected: DataType) -> ValidationError {
+    ValidationError::TypeMismatch {
+        column_name: column_name.to_string(),
+        actual_type: format!("{:?}", value.dtype()),
+        expected_type: format!("{:?}", expected),
+    }
+}
+
+/// Error for a cell of the right dtype that the Rust type cannot represent
+pub(crate) fn cell_invalid(value: &AnyValue<'_>, column_name: &str, reason: impl ToString) -> ValidationError {
+    ValidationError::InvalidValue {
+        column_name: column_name.to_string(),
+        value: value.to_string(),
+        reason: reason.to_string(),
+    }
+}
+
+macro_rules! impl_cell_value {
+    ($($ty:ty => $variant:ident),* $(,)?) => {
+        $(
+            impl CellValue for $ty {
+                type Value = $ty;
+
+                fn from_any_value(value: &AnyValue<'_>, column_name: &str) -> Result<$ty> {
+                    match value {
+                        AnyValue::$variant(v) => Ok(*v),
+                        other => Err(cell_type_mismatch(other, column_name, DataType::$variant)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_cell_value! {
+    i8 => Int8,
+    i16 => Int16,
+    i32 => Int32,
+    i64 => Int64,
+    i128 => Int128,
+    u8 => UInt8,
+    u16 => UInt16,
+    u32 => UInt32,
+    u64 => UInt64,
+    f32 => Float32,
+    f64 => Float64,
+    bool => Boolean,
+}
+
+impl CellValue for String {
+    type Value = String;
+
+    fn from_any_value(value: &AnyValue<'_>, column_name: &str) -> Result<String> {
+        match value {
+            AnyValue::String(s) => Ok(s.to_string()),
+            AnyValue::StringOwned(s) => Ok(s.to_string()),
+            other => Err(cell_type_mismatch(other, column_name, DataType::String)),
+        }
+    }
+}
+
+impl CellValue for StringColumn {
+    type Value = String;
+
+    fn from_any_value(value: &AnyValue<'_>, column_name: &str) -> Result<String> {
+        String::from_any_value(value, column_name)
+    }
+}
+
+impl CellValue for BinaryColumn {
+    type Value = Vec<u8>;
+
+    fn from_any_value(value: &AnyValue<'_>, column_name: &str) -> Result<Vec<u8>> {
+        match value {
+            AnyValue::Binary(b) => Ok(b.to_vec()),
+            AnyValue::BinaryOwned(b) => Ok(b.clone()),
+            other => Err(cell_type_mismatch(other, column_name, DataType::Binary)),
+        }
+    }
+}
+
+impl<T: CellValue> CellValue for Option<T> {
+    type Value = T::Value;
+
+    fn from_any_value(value: &AnyValue<'_>, column_name: &str) -> Result<T::Value> {
+        T::from_any_value(value, column_name)
+    }
+}
+
+/// Lists whose items are all non-null
+impl<T: CellValue> CellValue for Vec<T> {
+    type Value = Vec<T::Value>;
+
+    fn from_any_value(value: &AnyValue<'_>, column_name: &str) -> Result<Vec<T::Value>> {
+        let AnyValue::List(items) = value else {
+            return Err(cell_type_mismatch(
+                value,
+                column_name,
+                DataType::List(Box::new(DataType::Null)),
+            ));
+        };
+        items
+            .iter()
+            .map(|item| match item {
+                AnyValue::Null => Err(cell_invalid(value, column_name, "null list item")),
+                item => T::from_any_value(&item, column_name),
+            })
+            .collect()
+    }
+}
+
+impl<T: ValidatableEnum> CellValue for T {
+    type Value = T;
+
+    fn from_any_value(value: &AnyValue<'_>, column_name: &str) -> Result<T> {
+        <T as ValidatableEnum>::from_str(&String::from_any_value(value, column_name)?)
+    }
+}
+
+/// Signed duration in the given unit as a `std::time::Duration`
+impl CellValue for std::time::Duration {
+    type Value = std::time::Duration;
+
+    fn from_any_value(value: &AnyValue<'_>, column_name: &str) -> Result<std::time::Duration> {
+        let AnyValue::Duration(v, unit) = value else {
+            return Err(cell_type_mismatch(
+                value,
+                column_name,
+                DataType::Duration(TimeUnit::Microseconds),
+            ));
+        };
+        let v = u64::try_from(*v).map_err(|_| cell_invalid(value, column_name, "negative duration"))?;
+        Ok(match unit {
+            TimeUnit::Nanoseconds => std::time::Duration::from_nanos(v),
+            TimeUnit::Microseconds => std::time::Duration::from_micros(v),
+            TimeUnit::Milliseconds => std::time::Duration::from_millis(v),
+        })
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_cells {
+    use super::{cell_invalid, cell_type_mismatch, CellValue};
+    use crate::Result;
+    use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+    use polars::prelude::{AnyValue, DataType, TimeUnit};
+
+    /// Days from 0001-01-01 (CE) to 1970-01-01
+    const UNIX_EPOCH_DAYS_FROM_CE: i32 = 719_163;
+
+    fn to_utc(value: &AnyValue<'_>, column_name: &str) -> Result<DateTime<Utc>> {
+        let (AnyValue::Datetime(v, unit, _) | AnyValue::DatetimeOwned(v, unit, _)) = value else {
+            return Err(cell_type_mismatch(
+                value,
+                column_name,
+                DataType::Datetime(TimeUnit::Microseconds, None),
+            ));
+        };
+        let datetime = match unit {
+            TimeUnit::Nanoseconds => Some(DateTime::from_timestamp_nanos(*v)),
+            TimeUnit::Microseconds => DateTime::from_timestamp_micros(*v),
+            TimeUnit::Milliseconds => DateTime::from_timestamp_millis(*v),
+        };
+        datetime.ok_or_else(|| cell_invalid(value, column_name, "timestamp out of range"))
+    }
+
+    impl CellValue for NaiveDate {
+        type Value = NaiveDate;
+
+        fn from_any_value(value: &AnyValue<'_>, column_name: &str) -> Result<NaiveDate> {
+            let AnyValue::Date(days) = value else {
+                return Err(cell_type_mismatch(value, column_name, DataType::Date));
+            };
+            days.checked_add(UNIX_EPOCH_DAYS_FROM_CE)
+                .and_then(NaiveDate::from_num_days_from_ce_opt)
+                .ok_or_else(|| cell_invalid(value, column_name, "date out of range"))
+        }
+    }
+
+    impl CellValue for NaiveDateTime {
+        type Value = NaiveDateTime;
+
+        fn from_any_value(value: &AnyValue<'_>, column_name: &str) -> Result<NaiveDateTime> {
+            to_utc(value, column_name).map(|dt| dt.naive_utc())
+        }
+    }
+
+    impl CellValue for DateTime<Utc> {
+        type Value = DateTime<Utc>;
+
+        fn from_any_value(value: &AnyValue<'_>, column_name: &str) -> Result<DateTime<Utc>> {
+            to_utc(value, column_name)
+        }
+    }
+
+    impl CellValue for DateTime<FixedOffset> {
+        type Value = DateTime<FixedOffset>;
+
+        fn from_any_value(value: &AnyValue<'_>, column_name: &str) -> Result<DateTime<FixedOffset>> {
+            to_utc(value, column_name).map(|dt| dt.fixed_offset())
+        }
+    }
+
+    impl CellValue for NaiveTime {
+        type Value = NaiveTime;
+
+        fn from_any_value(value: &AnyValue<'_>, column_name: &str) -> Result<NaiveTime> {
+            let AnyValue::Time(nanos) = value else {
+                return Err(cell_type_mismatch(value, column_name, DataType::Time));
+            };
+            let secs = (*nanos / 1_000_000_000) as u32;
+            let nano = (*nanos % 1_000_000_000) as u32;
+            NaiveTime::from_num_seconds_from_midnight_opt(secs, nano)
+                .ok_or_else(|| cell_invalid(value, column_name, "time out of range"))
+        }
+    }
+
+    impl CellValue for chrono::Duration {
+        type Value = chrono::Duration;
+
+        fn from_any_value(value: &AnyValue<'_>, column_name: &str) -> Result<chrono::Duration> {
+            match value {
+                AnyValue::Duration(v, TimeUnit::Nanoseconds) => Ok(chrono::Duration::nanoseconds(*v)),
+                AnyValue::Duration(v, TimeUnit::Microseconds) => Ok(chrono::Duration::microseconds(*v)),
+                AnyValue::Duration(v, TimeUnit::Milliseconds) => Ok(chrono::Duration::milliseconds(*v)),
+                other => Err(cell_type_mismatch(
+                    other,
+                    column_name,
+                    DataType::Duration(TimeUnit::Microseconds),
+                )),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl CellValue for rust_decimal::Decimal {
+    type Value = rust_decimal::Decimal;
+
+    fn from_any_value(value: &AnyValue<'_>, column_name: &str) -> Result<rust_decimal::Decimal> {
+        let AnyValue::Decimal(v, scale) = value else {
+            return Err(cell_type_mismatch(
+                value,
+                column_name,
+                <rust_decimal::Decimal as crate::PolarsDType>::dtype(),
+            ));
+        };
+        rust_decimal::Decimal::try_from_i128_with_scale(*v, *scale as u32)
+            .map_err(|err| cell_invalid(value, column_name, err))
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl CellValue for uuid::Uuid {
+    type Value = uuid::Uuid;
+
+    fn from_any_value(value: &AnyValue<'_>, column_name: &str) -> Result<uuid::Uuid> {
+        uuid::Uuid::parse_str(&String::from_any_value(value, column_name)?)
+            .map_err(|err| cell_invalid(value, column_name, err))
+    }
+}
+
+/// Column `name` of `df` or, when it is missing, the first of its
+/// `(alias, column)` aliases present, as `validate` accepts them, reporting
+/// it as missing with suggestions
+#[doc(hidden)]
+pub fn cell_column<'a>(df: &'a DataFrame, name: &str, aliases: &[(&str, &str)]) -> Result<&'a Column> {
+    df.column(name)
+        .ok()
+        .or_else(|| {
+            aliases
+                .iter()
+                .filter(|(_, column)| *column == name)
+                .find_map(|(alias, _)| df.column(alias).ok())
+        })
+        .ok_or_else(|| missing_column(name, df.get_column_names_str()))
+}
+
+/// Read a single cell of a column, reporting missing columns, rows past the
+/// end of the frame and undecodable values as a `ValidationError`
+#[doc(hidden)]
+pub fn typed_cell<T: CellValue + ?Sized>(
+    df: &DataFrame,
+    name: &str,
+    aliases: &[(&str, &str)],
+    row: usize,
+) -> Result<Option<T::Value>> {
+    column_cell::<T>(cell_column(df, name, aliases)?, name, row)
+}
+
+/// `typed_cell` of a column looked up beforehand, for reading row after row
+/// without resolving the column name each time
+#[doc(hidden)]
+pub fn column_cell<T: CellValue + ?Sized>(
+    column: &Column,
+    name: &str,
+    row: usize,
+) -> Result<Option<T::Value>> {
+    if row >= column.len() {
+        return Err(ValidationError::RowOutOfBounds {
+            row,
+            height: column.len(),
+        });
+    }
+
+    match column.get(row) {
+        Ok(AnyValue::Null) => Ok(None),
+        Ok(value) => T::from_any_value(&value, name).map(Some),
+        Err(err) => Err(ValidationError::InvalidValue {
+            column_name: name.to_string(),
+            value: format!("row {}", row),
+            reason: err.to_string(),
+        }),
+    }
+}
+
+/// Read a single cell of a non-`Option` field, rejecting nulls
+#[doc(hidden)]
+pub fn required_cell<T: CellValue + ?Sized>(
+    df: &DataFrame,
+    name: &str,
+    aliases: &[(&str, &str)],
+    row: usize,
+) -> Result<T::Value> {
+    required_column_cell::<T>(cell_column(df, name, aliases)?, name, row)
+}
+
+/// `required_cell` of a column looked up beforehand
+#[doc(hidden)]
+pub fn required_column_cell<T: CellValue + ?Sized>(
+    column: &Column,
+    name: &str,
+    row: usize,
+) -> Result<T::Value> {
+    column_cell::<T>(column, name, row)?.ok_or_else(|| ValidationError::InvalidValue {
+        column_name: name.to_string(),
+        value: "null".to_string(),
+        reason: format!("row {} of a non-Option field is null", row),
+    })
+}
+
+/// Check that a row of values has one value per column of the schema
+#[doc(hidden)]
+pub fn row_width(values: &[AnyValue<'_>], expected: usize) -> Result<()> {
+    if values.len() != expected {
+        return Err(ValidationError::RowWidthMismatch {
+            expected,
+            actual: values.len(),
+        });
+    }
+    Ok(())
+}
+
+/// `column_cell` of the value at `position` of a row, the error wrapped in
+/// `RowValue` with that position
+#[doc(hidden)]
+pub fn value_cell<T: CellValue + ?Sized>(
+    values: &[AnyValue<'_>],
+    name: &str,
+    position: usize,
+) -> Result<Option<T::Value>> {
+    match &values[position] {
+        AnyValue::Null => Ok(None),
+        value => T::from_any_value(value, name)
+            .map(Some)
+            .map_err(|source| ValidationError::RowValue {
+                position,
+                source: Box::new(source),
+            }),
+    }
+}
+
+/// `value_cell` of a non-`Option` field, rejecting nulls
+#[doc(hidden)]
+pub fn required_value_cell<T: CellValue + ?Sized>(
+    values: &[AnyValue<'_>],
+    name: &str,
+    position: usize,
+) -> Result<T::Value> {
+    value_cell::<T>(values, name, position)?.ok_or_else(|| ValidationError::RowValue {
+        position,
+        source: Box::new(ValidationError::InvalidValue {
+            column_name: name.to_string(),
+            value: "null".to_string(),
+            reason: "non-Option field is null".to_string(),
+        }),
+    })
+}
+
+/// Replace or add a column after checking its dtype against the schema,
+/// renaming the values to the column name
+#[doc(hidden)]
+pub fn with_typed_column(
+    mut df: DataFrame,
+    name: &str,
+    mut values: Series,
+    expected_type: &DataType,
+    any_time_unit: bool,
+) -> Result<DataFrame> {
+    let matches = if any_time_unit {
+        crate::dtype::dtype_eq_ignoring_time_unit(values.dtype(), expected_type)
+    } else {
+        values.dtype() == expected_type
+    };
+    if !matches {
+        return Err(ValidationError::TypeMismatch {
+            column_name: name.to_string(),
+            actual_type: format!("{:?}", values.dtype()),
+            expected_type: format!("{:?}", expected_type),
+        });
+    }
+
+    let (expected, actual) = (df.height(), values.len());
+    let length_mismatch = || ValidationError::LengthMismatch {
+        column_name: name.to_string(),
+        expected,
+        actual,
+    };
+    if df.width() > 0 && actual != expected {
+        return Err(length_mismatch());
+    }
+
+    values.rename(name.into());
+    df.with_column(values).map_err(|_| length_mismatch())?;
+    Ok(df)
+}
+
+/// Trait for Rust values that can be written back as a single cell, the
+/// inverse of `CellValue`
+///
+/// Used to build columns from generated values (`fake` and `proptest`
+/// features); the resulting cells are cast to the schema dtype afterwards.
+pub trait ToAnyValue {
+    fn to_any_value(&self) -> AnyValue<'static>;
+}
+
+macro_rules! impl_to_any_value {
+    ($($ty:ty => $variant:ident),* $(,)?) => {
+        $(
+            impl ToAnyValue for $ty {
+                fn to_any_value(&self) -> AnyValue<'static> {
+                    AnyValue::$variant(*self)
+                }
+            }
+        )*
+    };
+}
+
+impl_to_any_value! {
+    i8 => Int8,
+    i16 => Int16,
+    i32 => Int32,
+    i64 => Int64,
+    i128 => Int128,
+    u8 => UInt8,
+    u16 => UInt16,
+    u32 => UInt32,
+    u64 => UInt64,
+    f32 => Float32,
+    f64 => Float64,
+    bool => Boolean,
+}
+
+impl ToAnyValue for String {
+    fn to_any_value(&self) -> AnyValue<'static> {
+        AnyValue::StringOwned(self.as_str().into())
+    }
+}
+
+impl ToAnyValue for &str {
+    fn to_any_value(&self) -> AnyValue<'static> {
+        AnyValue::StringOwned((*self).into())
+    }
+}
+
+impl ToAnyValue for std::borrow::Cow<'_, str> {
+    fn to_any_value(&self) -> AnyValue<'static> {
+        AnyValue::StringOwned(self.as_ref().into())
+    }
+}
+
+impl<T: ToAnyValue> ToAnyValue for Option<T> {
+    fn to_any_value(&self) -> AnyValue<'static> {
+        self.as_ref().map_or(AnyValue::Null, T::to_any_value)
+    }
+}
+
+impl<T: ToAnyValue> ToAnyValue for Vec<T> {
+    fn to_any_value(&self) -> AnyValue<'static> {
+        let items: Vec<_> = self.iter().map(T::to_any_value).collect();
+        match Series::from_any_values(PlSmallStr::EMPTY, &items, false) {
+            Ok(series) => AnyValue::List(series),
+            Err(_) => AnyValue::Null,
+        }
+    }
+}
+
+impl<T: ValidatableEnum> ToAnyValue for T {
+    fn to_any_value(&self) -> AnyValue<'static> {
+        AnyValue::StringOwned(self.to_str().into())
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_any_values {
+    use super::ToAnyValue;
+    use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+    use polars::prelude::{AnyValue, TimeUnit};
+
+    impl ToAnyValue for NaiveDate {
+        fn to_any_value(&self) -> AnyValue<'static> {
+            AnyValue::Date((*self - DateTime::UNIX_EPOCH.date_naive()).num_days() as i32)
+        }
+    }
+
+    impl ToAnyValue for NaiveDateTime {
+        fn to_any_value(&self) -> AnyValue<'static> {
+            AnyValue::Datetime(
+                self.and_utc().timestamp_micros(),
+                TimeUnit::Microseconds,
+                None,
+            )
+        }
+    }
+
+    impl ToAnyValue for DateTime<Utc> {
+        fn to_any_value(&self) -> AnyValue<'static> {
+            AnyValue::Datetime(self.timestamp_micros(), TimeUnit::Microseconds, None)
+        }
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl ToAnyValue for uuid::Uuid {
+    fn to_any_value(&self) -> AnyValue<'static> {
+        AnyValue::StringOwned(self.to_string().into())
+    }
+}
+
+/// Build a column from written-back cells, converting them to `dtype`
+#[doc(hidden)]
+pub fn column_from_values(
+    name: &str,
+    values: &[AnyValue<'static>],
+    dtype: &DataType,
+) -> PolarsResult<Column> {
+    Series::from_any_values_and_dtype(name.into(), values, dtype, false).map(Column::from)
+}
+
+/// Written-back cell converted to `dtype`, or left as written when the
+/// conversion does not apply, e.g. for enum fields stored as `Categorical`
+#[doc(hidden)]
+pub fn row_value(value: AnyValue<'static>, dtype: &'static DataType) -> AnyValue<'static> {
+    if value.is_null() || &value.dtype() == dtype {
+        return value;
+    }
+    match value.strict_cast(dtype) {
+        Some(cast) => cast.into_static(),
+        None => value,
+    }
+}
@@ -0,0 +1,20 @@
+//! Aggregation presets for derived schemas
+
+use polars::prelude::*;
+
+/// Apply `agg` to every numeric column, aliasing each result as
+/// `<column>_<suffix>`
+#[doc(hidden)]
+pub fn numeric_aggregations(
+    columns: &[&str],
+    dtypes: &[DataType],
+    suffix: &str,
+    agg: fn(Expr) -> Expr,
+) -> Vec<Expr> {
+    columns
+        .iter()
+        .zip(dtypes)
+        .filter(|(_, dtype)| dtype.is_primitive_numeric())
+        .map(|(column, _)| agg(col(*column)).alias(format!("{}_{}", column, suffix)))
+        .collect()
+}
@@ -0,0 +1,156 @@
+//! Test assertions for frames checked against a derived schema
+//!
+//! The macros panic with a report listing every problem at once (missing
+//! columns, dtype differences, differing rows) instead of the first
+//! `ValidationError`:
+//!
+//! ```ignore
+//! assert_valid!(User, df);
+//! assert_df_eq_schema_subset!(User, actual, expected);
+//! ```
+
+use std::fmt::Write;
+
+use polars::prelude::*;
+
+/// Number of differing cells listed by `frame_differences`
+const MAX_REPORTED_DIFFERENCES: usize = 10;
+
+/// Missing columns and dtype differences of `df` against the schema, or
+/// `None` when every column is present with the expected dtype
+pub fn schema_differences(
+    df: &DataFrame,
+    columns: &[&str],
+    dtypes: &[DataType],
+) -> Option<String> {
+    let mut report = String::new();
+
+    for (name, expected) in columns.iter().zip(dtypes) {
+        match df.column(name) {
+            Err(_) => writeln!(report, "  missing column `{}` ({})", name, expected).unwrap(),
+            Ok(column) if column.dtype() != expected => writeln!(
+                report,
+                "  column `{}`: expected {}, found {}",
+                name,
+                expected,
+                column.dtype()
+            )
+            .unwrap(),
+            Ok(_) => {}
+        }
+    }
+
+    (!report.is_empty()).then_some(report)
+}
+
+/// Differences between two frames restricted to the schema columns: schema
+/// problems of either side, row counts and the first differing cells
+///
+/// Returns `None` when the frames are equal on those columns. Extra columns
+/// outside the schema are ignored.
+pub fn frame_differences(
+    left: &DataFrame,
+    right: &DataFrame,
+    columns: &[&str],
+    dtypes: &[DataType],
+) -> Option<String> {
+    let mut report = String::new();
+
+    for (side, df) in [("left", left), ("right", right)] {
+        if let Some(differences) = schema_differences(df, columns, dtypes) {
+            write!(report, "{} frame does not match the schema:\n{}", side, differences).unwrap();
+        }
+    }
+    if !report.is_empty() {
+        return Some(report);
+    }
+
+    if left.height() != right.height() {
+        writeln!(
+            report,
+            "  row count: left has {}, right has {}",
+            left.height(),
+            right.height()
+        )
+        .unwrap();
+    }
+
+    let mut reported = 0;
+    'rows: for row in 0..left.height().min(right.height()) {
+        for name in columns {
+            let (Ok(l), Ok(r)) = (left.column(name), right.column(name)) else {
+                continue;
+            };
+            let (l, r) = (l.get(row).ok(), r.get(row).ok());
+            if l != r {
+                if reported == MAX_REPORTED_DIFFERENCES {
+                    writeln!(report, "  ...").unwrap();
+                    break 'rows;
+                }
+                writeln!(
+                    report,
+                    "  row {}, column `{}`: left {}, right {}",
+                    row,
+                    name,
+                    l.map_or("?".to_string(), |v| v.to_string()),
+                    r.map_or("?".to_string(), |v| v.to_string()),
+                )
+                .unwrap();
+                reported += 1;
+            }
+        }
+    }
+
+    (!report.is_empty()).then_some(report)
+}
+
+/// Assert that a frame passes `Struct::validate`, panicking with every
+/// missing column and dtype difference
+#[macro_export]
+macro_rules! assert_valid {
+    ($schema:ty, $df:expr $(,)?) => {{
+        let df: &$crate::DataFrame = &$df;
+        if let Err(err) = <$schema>::validate(df) {
+            panic!(
+                "DataFrame does not match schema `{}`: {}\n{}",
+                stringify!($schema),
+                err,
+                $crate::assertions::schema_differences(
+                    df,
+                    &<$schema>::all_columns(),
+                    &<$schema>::all_types(),
+                )
+                .unwrap_or_default(),
+            );
+        }
+    }};
+}
+
+/// Alias of `assert_valid!` for snapshot-style tests
+#[macro_export]
+macro_rules! assert_df_matches_schema {
+    ($($args:tt)+) => {
+        $crate::assert_valid!($($args)+)
+    };
+}
+
+/// Assert that two frames are equal on the schema columns, panicking with the
+/// schema problems, row counts and first differing cells
+#[macro_export]
+macro_rules! assert_df_eq_schema_subset {
+    ($schema:ty, $left:expr, $right:expr $(,)?) => {{
+        let (left, right): (&$crate::DataFrame, &$crate::DataFrame) = (&$left, &$right);
+        if let Some(report) = $crate::assertions::frame_differences(
+            left,
+            right,
+            &<$schema>::all_columns(),
+            &<$schema>::all_types(),
+        ) {
+            panic!(
+                "DataFrames differ on the columns of `{}`:\n{}",
+                stringify!($schema),
+                report
+            );
+        }
+    }};
+}
@@ -0,0 +1,489 @@
+//! Avro schemas and object container files driven by a derived schema
+//!
+//! Files are written uncompressed (the `null` codec) with a single data
+//! block. Reading accepts any number of blocks but only the `null` codec, and
+//! rejects files whose record fields differ from the derived schema.
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::io::{Read, Write};
+
+use polars::prelude::*;
+use serde_json::{json, Value};
+
+use crate::{Result, ValidationError};
+
+const MAGIC: &[u8; 4] = b"Obj\x01";
+
+fn avro_error(message: impl Into<String>) -> ValidationError {
+    ValidationError::Avro(message.into())
+}
+
+/// Avro record schema of a derived schema; `nullable` marks the `Option`
+/// fields, whose type becomes a `["null", T]` union, and `docs` gives the
+/// `doc` of documented fields
+pub fn avro_schema(
+    name: &str,
+    schema: &Schema,
+    nullable: &[bool],
+    docs: &[(&str, &str)],
+) -> Result<Value> {
+    let fields = schema
+        .iter()
+        .zip(nullable)
+        .map(|((column, dtype), nullable)| {
+            let avro_type = avro_type(&format!("{}_{}", name, column), dtype, false)?;
+            let mut field = json!({
+                "name": column.as_str(),
+                "type": if *nullable { json!(["null", avro_type]) } else { avro_type },
+            });
+            if let Some((_, doc)) = docs
+                .iter()
+                .find(|(doc_column, _)| *doc_column == column.as_str())
+            {
+                field["doc"] = json!(doc);
+            }
+            Ok(field)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(json!({ "type": "record", "name": name, "fields": fields }))
+}
+
+/// Avro type of a dtype; values nested in lists and structs are always
+/// nullable, and decimals are only supported as top-level columns
+#[cfg_attr(not(feature = "decimal"), allow(unused_variables))]
+fn avro_type(path: &str, dtype: &DataType, nested: bool) -> Result<Value> {
+    Ok(match dtype {
+        DataType::Boolean => json!("boolean"),
+        DataType::Int8 | DataType::Int16 | DataType::Int32 | DataType::UInt8 | DataType::UInt16 => {
+            json!("int")
+        }
+        DataType::Int64 | DataType::UInt32 | DataType::UInt64 | DataType::Duration(_) => {
+            json!("long")
+        }
+        DataType::Float32 => json!("float"),
+        DataType::Float64 => json!("double"),
+        DataType::String => json!("string"),
+        DataType::Binary => json!("bytes"),
+        DataType::Date => json!({ "type": "int", "logicalType": "date" }),
+        DataType::Time => json!({ "type": "long", "logicalType": "time-micros" }),
+        DataType::Datetime(unit, _) => {
+            let logical_type = match unit {
+                TimeUnit::Milliseconds => "timestamp-millis",
+                TimeUnit::Microseconds => "timestamp-micros",
+                TimeUnit::Nanoseconds => "timestamp-nanos",
+            };
+            json!({ "type": "long", "logicalType": logical_type })
+        }
+        #[cfg(feature = "decimal")]
+        DataType::Decimal(precision, scale) if !nested => json!({
+            "type": "bytes",
+            "logicalType": "decimal",
+            "precision": precision.unwrap_or(38),
+            "scale": scale.unwrap_or(0),
+        }),
+        DataType::List(inner) => json!({
+            "type": "array",
+            "items": ["null", avro_type(path, inner, true)?],
+        }),
+        DataType::Struct(fields) => {
+            let fields = fields
+                .iter()
+                .map(|field| {
+                    let path = format!("{}_{}", path, field.name());
+                    Ok(json!({
+                        "name": field.name().as_str(),
+                        "type": ["null", avro_type(&path, field.dtype(), true)?],
+                    }))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            json!({ "type": "record", "name": path, "fields": fields })
+        }
+        other => {
+            return Err(avro_error(format!(
+                "dtype {} of '{}' has no Avro representation",
+                other, path
+            )))
+        }
+    })
+}
+
+/// Write the columns of `schema` as an Avro object container file
+pub fn write_avro<W: Write>(
+    mut writer: W,
+    df: &DataFrame,
+    name: &str,
+    schema: &Schema,
+    nullable: &[bool],
+) -> Result<()> {
+    let avro_schema = avro_schema(name, schema, nullable, &[])?;
+    let columns = schema
+        .iter()
+        .map(|(column, _)| {
+            Ok(df
+                .column(column.as_str())?
+                .as_materialized_series()
+                .to_physical_repr()
+                .rechunk())
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut block = Vec::new();
+    for row in 0..df.height() {
+        for ((series, (column, dtype)), nullable) in columns.iter().zip(schema.iter()).zip(nullable)
+        {
+            let value = series.get(row)?;
+            if matches!(value, AnyValue::Null) && !nullable {
+                return Err(ValidationError::InvalidValue {
+                    column_name: column.to_string(),
+                    value: "null".to_string(),
+                    reason: "null in a non-nullable Avro field".to_string(),
+                });
+            }
+            encode_value(&mut block, column, value, dtype, *nullable)?;
+        }
+    }
+
+    let hasher = RandomState::new();
+    let mut sync = [0u8; 16];
+    for (i, half) in sync.chunks_mut(8).enumerate() {
+        let mut state = hasher.build_hasher();
+        state.write_usize(i);
+        half.copy_from_slice(&state.finish().to_le_bytes());
+    }
+
+    let mut out = MAGIC.to_vec();
+    encode_long(&mut out, 2);
+    encode_bytes(&mut out, b"avro.schema");
+    encode_bytes(&mut out, avro_schema.to_string().as_bytes());
+    encode_bytes(&mut out, b"avro.codec");
+    encode_bytes(&mut out, b"null");
+    encode_long(&mut out, 0);
+    out.extend_from_slice(&sync);
+    if df.height() > 0 {
+        encode_long(&mut out, df.height() as i64);
+        encode_long(&mut out, block.len() as i64);
+        out.extend_from_slice(&block);
+        out.extend_from_slice(&sync);
+    }
+    writer
+        .write_all(&out)
+        .map_err(|err| avro_error(err.to_string()))
+}
+
+fn encode_long(out: &mut Vec<u8>, value: i64) {
+    let mut zigzag = ((value << 1) ^ (value >> 63)) as u64;
+    while zigzag >= 0x80 {
+        out.push((zigzag as u8) | 0x80);
+        zigzag >>= 7;
+    }
+    out.push(zigzag as u8);
+}
+
+fn encode_bytes(out: &mut Vec<u8>, bytes: &[u8]) {
+    encode_long(out, bytes.len() as i64);
+    out.extend_from_slice(bytes);
+}
+
+/// Encode a physical value of a column of logical type `dtype`
+fn encode_value(
+    out: &mut Vec<u8>,
+    column: &str,
+    value: AnyValue<'_>,
+    dtype: &DataType,
+    nullable: bool,
+) -> Result<()> {
+    if nullable {
+        if matches!(value, AnyValue::Null) {
+            encode_long(out, 0);
+            return Ok(());
+        }
+        encode_long(out, 1);
+    }
+    let mismatch = |value: &AnyValue<'_>| {
+        avro_error(format!(
+            "unexpected value {} in column '{}' of dtype {}",
+            value, column, dtype
+        ))
+    };
+    match (value, dtype) {
+        (AnyValue::Boolean(b), _) => out.push(b as u8),
+        (AnyValue::Int8(i), _) => encode_long(out, i.into()),
+        (AnyValue::Int16(i), _) => encode_long(out, i.into()),
+        (AnyValue::Int32(i), _) => encode_long(out, i.into()),
+        (AnyValue::UInt8(u), _) => encode_long(out, u.into()),
+        (AnyValue::UInt16(u), _) => encode_long(out, u.into()),
+        (AnyValue::UInt32(u), _) => encode_long(out, u.into()),
+        (AnyValue::Int64(i), DataType::Time) => encode_long(out, i / 1_000),
+        (AnyValue::Int64(i), _) => encode_long(out, i),
+        (AnyValue::UInt64(u), _) => {
+            let value = AnyValue::UInt64(u);
+            encode_long(out, i64::try_from(u).map_err(|_| mismatch(&value))?)
+        }
+        (AnyValue::Int128(i), _) => {
+            // Big-endian two's complement, without redundant sign bytes
+            let bytes = i.to_be_bytes();
+            let mut start = 0;
+            while start < 15
+                && ((bytes[start] == 0 && bytes[start + 1] < 0x80)
+                    || (bytes[start] == 0xff && bytes[start + 1] >= 0x80))
+            {
+                start += 1;
+            }
+            encode_bytes(out, &bytes[start..]);
+        }
+        (AnyValue::Float32(f), _) => out.extend_from_slice(&f.to_le_bytes()),
+        (AnyValue::Float64(f), _) => out.extend_from_slice(&f.to_le_bytes()),
+        (AnyValue::String(s), _) => encode_bytes(out, s.as_bytes()),
+        (AnyValue::StringOwned(s), _) => encode_bytes(out, s.as_bytes()),
+        (AnyValue::Binary(b), _) => encode_bytes(out, b),
+        (AnyValue::BinaryOwned(b), _) => encode_bytes(out, &b),
+        (AnyValue::List(items), DataType::List(inner)) => {
+            if !items.is_empty() {
+                encode_long(out, items.len() as i64);
+                for item in items.rechunk().iter() {
+                    encode_value(out, column, item, inner, true)?;
+                }
+            }
+            encode_long(out, 0);
+        }
+        (value @ AnyValue::Struct(..), DataType::Struct(fields)) => {
+            for (item, field) in value._iter_struct_av().zip(fields) {
+                encode_value(out, column, item, field.dtype(), true)?;
+            }
+        }
+        (value, _) => return Err(mismatch(&value)),
+    }
+    Ok(())
+}
+
+/// Read an Avro object container file into a frame with the columns of
+/// `schema`, after checking that its record fields match `avro_schema`
+pub fn read_avro<R: Read>(
+    mut reader: R,
+    name: &str,
+    schema: &Schema,
+    nullable: &[bool],
+) -> Result<DataFrame> {
+    let expected = avro_schema(name, schema, nullable, &[])?;
+    let mut bytes = Vec::new();
+    reader
+        .read_to_end(&mut bytes)
+        .map_err(|err| avro_error(err.to_string()))?;
+    let mut input = Decoder { bytes: &bytes };
+
+    if input.take(4)? != MAGIC {
+        return Err(avro_error("not an Avro object container file"));
+    }
+    let mut file_schema = None;
+    let mut codec = b"null".to_vec();
+    loop {
+        let count = input.block_count()?;
+        if count == 0 {
+            break;
+        }
+        for _ in 0..count {
+            let key = input.bytes()?.to_vec();
+            let value = input.bytes()?;
+            match key.as_slice() {
+                b"avro.schema" => {
+                    file_schema = Some(
+                        serde_json::from_slice::<Value>(value)
+                            .map_err(|err| avro_error(err.to_string()))?,
+                    )
+                }
+                b"avro.codec" => codec = value.to_vec(),
+                _ => {}
+            }
+        }
+    }
+    if codec != b"null" {
+        return Err(avro_error(format!(
+            "unsupported Avro codec {}",
+            String::from_utf8_lossy(&codec)
+        )));
+    }
+    let file_schema = file_schema.ok_or_else(|| avro_error("missing avro.schema metadata"))?;
+    if file_schema.get("fields") != expected.get("fields") {
+        return Err(avro_error(format!(
+            "file schema {} does not match {}",
+            file_schema, expected
+        )));
+    }
+    let sync = input.take(16)?.to_vec();
+
+    let mut values: Vec<Vec<AnyValue<'static>>> = vec![Vec::new(); schema.len()];
+    while !input.bytes.is_empty() {
+        let rows = input.long()?;
+        input.long()?;
+        for _ in 0..rows {
+            for ((column, (_, dtype)), nullable) in
+                values.iter_mut().zip(schema.iter()).zip(nullable)
+            {
+                column.push(input.value(dtype, *nullable)?);
+            }
+        }
+        if input.take(16)? != sync.as_slice() {
+            return Err(avro_error("corrupt Avro block: sync marker mismatch"));
+        }
+    }
+
+    let columns = schema
+        .iter()
+        .zip(values)
+        .map(|((column, dtype), values)| {
+            let physical = Series::from_any_values_and_dtype(
+                column.clone(),
+                &values,
+                &dtype.to_physical(),
+                true,
+            )?;
+            Ok(Column::from(from_physical(physical, dtype)?))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(DataFrame::new(columns)?)
+}
+
+fn from_physical(series: Series, dtype: &DataType) -> Result<Series> {
+    Ok(match dtype {
+        #[cfg(feature = "decimal")]
+        DataType::Decimal(precision, scale) => series
+            .i128()?
+            .clone()
+            .into_decimal_unchecked(*precision, scale.unwrap_or(0))
+            .into_series(),
+        _ => series.cast(dtype)?,
+    })
+}
+
+struct Decoder<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Decoder<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8]> {
+        if self.bytes.len() < n {
+            return Err(avro_error("unexpected end of Avro data"));
+        }
+        let (head, tail) = self.bytes.split_at(n);
+        self.bytes = tail;
+        Ok(head)
+    }
+
+    fn long(&mut self) -> Result<i64> {
+        let mut zigzag = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.take(1)?[0];
+            zigzag |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok((zigzag >> 1) as i64 ^ -((zigzag & 1) as i64));
+            }
+        }
+        Err(avro_error("invalid Avro varint"))
+    }
+
+    fn bytes(&mut self) -> Result<&'a [u8]> {
+        let len = self.long()?;
+        let len = usize::try_from(len).map_err(|_| avro_error("negative Avro length"))?;
+        self.take(len)
+    }
+
+    /// Item count of an array or map block; negative counts are followed by
+    /// the block size in bytes
+    fn block_count(&mut self) -> Result<i64> {
+        let count = self.long()?;
+        if count < 0 {
+            self.long()?;
+        }
+        Ok(count.abs())
+    }
+
+    /// Decode a value of logical type `dtype` into its physical `AnyValue`
+    fn value(&mut self, dtype: &DataType, nullable: bool) -> Result<AnyValue<'static>> {
+        if nullable {
+            match self.long()? {
+                0 => return Ok(AnyValue::Null),
+                1 => {}
+                branch => return Err(avro_error(format!("invalid union branch {}", branch))),
+            }
+        }
+        Ok(match dtype {
+            DataType::Boolean => AnyValue::Boolean(self.take(1)?[0] != 0),
+            DataType::Int8
+            | DataType::Int16
+            | DataType::Int32
+            | DataType::UInt8
+            | DataType::UInt16
+            | DataType::Date => AnyValue::Int32(self.long()? as i32),
+            DataType::Time => AnyValue::Int64(self.long()? * 1_000),
+            DataType::Int64
+            | DataType::UInt32
+            | DataType::UInt64
+            | DataType::Duration(_)
+            | DataType::Datetime(_, _) => AnyValue::Int64(self.long()?),
+            DataType::Float32 => {
+                AnyValue::Float32(f32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+            }
+            DataType::Float64 => {
+                AnyValue::Float64(f64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+            }
+            DataType::String => AnyValue::StringOwned(
+                std::str::from_utf8(self.bytes()?)
+                    .map_err(|err| avro_error(err.to_string()))?
+                    .into(),
+            ),
+            DataType::Binary => AnyValue::BinaryOwned(self.bytes()?.to_vec()),
+            #[cfg(feature = "decimal")]
+            DataType::Decimal(_, _) => {
+                let bytes = self.bytes()?;
+                if bytes.len() > 16 {
+                    return Err(avro_error("Avro decimal wider than 128 bits"));
+                }
+                let fill = if bytes.first().is_some_and(|b| b & 0x80 != 0) {
+                    0xff
+                } else {
+                    0
+                };
+                let mut wide = [fill; 16];
+                wide[16 - bytes.len()..].copy_from_slice(bytes);
+                AnyValue::Int128(i128::from_be_bytes(wide))
+            }
+            DataType::List(inner) => {
+                let mut items = Vec::new();
+                loop {
+                    let count = self.block_count()?;
+                    if count == 0 {
+                        break;
+                    }
+                    for _ in 0..count {
+                        items.push(self.value(inner, true)?);
+                    }
+                }
+                let physical = inner.to_physical();
+                AnyValue::List(Series::from_any_values_and_dtype(
+                    PlSmallStr::EMPTY,
+                    &items,
+                    &physical,
+                    true,
+                )?)
+            }
+            DataType::Struct(fields) => {
+                let values = fields
+                    .iter()
+                    .map(|field| self.value(field.dtype(), true))
+                    .collect::<Result<Vec<_>>>()?;
+                let physical = fields
+                    .iter()
+                    .map(|field| Field::new(field.name().clone(), field.dtype().to_physical()))
+                    .collect();
+                AnyValue::StructOwned(Box::new((values, physical)))
+            }
+            other => {
+                return Err(avro_error(format!(
+                    "dtype {} has no Avro representation",
+                    other
+                )))
+            }
+        })
+    }
+}
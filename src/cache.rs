@@ -0,0 +1,97 @@
+//! Memoized validation of unchanged frames, behind the generated
+//! `validate_cached`
+//!
+//! A frame is identified by its height and the name and data of each column.
+//! The cache holds `Weak` references to the column data: while one exists the
+//! allocation is neither reused for other data nor mutated in place (Polars'
+//! copy-on-write sees it as shared), so a matching pointer means the very same,
+//! unchanged column.
+
+use std::sync::{Arc, Mutex, Weak};
+
+use polars::prelude::*;
+
+use crate::Result;
+
+/// Number of validated frames remembered, the oldest being forgotten first
+const CACHE_SIZE: usize = 64;
+
+struct Entry {
+    /// Address of the generated `validate` the frame passed
+    validate: usize,
+    height: usize,
+    columns: Vec<(PlSmallStr, Weak<dyn SeriesTrait>)>,
+}
+
+impl Entry {
+    fn matches(&self, validate: usize, df: &DataFrame) -> bool {
+        self.validate == validate
+            && self.height == df.height()
+            && self.columns.len() == df.width()
+            && self
+                .columns
+                .iter()
+                .zip(df.get_columns())
+                .all(|((name, data), column)| {
+                    name == column.name()
+                        && column.as_series().is_some_and(|series| {
+                            std::ptr::addr_eq(data.as_ptr(), Arc::as_ptr(&series.0))
+                        })
+                })
+    }
+
+    fn is_alive(&self) -> bool {
+        self.columns.iter().all(|(_, data)| data.strong_count() > 0)
+    }
+}
+
+static VALIDATED: Mutex<Vec<Entry>> = Mutex::new(Vec::new());
+
+/// Run `validate` on `df` unless the same unchanged frame already passed it
+///
+/// Only successful validations are remembered, and only for frames whose
+/// columns are all materialized series.
+#[doc(hidden)]
+pub fn validate_cached(df: &DataFrame, validate: fn(&DataFrame) -> Result<()>) -> Result<()> {
+    let key = validate as usize;
+    let cache = || {
+        VALIDATED
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    };
+    if cache().iter().any(|entry| entry.matches(key, df)) {
+        return Ok(());
+    }
+
+    validate(df)?;
+
+    let columns: Option<Vec<_>> = df
+        .get_columns()
+        .iter()
+        .map(|column| {
+            let series = column.as_series()?;
+            Some((column.name().clone(), Arc::downgrade(&series.0)))
+        })
+        .collect();
+    if let Some(columns) = columns {
+        let mut cache = cache();
+        cache.retain(Entry::is_alive);
+        if cache.len() >= CACHE_SIZE {
+            cache.remove(0);
+        }
+        cache.push(Entry {
+            validate: key,
+            height: df.height(),
+            columns,
+        });
+    }
+    Ok(())
+}
+
+/// Forget every frame remembered by the generated `validate_cached`
+pub fn clear_validation_cache() {
+    VALIDATED
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clear();
+}
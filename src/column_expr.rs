@@ -0,0 +1,13 @@
+//! Column expressions for the static column names of generated code
+
+use polars::prelude::*;
+
+/// `col(name)` for a column name known at compile time
+///
+/// Builds `Expr::Column` around a static `PlSmallStr`, skipping the wildcard
+/// and regex parsing of `col` as well as the allocation of names longer than
+/// the inline small-string capacity.
+#[doc(hidden)]
+pub const fn column_expr(name: &'static str) -> Expr {
+    Expr::Column(PlSmallStr::from_static(name))
+}
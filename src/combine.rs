@@ -0,0 +1,155 @@
+//! Support of the schemas generated by `combine!`: the merged column list and
+//! schema, and the compile-time check that the two sides share no column
+//! but the join keys
+
+use polars::prelude::*;
+
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+const fn contains(columns: &[&str], column: &str) -> bool {
+    let mut i = 0;
+    while i < columns.len() {
+        if str_eq(columns[i], column) {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Columns of `left` followed by those of `right` other than `keys`, with
+/// `N` the number of them
+pub const fn combined_columns<const N: usize>(
+    left: &[&'static str],
+    right: &[&'static str],
+    keys: &[&str],
+) -> [&'static str; N] {
+    let mut columns = [""; N];
+    let mut len = 0;
+    while len < left.len() {
+        columns[len] = left[len];
+        len += 1;
+    }
+    let mut i = 0;
+    while i < right.len() {
+        if !contains(keys, right[i]) {
+            columns[len] = right[i];
+            len += 1;
+        }
+        i += 1;
+    }
+    columns
+}
+
+/// Schema of `left` followed by the columns of `right` other than `keys`
+pub fn combined_schema(left: &Schema, right: &Schema, keys: &[&str]) -> Schema {
+    let right = right
+        .iter()
+        .filter(|(name, _)| !keys.contains(&name.as_str()))
+        .map(|(name, dtype)| (name.clone(), dtype.clone()));
+    left.iter()
+        .map(|(name, dtype)| (name.clone(), dtype.clone()))
+        .chain(right)
+        .collect()
+}
+
+const MESSAGE_CAPACITY: usize = 512;
+
+/// Compile error message assembled in a const context, where `format!` is
+/// not available
+pub struct CombineError {
+    bytes: [u8; MESSAGE_CAPACITY],
+    len: usize,
+}
+
+impl CombineError {
+    const fn new() -> Self {
+        CombineError {
+            bytes: [0; MESSAGE_CAPACITY],
+            len: 0,
+        }
+    }
+
+    /// Append `part`, or nothing if it does not fit, so that the message
+    /// stays valid UTF-8
+    const fn push(mut self, part: &str) -> Self {
+        let part = part.as_bytes();
+        if self.len + part.len() > MESSAGE_CAPACITY {
+            return self;
+        }
+        let mut i = 0;
+        while i < part.len() {
+            self.bytes[self.len + i] = part[i];
+            i += 1;
+        }
+        self.len += part.len();
+        self
+    }
+
+    pub const fn as_str(&self) -> &str {
+        match std::str::from_utf8(self.bytes.split_at(self.len).0) {
+            Ok(message) => message,
+            Err(_) => "",
+        }
+    }
+}
+
+/// Why `left` and `right` cannot be combined on `keys`: a key missing from
+/// one side, or a column on both sides that is not a key
+pub const fn combine_error(
+    left_name: &str,
+    left: &[&str],
+    right_name: &str,
+    right: &[&str],
+    keys: &[&str],
+) -> Option<CombineError> {
+    let mut i = 0;
+    while i < keys.len() {
+        let missing_from = if !contains(left, keys[i]) {
+            left_name
+        } else if !contains(right, keys[i]) {
+            right_name
+        } else {
+            ""
+        };
+        if !missing_from.is_empty() {
+            let error = CombineError::new()
+                .push("join key `")
+                .push(keys[i])
+                .push("` is not a column of `")
+                .push(missing_from)
+                .push("`");
+            return Some(error);
+        }
+        i += 1;
+    }
+    let mut i = 0;
+    while i < right.len() {
+        if contains(left, right[i]) && !contains(keys, right[i]) {
+            let error = CombineError::new()
+                .push("column `")
+                .push(right[i])
+                .push("` is in both `")
+                .push(left_name)
+                .push("` and `")
+                .push(right_name)
+                .push("`; list join keys with `on = [...]`");
+            return Some(error);
+        }
+        i += 1;
+    }
+    None
+}
@@ -0,0 +1,71 @@
+//! Schema-checked vertical concatenation, behind the generated `concat`
+
+use polars::prelude::*;
+
+use crate::{MigrationReport, PolarsSchemaInfo, Result, ValidateOptions, ValidationError};
+
+/// Options for the generated `concat_with`
+///
+/// ```ignore
+/// let opts = ConcatOptions::default()
+///     .match_columns(ValidateOptions::default().case_insensitive())
+///     .rechunk();
+/// let df = Trade::concat_with(batches, &opts)?;
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConcatOptions {
+    /// How the columns of each input are matched to the schema
+    pub match_columns: ValidateOptions,
+    /// Rechunk the result into contiguous memory
+    pub rechunk: bool,
+}
+
+impl ConcatOptions {
+    /// Match the columns of the inputs as set in `opts`
+    pub fn match_columns(mut self, opts: ValidateOptions) -> Self {
+        self.match_columns = opts;
+        self
+    }
+
+    /// Rechunk the result
+    pub fn rechunk(mut self) -> Self {
+        self.rechunk = true;
+        self
+    }
+}
+
+/// The schema columns of `frames` one after the other, each `conform`ed to
+/// `S` so that alias columns line up
+///
+/// The error of an invalid input is wrapped in `InputFrame` with its index.
+pub fn concat_frames<S: PolarsSchemaInfo>(
+    frames: impl IntoIterator<Item = DataFrame>,
+    opts: &ConcatOptions,
+    conform_with: fn(&DataFrame, &ValidateOptions) -> Result<(DataFrame, MigrationReport)>,
+) -> Result<DataFrame> {
+    let columns: Vec<Expr> = S::polars_schema()
+        .iter_names()
+        .map(|name| col(name.clone()))
+        .collect();
+    let inputs = frames
+        .into_iter()
+        .enumerate()
+        .map(|(index, df)| {
+            let (df, _) = conform_with(&df, &opts.match_columns).map_err(|source| {
+                ValidationError::InputFrame {
+                    index,
+                    source: Box::new(source),
+                }
+            })?;
+            Ok(df.lazy().select(&columns))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    if inputs.is_empty() {
+        return Ok(DataFrame::empty_with_schema(S::polars_schema()));
+    }
+    let args = UnionArgs {
+        rechunk: opts.rechunk,
+        ..Default::default()
+    };
+    Ok(concat(inputs, args)?.collect()?)
+}
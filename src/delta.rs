@@ -0,0 +1,316 @@
+//! Delta Lake tables read through their transaction log
+//!
+//! `DeltaTable::open` replays the JSON commits in `_delta_log` to find the
+//! table schema, partition columns and live data files. Scanning the Parquet
+//! files themselves is left to a caller-supplied function, so any reader that
+//! produces a `LazyFrame` can be used.
+//!
+//! Only tables on the local filesystem are supported, and tables whose log
+//! relies on checkpoints, column mapping or deletion vectors are rejected.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use polars::prelude::*;
+use serde_json::Value;
+
+use crate::{Result, ValidationError};
+
+fn delta_error(message: impl Into<String>) -> ValidationError {
+    ValidationError::Delta(message.into())
+}
+
+/// A live data file of a Delta table
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeltaFile {
+    /// Path of the file, relative to the table root
+    pub path: String,
+    /// Partition column values of the rows in the file, `None` for nulls
+    pub partition_values: Vec<(String, Option<String>)>,
+}
+
+/// Snapshot of a Delta table at its latest version
+#[derive(Debug, Clone)]
+pub struct DeltaTable {
+    root: PathBuf,
+    version: u64,
+    schema: Schema,
+    partition_columns: Vec<String>,
+    files: Vec<DeltaFile>,
+}
+
+impl DeltaTable {
+    /// Open the table at `uri`, a local path or `file://` URI
+    pub fn open(uri: &str) -> Result<Self> {
+        let root = match uri.split_once("://") {
+            None => PathBuf::from(uri),
+            Some(("file", path)) => PathBuf::from(path),
+            Some((scheme, _)) => {
+                return Err(delta_error(format!(
+                    "unsupported storage scheme '{}', only local tables can be opened",
+                    scheme
+                )))
+            }
+        };
+        let log = root.join("_delta_log");
+
+        let mut version = None;
+        let mut metadata = None;
+        let mut files = BTreeMap::new();
+        for next in 0u64.. {
+            let commit = log.join(format!("{:020}.json", next));
+            let contents = match fs::read_to_string(&commit) {
+                Ok(contents) => contents,
+                Err(e) if e.kind() == ErrorKind::NotFound => break,
+                Err(e) => return Err(delta_error(format!("{}: {}", commit.display(), e))),
+            };
+            for line in contents.lines().filter(|line| !line.trim().is_empty()) {
+                let action: Value = serde_json::from_str(line)
+                    .map_err(|e| delta_error(format!("{}: {}", commit.display(), e)))?;
+                if let Some(meta) = action.get("metaData") {
+                    metadata = Some(meta.clone());
+                } else if let Some(add) = action.get("add") {
+                    if add.get("deletionVector").is_some_and(|dv| !dv.is_null()) {
+                        return Err(delta_error("deletion vectors are not supported"));
+                    }
+                    let path = action_path(add)?;
+                    files.insert(path, add.clone());
+                } else if let Some(remove) = action.get("remove") {
+                    files.remove(&action_path(remove)?);
+                }
+            }
+            version = Some(next);
+        }
+
+        let Some(version) = version else {
+            return Err(if log.join("_last_checkpoint").exists() {
+                delta_error("tables whose log starts at a checkpoint are not supported")
+            } else {
+                delta_error(format!("no Delta log found at {}", log.display()))
+            });
+        };
+        let metadata = metadata.ok_or_else(|| delta_error("the log has no metaData action"))?;
+        let mapping = metadata
+            .pointer("/configuration/delta.columnMapping.mode")
+            .and_then(Value::as_str);
+        if mapping.is_some_and(|mode| mode != "none") {
+            return Err(delta_error("column mapping is not supported"));
+        }
+
+        let schema_string = metadata
+            .get("schemaString")
+            .and_then(Value::as_str)
+            .ok_or_else(|| delta_error("the metaData action has no schemaString"))?;
+        let delta_schema: Value = serde_json::from_str(schema_string)
+            .map_err(|e| delta_error(format!("invalid schemaString: {}", e)))?;
+        let schema = struct_fields(&delta_schema)?.into_iter().collect();
+
+        let partition_columns = metadata
+            .get("partitionColumns")
+            .and_then(Value::as_array)
+            .map(|columns| {
+                columns
+                    .iter()
+                    .filter_map(|column| column.as_str().map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let files = files
+            .into_iter()
+            .map(|(path, add)| {
+                let partition_values = add
+                    .get("partitionValues")
+                    .and_then(Value::as_object)
+                    .map(|values| {
+                        values
+                            .iter()
+                            .map(|(column, value)| {
+                                (column.clone(), value.as_str().map(str::to_string))
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                DeltaFile {
+                    path,
+                    partition_values,
+                }
+            })
+            .collect();
+
+        Ok(DeltaTable {
+            root,
+            version,
+            schema,
+            partition_columns,
+            files,
+        })
+    }
+
+    /// Version of the latest commit in the log
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Table schema, with Delta types mapped to polars dtypes
+    pub fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    /// Columns the table is partitioned by, whose values are stored in the
+    /// log rather than in the data files
+    pub fn partition_columns(&self) -> &[String] {
+        &self.partition_columns
+    }
+
+    /// Live data files, ordered by path
+    pub fn files(&self) -> &[DeltaFile] {
+        &self.files
+    }
+
+    /// Scan every live data file with `scan` and concatenate the frames,
+    /// adding the partition columns and selecting the table columns in order
+    pub fn scan<F>(&self, mut scan: F) -> Result<LazyFrame>
+    where
+        F: FnMut(&Path) -> PolarsResult<LazyFrame>,
+    {
+        if self.files.is_empty() {
+            return Ok(DataFrame::empty_with_schema(&self.schema).lazy());
+        }
+        let columns: Vec<Expr> = self
+            .schema
+            .iter_names()
+            .map(|name| col(name.clone()))
+            .collect();
+        let frames = self
+            .files
+            .iter()
+            .map(|file| {
+                let partitions = self
+                    .partition_columns
+                    .iter()
+                    .map(|column| {
+                        let dtype = self.schema.get(column).ok_or_else(|| {
+                            delta_error(format!(
+                                "partition column '{}' is not in the schema",
+                                column
+                            ))
+                        })?;
+                        let value = file
+                            .partition_values
+                            .iter()
+                            .find(|(name, _)| name == column)
+                            .and_then(|(_, value)| value.clone());
+                        let value = match value {
+                            Some(value) => lit(value),
+                            None => lit(NULL),
+                        };
+                        Ok(value.strict_cast(dtype.clone()).alias(column.as_str()))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                let frame = scan(&self.root.join(percent_decode(&file.path)))?;
+                Ok(frame.with_columns(partitions).select(columns.clone()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(concat(frames, UnionArgs::default())?)
+    }
+}
+
+fn action_path(action: &Value) -> Result<String> {
+    action
+        .get("path")
+        .and_then(Value::as_str)
+        .map(str::to_string)
+        .ok_or_else(|| delta_error("a file action has no path"))
+}
+
+/// Paths in the log are URL-encoded
+fn percent_decode(path: &str) -> String {
+    let bytes = path.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+fn struct_fields(delta_type: &Value) -> Result<Vec<Field>> {
+    delta_type
+        .get("fields")
+        .and_then(Value::as_array)
+        .ok_or_else(|| delta_error(format!("expected a struct type, found {}", delta_type)))?
+        .iter()
+        .map(|field| {
+            let name = field
+                .get("name")
+                .and_then(Value::as_str)
+                .ok_or_else(|| delta_error(format!("field without a name: {}", field)))?;
+            let dtype = field
+                .get("type")
+                .ok_or_else(|| delta_error(format!("field '{}' has no type", name)))?;
+            Ok(Field::new(name.into(), delta_dtype(dtype)?))
+        })
+        .collect()
+}
+
+/// Polars dtype of a Delta type; timestamps are read as UTC microseconds
+fn delta_dtype(delta_type: &Value) -> Result<DataType> {
+    if let Some(name) = delta_type.as_str() {
+        return Ok(match name {
+            "boolean" => DataType::Boolean,
+            "byte" => DataType::Int8,
+            "short" => DataType::Int16,
+            "integer" => DataType::Int32,
+            "long" => DataType::Int64,
+            "float" => DataType::Float32,
+            "double" => DataType::Float64,
+            "string" => DataType::String,
+            "binary" => DataType::Binary,
+            "date" => DataType::Date,
+            "timestamp" => DataType::Datetime(TimeUnit::Microseconds, Some("UTC".into())),
+            "timestamp_ntz" => DataType::Datetime(TimeUnit::Microseconds, None),
+            #[cfg(feature = "decimal")]
+            decimal if decimal.starts_with("decimal(") => {
+                let (precision, scale) = decimal
+                    .trim_start_matches("decimal(")
+                    .trim_end_matches(')')
+                    .split_once(',')
+                    .and_then(|(precision, scale)| {
+                        Some((precision.trim().parse().ok()?, scale.trim().parse().ok()?))
+                    })
+                    .ok_or_else(|| delta_error(format!("invalid decimal type '{}'", decimal)))?;
+                DataType::Decimal(Some(precision), Some(scale))
+            }
+            other => return Err(delta_error(format!("unsupported Delta type '{}'", other))),
+        });
+    }
+    match delta_type.get("type").and_then(Value::as_str) {
+        Some("array") => {
+            let element = delta_type
+                .get("elementType")
+                .ok_or_else(|| delta_error("array type without an elementType"))?;
+            Ok(DataType::List(Box::new(delta_dtype(element)?)))
+        }
+        Some("struct") => Ok(DataType::Struct(struct_fields(delta_type)?)),
+        _ => Err(delta_error(format!(
+            "unsupported Delta type {}",
+            delta_type
+        ))),
+    }
+}
@@ -0,0 +1,114 @@
+//! Printable descriptions of derived schemas, behind the generated
+//! `describe_schema`
+
+use std::fmt;
+
+use polars::prelude::*;
+use serde::Serialize;
+
+use crate::profile::serialize_dtype;
+
+/// Columns of a derived schema with their dtype, nullability, doc and tags
+///
+/// Displays as an aligned plain-text table and serializes (e.g. to JSON) as
+/// `{"name": ..., "columns": [...]}`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SchemaDescription {
+    /// Name of the schema struct, or the name it is registered under
+    pub name: String,
+    pub columns: Vec<ColumnDescription>,
+}
+
+/// One column of a `SchemaDescription`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ColumnDescription {
+    pub name: String,
+    #[serde(serialize_with = "serialize_dtype")]
+    pub dtype: DataType,
+    /// Whether the field is an `Option`
+    pub nullable: bool,
+    pub doc: Option<String>,
+    pub tags: Vec<String>,
+}
+
+impl SchemaDescription {
+    /// Description of `schema`; `nullable` is in column order, `docs` and
+    /// `tags` are `(column, doc)` / `(column, tag)` pairs
+    #[doc(hidden)]
+    pub fn new(
+        name: &str,
+        schema: &Schema,
+        nullable: &[bool],
+        docs: &[(&str, &str)],
+        tags: &[(&str, &str)],
+    ) -> Self {
+        let columns = schema
+            .iter()
+            .zip(nullable)
+            .map(|((column, dtype), nullable)| ColumnDescription {
+                name: column.to_string(),
+                dtype: dtype.clone(),
+                nullable: *nullable,
+                doc: docs
+                    .iter()
+                    .find(|(doc_column, _)| *doc_column == column.as_str())
+                    .map(|(_, doc)| doc.to_string()),
+                tags: tags
+                    .iter()
+                    .filter(|(tag_column, _)| *tag_column == column.as_str())
+                    .map(|(_, tag)| tag.to_string())
+                    .collect(),
+            })
+            .collect();
+        SchemaDescription {
+            name: name.to_string(),
+            columns,
+        }
+    }
+
+    /// Pretty-printed JSON of the description, e.g. for a `--json` flag
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> crate::Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+}
+
+impl fmt::Display for SchemaDescription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let headers = ["column", "dtype", "nullable", "doc", "tags"];
+        let rows: Vec<[String; 5]> = self
+            .columns
+            .iter()
+            .map(|column| {
+                [
+                    column.name.clone(),
+                    column.dtype.to_string(),
+                    if column.nullable { "yes" } else { "no" }.to_string(),
+                    column.doc.clone().unwrap_or_default(),
+                    column.tags.join(", "),
+                ]
+            })
+            .collect();
+        let widths: Vec<usize> = (0..headers.len())
+            .map(|i| {
+                rows.iter()
+                    .map(|row| row[i].chars().count())
+                    .max()
+                    .unwrap_or(0)
+                    .max(headers[i].len())
+            })
+            .collect();
+
+        write!(f, "{} ({} columns)", self.name, self.columns.len())?;
+        let cells = std::iter::once(headers.map(str::to_string)).chain(rows);
+        for row in cells {
+            let line: Vec<String> = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{:width$}", cell))
+                .collect();
+            write!(f, "\n{}", line.join("  ").trim_end())?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,180 @@
+//! Readable rendering of validation errors
+//!
+//! A `Diagnostic` puts a `ValidationError` next to the schema it was checked
+//! against and the schema of the frame that failed: a hint for the likely fix,
+//! such as the column a missing one was probably misspelled as, and a table
+//! of expected vs actual dtypes. `{}` renders plain text for logs, `{:#}`
+//! adds ANSI colors for terminals.
+
+use std::fmt;
+
+use polars::prelude::*;
+
+use crate::suggest::suggestions;
+use crate::ValidationError;
+
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const YELLOW: &str = "\x1b[33m";
+const CYAN: &str = "\x1b[36m";
+const BOLD: &str = "\x1b[1m";
+const RESET: &str = "\x1b[0m";
+
+/// A validation error with a hint and the expected vs actual schema
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    /// Message of the error
+    pub message: String,
+    /// Likely fix, e.g. "did you mean column `usr_id`?"
+    pub help: Option<String>,
+    /// Expected columns in schema order, then the unexpected ones
+    pub columns: Vec<ColumnRow>,
+}
+
+/// A column of either schema, with its dtype on each side
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnRow {
+    pub column: String,
+    /// `None` for columns the expected schema does not have
+    pub expected: Option<DataType>,
+    /// `None` for columns missing from the frame
+    pub actual: Option<DataType>,
+}
+
+impl ColumnRow {
+    /// Whether the column is present with its expected dtype
+    pub fn is_ok(&self) -> bool {
+        self.expected.is_some() && self.expected == self.actual
+    }
+}
+
+impl Diagnostic {
+    /// Diagnose `error`, raised checking a frame of schema `actual` against
+    /// `expected`
+    pub fn new(error: &ValidationError, expected: &Schema, actual: &Schema) -> Self {
+        let mut columns: Vec<ColumnRow> = expected
+            .iter()
+            .map(|(name, dtype)| ColumnRow {
+                column: name.to_string(),
+                expected: Some(dtype.clone()),
+                actual: actual.get(name).cloned(),
+            })
+            .collect();
+        columns.extend(
+            actual
+                .iter()
+                .filter(|(name, _)| !expected.contains(name))
+                .map(|(name, dtype)| ColumnRow {
+                    column: name.to_string(),
+                    expected: None,
+                    actual: Some(dtype.clone()),
+                }),
+        );
+        Diagnostic {
+            message: error.to_string(),
+            help: help(error, expected, actual),
+            columns,
+        }
+    }
+}
+
+fn help(error: &ValidationError, expected: &Schema, actual: &Schema) -> Option<String> {
+    let missing_help = |column: &str| {
+        let unexpected = actual
+            .iter_names()
+            .filter(|name| !expected.contains(name))
+            .map(|name| name.as_str());
+        match suggestions(column, unexpected).first() {
+            Some(found) => format!("did you mean column `{found}`?"),
+            None => format!("add column `{column}` to the frame"),
+        }
+    };
+    match error {
+        ValidationError::MissingColumn { column_name, .. } => Some(missing_help(column_name)),
+        ValidationError::SchemaMismatch(diff) => {
+            match (diff.missing.first(), diff.mismatched.first()) {
+                (Some(column), _) => Some(missing_help(column)),
+                (None, Some(mismatch)) => Some(format!(
+                    "cast column `{}` to {} before validating",
+                    mismatch.column, mismatch.expected
+                )),
+                (None, None) => None,
+            }
+        }
+        ValidationError::TypeMismatch {
+            column_name,
+            expected_type,
+            ..
+        } => Some(format!(
+            "cast column `{column_name}` to {expected_type} before validating"
+        )),
+        ValidationError::UnexpectedColumn { column_name } => Some(format!(
+            "drop column `{column_name}` or select only the expected columns"
+        )),
+        ValidationError::ColumnCountMismatch { .. } => {
+            Some("select only the expected columns before strict validation".to_string())
+        }
+        _ => None,
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let color = f.alternate();
+        let paint = |style: &'static str| if color { style } else { "" };
+        let reset = paint(RESET);
+
+        writeln!(f, "{}error{reset}: {}", paint(BOLD), self.message)?;
+        if let Some(help) = &self.help {
+            writeln!(f, "  {}help{reset}: {help}", paint(CYAN))?;
+        }
+        if self.columns.is_empty() {
+            return Ok(());
+        }
+
+        let dtype = |dtype: &Option<DataType>, absent: &str| {
+            dtype
+                .as_ref()
+                .map_or_else(|| absent.to_string(), |dtype| dtype.to_string())
+        };
+        let rows: Vec<(&ColumnRow, String, String)> = self
+            .columns
+            .iter()
+            .map(|row| {
+                (
+                    row,
+                    dtype(&row.expected, "-"),
+                    dtype(&row.actual, "missing"),
+                )
+            })
+            .collect();
+        let width = |header: &str, cell: fn(&(&ColumnRow, String, String)) -> usize| {
+            rows.iter().map(cell).max().unwrap_or(0).max(header.len())
+        };
+        let column_width = width("column", |(row, _, _)| row.column.chars().count());
+        let expected_width = width("expected", |(_, expected, _)| expected.chars().count());
+
+        writeln!(f)?;
+        writeln!(
+            f,
+            "    {}{:column_width$}  {:expected_width$}  actual{reset}",
+            paint(BOLD),
+            "column",
+            "expected"
+        )?;
+        for (row, expected, actual) in &rows {
+            let (marker, style) = match (&row.expected, row.is_ok()) {
+                (None, _) => ('+', YELLOW),
+                (Some(_), true) => ('✓', GREEN),
+                (Some(_), false) => ('✗', RED),
+            };
+            writeln!(
+                f,
+                "  {}{marker} {:column_width$}  {expected:expected_width$}  {actual}{reset}",
+                paint(style),
+                row.column
+            )?;
+        }
+        Ok(())
+    }
+}
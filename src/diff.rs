@@ -0,0 +1,168 @@
+//! Key-based row comparison of two frames over the schema columns
+
+use std::fmt;
+
+use polars::prelude::*;
+
+use crate::suggest::missing_column;
+use crate::Result;
+
+const LEFT_MARKER: &str = "__polars_tools_left";
+const RIGHT_MARKER: &str = "__polars_tools_right";
+
+/// Outcome of the generated `Struct::diff_rows(&left, &right, keys)`
+#[derive(Debug, Clone)]
+pub struct DiffResult {
+    /// Rows only present in the right frame
+    pub added: DataFrame,
+    /// Rows only present in the left frame
+    pub removed: DataFrame,
+    /// Rows present in both frames with at least one differing column: the
+    /// key columns, then `<column>` (left value) and `<column>_right` for
+    /// every other schema column
+    pub changed: DataFrame,
+    /// Number of changed rows per non-key column, in schema order
+    pub column_changes: Vec<(String, usize)>,
+}
+
+impl DiffResult {
+    /// Whether the frames hold the same rows
+    pub fn is_empty(&self) -> bool {
+        self.added.height() == 0 && self.removed.height() == 0 && self.changed.height() == 0
+    }
+
+    /// Number of changed rows in a column
+    pub fn changes(&self, column: &str) -> usize {
+        self.column_changes
+            .iter()
+            .find(|(name, _)| name == column)
+            .map_or(0, |(_, count)| *count)
+    }
+}
+
+impl fmt::Display for DiffResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} added, {} removed, {} changed",
+            self.added.height(),
+            self.removed.height(),
+            self.changed.height()
+        )?;
+        for (column, count) in self.column_changes.iter().filter(|(_, count)| *count > 0) {
+            write!(f, "\n  {}: {} changed", column, count)?;
+        }
+        Ok(())
+    }
+}
+
+/// Compare `left` and `right` by `keys`, looking only at `columns`
+///
+/// Keys are expected to identify rows uniquely; duplicated keys pair every
+/// left row with every right row of the same key. Nulls compare equal.
+#[doc(hidden)]
+pub fn diff_rows(
+    left: &DataFrame,
+    right: &DataFrame,
+    columns: &[&str],
+    keys: &[&str],
+) -> Result<DiffResult> {
+    let values: Vec<&str> = columns
+        .iter()
+        .filter(|column| !keys.contains(column))
+        .copied()
+        .collect();
+    let selected: Vec<&str> = keys.iter().chain(&values).copied().collect();
+
+    for df in [left, right] {
+        for name in &selected {
+            df.column(name)
+                .map_err(|_| missing_column(name, df.get_column_names_str()))?;
+        }
+    }
+
+    let side = |df: &DataFrame, marker: &str| {
+        df.clone()
+            .lazy()
+            .select(selected.iter().map(|name| col(*name)).collect::<Vec<_>>())
+            .with_column(lit(true).alias(marker))
+    };
+    let key_exprs: Vec<Expr> = keys.iter().map(|key| col(*key)).collect();
+    let joined = side(left, LEFT_MARKER)
+        .join(
+            side(right, RIGHT_MARKER),
+            key_exprs.clone(),
+            key_exprs,
+            JoinArgs::new(JoinType::Full).with_coalesce(JoinCoalesce::CoalesceColumns),
+        )
+        .collect()?;
+
+    let right_name = |column: &str| format!("{}_right", column);
+    let key_cols = || keys.iter().map(|key| col(*key));
+
+    let removed = joined
+        .clone()
+        .lazy()
+        .filter(col(RIGHT_MARKER).is_null())
+        .select(key_cols().chain(values.iter().map(|v| col(*v))).collect::<Vec<_>>())
+        .collect()?;
+    let added = joined
+        .clone()
+        .lazy()
+        .filter(col(LEFT_MARKER).is_null())
+        .select(
+            key_cols()
+                .chain(values.iter().map(|v| col(right_name(v)).alias(*v)))
+                .collect::<Vec<_>>(),
+        )
+        .collect()?;
+
+    let both = joined
+        .lazy()
+        .filter(col(LEFT_MARKER).and(col(RIGHT_MARKER)));
+    let changed_flags: Vec<Expr> = values
+        .iter()
+        .map(|v| col(*v).neq_missing(col(right_name(v))))
+        .collect();
+
+    let counts = both
+        .clone()
+        .select(
+            values
+                .iter()
+                .zip(&changed_flags)
+                .map(|(v, flag)| flag.clone().sum().cast(DataType::UInt64).alias(*v))
+                .collect::<Vec<_>>(),
+        )
+        .collect()?;
+    let column_changes = values
+        .iter()
+        .map(|v| -> Result<(String, usize)> {
+            let count = counts.column(v)?.as_materialized_series().u64()?.get(0);
+            Ok((v.to_string(), count.unwrap_or(0) as usize))
+        })
+        .collect::<Result<_>>()?;
+
+    let any_changed = changed_flags
+        .into_iter()
+        .fold(lit(false), |any, flag| any.or(flag));
+    let changed = both
+        .filter(any_changed)
+        .select(
+            key_cols()
+                .chain(
+                    values
+                        .iter()
+                        .flat_map(|v| [col(*v), col(right_name(v))]),
+                )
+                .collect::<Vec<_>>(),
+        )
+        .collect()?;
+
+    Ok(DiffResult {
+        added,
+        removed,
+        changed,
+        column_changes,
+    })
+}
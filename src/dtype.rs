@@ -0,0 +1,406 @@
+//! Mapping from Rust field types to Polars data types
+
+use std::marker::PhantomData;
+
+use polars::prelude::{DataType, Field, PlSmallStr, TimeUnit};
+
+use crate::ValidatableEnum;
+
+/// Trait for Rust types that can be stored in a Polars column
+///
+/// The derive macros resolve every field's dtype through this trait, so
+/// implementing it for your own type teaches the macros how to map it.
+pub trait PolarsDType {
+    /// Polars data type of a column holding values of this type
+    fn dtype() -> DataType;
+
+    /// Whether the column may contain nulls
+    const NULLABLE: bool = false;
+}
+
+macro_rules! impl_polars_dtype {
+    ($($ty:ty => $dtype:expr),* $(,)?) => {
+        $(
+            impl PolarsDType for $ty {
+                fn dtype() -> DataType {
+                    $dtype
+                }
+            }
+        )*
+    };
+}
+
+impl_polars_dtype! {
+    // Signed integers
+    i8 => DataType::Int8,
+    i16 => DataType::Int16,
+    i32 => DataType::Int32,
+    i64 => DataType::Int64,
+    i128 => DataType::Int128,
+    // Unsigned integers
+    u8 => DataType::UInt8,
+    u16 => DataType::UInt16,
+    u32 => DataType::UInt32,
+    u64 => DataType::UInt64,
+    // Floats
+    f32 => DataType::Float32,
+    f64 => DataType::Float64,
+    // Boolean and String
+    bool => DataType::Boolean,
+    String => DataType::String,
+    str => DataType::String,
+    &str => DataType::String,
+    std::borrow::Cow<'_, str> => DataType::String,
+}
+
+/// Durations default to microsecond precision, like Polars itself
+impl PolarsDType for std::time::Duration {
+    fn dtype() -> DataType {
+        DataType::Duration(TimeUnit::Microseconds)
+    }
+}
+
+impl<T: PolarsDType> PolarsDType for Option<T> {
+    fn dtype() -> DataType {
+        T::dtype()
+    }
+
+    const NULLABLE: bool = true;
+}
+
+impl<T: PolarsDType> PolarsDType for Vec<T> {
+    fn dtype() -> DataType {
+        DataType::List(Box::new(T::dtype()))
+    }
+}
+
+/// Maps are stored as a list of `{key, value}` structs; use
+/// `#[polars(struct_keys(...))]` on the field for a struct with fixed keys
+impl<K: PolarsDType, V: PolarsDType, S> PolarsDType for std::collections::HashMap<K, V, S> {
+    fn dtype() -> DataType {
+        map_entries_dtype(K::dtype(), V::dtype())
+    }
+}
+
+impl<K: PolarsDType, V: PolarsDType> PolarsDType for std::collections::BTreeMap<K, V> {
+    fn dtype() -> DataType {
+        map_entries_dtype(K::dtype(), V::dtype())
+    }
+}
+
+/// `List(Struct{key, value})` dtype used for map fields
+pub fn map_entries_dtype(key: DataType, value: DataType) -> DataType {
+    DataType::List(Box::new(DataType::Struct(vec![
+        Field::new(PlSmallStr::from_static("key"), key),
+        Field::new(PlSmallStr::from_static("value"), value),
+    ])))
+}
+
+/// `Struct` dtype with one field of the same dtype per fixed key
+pub fn map_struct_dtype(keys: &[&str], value: DataType) -> DataType {
+    DataType::Struct(
+        keys.iter()
+            .map(|key| Field::new(PlSmallStr::from(*key), value.clone()))
+            .collect(),
+    )
+}
+
+/// Enums are stored as their string representation
+impl<T: ValidatableEnum> PolarsDType for T {
+    fn dtype() -> DataType {
+        DataType::String
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_impls {
+    use super::PolarsDType;
+    use polars::prelude::{DataType, PlSmallStr, TimeUnit};
+
+    impl PolarsDType for chrono::NaiveDate {
+        fn dtype() -> DataType {
+            DataType::Date
+        }
+    }
+
+    impl PolarsDType for chrono::NaiveDateTime {
+        fn dtype() -> DataType {
+            DataType::Datetime(TimeUnit::Microseconds, None)
+        }
+    }
+
+    impl PolarsDType for chrono::NaiveTime {
+        fn dtype() -> DataType {
+            DataType::Time
+        }
+    }
+
+    impl PolarsDType for chrono::DateTime<chrono::Utc> {
+        fn dtype() -> DataType {
+            DataType::Datetime(TimeUnit::Microseconds, Some(PlSmallStr::from_static("UTC")))
+        }
+    }
+
+    /// Offsets are normalised to UTC when stored
+    impl PolarsDType for chrono::DateTime<chrono::FixedOffset> {
+        fn dtype() -> DataType {
+            DataType::Datetime(TimeUnit::Microseconds, Some(PlSmallStr::from_static("UTC")))
+        }
+    }
+
+    /// The zone of a `chrono_tz::Tz` is only known per value, so the column
+    /// defaults to UTC; set `#[polars(tz = "...")]` to declare the zone
+    #[cfg(feature = "chrono-tz")]
+    impl PolarsDType for chrono::DateTime<chrono_tz::Tz> {
+        fn dtype() -> DataType {
+            DataType::Datetime(TimeUnit::Microseconds, Some(PlSmallStr::from_static("UTC")))
+        }
+    }
+
+    impl PolarsDType for chrono::Duration {
+        fn dtype() -> DataType {
+            DataType::Duration(TimeUnit::Microseconds)
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+mod time_impls {
+    use super::PolarsDType;
+    use polars::prelude::{DataType, PlSmallStr, TimeUnit};
+
+    impl PolarsDType for time::Date {
+        fn dtype() -> DataType {
+            DataType::Date
+        }
+    }
+
+    impl PolarsDType for time::PrimitiveDateTime {
+        fn dtype() -> DataType {
+            DataType::Datetime(TimeUnit::Microseconds, None)
+        }
+    }
+
+    /// Offsets are normalised to UTC when stored
+    impl PolarsDType for time::OffsetDateTime {
+        fn dtype() -> DataType {
+            DataType::Datetime(TimeUnit::Microseconds, Some(PlSmallStr::from_static("UTC")))
+        }
+    }
+
+    impl PolarsDType for time::Time {
+        fn dtype() -> DataType {
+            DataType::Time
+        }
+    }
+
+    impl PolarsDType for time::Duration {
+        fn dtype() -> DataType {
+            DataType::Duration(TimeUnit::Microseconds)
+        }
+    }
+}
+
+/// Decimal precision used when a field only sets `#[polars(scale = ...)]`
+/// or carries no decimal attributes at all
+#[cfg(feature = "decimal")]
+pub const DEFAULT_DECIMAL_PRECISION: usize = 38;
+
+/// Decimal scale used when a field only sets `#[polars(precision = ...)]`
+/// or carries no decimal attributes at all
+#[cfg(feature = "decimal")]
+pub const DEFAULT_DECIMAL_SCALE: usize = 10;
+
+/// Polars has no 128-bit unsigned integer, so `u128` is stored as an integral
+/// decimal (values above `10^38 - 1` do not fit)
+#[cfg(feature = "decimal")]
+impl PolarsDType for u128 {
+    fn dtype() -> DataType {
+        DataType::Decimal(Some(DEFAULT_DECIMAL_PRECISION), Some(0))
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl PolarsDType for rust_decimal::Decimal {
+    fn dtype() -> DataType {
+        DataType::Decimal(Some(DEFAULT_DECIMAL_PRECISION), Some(DEFAULT_DECIMAL_SCALE))
+    }
+}
+
+/// UUIDs are stored in their hyphenated string form; use `#[polars(binary)]`
+/// on the field to store the 16 raw bytes instead
+#[cfg(feature = "uuid")]
+impl PolarsDType for uuid::Uuid {
+    fn dtype() -> DataType {
+        DataType::String
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl PolarsDType for bytes::Bytes {
+    fn dtype() -> DataType {
+        DataType::Binary
+    }
+}
+
+/// Replace the time unit of a `Datetime` or `Duration` dtype (also inside lists)
+#[doc(hidden)]
+pub fn with_time_unit(dtype: DataType, unit: TimeUnit) -> DataType {
+    match dtype {
+        DataType::Datetime(_, tz) => DataType::Datetime(unit, tz),
+        DataType::Duration(_) => DataType::Duration(unit),
+        DataType::List(inner) => DataType::List(Box::new(with_time_unit(*inner, unit))),
+        other => other,
+    }
+}
+
+/// Replace the time zone of a `Datetime` dtype (also inside lists)
+#[doc(hidden)]
+pub fn with_time_zone(dtype: DataType, tz: PlSmallStr) -> DataType {
+    match dtype {
+        DataType::Datetime(unit, _) => DataType::Datetime(unit, Some(tz)),
+        DataType::List(inner) => DataType::List(Box::new(with_time_zone(*inner, tz))),
+        other => other,
+    }
+}
+
+/// Compare two dtypes, treating `Datetime`/`Duration` of any time unit as equal
+#[doc(hidden)]
+pub fn dtype_eq_ignoring_time_unit(actual: &DataType, expected: &DataType) -> bool {
+    match (actual, expected) {
+        (DataType::Datetime(_, actual_tz), DataType::Datetime(_, expected_tz)) => {
+            actual_tz == expected_tz
+        }
+        (DataType::Duration(_), DataType::Duration(_)) => true,
+        (DataType::List(actual), DataType::List(expected)) => {
+            dtype_eq_ignoring_time_unit(actual, expected)
+        }
+        _ => actual == expected,
+    }
+}
+
+/// Column type constant generated for each field (`Struct::field_type`)
+///
+/// Compares equal to the `DataType` it resolves to.
+#[derive(Clone, Copy)]
+pub struct ColumnDType(fn() -> DataType);
+
+impl ColumnDType {
+    #[doc(hidden)]
+    pub const fn new(resolve: fn() -> DataType) -> Self {
+        ColumnDType(resolve)
+    }
+
+    /// Get the Polars data type of the column
+    pub fn dtype(&self) -> DataType {
+        (self.0)()
+    }
+}
+
+impl std::fmt::Debug for ColumnDType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(&self.dtype(), f)
+    }
+}
+
+impl PartialEq for ColumnDType {
+    fn eq(&self, other: &ColumnDType) -> bool {
+        self.dtype() == other.dtype()
+    }
+}
+
+impl PartialEq<DataType> for ColumnDType {
+    fn eq(&self, other: &DataType) -> bool {
+        &self.dtype() == other
+    }
+}
+
+impl PartialEq<ColumnDType> for DataType {
+    fn eq(&self, other: &ColumnDType) -> bool {
+        self == &other.dtype()
+    }
+}
+
+impl From<ColumnDType> for DataType {
+    fn from(value: ColumnDType) -> Self {
+        value.dtype()
+    }
+}
+
+/// Resolves a field type to its dtype, falling back to `String` for types
+/// without a `PolarsDType` impl (the enum heuristic).
+///
+/// Used by generated code as `(&DTypeProbe::<T>::new()).probe_dtype()`
+/// with both probe traits in scope.
+#[doc(hidden)]
+pub struct DTypeProbe<T: ?Sized>(PhantomData<fn() -> Box<T>>);
+
+impl<T: ?Sized> DTypeProbe<T> {
+    pub const fn new() -> Self {
+        DTypeProbe(PhantomData)
+    }
+}
+
+impl<T: ?Sized> Default for DTypeProbe<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[doc(hidden)]
+pub trait ViaPolarsDType {
+    fn probe_dtype(&self) -> DataType;
+    fn probe_nullable(&self) -> bool;
+}
+
+impl<T: ?Sized + PolarsDType> ViaPolarsDType for DTypeProbe<T> {
+    fn probe_dtype(&self) -> DataType {
+        T::dtype()
+    }
+
+    fn probe_nullable(&self) -> bool {
+        T::NULLABLE
+    }
+}
+
+#[doc(hidden)]
+pub trait ViaFallback {
+    fn probe_dtype(&self) -> DataType;
+    fn probe_nullable(&self) -> bool;
+}
+
+impl<T: ?Sized> ViaFallback for &DTypeProbe<T> {
+    fn probe_dtype(&self) -> DataType {
+        DataType::String
+    }
+
+    fn probe_nullable(&self) -> bool {
+        false
+    }
+}
+
+/// Valid values of enum field types, `None` for every other type
+///
+/// Used by generated code as `(&DTypeProbe::<T>::new()).probe_enum_values()`
+/// with both traits in scope.
+#[doc(hidden)]
+pub trait ViaEnumValues {
+    fn probe_enum_values(&self) -> Option<Vec<&'static str>>;
+}
+
+impl<T: ValidatableEnum> ViaEnumValues for DTypeProbe<T> {
+    fn probe_enum_values(&self) -> Option<Vec<&'static str>> {
+        Some(T::valid_values())
+    }
+}
+
+#[doc(hidden)]
+pub trait ViaNoEnumValues {
+    fn probe_enum_values(&self) -> Option<Vec<&'static str>>;
+}
+
+impl<T: ?Sized> ViaNoEnumValues for &DTypeProbe<T> {
+    fn probe_enum_values(&self) -> Option<Vec<&'static str>> {
+        None
+    }
+}
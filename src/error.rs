@@ -0,0 +1,283 @@
+//! Stable codes and machine-readable form of validation errors, and
+//! conversions between them and Polars errors
+
+use std::collections::{BTreeSet, HashSet};
+use std::io;
+
+use polars::prelude::{polars_err, PolarsError};
+use serde::ser::{Serialize, SerializeMap, Serializer};
+
+use crate::ValidationError;
+
+/// Any error of a pipeline mixing Polars calls, validation and IO, so `?`
+/// works on all three in one function
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    Polars(PolarsError),
+
+    #[error(transparent)]
+    Validation(ValidationError),
+
+    #[error(transparent)]
+    Io(#[from] io::Error),
+}
+
+impl From<PolarsError> for Error {
+    fn from(err: PolarsError) -> Self {
+        Error::Polars(err)
+    }
+}
+
+/// Polars errors wrapped in a `ValidationError` become `Error::Polars`
+impl From<ValidationError> for Error {
+    fn from(err: ValidationError) -> Self {
+        match err {
+            ValidationError::Polars(err) => Error::Polars(err),
+            err => Error::Validation(err),
+        }
+    }
+}
+
+impl From<Error> for PolarsError {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::Polars(err) => err,
+            Error::Validation(err) => err.into(),
+            Error::Io(err) => err.into(),
+        }
+    }
+}
+
+/// For functions returning `PolarsResult` that also validate: each error
+/// becomes the closest Polars error kind, keeping the validation message
+impl From<ValidationError> for PolarsError {
+    fn from(err: ValidationError) -> Self {
+        match err {
+            ValidationError::Polars(err) => err,
+            ValidationError::MissingColumn { .. } => polars_err!(ColumnNotFound: "{}", err),
+            ValidationError::TypeMismatch { .. }
+            | ValidationError::ColumnCountMismatch { .. }
+            | ValidationError::UnexpectedColumn { .. }
+            | ValidationError::ForbiddenColumns { .. }
+            | ValidationError::SchemaMismatch(_) => polars_err!(SchemaMismatch: "{}", err),
+            ValidationError::RowOutOfBounds { .. } => polars_err!(OutOfBounds: "{}", err),
+            ValidationError::LengthMismatch { .. } | ValidationError::RowWidthMismatch { .. } => {
+                polars_err!(ShapeMismatch: "{}", err)
+            }
+            ValidationError::InputFrame { index, source } => {
+                PolarsError::from(*source).context(format!("input frame {index}").into())
+            }
+            ValidationError::RowValue { position, source } => {
+                PolarsError::from(*source).context(format!("row value {position}").into())
+            }
+            err => polars_err!(ComputeError: "{}", err),
+        }
+    }
+}
+
+impl ValidationError {
+    /// Stable code of the error variant, e.g. `PT0001` for a missing column
+    ///
+    /// Codes are never reused or renumbered, so alerting and routing rules can
+    /// match on them instead of on messages.
+    pub fn code(&self) -> &'static str {
+        match self {
+            ValidationError::MissingColumn { .. } => "PT0001",
+            ValidationError::TypeMismatch { .. } => "PT0002",
+            ValidationError::ColumnCountMismatch { .. } => "PT0003",
+            ValidationError::UnexpectedColumn { .. } => "PT0004",
+            ValidationError::InvalidEnumValue { .. } => "PT0005",
+            ValidationError::RowOutOfBounds { .. } => "PT0006",
+            ValidationError::InvalidValue { .. } => "PT0007",
+            ValidationError::MissingReferenceFrame { .. } => "PT0008",
+            ValidationError::Polars(_) => "PT0009",
+            #[cfg(feature = "serde")]
+            ValidationError::Json(_) => "PT0010",
+            #[cfg(feature = "avro")]
+            ValidationError::Avro(_) => "PT0011",
+            #[cfg(feature = "delta")]
+            ValidationError::Delta(_) => "PT0012",
+            #[cfg(feature = "flight")]
+            ValidationError::Flight(_) => "PT0013",
+            ValidationError::SchemaMismatch(_) => "PT0014",
+            ValidationError::LengthMismatch { .. } => "PT0015",
+            ValidationError::InputFrame { .. } => "PT0016",
+            ValidationError::UnknownGroup { .. } => "PT0017",
+            ValidationError::DuplicateKeys { .. } => "PT0018",
+            ValidationError::OrphanedReferences { .. } => "PT0019",
+            ValidationError::FingerprintMismatch { .. } => "PT0020",
+            ValidationError::ForbiddenColumns { .. } => "PT0021",
+            ValidationError::UnparseableValues { .. } => "PT0022",
+            ValidationError::RowWidthMismatch { .. } => "PT0023",
+            ValidationError::RowValue { .. } => "PT0024",
+        }
+    }
+
+    /// Name of the error variant, e.g. `MissingColumn`
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ValidationError::MissingColumn { .. } => "MissingColumn",
+            ValidationError::TypeMismatch { .. } => "TypeMismatch",
+            ValidationError::ColumnCountMismatch { .. } => "ColumnCountMismatch",
+            ValidationError::UnexpectedColumn { .. } => "UnexpectedColumn",
+            ValidationError::InvalidEnumValue { .. } => "InvalidEnumValue",
+            ValidationError::RowOutOfBounds { .. } => "RowOutOfBounds",
+            ValidationError::InvalidValue { .. } => "InvalidValue",
+            ValidationError::MissingReferenceFrame { .. } => "MissingReferenceFrame",
+            ValidationError::Polars(_) => "Polars",
+            #[cfg(feature = "serde")]
+            ValidationError::Json(_) => "Json",
+            #[cfg(feature = "avro")]
+            ValidationError::Avro(_) => "Avro",
+            #[cfg(feature = "delta")]
+            ValidationError::Delta(_) => "Delta",
+            #[cfg(feature = "flight")]
+            ValidationError::Flight(_) => "Flight",
+            ValidationError::SchemaMismatch(_) => "SchemaMismatch",
+            ValidationError::LengthMismatch { .. } => "LengthMismatch",
+            ValidationError::InputFrame { .. } => "InputFrame",
+            ValidationError::UnknownGroup { .. } => "UnknownGroup",
+            ValidationError::DuplicateKeys { .. } => "DuplicateKeys",
+            ValidationError::OrphanedReferences { .. } => "OrphanedReferences",
+            ValidationError::FingerprintMismatch { .. } => "FingerprintMismatch",
+            ValidationError::ForbiddenColumns { .. } => "ForbiddenColumns",
+            ValidationError::UnparseableValues { .. } => "UnparseableValues",
+            ValidationError::RowWidthMismatch { .. } => "RowWidthMismatch",
+            ValidationError::RowValue { .. } => "RowValue",
+        }
+    }
+}
+
+/// Serialized as an object with the `code`, `kind` and `message` of the
+/// error, followed by the fields of the variant
+impl Serialize for ValidationError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("code", self.code())?;
+        map.serialize_entry("kind", self.kind())?;
+        map.serialize_entry("message", &self.to_string())?;
+        let sorted = |names: &HashSet<String>| names.iter().cloned().collect::<BTreeSet<_>>();
+        match self {
+            ValidationError::MissingColumn {
+                column_name,
+                suggestions,
+            } => {
+                map.serialize_entry("column", column_name)?;
+                map.serialize_entry("suggestions", suggestions)?;
+            }
+            ValidationError::TypeMismatch {
+                column_name,
+                actual_type,
+                expected_type,
+            } => {
+                map.serialize_entry("column", column_name)?;
+                map.serialize_entry("actual_type", actual_type)?;
+                map.serialize_entry("expected_type", expected_type)?;
+            }
+            ValidationError::ColumnCountMismatch { expected, actual } => {
+                map.serialize_entry("expected", &sorted(expected))?;
+                map.serialize_entry("actual", &sorted(actual))?;
+            }
+            ValidationError::UnexpectedColumn { column_name } => {
+                map.serialize_entry("column", column_name)?;
+            }
+            ValidationError::InvalidEnumValue {
+                field,
+                value,
+                valid_values,
+            } => {
+                map.serialize_entry("column", field)?;
+                map.serialize_entry("value", value)?;
+                map.serialize_entry("valid_values", valid_values)?;
+            }
+            ValidationError::RowOutOfBounds { row, height } => {
+                map.serialize_entry("row", row)?;
+                map.serialize_entry("height", height)?;
+            }
+            ValidationError::InvalidValue {
+                column_name,
+                value,
+                reason,
+            } => {
+                map.serialize_entry("column", column_name)?;
+                map.serialize_entry("value", value)?;
+                map.serialize_entry("reason", reason)?;
+            }
+            ValidationError::MissingReferenceFrame { frame } => {
+                map.serialize_entry("frame", frame)?;
+            }
+            ValidationError::SchemaMismatch(diff) => {
+                map.serialize_entry("diff", diff)?;
+            }
+            ValidationError::LengthMismatch {
+                column_name,
+                expected,
+                actual,
+            } => {
+                map.serialize_entry("column", column_name)?;
+                map.serialize_entry("expected", expected)?;
+                map.serialize_entry("actual", actual)?;
+            }
+            ValidationError::InputFrame { index, source } => {
+                map.serialize_entry("index", index)?;
+                map.serialize_entry("error", source)?;
+            }
+            ValidationError::UnknownGroup { group, groups } => {
+                map.serialize_entry("group", group)?;
+                map.serialize_entry("groups", groups)?;
+            }
+            ValidationError::DuplicateKeys {
+                columns,
+                duplicates,
+                sample,
+            } => {
+                map.serialize_entry("columns", columns)?;
+                map.serialize_entry("duplicates", duplicates)?;
+                map.serialize_entry("sample", sample)?;
+            }
+            ValidationError::OrphanedReferences {
+                column_name,
+                referenced,
+                rows,
+                values,
+                sample,
+            } => {
+                map.serialize_entry("column", column_name)?;
+                map.serialize_entry("referenced", referenced)?;
+                map.serialize_entry("rows", rows)?;
+                map.serialize_entry("values", values)?;
+                map.serialize_entry("sample", sample)?;
+            }
+            ValidationError::FingerprintMismatch { expected, actual } => {
+                map.serialize_entry("expected", expected)?;
+                map.serialize_entry("actual", actual)?;
+            }
+            ValidationError::ForbiddenColumns { schema, columns } => {
+                map.serialize_entry("schema", schema)?;
+                map.serialize_entry("columns", columns)?;
+            }
+            ValidationError::UnparseableValues {
+                column_name,
+                formats,
+                rows,
+                sample,
+            } => {
+                map.serialize_entry("column", column_name)?;
+                map.serialize_entry("formats", formats)?;
+                map.serialize_entry("rows", rows)?;
+                map.serialize_entry("sample", sample)?;
+            }
+            ValidationError::RowWidthMismatch { expected, actual } => {
+                map.serialize_entry("expected", expected)?;
+                map.serialize_entry("actual", actual)?;
+            }
+            ValidationError::RowValue { position, source } => {
+                map.serialize_entry("position", position)?;
+                map.serialize_entry("error", source)?;
+            }
+            _ => {}
+        }
+        map.end()
+    }
+}
@@ -0,0 +1,241 @@
+//! Versioned suites of data-quality checks whose runs are kept and compared
+//!
+//! An `ExpectationSuite` is a named, versioned `QualityChecks` collection,
+//! usually started from the generated `Struct::expectation_suite()` and
+//! extended with runtime rules. It serializes to JSON next to the pipeline:
+//!
+//! ```json
+//! {"name": "Order", "version": 2, "checks": [
+//!     {"kind": "unique", "column": "id"},
+//!     {"kind": "range", "column": "amount", "min": 0, "max": null}
+//! ]}
+//! ```
+//!
+//! Running a suite gives a `SuiteRun`, which is serializable too, so the run
+//! of yesterday's data can be stored and today's run compared against it with
+//! `SuiteRun::compare`.
+
+use std::fmt;
+
+use polars::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::quality::{CheckResult, QualityCheck, QualityChecks, QualityReport};
+use crate::Result;
+
+/// Named and versioned collection of data-quality checks
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExpectationSuite {
+    /// Name of the suite, the schema struct name for generated suites
+    pub name: String,
+    /// Version of the suite, the `SCHEMA_VERSION` for generated suites;
+    /// bump it when the checks change
+    pub version: u32,
+    #[serde(flatten)]
+    pub checks: QualityChecks,
+}
+
+impl ExpectationSuite {
+    /// Empty suite
+    pub fn new(name: &str, version: u32) -> Self {
+        ExpectationSuite {
+            name: name.to_string(),
+            version,
+            checks: QualityChecks::new(),
+        }
+    }
+
+    /// Add any check
+    pub fn check(mut self, check: QualityCheck) -> Self {
+        self.checks = self.checks.check(check);
+        self
+    }
+
+    /// Append the checks (and reference frames) of a collection
+    pub fn checks(mut self, checks: QualityChecks) -> Self {
+        self.checks = self.checks.extend(checks);
+        self
+    }
+
+    /// Register the frame that `References` checks name as `name`
+    pub fn reference_frame(mut self, name: &str, frame: DataFrame) -> Self {
+        self.checks = self.checks.reference_frame(name, frame);
+        self
+    }
+
+    /// Run every check against `df`, see `QualityChecks::run`
+    pub fn run(&self, df: &DataFrame) -> Result<SuiteRun> {
+        Ok(SuiteRun {
+            suite: self.name.clone(),
+            version: self.version,
+            report: self.checks.run(df)?,
+        })
+    }
+
+    /// Pretty-printed JSON of the suite, without its reference frames
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Suite stored by `to_json`
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+}
+
+/// Outcome of `ExpectationSuite::run`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SuiteRun {
+    /// Name of the suite that ran
+    pub suite: String,
+    /// Version of the suite that ran
+    pub version: u32,
+    pub report: QualityReport,
+}
+
+impl SuiteRun {
+    /// Whether every check passed
+    pub fn passed(&self) -> bool {
+        self.report.passed()
+    }
+
+    /// Changes in outcome since the `previous` run, matching checks by
+    /// equality so runs of different suite versions can be compared
+    pub fn compare(&self, previous: &SuiteRun) -> RunDelta {
+        let mut matched = vec![false; previous.report.results.len()];
+        let mut delta = RunDelta {
+            suite: self.suite.clone(),
+            previous_version: previous.version,
+            version: self.version,
+            newly_failing: Vec::new(),
+            newly_passing: Vec::new(),
+            still_failing: Vec::new(),
+            added: Vec::new(),
+            removed: Vec::new(),
+        };
+
+        for result in &self.report.results {
+            let before = previous
+                .report
+                .results
+                .iter()
+                .enumerate()
+                .find(|(i, before)| !matched[*i] && before.check == result.check);
+            let Some((i, before)) = before else {
+                delta.added.push(result.clone());
+                continue;
+            };
+            matched[i] = true;
+
+            let change = CheckDelta {
+                check: result.check.clone(),
+                previous_violations: before.violations,
+                violations: result.violations,
+            };
+            match (before.passed, result.passed) {
+                (true, false) => delta.newly_failing.push(change),
+                (false, true) => delta.newly_passing.push(change),
+                (false, false) => delta.still_failing.push(change),
+                (true, true) => {}
+            }
+        }
+
+        delta.removed = previous
+            .report
+            .results
+            .iter()
+            .zip(matched)
+            .filter(|(_, matched)| !matched)
+            .map(|(before, _)| before.check.clone())
+            .collect();
+        delta
+    }
+
+    /// Pretty-printed JSON of the run, to compare the next run against
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Run stored by `to_json`
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+}
+
+/// Violations of a check in two runs
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CheckDelta {
+    pub check: QualityCheck,
+    pub previous_violations: usize,
+    pub violations: usize,
+}
+
+/// Pass/fail changes between two runs of a suite, see `SuiteRun::compare`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RunDelta {
+    pub suite: String,
+    pub previous_version: u32,
+    pub version: u32,
+    /// Checks that passed in the previous run and fail now
+    pub newly_failing: Vec<CheckDelta>,
+    /// Checks that failed in the previous run and pass now
+    pub newly_passing: Vec<CheckDelta>,
+    /// Checks that failed in both runs
+    pub still_failing: Vec<CheckDelta>,
+    /// Checks the previous run did not have
+    pub added: Vec<CheckResult>,
+    /// Checks of the previous run that are gone
+    pub removed: Vec<QualityCheck>,
+}
+
+impl RunDelta {
+    /// Whether a check started failing, counting new checks that fail
+    pub fn regressed(&self) -> bool {
+        !self.newly_failing.is_empty() || self.added.iter().any(|result| !result.passed)
+    }
+}
+
+impl fmt::Display for RunDelta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} v{} against v{}: {} newly failing, {} newly passing, {} still failing",
+            self.suite,
+            self.version,
+            self.previous_version,
+            self.newly_failing.len(),
+            self.newly_passing.len(),
+            self.still_failing.len()
+        )?;
+        let changes = [
+            ("newly failing", &self.newly_failing),
+            ("newly passing", &self.newly_passing),
+            ("still failing", &self.still_failing),
+        ];
+        for (status, deltas) in changes {
+            for delta in deltas {
+                write!(
+                    f,
+                    "\n[{}] {} ({} -> {} violations)",
+                    status, delta.check, delta.previous_violations, delta.violations
+                )?;
+            }
+        }
+        for result in &self.added {
+            let status = if result.passed { "ok" } else { "FAILED" };
+            write!(
+                f,
+                "\n[added, {}] {} ({} violations)",
+                status, result.check, result.violations
+            )?;
+        }
+        for check in &self.removed {
+            write!(f, "\n[removed] {}", check)?;
+        }
+        Ok(())
+    }
+}
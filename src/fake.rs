@@ -0,0 +1,161 @@
+//! Random data generation for derived schemas (`fake` feature)
+//!
+//! Used by the generated `Struct::fake_rows(n)` and `Struct::fake_df(n)`.
+//! Numeric fields honour the `#[polars(min = ..., max = ...)]` bounds and
+//! `Option` fields are null with probability `FakeOptions::null_probability`.
+
+use rand::distributions::Alphanumeric;
+use rand::Rng;
+
+pub use rand;
+
+use crate::{ToAnyValue, ValidatableEnum};
+
+/// Generation settings of a single field
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FakeOptions {
+    /// Inclusive lower bound of numeric values
+    pub min: Option<f64>,
+    /// Inclusive upper bound of numeric values
+    pub max: Option<f64>,
+    /// Probability of `None` for `Option` fields
+    pub null_probability: f64,
+}
+
+impl Default for FakeOptions {
+    fn default() -> Self {
+        Self {
+            min: None,
+            max: None,
+            null_probability: 0.1,
+        }
+    }
+}
+
+impl FakeOptions {
+    fn bounds(&self, min: f64, max: f64) -> (f64, f64) {
+        let low = self.min.unwrap_or(min);
+        let high = self.max.unwrap_or(max.max(low));
+        (low, high.max(low))
+    }
+}
+
+/// Trait for Rust types that can produce random, plausible values
+///
+/// Generated frames are built through the values' `ToAnyValue` impl.
+pub trait Fake: ToAnyValue + Sized {
+    /// Random value within the field's options
+    fn fake<R: Rng + ?Sized>(rng: &mut R, options: &FakeOptions) -> Self;
+}
+
+macro_rules! impl_fake_int {
+    ($($ty:ty => $min:expr, $max:expr);* $(;)?) => {
+        $(
+            impl Fake for $ty {
+                fn fake<R: Rng + ?Sized>(rng: &mut R, options: &FakeOptions) -> Self {
+                    let (low, high) = options.bounds($min, $max);
+                    rng.gen_range(low.ceil() as $ty..=high.floor() as $ty)
+                }
+            }
+        )*
+    };
+}
+
+impl_fake_int! {
+    i8 => 0.0, 127.0;
+    i16 => 0.0, 1_000.0;
+    i32 => 0.0, 1_000.0;
+    i64 => 0.0, 1_000.0;
+    u8 => 0.0, 255.0;
+    u16 => 0.0, 1_000.0;
+    u32 => 0.0, 1_000.0;
+    u64 => 0.0, 1_000.0;
+}
+
+macro_rules! impl_fake_float {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Fake for $ty {
+                fn fake<R: Rng + ?Sized>(rng: &mut R, options: &FakeOptions) -> Self {
+                    let (low, high) = options.bounds(0.0, 1_000.0);
+                    rng.gen_range(low..=high) as $ty
+                }
+            }
+        )*
+    };
+}
+
+impl_fake_float!(f32, f64);
+
+impl Fake for bool {
+    fn fake<R: Rng + ?Sized>(rng: &mut R, _options: &FakeOptions) -> Self {
+        rng.gen()
+    }
+}
+
+/// Alphanumeric words of 4 to 12 characters
+impl Fake for String {
+    fn fake<R: Rng + ?Sized>(rng: &mut R, _options: &FakeOptions) -> Self {
+        let len = rng.gen_range(4..=12);
+        (0..len).map(|_| rng.sample(Alphanumeric) as char).collect()
+    }
+}
+
+impl<T: Fake> Fake for Option<T> {
+    fn fake<R: Rng + ?Sized>(rng: &mut R, options: &FakeOptions) -> Self {
+        if rng.gen_bool(options.null_probability.clamp(0.0, 1.0)) {
+            None
+        } else {
+            Some(T::fake(rng, options))
+        }
+    }
+}
+
+/// Enum fields pick one of their valid values
+impl<T: ValidatableEnum> Fake for T {
+    fn fake<R: Rng + ?Sized>(rng: &mut R, _options: &FakeOptions) -> Self {
+        let values = T::valid_values();
+        let value = values[rng.gen_range(0..values.len())];
+        <T as ValidatableEnum>::from_str(value).expect("valid_values() entries must parse")
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_fakes {
+    use super::{Fake, FakeOptions};
+    use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+    use rand::Rng;
+
+    /// 2000-01-01 and 2030-12-31 as Unix seconds
+    const START: i64 = 946_684_800;
+    const END: i64 = 1_924_991_999;
+
+    fn fake_timestamp<R: Rng + ?Sized>(rng: &mut R) -> DateTime<Utc> {
+        DateTime::from_timestamp(rng.gen_range(START..=END), 0).unwrap_or_default()
+    }
+
+    impl Fake for NaiveDate {
+        fn fake<R: Rng + ?Sized>(rng: &mut R, _options: &FakeOptions) -> Self {
+            fake_timestamp(rng).date_naive()
+        }
+    }
+
+    impl Fake for NaiveDateTime {
+        fn fake<R: Rng + ?Sized>(rng: &mut R, _options: &FakeOptions) -> Self {
+            fake_timestamp(rng).naive_utc()
+        }
+    }
+
+    impl Fake for DateTime<Utc> {
+        fn fake<R: Rng + ?Sized>(rng: &mut R, _options: &FakeOptions) -> Self {
+            fake_timestamp(rng)
+        }
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl Fake for uuid::Uuid {
+    fn fake<R: Rng + ?Sized>(rng: &mut R, _options: &FakeOptions) -> Self {
+        uuid::Builder::from_random_bytes(rng.gen()).into_uuid()
+    }
+}
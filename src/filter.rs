@@ -0,0 +1,146 @@
+//! Fluent predicate building for derived schemas
+
+use std::marker::PhantomData;
+
+use polars::prelude::*;
+
+use crate::column_expr::column_expr;
+use crate::IntoLiteral;
+
+/// Accumulated predicate of a generated `FilterFor{Struct}` builder
+///
+/// Conditions are joined with AND unless `or()` was called before adding
+/// the next one. Joining is left-associative, so conditions `a`, `or()`, `b`,
+/// `c` build `(a OR b) AND c`.
+#[derive(Clone, Default)]
+pub struct FilterBuilder {
+    predicate: Option<Expr>,
+    or_next: bool,
+}
+
+impl FilterBuilder {
+    /// Empty builder with no conditions
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Join the next condition with OR instead of AND
+    pub fn or(mut self) -> Self {
+        self.or_next = true;
+        self
+    }
+
+    /// Join the next condition with AND (the default)
+    pub fn and(mut self) -> Self {
+        self.or_next = false;
+        self
+    }
+
+    /// Add a condition to the predicate
+    pub fn push(self, condition: Expr) -> Self {
+        let predicate = match self.predicate {
+            Some(predicate) if self.or_next => predicate.or(condition),
+            Some(predicate) => predicate.and(condition),
+            None => condition,
+        };
+        Self {
+            predicate: Some(predicate),
+            or_next: false,
+        }
+    }
+
+    /// Final predicate; an empty builder matches every row
+    pub fn build(self) -> Expr {
+        self.predicate.unwrap_or_else(|| lit(true))
+    }
+}
+
+/// Builders that can take another condition, implemented by the generated
+/// `FilterFor{Struct}` types
+pub trait FilterChain: Sized {
+    /// Add a condition to the builder's predicate
+    fn push(self, condition: Expr) -> Self;
+}
+
+/// Comparison on a single column, returned by the per-field methods of a
+/// generated filter builder
+///
+/// Values are converted with `IntoLiteral` and cast to the column dtype, so
+/// `filter().age().gt(30)` compares against an `Int64` literal when `age` is an
+/// `i64` field.
+pub struct FieldFilter<B, T> {
+    builder: B,
+    column: &'static str,
+    dtype: DataType,
+    _value: PhantomData<fn(T)>,
+}
+
+impl<B: FilterChain, T> FieldFilter<B, T> {
+    #[doc(hidden)]
+    pub fn new(builder: B, column: &'static str, dtype: DataType) -> Self {
+        Self {
+            builder,
+            column,
+            dtype,
+            _value: PhantomData,
+        }
+    }
+
+    fn compare(self, op: fn(Expr, Expr) -> Expr, value: T) -> B
+    where
+        T: IntoLiteral,
+    {
+        let value = value.into_literal().cast(self.dtype);
+        self.builder.push(op(column_expr(self.column), value))
+    }
+
+    pub fn eq(self, value: impl Into<T>) -> B
+    where
+        T: IntoLiteral,
+    {
+        self.compare(Expr::eq, value.into())
+    }
+
+    pub fn neq(self, value: impl Into<T>) -> B
+    where
+        T: IntoLiteral,
+    {
+        self.compare(Expr::neq, value.into())
+    }
+
+    pub fn gt(self, value: impl Into<T>) -> B
+    where
+        T: IntoLiteral,
+    {
+        self.compare(Expr::gt, value.into())
+    }
+
+    pub fn gt_eq(self, value: impl Into<T>) -> B
+    where
+        T: IntoLiteral,
+    {
+        self.compare(Expr::gt_eq, value.into())
+    }
+
+    pub fn lt(self, value: impl Into<T>) -> B
+    where
+        T: IntoLiteral,
+    {
+        self.compare(Expr::lt, value.into())
+    }
+
+    pub fn lt_eq(self, value: impl Into<T>) -> B
+    where
+        T: IntoLiteral,
+    {
+        self.compare(Expr::lt_eq, value.into())
+    }
+
+    pub fn is_null(self) -> B {
+        self.builder.push(column_expr(self.column).is_null())
+    }
+
+    pub fn is_not_null(self) -> B {
+        self.builder.push(column_expr(self.column).is_not_null())
+    }
+}
@@ -0,0 +1,29 @@
+//! Stable fingerprints of schemas, behind the generated `schema_hash`
+
+use polars::prelude::*;
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// 64-bit FNV-1a hash of the column names and dtypes of `schema`, in order
+///
+/// Unlike `std`'s hashers the result is the same across processes, platforms
+/// and Rust versions, so it can be stored alongside data and compared later.
+/// Dtypes are hashed by their `Debug` form, which includes time units, time
+/// zones and struct fields.
+pub fn schema_hash(schema: &Schema) -> u64 {
+    let mut hash = FNV_OFFSET;
+    for (name, dtype) in schema.iter() {
+        let entry = format!("{}\0{:?}\n", name, dtype);
+        for byte in entry.bytes() {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(FNV_PRIME);
+        }
+    }
+    hash
+}
+
+/// `schema_hash` as 16 lowercase hex digits, e.g. for file metadata
+pub fn schema_fingerprint(schema: &Schema) -> String {
+    format!("{:016x}", schema_hash(schema))
+}
@@ -0,0 +1,125 @@
+//! Arrow Flight payloads of frames with a derived schema
+//!
+//! A Flight `DoGet` / `DoPut` stream is a sequence of `FlightData` messages,
+//! each holding one Arrow IPC message: the schema first, then the record
+//! batches. Frames are converted to and from those messages through the
+//! polars IPC stream reader and writer, and sent through a `FlightClient`,
+//! which adapts whatever gRPC client the application uses.
+
+use std::io::Cursor;
+
+use polars::prelude::*;
+
+use crate::{Result, ValidationError};
+
+const CONTINUATION: [u8; 4] = [0xFF; 4];
+
+fn flight_error(message: impl Into<String>) -> ValidationError {
+    ValidationError::Flight(message.into())
+}
+
+/// The IPC message of a Flight `FlightData`, i.e. its `data_header` and
+/// `data_body` fields
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FlightData {
+    /// Flatbuffer-encoded IPC `Message`
+    pub data_header: Vec<u8>,
+    /// Buffers of a record or dictionary batch, empty for the schema
+    pub data_body: Vec<u8>,
+}
+
+/// Transport of Flight streams, implemented over a gRPC Flight client
+pub trait FlightClient {
+    /// `DoGet` of `ticket`, returning the messages of the response stream
+    fn do_get(&mut self, ticket: &[u8]) -> Result<Vec<FlightData>>;
+
+    /// `DoPut` of the messages of a stream
+    fn do_put(&mut self, data: Vec<FlightData>) -> Result<()>;
+}
+
+/// Encode the columns of `schema` as Flight messages, schema first
+///
+/// Strings and binaries are written as large (non-view) Arrow types, which
+/// every Arrow implementation reads.
+pub fn encode_flight(df: &DataFrame, schema: &Schema) -> Result<Vec<FlightData>> {
+    let mut df = df.select(schema.iter_names().cloned())?;
+    let mut stream = Vec::new();
+    IpcStreamWriter::new(&mut stream)
+        .with_compat_level(CompatLevel::oldest())
+        .finish(&mut df)?;
+
+    let mut messages = Vec::new();
+    let mut rest = stream.as_slice();
+    loop {
+        let (len, after_len) = match rest {
+            [0xFF, 0xFF, 0xFF, 0xFF, len @ ..] => take_i32(len)?,
+            _ => take_i32(rest)?,
+        };
+        if len == 0 {
+            break;
+        }
+        let header = after_len
+            .get(..len)
+            .ok_or_else(|| flight_error("truncated IPC message"))?;
+        let body_len = body_length(header)?;
+        let body = after_len
+            .get(len..len + body_len)
+            .ok_or_else(|| flight_error("truncated IPC message body"))?;
+        messages.push(FlightData {
+            data_header: header.to_vec(),
+            data_body: body.to_vec(),
+        });
+        rest = &after_len[len + body_len..];
+    }
+    Ok(messages)
+}
+
+/// Decode Flight messages, schema first, into a frame
+pub fn decode_flight(data: &[FlightData]) -> Result<DataFrame> {
+    let mut stream = Vec::new();
+    for message in data {
+        let padding = (8 - message.data_header.len() % 8) % 8;
+        stream.extend_from_slice(&CONTINUATION);
+        stream.extend_from_slice(&((message.data_header.len() + padding) as i32).to_le_bytes());
+        stream.extend_from_slice(&message.data_header);
+        stream.resize(stream.len() + padding, 0);
+        stream.extend_from_slice(&message.data_body);
+    }
+    stream.extend_from_slice(&CONTINUATION);
+    stream.extend_from_slice(&[0; 4]);
+    Ok(IpcStreamReader::new(Cursor::new(stream)).finish()?)
+}
+
+fn take_i32(bytes: &[u8]) -> Result<(usize, &[u8])> {
+    let (len, rest) = bytes
+        .split_first_chunk::<4>()
+        .ok_or_else(|| flight_error("truncated IPC stream"))?;
+    let len = usize::try_from(i32::from_le_bytes(*len))
+        .map_err(|_| flight_error("negative IPC message length"))?;
+    Ok((len, rest))
+}
+
+/// `bodyLength` (field 3) of a flatbuffer IPC `Message`
+fn body_length(header: &[u8]) -> Result<usize> {
+    let read = |at: usize, n: usize| {
+        header
+            .get(at..at + n)
+            .ok_or_else(|| flight_error("malformed IPC message header"))
+    };
+    let u32_at = |at| read(at, 4).map(|b| u32::from_le_bytes(b.try_into().unwrap()) as usize);
+    let table = u32_at(0)?;
+    let vtable_offset = i32::from_le_bytes(read(table, 4)?.try_into().unwrap());
+    let vtable = usize::try_from(table as i64 - vtable_offset as i64)
+        .map_err(|_| flight_error("malformed IPC message header"))?;
+    let vtable_len = u16::from_le_bytes(read(vtable, 2)?.try_into().unwrap()) as usize;
+    let slot = 4 + 2 * 3;
+    if slot + 2 > vtable_len {
+        return Ok(0);
+    }
+    let field = u16::from_le_bytes(read(vtable + slot, 2)?.try_into().unwrap()) as usize;
+    if field == 0 {
+        return Ok(0);
+    }
+    let body_length = i64::from_le_bytes(read(table + field, 8)?.try_into().unwrap());
+    usize::try_from(body_length).map_err(|_| flight_error("negative IPC body length"))
+}
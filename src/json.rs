@@ -0,0 +1,286 @@
+//! Conversion between frames and JSON arrays of row objects, driven by a
+//! derived schema
+
+use polars::prelude::*;
+use serde_json::{Map, Number, Value};
+
+use crate::parse::{is_zone_aware, parse_temporal};
+use crate::{Result, ValidationError};
+
+/// Format of date columns without a `#[polars(format = "...")]` attribute
+pub const DEFAULT_DATE_FORMAT: &str = "%Y-%m-%d";
+/// Format of datetime columns without a `#[polars(format = "...")]` attribute
+pub const DEFAULT_DATETIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f";
+/// Format of time columns without a `#[polars(format = "...")]` attribute
+pub const DEFAULT_TIME_FORMAT: &str = "%H:%M:%S%.f";
+
+fn temporal_format<'a>(dtype: &DataType, format: Option<&'a str>) -> &'a str {
+    match (format, dtype) {
+        (Some(format), _) => format,
+        (None, DataType::Date) => DEFAULT_DATE_FORMAT,
+        (None, DataType::Time) => DEFAULT_TIME_FORMAT,
+        (None, _) => DEFAULT_DATETIME_FORMAT,
+    }
+}
+
+fn invalid(column: &str, value: &Value, reason: &str) -> ValidationError {
+    ValidationError::InvalidValue {
+        column_name: column.to_string(),
+        value: value.to_string(),
+        reason: reason.to_string(),
+    }
+}
+
+/// Build a frame with the columns of `schema` from a JSON array of objects
+///
+/// Keys missing from a row are read as nulls and keys outside the schema are
+/// ignored. Temporal columns are read from strings in the format given for
+/// them in `formats`, or the `DEFAULT_*_FORMAT` of their dtype; naive
+/// datetimes of a zoned column are read as UTC.
+pub fn from_json_rows(json: &str, schema: &Schema, formats: &[(&str, &str)]) -> Result<DataFrame> {
+    let rows: Vec<Map<String, Value>> = serde_json::from_str(json)?;
+    from_json_objects(&rows, schema, formats)
+}
+
+/// `from_json_rows` of already parsed row objects
+pub(crate) fn from_json_objects(
+    rows: &[Map<String, Value>],
+    schema: &Schema,
+    formats: &[(&str, &str)],
+) -> Result<DataFrame> {
+    let columns = schema
+        .iter()
+        .map(|(name, dtype)| {
+            let values: Vec<&Value> = rows
+                .iter()
+                .map(|row| row.get(name.as_str()).unwrap_or(&Value::Null))
+                .collect();
+            let format = formats
+                .iter()
+                .find(|(column, _)| *column == name.as_str())
+                .map(|(_, format)| *format);
+            series_from_json(name.as_str(), &values, dtype, format).map(Column::from)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(DataFrame::new(columns)?)
+}
+
+fn series_from_json(
+    name: &str,
+    values: &[&Value],
+    dtype: &DataType,
+    format: Option<&str>,
+) -> Result<Series> {
+    let series = match dtype {
+        DataType::Date | DataType::Datetime(_, _) | DataType::Time => {
+            let strings = values
+                .iter()
+                .map(|value| match value {
+                    Value::Null => Ok(None),
+                    Value::String(s) => Ok(Some(s.as_str())),
+                    other => Err(invalid(name, other, "expected a string")),
+                })
+                .collect::<Result<StringChunked>>()?
+                .with_name(name.into());
+            let format = temporal_format(dtype, format);
+            let parsed = parse_temporal(&strings, dtype, format)?;
+            // Strings that do not match the format parse to nulls
+            if let Some(unparsed) = strings
+                .into_iter()
+                .zip(&parsed.is_null())
+                .find_map(|(s, null)| s.filter(|_| null == Some(true)))
+            {
+                return Err(invalid(
+                    name,
+                    &Value::String(unparsed.to_string()),
+                    &format!("does not match the format {}", format),
+                ));
+            }
+            parsed
+        }
+        DataType::List(inner) => {
+            let lists = values
+                .iter()
+                .map(|value| match value {
+                    Value::Null => Ok(None),
+                    Value::Array(items) => {
+                        let items: Vec<&Value> = items.iter().collect();
+                        series_from_json("", &items, inner, None).map(Some)
+                    }
+                    other => Err(invalid(name, other, "expected an array")),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let mut list: ListChunked = lists.into_iter().collect();
+            list.rename(name.into());
+            list.into_series()
+        }
+        DataType::Struct(fields) => {
+            let field_series = fields
+                .iter()
+                .map(|field| {
+                    let field_values = values
+                        .iter()
+                        .map(|value| match value {
+                            Value::Null => Ok(&Value::Null),
+                            Value::Object(object) => {
+                                Ok(object.get(field.name().as_str()).unwrap_or(&Value::Null))
+                            }
+                            other => Err(invalid(name, other, "expected an object")),
+                        })
+                        .collect::<Result<Vec<_>>>()?;
+                    series_from_json(field.name(), &field_values, field.dtype(), None)
+                })
+                .collect::<Result<Vec<_>>>()?;
+            StructChunked::from_series(name.into(), values.len(), field_series.iter())?
+                .into_series()
+        }
+        DataType::Binary => {
+            let bytes = values
+                .iter()
+                .map(|value| match value {
+                    Value::Null => Ok(None),
+                    Value::Array(items) => items
+                        .iter()
+                        .map(|item| item.as_u64().and_then(|byte| u8::try_from(byte).ok()))
+                        .collect::<Option<Vec<u8>>>()
+                        .map(Some)
+                        .ok_or_else(|| invalid(name, value, "expected an array of bytes")),
+                    other => Err(invalid(name, other, "expected an array of bytes")),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Series::new(name.into(), bytes)
+        }
+        _ => {
+            let any_values = values
+                .iter()
+                .map(|value| match value {
+                    Value::Null => Ok(AnyValue::Null),
+                    Value::Bool(b) => Ok(AnyValue::Boolean(*b)),
+                    Value::Number(n) => Ok(if let Some(i) = n.as_i64() {
+                        AnyValue::Int64(i)
+                    } else if let Some(u) = n.as_u64() {
+                        AnyValue::UInt64(u)
+                    } else {
+                        AnyValue::Float64(n.as_f64().unwrap_or(f64::NAN))
+                    }),
+                    Value::String(s) => Ok(AnyValue::StringOwned(s.as_str().into())),
+                    other => Err(invalid(name, other, "expected a scalar")),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Series::from_any_values(name.into(), &any_values, false)?
+        }
+    };
+    Ok(series.strict_cast(dtype)?)
+}
+
+/// Write the columns of `schema` as a JSON array of objects, one per row
+///
+/// Temporal columns are written as strings in the format given for them in
+/// `formats`, or the `DEFAULT_*_FORMAT` of their dtype. Decimal and 128-bit
+/// integer columns are written as strings to keep their precision.
+pub fn to_json_rows(df: &DataFrame, schema: &Schema, formats: &[(&str, &str)]) -> Result<String> {
+    let mut rows = vec![Map::new(); df.height()];
+    for (name, dtype) in schema.iter() {
+        let series = df.column(name.as_str())?.as_materialized_series();
+        let format = formats
+            .iter()
+            .find(|(column, _)| *column == name.as_str())
+            .map(|(_, format)| *format);
+        let values = series_to_json(series, dtype, format)?;
+        for (row, value) in rows.iter_mut().zip(values) {
+            row.insert(name.to_string(), value);
+        }
+    }
+    Ok(serde_json::to_string(&rows)?)
+}
+
+fn series_to_json(series: &Series, dtype: &DataType, format: Option<&str>) -> Result<Vec<Value>> {
+    let strings = |series: &Series| -> Result<Vec<Value>> {
+        Ok(series
+            .str()?
+            .into_iter()
+            .map(|s| s.map_or(Value::Null, |s| Value::String(s.to_string())))
+            .collect())
+    };
+    match dtype {
+        DataType::Date => {
+            let formatted = series.date()?.to_string(temporal_format(dtype, format))?;
+            strings(&formatted.into_series())
+        }
+        DataType::Datetime(unit, zone) => {
+            let format = temporal_format(dtype, format);
+            // Naive formats write zoned datetimes in UTC, as they are read back
+            let series = if zone.is_some() && !is_zone_aware(format) {
+                series.cast(&DataType::Datetime(*unit, None))?
+            } else {
+                series.clone()
+            };
+            let formatted = series.datetime()?.to_string(format)?;
+            strings(&formatted.into_series())
+        }
+        DataType::Time => {
+            let formatted = series.time()?.to_string(temporal_format(dtype, format));
+            strings(&formatted.into_series())
+        }
+        DataType::String | DataType::Int128 => strings(&series.cast(&DataType::String)?),
+        #[cfg(feature = "decimal")]
+        DataType::Decimal(_, _) => strings(&series.cast(&DataType::String)?),
+        DataType::Duration(_) => series_to_json(&series.to_physical_repr(), &DataType::Int64, None),
+        DataType::Binary => Ok(series
+            .binary()?
+            .into_iter()
+            .map(|bytes| {
+                bytes.map_or(Value::Null, |bytes| {
+                    Value::Array(bytes.iter().map(|byte| Value::from(*byte)).collect())
+                })
+            })
+            .collect()),
+        DataType::List(inner) => series
+            .list()?
+            .into_iter()
+            .map(|list| match list {
+                Some(list) => series_to_json(&list, inner, None).map(Value::Array),
+                None => Ok(Value::Null),
+            })
+            .collect(),
+        DataType::Struct(fields) => {
+            let structs = series.struct_()?;
+            let mut objects = vec![Map::new(); series.len()];
+            for (field, field_series) in fields.iter().zip(structs.fields_as_series()) {
+                let values = series_to_json(&field_series, field.dtype(), None)?;
+                for (object, value) in objects.iter_mut().zip(values) {
+                    object.insert(field.name().to_string(), value);
+                }
+            }
+            Ok(objects
+                .into_iter()
+                .zip(&structs.is_not_null())
+                .map(|(object, valid)| match valid {
+                    Some(true) => Value::Object(object),
+                    _ => Value::Null,
+                })
+                .collect())
+        }
+        _ => Ok(series
+            .iter()
+            .map(|value| match value {
+                AnyValue::Boolean(b) => Value::Bool(b),
+                AnyValue::Int8(i) => Value::from(i),
+                AnyValue::Int16(i) => Value::from(i),
+                AnyValue::Int32(i) => Value::from(i),
+                AnyValue::Int64(i) => Value::from(i),
+                AnyValue::UInt8(u) => Value::from(u),
+                AnyValue::UInt16(u) => Value::from(u),
+                AnyValue::UInt32(u) => Value::from(u),
+                AnyValue::UInt64(u) => Value::from(u),
+                AnyValue::Float32(f) => {
+                    Number::from_f64(f.into()).map_or(Value::Null, Value::Number)
+                }
+                AnyValue::Float64(f) => Number::from_f64(f).map_or(Value::Null, Value::Number),
+                other => other
+                    .get_str()
+                    .map_or(Value::Null, |s| Value::String(s.to_string())),
+            })
+            .collect()),
+    }
+}
@@ -0,0 +1,106 @@
+//! Primary-key checks, deduplication and joins, behind the helpers generated
+//! for `#[polars(key)]` fields
+
+use polars::prelude::*;
+
+use crate::{Result, ValidationError};
+
+/// Number of duplicated or orphaned key values quoted in errors
+pub(crate) const SAMPLE_SIZE: usize = 5;
+
+/// Key value of row `row` of `df`, e.g. `7` or `(7, "ams")` for a compound key
+pub(crate) fn key_value(df: &DataFrame, row: usize) -> Result<String> {
+    let row = df.get_row(row)?;
+    let values: Vec<String> = row
+        .0
+        .iter()
+        .map(|value| match value.get_str() {
+            Some(value) => format!("{:?}", value),
+            None => value.to_string(),
+        })
+        .collect();
+    Ok(match values.as_slice() {
+        [value] => value.clone(),
+        values => format!("({})", values.join(", ")),
+    })
+}
+
+fn key_exprs(keys: &[&str]) -> Vec<Expr> {
+    keys.iter().map(|key| col(*key)).collect()
+}
+
+/// Check that no two rows of `df` share their `keys` values, reporting the
+/// number of repeated key values and a sample of them
+#[doc(hidden)]
+pub fn assert_unique_keys(df: &DataFrame, keys: &[&str]) -> Result<()> {
+    let duplicates = df
+        .clone()
+        .lazy()
+        .group_by_stable(key_exprs(keys))
+        .agg([len().alias("__count")])
+        .filter(col("__count").gt(lit(1)))
+        .select(key_exprs(keys))
+        .collect()?;
+    if duplicates.height() == 0 {
+        return Ok(());
+    }
+    let sample = (0..duplicates.height().min(SAMPLE_SIZE))
+        .map(|row| key_value(&duplicates, row))
+        .collect::<Result<_>>()?;
+    Err(ValidationError::DuplicateKeys {
+        columns: keys.iter().map(|key| key.to_string()).collect(),
+        duplicates: duplicates.height(),
+        sample,
+    })
+}
+
+/// `df` with one row per `keys` value, keeping the first of each in row order
+#[doc(hidden)]
+pub fn dedupe(df: &DataFrame, keys: &[&str]) -> Result<DataFrame> {
+    let keys: Vec<String> = keys.iter().map(|key| key.to_string()).collect();
+    Ok(df.unique_stable(Some(&keys), UniqueKeepStrategy::First, None)?)
+}
+
+/// `df` with one row per `keys` value, keeping the last of each when sorted
+/// by `order_by` (e.g. an update timestamp), with rows in that order
+#[doc(hidden)]
+pub fn dedupe_keep_last(df: &DataFrame, keys: &[&str], order_by: &[&str]) -> Result<DataFrame> {
+    let sorted = df.sort(
+        order_by.iter().copied(),
+        SortMultipleOptions::default().with_maintain_order(true),
+    )?;
+    let keys: Vec<String> = keys.iter().map(|key| key.to_string()).collect();
+    Ok(sorted.unique_stable(Some(&keys), UniqueKeepStrategy::Last, None)?)
+}
+
+/// `left`, valid under `left_schema`, joined with `right`, valid under
+/// `right_schema`, on the `keys` columns of both
+#[doc(hidden)]
+pub fn join_on_key(
+    left: &DataFrame,
+    left_schema: &Schema,
+    right: &DataFrame,
+    right_schema: &Schema,
+    keys: &[&str],
+    how: JoinType,
+) -> Result<DataFrame> {
+    crate::validate::validate_schema(left, left_schema, &[])?;
+    crate::validate::validate_schema(right, right_schema, &[])?;
+    for key in keys {
+        let (Some(left_dtype), Some(right_dtype)) = (left_schema.get(key), right_schema.get(key))
+        else {
+            return Err(ValidationError::MissingColumn {
+                column_name: key.to_string(),
+                suggestions: Vec::new(),
+            });
+        };
+        if left_dtype != right_dtype {
+            return Err(ValidationError::TypeMismatch {
+                column_name: key.to_string(),
+                actual_type: format!("{:?}", right_dtype),
+                expected_type: format!("{:?}", left_dtype),
+            });
+        }
+    }
+    Ok(left.join(right, keys.iter().copied(), keys.iter().copied(), how.into(), None)?)
+}
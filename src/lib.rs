@@ -1,19 +1,165 @@
 //! Polars Tools - Helper library for working with Polars DataFrames
 //!
 //! This library provides schema validation and column helper utilities for Polars DataFrames.
+//!
+//! With the default `prelude` feature the polars prelude is re-exported, so
+//! `use polars_tools::*` is enough. Without it only polars-tools' own items
+//! are exported, and the derives refer to `::polars`, i.e. the polars
+//! dependency of the crate using them.
 
+#[cfg(feature = "prelude")]
 pub use polars::prelude::*;
+#[cfg(not(feature = "prelude"))]
+use polars::prelude::*;
 pub use polars_tools_derive::*;
 
 // For internal tests to work with absolute paths
 #[doc(hidden)]
 pub extern crate self as polars_tools;
 
+mod access;
+mod aggregate;
+#[cfg(feature = "avro")]
+pub mod avro;
+pub mod assertions;
+mod cache;
+mod column_expr;
+mod combine;
+mod concat;
+#[cfg(feature = "delta")]
+pub mod delta;
+#[cfg(feature = "pretty-errors")]
+pub mod diagnostic;
+mod diff;
+mod describe;
+mod dtype;
+mod error;
+pub mod expectations;
+#[cfg(feature = "fake")]
+pub mod fake;
+mod filter;
+mod fingerprint;
+mod keys;
+#[cfg(feature = "flight")]
+pub mod flight;
+#[cfg(feature = "serde")]
+pub mod json;
+mod literal;
+mod migrate;
+mod naming;
+mod nested;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod parse;
+pub mod pipeline;
+mod preview;
+mod profile;
+mod references;
+mod reshape;
+mod proto;
+pub mod python;
+pub mod quality;
+#[cfg(feature = "parquet")]
+pub mod parquet;
+#[cfg(feature = "registry")]
+pub mod registry;
+mod schema_diff;
+mod sort_spec;
+pub mod stream;
+mod typed;
+pub mod sql;
+#[cfg(feature = "proptest")]
+pub mod strategy;
+mod suggest;
+mod tables;
+mod validate;
+mod versioning;
+mod window;
+
+pub use access::{CellValue, ChunkedColumn, ToAnyValue};
+pub use cache::clear_validation_cache;
+pub use concat::ConcatOptions;
+pub use proto::{ProtoDuration, ProtoTimestamp};
+pub use describe::{ColumnDescription, SchemaDescription};
+pub use diff::DiffResult;
+pub use error::Error;
+pub use dtype::{map_entries_dtype, map_struct_dtype, ColumnDType, PolarsDType};
+pub use filter::{FieldFilter, FilterBuilder, FilterChain};
+pub use fingerprint::{schema_fingerprint, schema_hash};
+pub use literal::IntoLiteral;
+pub use migrate::{ColumnCast, ColumnParse, MigrationReport};
+pub use nested::{exploded_schema, unnested_schema};
+pub use profile::{ColumnProfile, ColumnStats, ProfileReport};
+pub use reshape::{melted_schema, MELT_VALUE, MELT_VARIABLE};
+pub use schema_diff::{DTypeMismatch, SchemaDiff};
+pub use sort_spec::SortKey::{Asc, Desc};
+pub use sort_spec::{SortKey, SortSpec};
+pub use typed::{TypedFrame, TypedLazyFrame};
+pub use validate::{ValidateOptions, DEBUG_CHECKS};
+#[cfg(feature = "decimal")]
+pub use dtype::{DEFAULT_DECIMAL_PRECISION, DEFAULT_DECIMAL_SCALE};
+
+// Support items referenced by the derive macros
+#[doc(hidden)]
+pub mod __private {
+    pub use polars;
+    pub use crate::access::{
+        cell_column, column_cell, column_from_values, required_cell, required_column_cell,
+        required_value_cell, row_value, row_width, typed_cell, typed_column, value_cell,
+        with_typed_column, BinaryColumn, StringColumn, StructColumn,
+    };
+    pub use crate::proto::{duration_value, timestamp_value};
+    #[cfg(feature = "rayon")]
+    pub use crate::parallel::par_map_chunks;
+    pub use crate::aggregate::numeric_aggregations;
+    pub use crate::cache::validate_cached;
+    pub mod tables {
+        pub use crate::tables::*;
+    }
+    pub use crate::diff::diff_rows;
+    pub use crate::migrate::migrate;
+    pub use crate::naming::rename_to_camel;
+    pub use crate::reshape::{group_columns, melt_columns};
+    pub use crate::keys::{assert_unique_keys, dedupe, dedupe_keep_last, join_on_key};
+    pub use crate::nested::{explode_column, unnest_column};
+    pub use crate::preview::preview;
+    pub use crate::references::validate_reference;
+    pub use crate::window::{group_by_dynamic, group_by_every, numeric_rolling, rolling_by};
+    pub use crate::column_expr::column_expr;
+    pub use crate::combine::{combine_error, combined_columns, combined_schema, CombineError};
+    pub use crate::concat::concat_frames;
+    #[cfg(feature = "proptest")]
+    pub use crate::strategy::{column_strategy, frame_strategy};
+    #[cfg(feature = "registry")]
+    pub use inventory;
+    #[cfg(feature = "serde")]
+    pub use crate::json::{from_json_rows, to_json_rows};
+    #[cfg(feature = "avro")]
+    pub use crate::avro::{avro_schema, read_avro, write_avro};
+    pub use crate::parse::parse_dates;
+    pub use crate::validate::{
+        anti_validate, conform_frame, match_columns, validate_column_set, validate_schema,
+        validate_schema_with,
+    };
+    pub use crate::versioning::{
+        columns_for_version, migrate_to_version, validate_version, VersionedColumn,
+    };
+    pub use crate::dtype::{
+        dtype_eq_ignoring_time_unit, with_time_unit, with_time_zone, DTypeProbe, ViaEnumValues,
+        ViaFallback, ViaNoEnumValues, ViaPolarsDType,
+    };
+}
+
 /// Validation error types that can occur during schema validation
 #[derive(Debug, thiserror::Error)]
 pub enum ValidationError {
-    #[error("Missing required column: {column_name}")]
-    MissingColumn { column_name: String },
+    #[error("Missing required column: {column_name}{}", suggest::did_you_mean(suggestions))]
+    MissingColumn {
+        column_name: String,
+        /// Closest columns of the frame, closest first, e.g. for a
+        /// one-character typo or a case slip
+        suggestions: Vec<String>,
+    },
 
     #[error("Column '{column_name}' has type {actual_type:?}, expected {expected_type:?}")]
     TypeMismatch {
@@ -37,6 +183,121 @@ pub enum ValidationError {
         value: String,
         valid_values: Vec<String>,
     },
+
+    #[error("Row {row} is out of bounds for a frame of height {height}")]
+    RowOutOfBounds { row: usize, height: usize },
+
+    #[error("Invalid value {value} in column '{column_name}': {reason}")]
+    InvalidValue {
+        column_name: String,
+        value: String,
+        reason: String,
+    },
+
+    #[error("Reference frame '{frame}' is not registered")]
+    MissingReferenceFrame { frame: String },
+
+    #[error(transparent)]
+    Polars(#[from] PolarsError),
+
+    #[cfg(feature = "serde")]
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+
+    #[cfg(feature = "avro")]
+    #[error("Avro error: {0}")]
+    Avro(String),
+
+    #[cfg(feature = "delta")]
+    #[error("Delta error: {0}")]
+    Delta(String),
+
+    #[cfg(feature = "flight")]
+    #[error("Flight error: {0}")]
+    Flight(String),
+
+    #[error("Schema mismatch: {0}")]
+    SchemaMismatch(SchemaDiff),
+
+    #[error("Column '{column_name}' has {actual} rows, expected {expected}")]
+    LengthMismatch {
+        column_name: String,
+        expected: usize,
+        actual: usize,
+    },
+
+    #[error("Input frame {index}: {source}")]
+    InputFrame {
+        /// Position of the frame among the inputs, from 0
+        index: usize,
+        source: Box<ValidationError>,
+    },
+
+    #[error("Unknown column group '{group}', declared groups are: {groups:?}")]
+    UnknownGroup { group: String, groups: Vec<String> },
+
+    #[error("{duplicates} values of the key {columns:?} appear more than once, e.g. {}", sample.join(", "))]
+    DuplicateKeys {
+        columns: Vec<String>,
+        /// Number of distinct key values on more than one row
+        duplicates: usize,
+        /// Some of those key values, e.g. `(7, "ams")` for a compound key
+        sample: Vec<String>,
+    },
+
+    #[error(
+        "Column '{column_name}' has {rows} rows with {values} values missing from the referenced column '{referenced}', e.g. {}",
+        sample.join(", ")
+    )]
+    OrphanedReferences {
+        column_name: String,
+        referenced: String,
+        /// Number of rows whose value is not in the referenced column
+        rows: usize,
+        /// Number of distinct such values
+        values: usize,
+        /// Some of those values
+        sample: Vec<String>,
+    },
+
+    #[error("Schema fingerprint mismatch: expected {expected}, found {}", actual.as_deref().unwrap_or("none"))]
+    FingerprintMismatch {
+        expected: String,
+        /// Stored fingerprint, `None` when there is none
+        actual: Option<String>,
+    },
+
+    #[error(
+        "Column '{column_name}' has {rows} values matching none of the formats {formats:?}, e.g. {}",
+        sample.join(", ")
+    )]
+    UnparseableValues {
+        column_name: String,
+        /// Formats tried, in order
+        formats: Vec<String>,
+        /// Number of non-null values that do not parse
+        rows: usize,
+        /// Some of those values
+        sample: Vec<String>,
+    },
+
+    #[error("Row has {actual} values, expected {expected}")]
+    RowWidthMismatch { expected: usize, actual: usize },
+
+    #[error("Row value {position}: {source}")]
+    RowValue {
+        /// Position of the value in the row, from 0
+        position: usize,
+        source: Box<ValidationError>,
+    },
+
+    #[error("Frame holds columns of {schema}: {columns:?}")]
+    ForbiddenColumns {
+        /// Name of the schema whose columns must not appear
+        schema: String,
+        /// Columns of the frame matching the schema, in frame order
+        columns: Vec<String>,
+    },
 }
 
 pub type Result<T> = std::result::Result<T, ValidationError>;
@@ -53,6 +314,68 @@ pub trait PolarsColumns {
     fn col_expr(field_name: &str) -> Option<Expr>;
 }
 
+/// Column expression helper of a derived struct, e.g. `ExprForUser` for
+/// `User`, for code that is generic over derived structs
+pub trait PolarsExprs {
+    /// Type of `Struct::expr`, with one method per field returning its
+    /// column expression
+    type Exprs: Copy + Default;
+}
+
+/// Schema of a `#[derive(PolarsSchema)]` struct, for code that is generic
+/// over schemas such as `Struct::migrate_from::<Old>`
+///
+/// Implemented by the derive on top of the generated inherent functions.
+pub trait PolarsSchemaInfo {
+    /// Expected schema of the frame
+    fn polars_schema() -> &'static Schema;
+
+    /// Check `df` as the generated `validate` does
+    fn validate(df: &DataFrame) -> Result<()>;
+
+    /// Old column names declared with `#[polars(renamed_from = "...")]`, as
+    /// `(old, new)`
+    fn renamed_columns() -> &'static [(&'static str, &'static str)] {
+        &[]
+    }
+
+    /// Defaults declared with `#[polars(default = ...)]` or `default_fn`, as
+    /// literal expressions of the column dtype
+    fn column_defaults() -> Vec<(&'static str, Expr)> {
+        Vec::new()
+    }
+
+    /// Formats declared with `#[polars(format = "...")]`, as `(column, format)`
+    #[cfg(feature = "serde")]
+    fn json_formats() -> &'static [(&'static str, &'static str)] {
+        &[]
+    }
+
+    /// Validate `df`, diagnosing the error if it fails
+    #[cfg(feature = "pretty-errors")]
+    fn diagnose(df: &DataFrame) -> Option<diagnostic::Diagnostic> {
+        let error = Self::validate(df).err()?;
+        Some(diagnostic::Diagnostic::new(
+            &error,
+            Self::polars_schema(),
+            df.schema(),
+        ))
+    }
+}
+
+/// Row conversions of a `#[derive(PolarsSchema)]` struct, for code that is
+/// generic over the rows it reads or builds such as `Struct::map_rows`
+///
+/// Implemented by the derive on top of the generated `to_df` and `from_df`,
+/// for structs whose fields all convert.
+pub trait PolarsRows: Sized {
+    /// Frame with one row per element of `rows`
+    fn to_df(rows: &[Self]) -> Result<DataFrame>;
+
+    /// Validate `df` and read every row back
+    fn from_df(df: &DataFrame) -> Result<Vec<Self>>;
+}
+
 /// Extension trait for additional column utilities
 pub trait PolarsColumnsExt {
     /// Get all column names (alias for column_names)
@@ -0,0 +1,140 @@
+//! Conversion of field values into literal expressions
+
+use polars::prelude::*;
+
+use crate::ValidatableEnum;
+
+/// Trait for Rust types that can be turned into a literal `Expr`
+///
+/// Used by the generated `Struct::lit_field(value)` helpers, which cast the
+/// literal to the field's column dtype afterwards.
+pub trait IntoLiteral {
+    /// Build the literal expression for this value
+    fn into_literal(self) -> Expr;
+}
+
+macro_rules! impl_into_literal {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl IntoLiteral for $ty {
+                fn into_literal(self) -> Expr {
+                    lit(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_into_literal!(i8, i16, i32, i64, i128, u32, u64, f32, f64, bool, String);
+
+/// Polars has no `u8`/`u16` literals, so these widen and cast back
+macro_rules! impl_into_widened_literal {
+    ($($ty:ty => $dtype:expr),* $(,)?) => {
+        $(
+            impl IntoLiteral for $ty {
+                fn into_literal(self) -> Expr {
+                    lit(self as u32).cast($dtype)
+                }
+            }
+        )*
+    };
+}
+
+impl_into_widened_literal!(u8 => DataType::UInt8, u16 => DataType::UInt16);
+
+impl IntoLiteral for &str {
+    fn into_literal(self) -> Expr {
+        lit(self)
+    }
+}
+
+impl<T: IntoLiteral> IntoLiteral for Option<T> {
+    fn into_literal(self) -> Expr {
+        match self {
+            Some(value) => value.into_literal(),
+            None => lit(NULL),
+        }
+    }
+}
+
+impl<T: ValidatableEnum> IntoLiteral for T {
+    fn into_literal(self) -> Expr {
+        lit(self.to_str())
+    }
+}
+
+impl IntoLiteral for std::time::Duration {
+    fn into_literal(self) -> Expr {
+        lit(self.as_micros() as i64).cast(DataType::Duration(TimeUnit::Microseconds))
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_literals {
+    use super::IntoLiteral;
+    use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Utc};
+    use polars::prelude::{lit, DataType, Expr, PlSmallStr, TimeUnit};
+
+    fn utc_micros(micros: i64) -> Expr {
+        lit(micros).cast(DataType::Datetime(
+            TimeUnit::Microseconds,
+            Some(PlSmallStr::from_static("UTC")),
+        ))
+    }
+
+    impl IntoLiteral for NaiveDate {
+        fn into_literal(self) -> Expr {
+            let days = (self - DateTime::UNIX_EPOCH.date_naive()).num_days() as i32;
+            lit(days).cast(DataType::Date)
+        }
+    }
+
+    impl IntoLiteral for NaiveDateTime {
+        fn into_literal(self) -> Expr {
+            lit(self.and_utc().timestamp_micros())
+                .cast(DataType::Datetime(TimeUnit::Microseconds, None))
+        }
+    }
+
+    impl IntoLiteral for DateTime<Utc> {
+        fn into_literal(self) -> Expr {
+            utc_micros(self.timestamp_micros())
+        }
+    }
+
+    impl IntoLiteral for DateTime<FixedOffset> {
+        fn into_literal(self) -> Expr {
+            utc_micros(self.timestamp_micros())
+        }
+    }
+
+    impl IntoLiteral for NaiveTime {
+        fn into_literal(self) -> Expr {
+            let nanos = self.num_seconds_from_midnight() as i64 * 1_000_000_000
+                + self.nanosecond() as i64;
+            lit(nanos).cast(DataType::Time)
+        }
+    }
+
+    impl IntoLiteral for chrono::Duration {
+        fn into_literal(self) -> Expr {
+            lit(self.num_microseconds().unwrap_or(i64::MAX))
+                .cast(DataType::Duration(TimeUnit::Microseconds))
+        }
+    }
+}
+
+/// Decimals are passed as strings so no precision is lost before the cast
+#[cfg(feature = "decimal")]
+impl IntoLiteral for rust_decimal::Decimal {
+    fn into_literal(self) -> Expr {
+        lit(self.to_string()).cast(DataType::Decimal(None, Some(self.scale() as usize)))
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl IntoLiteral for uuid::Uuid {
+    fn into_literal(self) -> Expr {
+        lit(self.to_string())
+    }
+}
@@ -0,0 +1,173 @@
+//! Conversion of frames between two derived schemas
+
+use std::fmt;
+
+use polars::prelude::*;
+use serde::Serialize;
+
+use crate::profile::serialize_dtype;
+use crate::{PolarsSchemaInfo, Result};
+
+/// Changes applied by the generated `Struct::migrate_from::<Old>(&df)`
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct MigrationReport {
+    /// Columns renamed from an old name declared with
+    /// `#[polars(renamed_from = "...")]`, as `(old, new)`
+    pub renamed: Vec<(String, String)>,
+    /// Columns whose dtype changed between the schemas
+    pub cast: Vec<ColumnCast>,
+    /// String columns `conform` parsed to a temporal dtype, also listed in
+    /// `cast`
+    pub parsed: Vec<ColumnParse>,
+    /// Columns of the new schema missing from the frame, added with their
+    /// default or as nulls
+    pub added: Vec<String>,
+    /// Columns of the frame that are not part of the new schema
+    pub dropped: Vec<String>,
+}
+
+/// A column cast from its old to its new dtype
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ColumnCast {
+    pub column: String,
+    #[serde(serialize_with = "serialize_dtype")]
+    pub from: DataType,
+    #[serde(serialize_with = "serialize_dtype")]
+    pub to: DataType,
+}
+
+/// A string column parsed with the formats of its `parse_date` attributes
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ColumnParse {
+    pub column: String,
+    /// Formats in the order tried, with the number of values each one read
+    pub formats: Vec<(String, usize)>,
+}
+
+impl MigrationReport {
+    /// Whether the frame already matched the new schema
+    pub fn is_empty(&self) -> bool {
+        self.renamed.is_empty()
+            && self.cast.is_empty()
+            && self.parsed.is_empty()
+            && self.added.is_empty()
+            && self.dropped.is_empty()
+    }
+}
+
+impl fmt::Display for MigrationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "no changes");
+        }
+        let mut lines = Vec::new();
+        for (old, new) in &self.renamed {
+            lines.push(format!("renamed {} -> {}", old, new));
+        }
+        for cast in &self.cast {
+            lines.push(format!(
+                "cast {}: {} -> {}",
+                cast.column, cast.from, cast.to
+            ));
+        }
+        for parse in &self.parsed {
+            let hits: Vec<String> = parse
+                .formats
+                .iter()
+                .map(|(format, hits)| format!("{} as {}", hits, format))
+                .collect();
+            lines.push(format!("parsed {}: {}", parse.column, hits.join(", ")));
+        }
+        for column in &self.added {
+            lines.push(format!("added {}", column));
+        }
+        for column in &self.dropped {
+            lines.push(format!("dropped {}", column));
+        }
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+/// Transform `df`, valid under `Old`, into a frame with exactly the columns
+/// of `New`
+///
+/// Columns are matched by name, then by the old names `New` declares with
+/// `renamed_from`. Matched columns with another dtype are cast strictly, so
+/// values that do not fit fail the migration; missing columns are added with
+/// their default (or as nulls) and all other columns are dropped.
+#[doc(hidden)]
+pub fn migrate<Old: PolarsSchemaInfo, New: PolarsSchemaInfo>(
+    df: &DataFrame,
+) -> Result<(DataFrame, MigrationReport)> {
+    Old::validate(df)?;
+
+    let defaults = New::column_defaults();
+    let mut report = MigrationReport::default();
+    let mut used = Vec::new();
+    let mut columns = Vec::new();
+
+    for (name, dtype) in New::polars_schema().iter() {
+        let source = if df.schema().contains(name) {
+            Some(name.as_str())
+        } else {
+            New::renamed_columns()
+                .iter()
+                .find(|(old, new)| new == name && df.schema().contains(old))
+                .map(|(old, _)| {
+                    report.renamed.push((old.to_string(), name.to_string()));
+                    *old
+                })
+        };
+
+        let column = match source {
+            Some(source) => {
+                used.push(source);
+                let column = df.column(source)?;
+                let column = if column.dtype() != dtype {
+                    report.cast.push(ColumnCast {
+                        column: name.to_string(),
+                        from: column.dtype().clone(),
+                        to: dtype.clone(),
+                    });
+                    column.strict_cast(dtype)?
+                } else {
+                    column.clone()
+                };
+                column.with_name(name.clone())
+            }
+            None => {
+                report.added.push(name.to_string());
+                missing_column(name, dtype, df.height(), &defaults)?
+            }
+        };
+        columns.push(column);
+    }
+
+    report.dropped = df
+        .get_column_names()
+        .into_iter()
+        .filter(|name| !used.contains(&name.as_str()))
+        .map(|name| name.to_string())
+        .collect();
+
+    Ok((DataFrame::new(columns)?, report))
+}
+
+/// Column of `height` rows filled with the default of `name`, or with nulls
+/// when it has none
+pub(crate) fn missing_column(
+    name: &PlSmallStr,
+    dtype: &DataType,
+    height: usize,
+    defaults: &[(&str, Expr)],
+) -> Result<Column> {
+    let Some((_, default)) = defaults.iter().find(|(column, _)| column == name) else {
+        return Ok(Column::full_null(name.clone(), height, dtype));
+    };
+
+    let value = DataFrame::empty()
+        .lazy()
+        .select([default.clone().cast(dtype.clone()).alias(name.clone())])
+        .collect()?;
+    Ok(value.get_columns()[0].new_from_index(0, height))
+}
@@ -0,0 +1,84 @@
+//! Column naming conventions, behind the generated `rename_to_snake` /
+//! `rename_to_camel` and `ValidateOptions::ignore_naming_convention`
+
+use polars::prelude::*;
+
+use crate::{MigrationReport, Result};
+
+/// Words of `name`, split at separators (`_`, `-`, spaces, ...) and at case
+/// changes, lowercased: `userId`, `UserID`, `USER_ID` and `user-id` all give
+/// `["user", "id"]`
+fn words(name: &str) -> Vec<String> {
+    let chars: Vec<char> = name.chars().collect();
+    let mut words = Vec::new();
+    let mut word = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if !c.is_alphanumeric() {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            continue;
+        }
+        if c.is_uppercase() && !word.is_empty() {
+            let prev = chars[i - 1];
+            let next_lower = chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+            // `userId`, `address2Line`, and the end of an acronym as in `HTTPServer`
+            if prev.is_lowercase() || prev.is_numeric() || (prev.is_uppercase() && next_lower) {
+                words.push(std::mem::take(&mut word));
+            }
+        }
+        word.extend(c.to_lowercase());
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+/// `name` in snake_case, e.g. `userId` -> `user_id`
+pub(crate) fn to_snake_case(name: &str) -> String {
+    words(name).join("_")
+}
+
+/// `name` in camelCase, e.g. `user_id` -> `userId`
+pub(crate) fn to_camel_case(name: &str) -> String {
+    let mut camel = String::new();
+    for (i, word) in words(name).iter().enumerate() {
+        let mut chars = word.chars();
+        match chars.next() {
+            Some(first) if i > 0 => {
+                camel.extend(first.to_uppercase());
+                camel.push_str(chars.as_str());
+            }
+            _ => camel.push_str(word),
+        }
+    }
+    camel
+}
+
+/// `df` with the `columns` it has renamed to camelCase, and the renames
+#[doc(hidden)]
+pub fn rename_to_camel(df: &DataFrame, columns: &[&str]) -> Result<(DataFrame, MigrationReport)> {
+    let mut renamed = Vec::new();
+    // Built anew, as `DataFrame::rename` keeps the cached schema
+    let frame_columns = df
+        .get_columns()
+        .iter()
+        .map(|c| {
+            let name = c.name().as_str();
+            let camel = to_camel_case(name);
+            if columns.contains(&name) && camel != name {
+                renamed.push((name.to_string(), camel.clone()));
+                c.clone().with_name(camel.into())
+            } else {
+                c.clone()
+            }
+        })
+        .collect();
+    let df = DataFrame::new(frame_columns)?;
+    let report = MigrationReport {
+        renamed,
+        ..Default::default()
+    };
+    Ok((df, report))
+}
@@ -0,0 +1,71 @@
+//! Child schemas of list and struct columns, behind the generated
+//! `explode_<field>` and `unnest_<field>` helpers
+
+use polars::prelude::*;
+
+use crate::{Result, ValidationError};
+
+/// Declared dtype of `column`, which must be a `List` or `Struct` as named
+/// by `expected`
+fn declared<'a>(schema: &'a Schema, column: &str, expected: &str) -> Result<&'a DataType> {
+    let dtype = schema.get(column).ok_or_else(|| ValidationError::MissingColumn {
+        column_name: column.to_string(),
+        suggestions: Vec::new(),
+    })?;
+    match (dtype, expected) {
+        (DataType::List(_), "List") | (DataType::Struct(_), "Struct") => Ok(dtype),
+        _ => Err(ValidationError::TypeMismatch {
+            column_name: column.to_string(),
+            actual_type: format!("{:?}", dtype),
+            expected_type: expected.to_string(),
+        }),
+    }
+}
+
+/// `schema` with the list column `column` holding its inner dtype, as after
+/// `DataFrame::explode`
+pub fn exploded_schema(schema: &Schema, column: &str) -> Result<Schema> {
+    let DataType::List(inner) = declared(schema, column, "List")? else {
+        unreachable!("checked by `declared`");
+    };
+    let mut exploded = schema.clone();
+    exploded.set_dtype(column, (**inner).clone());
+    Ok(exploded)
+}
+
+/// `schema` with the struct column `column` replaced by its fields in place,
+/// as after `DataFrame::unnest`
+pub fn unnested_schema(schema: &Schema, column: &str) -> Result<Schema> {
+    let DataType::Struct(fields) = declared(schema, column, "Struct")? else {
+        unreachable!("checked by `declared`");
+    };
+    let mut unnested = Schema::with_capacity(schema.len() + fields.len());
+    for (name, dtype) in schema.iter() {
+        if name == column {
+            unnested.extend(fields.iter().map(|field| (field.name().clone(), field.dtype().clone())));
+        } else {
+            unnested.insert(name.clone(), dtype.clone());
+        }
+    }
+    Ok(unnested)
+}
+
+/// `df`, valid under `schema`, with one row per element of the list column
+/// `column`, checked against `exploded_schema`
+#[doc(hidden)]
+pub fn explode_column(df: &DataFrame, schema: &Schema, column: &str) -> Result<DataFrame> {
+    crate::validate::validate_schema(df, schema, &[])?;
+    let exploded = df.explode([column])?;
+    crate::validate::validate_schema(&exploded, &exploded_schema(schema, column)?, &[])?;
+    Ok(exploded)
+}
+
+/// `df`, valid under `schema`, with the struct column `column` split into
+/// one column per struct field, checked against `unnested_schema`
+#[doc(hidden)]
+pub fn unnest_column(df: &DataFrame, schema: &Schema, column: &str) -> Result<DataFrame> {
+    crate::validate::validate_schema(df, schema, &[])?;
+    let unnested = df.unnest([column])?;
+    crate::validate::validate_schema(&unnested, &unnested_schema(schema, column)?, &[])?;
+    Ok(unnested)
+}
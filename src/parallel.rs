@@ -0,0 +1,35 @@
+//! Chunk-parallel row mapping behind the generated `par_map_rows`
+
+use polars::prelude::*;
+use rayon::prelude::*;
+
+use crate::Result;
+
+/// `map_chunk` applied to contiguous slices of `df`, one per rayon worker
+/// thread, in parallel, and the frames it returns stacked back in row order
+///
+/// A frame without rows is passed as a single empty slice, so the output
+/// keeps the columns `map_chunk` gives it.
+#[doc(hidden)]
+pub fn par_map_chunks<F>(df: &DataFrame, map_chunk: F) -> Result<DataFrame>
+where
+    F: Fn(&DataFrame) -> Result<DataFrame> + Sync,
+{
+    let size = df.height().div_ceil(rayon::current_num_threads()).max(1);
+    let chunks: Vec<DataFrame> = (0..df.height().max(1))
+        .step_by(size)
+        .map(|offset| df.slice(offset as i64, size))
+        .collect();
+    let frames = chunks
+        .par_iter()
+        .map(&map_chunk)
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut frames = frames.into_iter();
+    let mut stacked = frames.next().expect("at least one chunk");
+    for frame in frames {
+        stacked.vstack_mut(&frame)?;
+    }
+    stacked.as_single_chunk_par();
+    Ok(stacked)
+}
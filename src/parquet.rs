@@ -0,0 +1,164 @@
+//! Self-describing Parquet files: the generated `write_parquet` stores the
+//! schema name, version, fingerprint and column docs in the footer metadata,
+//! and `read_parquet` checks the file against the schema before reading
+
+use std::io::Write;
+
+use polars::io::mmap::MmapBytesReader;
+use polars::prelude::*;
+use polars_parquet::write::KeyValue;
+
+use crate::{schema_fingerprint, Result, SchemaDiff, ValidationError};
+
+/// Footer metadata key of the schema fingerprint
+pub const FINGERPRINT_KEY: &str = "polars_tools.schema_fingerprint";
+
+/// Footer metadata key of the name of the schema struct
+pub const SCHEMA_NAME_KEY: &str = "polars_tools.schema_name";
+
+/// Footer metadata key of the schema version, see `SCHEMA_VERSION`
+pub const SCHEMA_VERSION_KEY: &str = "polars_tools.schema_version";
+
+/// Prefix of the footer metadata keys of column docs, followed by the column
+/// name
+pub const COLUMN_DOC_PREFIX: &str = "polars_tools.doc.";
+
+/// Schema description stored in the footer of a Parquet file
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SchemaMetadata {
+    /// Name of the schema struct, e.g. `Event`
+    pub name: Option<String>,
+    /// Schema version the file was written with
+    pub version: Option<u32>,
+    /// `schema_fingerprint` of the schema the file was written with
+    pub fingerprint: Option<String>,
+    /// `(column, doc)` of the documented columns
+    pub column_docs: Vec<(String, String)>,
+}
+
+impl SchemaMetadata {
+    /// Footer key-value pairs of the metadata
+    pub fn to_key_values(&self) -> Vec<(String, String)> {
+        let mut pairs = Vec::new();
+        if let Some(name) = &self.name {
+            pairs.push((SCHEMA_NAME_KEY.to_string(), name.clone()));
+        }
+        if let Some(version) = self.version {
+            pairs.push((SCHEMA_VERSION_KEY.to_string(), version.to_string()));
+        }
+        if let Some(fingerprint) = &self.fingerprint {
+            pairs.push((FINGERPRINT_KEY.to_string(), fingerprint.clone()));
+        }
+        pairs.extend(
+            self.column_docs
+                .iter()
+                .map(|(column, doc)| (format!("{}{}", COLUMN_DOC_PREFIX, column), doc.clone())),
+        );
+        pairs
+    }
+
+    /// Metadata among footer key-value pairs, ignoring other keys
+    pub fn from_key_values(pairs: &[(String, String)]) -> Self {
+        let mut metadata = SchemaMetadata::default();
+        for (key, value) in pairs {
+            match key.as_str() {
+                SCHEMA_NAME_KEY => metadata.name = Some(value.clone()),
+                SCHEMA_VERSION_KEY => metadata.version = value.parse().ok(),
+                FINGERPRINT_KEY => metadata.fingerprint = Some(value.clone()),
+                key => {
+                    if let Some(column) = key.strip_prefix(COLUMN_DOC_PREFIX) {
+                        metadata
+                            .column_docs
+                            .push((column.to_string(), value.clone()));
+                    }
+                }
+            }
+        }
+        metadata
+    }
+}
+
+/// Write `df` as a Parquet file with the `metadata` key-value pairs in its
+/// footer, returning the size of the file
+pub fn write_parquet<W: Write>(
+    writer: W,
+    df: &DataFrame,
+    metadata: &[(String, String)],
+) -> Result<u64> {
+    let mut df = df.clone();
+    df.as_single_chunk_par();
+    let mut batched = ParquetWriter::new(writer).batched(df.schema())?;
+    batched.write_batch(&df)?;
+    let metadata = metadata
+        .iter()
+        .map(|(key, value)| KeyValue {
+            key: key.clone(),
+            value: Some(value.clone()),
+        })
+        .collect();
+    let size = batched.get_writer().lock().unwrap().end(Some(metadata))?;
+    Ok(size)
+}
+
+/// Key-value pairs of the footer metadata of a Parquet file, without the
+/// keys that have no value
+pub fn parquet_metadata<R: MmapBytesReader>(reader: R) -> Result<Vec<(String, String)>> {
+    let mut reader = ParquetReader::new(reader);
+    Ok(footer_metadata(&mut reader)?)
+}
+
+fn footer_metadata<R: MmapBytesReader>(
+    reader: &mut ParquetReader<R>,
+) -> PolarsResult<Vec<(String, String)>> {
+    let metadata = reader.get_metadata()?;
+    Ok(metadata
+        .key_value_metadata()
+        .iter()
+        .flatten()
+        .filter_map(|pair| Some((pair.key.clone(), pair.value.clone()?)))
+        .collect())
+}
+
+/// Schema description stored in the footer of a Parquet file, empty for
+/// files written without one
+pub fn read_schema_metadata<R: MmapBytesReader>(reader: R) -> Result<SchemaMetadata> {
+    Ok(SchemaMetadata::from_key_values(&parquet_metadata(reader)?))
+}
+
+/// Polars schema of the columns of a Parquet file, reading only its footer
+pub fn parquet_schema<R: MmapBytesReader>(reader: R) -> Result<Schema> {
+    let mut reader = ParquetReader::new(reader);
+    Ok(Schema::from_arrow_schema(&*reader.schema()?))
+}
+
+/// Check that a Parquet file was written with the fingerprint of `schema`,
+/// reading only its footer
+pub fn check_parquet_fingerprint<R: MmapBytesReader>(reader: R, schema: &Schema) -> Result<()> {
+    let expected = schema_fingerprint(schema);
+    let actual = read_schema_metadata(reader)?.fingerprint;
+    if actual.as_deref() != Some(expected.as_str()) {
+        return Err(ValidationError::FingerprintMismatch { expected, actual });
+    }
+    Ok(())
+}
+
+/// Read the `schema` columns of a Parquet file
+///
+/// Files stored with the fingerprint of `schema` are read directly. Others
+/// are compared column by column first, failing with `SchemaMismatch` when a
+/// column is missing or has another dtype.
+#[doc(hidden)]
+pub fn read_parquet<R: MmapBytesReader>(reader: R, schema: &Schema) -> Result<DataFrame> {
+    let mut reader = ParquetReader::new(reader);
+    let stored = SchemaMetadata::from_key_values(&footer_metadata(&mut reader)?);
+    if stored.fingerprint != Some(schema_fingerprint(schema)) {
+        let diff = SchemaDiff::between(schema, &Schema::from_arrow_schema(&*reader.schema()?));
+        if !diff.is_compatible() {
+            return Err(ValidationError::SchemaMismatch(diff));
+        }
+    }
+    let columns = schema.iter_names().map(|name| name.to_string()).collect();
+    let df = reader.with_columns(Some(columns)).finish()?;
+    crate::validate::validate_schema(&df, schema, &[])?;
+    Ok(df)
+}
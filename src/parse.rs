@@ -0,0 +1,152 @@
+//! Parsing of string columns into their declared temporal dtype, behind the
+//! generated `parse_dates` and the `conform` of `#[polars(parse_date = "...")]`
+//! columns
+//!
+//! A column can have several formats, tried in order on the values no earlier
+//! format read. Besides strftime formats, `epoch_s` and `epoch_ms` read
+//! integer strings as seconds or milliseconds since the Unix epoch.
+
+use polars::prelude::*;
+
+use crate::keys::SAMPLE_SIZE;
+use crate::{ColumnCast, ColumnParse, MigrationReport, Result, ValidationError};
+
+/// Format of integer strings counting seconds since the Unix epoch
+const EPOCH_SECONDS: &str = "epoch_s";
+/// Format of integer strings counting milliseconds since the Unix epoch
+const EPOCH_MILLIS: &str = "epoch_ms";
+
+/// Whether the strftime `format` reads or writes a UTC offset
+pub(crate) fn is_zone_aware(format: &str) -> bool {
+    format.contains("%z") || format.contains("%:z")
+}
+
+/// `strings` parsed to the temporal `dtype` with the strftime `format`, or
+/// as epoch seconds or milliseconds, the strings not matching it, or out of
+/// range as epoch milliseconds, becoming nulls
+///
+/// Zone-aware formats are parsed to UTC and naive ones read as UTC, then
+/// converted to the zone of `dtype`.
+pub(crate) fn parse_temporal(
+    strings: &StringChunked,
+    dtype: &DataType,
+    format: &str,
+) -> PolarsResult<Series> {
+    let epoch_factor = match format {
+        EPOCH_SECONDS => Some(1000),
+        EPOCH_MILLIS => Some(1),
+        _ => None,
+    };
+    let parsed = match (epoch_factor, dtype) {
+        (Some(factor), DataType::Date | DataType::Time | DataType::Datetime(_, _)) => {
+            // Values whose milliseconds overflow an i64 are left unparsed
+            let millis = strings
+                .clone()
+                .into_series()
+                .cast(&DataType::Int64)?
+                .i64()?
+                .apply(|value| value.and_then(|value| value.checked_mul(factor)));
+            millis
+                .into_datetime(TimeUnit::Milliseconds, None)
+                .into_series()
+        }
+        (None, DataType::Date) => strings.as_date(Some(format), false)?.into_series(),
+        (None, DataType::Time) => strings.as_time(Some(format), false)?.into_series(),
+        (None, DataType::Datetime(unit, _)) => {
+            let ambiguous = StringChunked::from_slice("".into(), &["raise"]);
+            strings
+                .as_datetime(
+                    Some(format),
+                    *unit,
+                    false,
+                    is_zone_aware(format),
+                    None,
+                    &ambiguous,
+                )?
+                .into_series()
+        }
+        (_, dtype) => polars_bail!(InvalidOperation: "cannot parse strings as {}", dtype),
+    };
+    parsed.strict_cast(dtype)
+}
+
+/// `strings` parsed to `dtype` with the first of `formats` each value
+/// matches, and the number of values read with each format
+fn parse_with_formats(
+    strings: &StringChunked,
+    dtype: &DataType,
+    formats: &[&str],
+) -> PolarsResult<(Series, Vec<(String, usize)>)> {
+    let mut parsed = Series::full_null(strings.name().clone(), strings.len(), dtype);
+    let mut hits = Vec::with_capacity(formats.len());
+    for format in formats {
+        let pending = strings.is_not_null() & parsed.is_null();
+        if pending.num_trues() == 0 {
+            hits.push((format.to_string(), 0));
+            continue;
+        }
+        let attempt = parse_temporal(strings, dtype, format)?;
+        let read = pending & attempt.is_not_null();
+        hits.push((format.to_string(), read.num_trues()));
+        parsed = attempt.zip_with(&read, &parsed)?;
+    }
+    Ok((parsed, hits))
+}
+
+/// `df` with each string column of `formats`, as `(column, formats)`, parsed
+/// to its dtype in `expected`, every parse recorded in `report` as a cast and
+/// with the number of values each format read
+///
+/// Columns that are missing or not strings are left to validation. Non-null
+/// strings that match none of the formats fail with `UnparseableValues`.
+#[doc(hidden)]
+pub fn parse_dates(
+    mut df: DataFrame,
+    expected: &Schema,
+    formats: &[(&str, &[&str])],
+    report: &mut MigrationReport,
+) -> Result<DataFrame> {
+    for (column, formats) in formats {
+        let (Some(dtype), Ok(source)) = (expected.get(column), df.column(column)) else {
+            continue;
+        };
+        if source.dtype() != &DataType::String {
+            continue;
+        }
+        let strings = source.str()?;
+        let (parsed, hits) = parse_with_formats(strings, dtype, formats)?;
+
+        let failed = strings.is_not_null() & parsed.is_null();
+        let rows = failed.num_trues();
+        if rows > 0 {
+            let mut sample: Vec<String> = Vec::new();
+            for value in strings.filter(&failed)?.into_iter().flatten() {
+                let value = format!("{:?}", value);
+                if !sample.contains(&value) {
+                    sample.push(value);
+                }
+                if sample.len() == SAMPLE_SIZE {
+                    break;
+                }
+            }
+            return Err(ValidationError::UnparseableValues {
+                column_name: column.to_string(),
+                formats: formats.iter().map(|format| format.to_string()).collect(),
+                rows,
+                sample,
+            });
+        }
+
+        report.cast.push(ColumnCast {
+            column: column.to_string(),
+            from: DataType::String,
+            to: dtype.clone(),
+        });
+        report.parsed.push(ColumnParse {
+            column: column.to_string(),
+            formats: hits,
+        });
+        df.with_column(parsed.with_name((*column).into()))?;
+    }
+    Ok(df)
+}
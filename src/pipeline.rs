@@ -0,0 +1,214 @@
+//! Pipelines of stages with schema checks at their boundaries
+//!
+//! A stage implements `Transform<In, Out>`, turning frames of the derived
+//! schema `In` into frames of `Out`. A `Pipeline` chains stages whose schemas
+//! line up at compile time and validates the frames passed between them at
+//! run time, as often as its `Checks` say. Errors name the stage and boundary
+//! they were raised at.
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use polars::prelude::*;
+
+use crate::{PolarsSchemaInfo, Result, ValidationError};
+
+/// A pipeline stage turning frames of schema `In` into frames of schema `Out`
+///
+/// Closures `Fn(DataFrame) -> Result<DataFrame>` are stages too, naming their
+/// output schema:
+///
+/// ```ignore
+/// let pipeline = Pipeline::<RawOrder>::new()
+///     .then(Clean)
+///     .then::<CleanOrder>(|df: DataFrame| Ok(df.lazy().filter(col("amount").gt(0)).collect()?));
+/// ```
+pub trait Transform<In, Out> {
+    /// Name of the stage in errors, its type name by default
+    fn name(&self) -> String {
+        std::any::type_name::<Self>().to_string()
+    }
+
+    /// Transform `df`, a frame of schema `In`, into a frame of schema `Out`
+    fn apply(&self, df: DataFrame) -> Result<DataFrame>;
+}
+
+impl<In, Out, F> Transform<In, Out> for F
+where
+    F: Fn(DataFrame) -> Result<DataFrame>,
+{
+    fn apply(&self, df: DataFrame) -> Result<DataFrame> {
+        self(df)
+    }
+}
+
+/// How often a pipeline validates the frames at its stage boundaries
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Checks {
+    /// On every run
+    #[default]
+    Always,
+    /// On every run of builds with debug assertions, never in release builds
+    /// (see `DEBUG_CHECKS`)
+    DebugOnly,
+    /// On the first of every `n` runs (every run for 0 and 1)
+    Sampled(u64),
+    /// Never
+    Never,
+}
+
+/// Boundary of a stage an error was raised at
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Boundary {
+    /// The frame passed to the stage did not match its input schema
+    Input,
+    /// The stage itself failed
+    Apply,
+    /// The frame returned by the stage did not match its output schema
+    Output,
+}
+
+impl fmt::Display for Boundary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Boundary::Input => "input",
+            Boundary::Apply => "failed",
+            Boundary::Output => "output",
+        })
+    }
+}
+
+/// An error of a pipeline run, attributed to a stage
+#[derive(Debug, thiserror::Error)]
+#[error("stage {index} `{stage}` {boundary}: {source}")]
+pub struct StageError {
+    /// Position of the stage in the pipeline, from 0
+    pub index: usize,
+    /// Name of the stage
+    pub stage: String,
+    /// Where the stage failed
+    pub boundary: Boundary,
+    /// The validation or stage error
+    pub source: Box<ValidationError>,
+}
+
+impl From<StageError> for PolarsError {
+    fn from(err: StageError) -> Self {
+        let message = format!("stage {} `{}` {}", err.index, err.stage, err.boundary);
+        PolarsError::from(*err.source).context(message.into())
+    }
+}
+
+type Check = fn(&DataFrame) -> Result<()>;
+
+struct Stage {
+    name: String,
+    apply: Box<dyn Fn(DataFrame) -> Result<DataFrame> + Send + Sync>,
+    validate_input: Check,
+    validate_output: Check,
+}
+
+/// Stages taking frames of schema `In` to frames of schema `Out`
+///
+/// Built from `Pipeline::<In>::new()`, each `then` appends a stage whose input
+/// schema is the current output schema.
+pub struct Pipeline<In, Out = In> {
+    stages: Vec<Stage>,
+    checks: Checks,
+    runs: AtomicU64,
+    schema: PhantomData<fn(In) -> Out>,
+}
+
+impl<S: PolarsSchemaInfo> Pipeline<S> {
+    /// Pipeline without stages, taking frames of schema `S`
+    pub fn new() -> Self {
+        Pipeline {
+            stages: Vec::new(),
+            checks: Checks::Always,
+            runs: AtomicU64::new(0),
+            schema: PhantomData,
+        }
+    }
+}
+
+impl<S: PolarsSchemaInfo> Default for Pipeline<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<In: PolarsSchemaInfo, Out: PolarsSchemaInfo> Pipeline<In, Out> {
+    /// Append `stage`, taking the frames of the current output schema
+    pub fn then<Next: PolarsSchemaInfo>(
+        self,
+        stage: impl Transform<Out, Next> + Send + Sync + 'static,
+    ) -> Pipeline<In, Next> {
+        let mut stages = self.stages;
+        stages.push(Stage {
+            name: stage.name(),
+            apply: Box::new(move |df| stage.apply(df)),
+            validate_input: Out::validate,
+            validate_output: Next::validate,
+        });
+        Pipeline {
+            stages,
+            checks: self.checks,
+            runs: self.runs,
+            schema: PhantomData,
+        }
+    }
+
+    /// How often to validate the frames at stage boundaries, `Checks::Always`
+    /// by default
+    pub fn checks(mut self, checks: Checks) -> Self {
+        self.checks = checks;
+        self
+    }
+
+    /// Names of the stages in order
+    pub fn stage_names(&self) -> Vec<&str> {
+        self.stages
+            .iter()
+            .map(|stage| stage.name.as_str())
+            .collect()
+    }
+
+    /// Whether this run validates its boundaries, counting the run
+    fn checks_run(&self) -> bool {
+        match self.checks {
+            Checks::Always => true,
+            Checks::DebugOnly => crate::DEBUG_CHECKS,
+            Checks::Sampled(n) => self
+                .runs
+                .fetch_add(1, Ordering::Relaxed)
+                .is_multiple_of(n.max(1)),
+            Checks::Never => false,
+        }
+    }
+
+    /// Run the stages on `df`, validating it as `In` before the first stage
+    /// and the output of every stage as that stage's output schema
+    ///
+    /// A pipeline without stages returns `df` unchanged.
+    pub fn run(&self, df: DataFrame) -> std::result::Result<DataFrame, StageError> {
+        let check = self.checks_run();
+        let mut df = df;
+        for (index, stage) in self.stages.iter().enumerate() {
+            let error = |boundary, source| StageError {
+                index,
+                stage: stage.name.clone(),
+                boundary,
+                source: Box::new(source),
+            };
+            if check && index == 0 {
+                (stage.validate_input)(&df).map_err(|err| error(Boundary::Input, err))?;
+            }
+            df = (stage.apply)(df).map_err(|err| error(Boundary::Apply, err))?;
+            if check {
+                (stage.validate_output)(&df).map_err(|err| error(Boundary::Output, err))?;
+            }
+        }
+        Ok(df)
+    }
+}
@@ -0,0 +1,72 @@
+//! Compact previews of frames for logs, behind the generated `preview`
+
+use polars::prelude::*;
+
+use crate::Result;
+
+/// Cells longer than this many characters are cut short with `…`
+const MAX_CELL_WIDTH: usize = 32;
+
+fn cell(value: &AnyValue) -> String {
+    let text = match value {
+        AnyValue::Null => "null".to_string(),
+        value => match value.get_str() {
+            Some(text) => text.to_string(),
+            None => value.to_string(),
+        },
+    };
+    if text.chars().count() > MAX_CELL_WIDTH {
+        let cut: String = text.chars().take(MAX_CELL_WIDTH - 1).collect();
+        format!("{}…", cut)
+    } else {
+        text
+    }
+}
+
+/// `df`, valid under `schema`, as a plain-text table of the `schema` columns
+/// headed by their names and dtypes, with the first and last `n` rows
+#[doc(hidden)]
+pub fn preview(df: &DataFrame, name: &str, schema: &Schema, n: usize) -> Result<String> {
+    crate::validate::validate_schema(df, schema, &[])?;
+    let columns: Vec<_> = schema.iter_names().cloned().collect();
+    let df = df.select(columns)?;
+    let height = df.height();
+
+    let mut rows: Vec<Vec<String>> = vec![
+        schema.iter_names().map(|name| name.to_string()).collect(),
+        schema.iter_values().map(|dtype| dtype.to_string()).collect(),
+    ];
+    let data_row = |row: usize| -> Result<Vec<String>> {
+        Ok(df.get_row(row)?.0.iter().map(cell).collect())
+    };
+    let summary = if height > 2 * n {
+        for row in 0..n {
+            rows.push(data_row(row)?);
+        }
+        rows.push(vec!["…".to_string(); df.width()]);
+        for row in height - n..height {
+            rows.push(data_row(row)?);
+        }
+        format!("{} rows, first and last {}", height, n)
+    } else {
+        for row in 0..height {
+            rows.push(data_row(row)?);
+        }
+        format!("{} rows", height)
+    };
+
+    let widths: Vec<usize> = (0..df.width())
+        .map(|i| rows.iter().map(|row| row[i].chars().count()).max().unwrap_or(0))
+        .collect();
+    let mut out = format!("{}: {}", name, summary);
+    for row in &rows {
+        let line: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:width$}", cell))
+            .collect();
+        out.push('\n');
+        out.push_str(line.join("  ").trim_end());
+    }
+    Ok(out)
+}
@@ -0,0 +1,211 @@
+//! Per-column profiling driven by a derived schema
+
+use std::collections::HashMap;
+use std::fmt;
+
+use polars::prelude::*;
+use serde::Serialize;
+
+use crate::suggest::missing_column;
+use crate::{Result, ValidationError};
+
+/// Number of most frequent values kept for string columns
+const TOP_VALUES: usize = 5;
+
+/// Column statistics of a frame, returned by the generated `Struct::profile(&df)`
+///
+/// Implements `Display` for a plain-text summary and `Serialize` for JSON or
+/// other serde formats.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ProfileReport {
+    pub rows: usize,
+    pub columns: Vec<ColumnProfile>,
+}
+
+/// Statistics of a single column
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ColumnProfile {
+    pub name: String,
+    /// Dtype declared by the schema
+    #[serde(serialize_with = "serialize_dtype")]
+    pub dtype: DataType,
+    pub null_count: usize,
+    /// Share of null values, from `0.0` to `1.0`
+    pub null_fraction: f64,
+    /// Number of distinct values, when the dtype supports it
+    pub n_unique: Option<usize>,
+    pub stats: ColumnStats,
+}
+
+/// Statistics chosen by the column's declared dtype
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ColumnStats {
+    /// Integer, float and decimal columns
+    Numeric {
+        min: Option<f64>,
+        max: Option<f64>,
+        mean: Option<f64>,
+    },
+    /// String and enum columns: most frequent values with their counts
+    Text { top_values: Vec<(String, usize)> },
+    /// Date, datetime, time and duration columns
+    Temporal {
+        min: Option<String>,
+        max: Option<String>,
+    },
+    /// Any other dtype
+    Other,
+}
+
+pub(crate) fn serialize_dtype<S: serde::Serializer>(
+    dtype: &DataType,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    serializer.collect_str(dtype)
+}
+
+impl ProfileReport {
+    /// Profile the given columns of `df`, choosing statistics by `dtypes`
+    pub fn from_schema(df: &DataFrame, columns: &[&str], dtypes: &[DataType]) -> Result<Self> {
+        let columns = columns
+            .iter()
+            .zip(dtypes)
+            .map(|(name, dtype)| ColumnProfile::new(df, name, dtype))
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            rows: df.height(),
+            columns,
+        })
+    }
+
+    /// Profile of a single column
+    pub fn column(&self, name: &str) -> Option<&ColumnProfile> {
+        self.columns.iter().find(|c| c.name == name)
+    }
+}
+
+impl ColumnProfile {
+    fn new(df: &DataFrame, name: &str, dtype: &DataType) -> Result<Self> {
+        let column = df
+            .column(name)
+            .map_err(|_| missing_column(name, df.get_column_names_str()))?;
+        let series = column.as_materialized_series();
+        let type_mismatch = |_: PolarsError| ValidationError::TypeMismatch {
+            column_name: name.to_string(),
+            actual_type: format!("{:?}", series.dtype()),
+            expected_type: format!("{:?}", dtype),
+        };
+
+        let stats = if dtype.is_primitive_numeric() {
+            let values = series.cast(&DataType::Float64).map_err(type_mismatch)?;
+            let values = values.f64().map_err(type_mismatch)?;
+            ColumnStats::Numeric {
+                min: values.min(),
+                max: values.max(),
+                mean: values.mean(),
+            }
+        } else if dtype.is_string() {
+            ColumnStats::Text {
+                top_values: top_values(series.str().map_err(type_mismatch)?),
+            }
+        } else if dtype.is_temporal() {
+            ColumnStats::Temporal {
+                min: reduced_value(series.min_reduce()),
+                max: reduced_value(series.max_reduce()),
+            }
+        } else {
+            ColumnStats::Other
+        };
+
+        let null_count = series.null_count();
+        Ok(Self {
+            name: name.to_string(),
+            dtype: dtype.clone(),
+            null_count,
+            null_fraction: if series.is_empty() {
+                0.0
+            } else {
+                null_count as f64 / series.len() as f64
+            },
+            n_unique: series.n_unique().ok(),
+            stats,
+        })
+    }
+}
+
+/// Most frequent non-null values, ties broken by value
+fn top_values(values: &StringChunked) -> Vec<(String, usize)> {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for value in values.into_iter().flatten() {
+        *counts.entry(value).or_default() += 1;
+    }
+
+    let mut counts: Vec<_> = counts.into_iter().collect();
+    counts.sort_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then(a.cmp(b)));
+    counts
+        .into_iter()
+        .take(TOP_VALUES)
+        .map(|(value, count)| (value.to_string(), count))
+        .collect()
+}
+
+fn reduced_value(scalar: PolarsResult<Scalar>) -> Option<String> {
+    let scalar = scalar.ok()?;
+    match scalar.value() {
+        AnyValue::Null => None,
+        value => Some(value.to_string()),
+    }
+}
+
+impl fmt::Display for ProfileReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} rows, {} columns", self.rows, self.columns.len())?;
+        for column in &self.columns {
+            write!(f, "\n{}", column)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for ColumnProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({}): nulls {} ({:.1}%)",
+            self.name,
+            self.dtype,
+            self.null_count,
+            self.null_fraction * 100.0
+        )?;
+        if let Some(n_unique) = self.n_unique {
+            write!(f, ", unique {}", n_unique)?;
+        }
+
+        let show = |value: &Option<f64>| value.map_or("-".to_string(), |v| v.to_string());
+        match &self.stats {
+            ColumnStats::Numeric { min, max, mean } => write!(
+                f,
+                ", min {}, max {}, mean {}",
+                show(min),
+                show(max),
+                show(mean)
+            ),
+            ColumnStats::Text { top_values } if !top_values.is_empty() => {
+                let top: Vec<_> = top_values
+                    .iter()
+                    .map(|(value, count)| format!("{} ({})", value, count))
+                    .collect();
+                write!(f, ", top {}", top.join(", "))
+            }
+            ColumnStats::Temporal { min, max } => write!(
+                f,
+                ", range {} .. {}",
+                min.as_deref().unwrap_or("-"),
+                max.as_deref().unwrap_or("-")
+            ),
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,88 @@
+//! Cells of the protobuf well-known `Timestamp` and `Duration` messages
+//!
+//! The derive recognizes `prost_types::Timestamp` / `prost_types::Duration`
+//! fields (and their `pbjson_types` equivalents) by path and converts them
+//! through their `seconds` / `nanos` fields, so no protobuf crate is needed
+//! here.
+
+use polars::prelude::*;
+
+use crate::access::{cell_invalid, cell_type_mismatch};
+use crate::{CellValue, Result};
+
+const NANOS_PER_SECOND: i64 = 1_000_000_000;
+
+/// Column source of `Timestamp` fields, stored as `Datetime(ns)` in UTC
+pub struct ProtoTimestamp;
+
+/// Column source of `Duration` fields, stored as `Duration(ns)`
+pub struct ProtoDuration;
+
+fn to_nanos(value: i64, unit: TimeUnit) -> Option<i64> {
+    match unit {
+        TimeUnit::Nanoseconds => Some(value),
+        TimeUnit::Microseconds => value.checked_mul(1_000),
+        TimeUnit::Milliseconds => value.checked_mul(1_000_000),
+    }
+}
+
+impl CellValue for ProtoTimestamp {
+    /// `(seconds, nanos)` with `nanos` in `0..1_000_000_000`
+    type Value = (i64, i32);
+
+    fn from_any_value(value: &AnyValue<'_>, column_name: &str) -> Result<(i64, i32)> {
+        let (AnyValue::Datetime(v, unit, _) | AnyValue::DatetimeOwned(v, unit, _)) = value else {
+            return Err(cell_type_mismatch(
+                value,
+                column_name,
+                DataType::Datetime(TimeUnit::Nanoseconds, None),
+            ));
+        };
+        let nanos = to_nanos(*v, *unit)
+            .ok_or_else(|| cell_invalid(value, column_name, "timestamp out of range"))?;
+        Ok((
+            nanos.div_euclid(NANOS_PER_SECOND),
+            nanos.rem_euclid(NANOS_PER_SECOND) as i32,
+        ))
+    }
+}
+
+impl CellValue for ProtoDuration {
+    /// `(seconds, nanos)` with `nanos` of the same sign as `seconds`
+    type Value = (i64, i32);
+
+    fn from_any_value(value: &AnyValue<'_>, column_name: &str) -> Result<(i64, i32)> {
+        let AnyValue::Duration(v, unit) = value else {
+            return Err(cell_type_mismatch(
+                value,
+                column_name,
+                DataType::Duration(TimeUnit::Nanoseconds),
+            ));
+        };
+        let nanos = to_nanos(*v, *unit)
+            .ok_or_else(|| cell_invalid(value, column_name, "duration out of range"))?;
+        Ok((
+            nanos / NANOS_PER_SECOND,
+            (nanos % NANOS_PER_SECOND) as i32,
+        ))
+    }
+}
+
+/// Cell of a `Timestamp` from its `seconds` and `nanos` fields
+#[doc(hidden)]
+pub fn timestamp_value(seconds: i64, nanos: i32) -> AnyValue<'static> {
+    AnyValue::Datetime(
+        seconds * NANOS_PER_SECOND + i64::from(nanos),
+        TimeUnit::Nanoseconds,
+        None,
+    )
+}
+
+/// Cell of a `Duration` from its `seconds` and `nanos` fields
+#[doc(hidden)]
+pub fn duration_value(seconds: i64, nanos: i32) -> AnyValue<'static> {
+    AnyValue::Duration(
+        seconds * NANOS_PER_SECOND + i64::from(nanos),
+        TimeUnit::Nanoseconds,
+    )
+}
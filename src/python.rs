@@ -0,0 +1,80 @@
+//! Python polars schemas of a derived schema
+
+use polars::prelude::*;
+
+use crate::{Result, ValidationError};
+
+/// Python literal of a string, with `"` and `\` escaped
+fn py_str(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn time_unit(unit: &TimeUnit) -> &'static str {
+    match unit {
+        TimeUnit::Nanoseconds => "ns",
+        TimeUnit::Microseconds => "us",
+        TimeUnit::Milliseconds => "ms",
+    }
+}
+
+/// Python polars dtype of a dtype, e.g. `pl.List(pl.Int64)`, with polars
+/// imported as `pl`; `None` for dtypes without one
+pub fn python_dtype(dtype: &DataType) -> Option<String> {
+    Some(match dtype {
+        DataType::Boolean => "pl.Boolean".to_string(),
+        DataType::Int8 => "pl.Int8".to_string(),
+        DataType::Int16 => "pl.Int16".to_string(),
+        DataType::Int32 => "pl.Int32".to_string(),
+        DataType::Int64 => "pl.Int64".to_string(),
+        DataType::Int128 => "pl.Int128".to_string(),
+        DataType::UInt8 => "pl.UInt8".to_string(),
+        DataType::UInt16 => "pl.UInt16".to_string(),
+        DataType::UInt32 => "pl.UInt32".to_string(),
+        DataType::UInt64 => "pl.UInt64".to_string(),
+        DataType::Float32 => "pl.Float32".to_string(),
+        DataType::Float64 => "pl.Float64".to_string(),
+        DataType::String => "pl.String".to_string(),
+        DataType::Binary => "pl.Binary".to_string(),
+        DataType::Date => "pl.Date".to_string(),
+        DataType::Time => "pl.Time".to_string(),
+        DataType::Null => "pl.Null".to_string(),
+        DataType::Datetime(unit, None) => format!("pl.Datetime({})", py_str(time_unit(unit))),
+        DataType::Datetime(unit, Some(zone)) => {
+            format!("pl.Datetime({}, {})", py_str(time_unit(unit)), py_str(zone))
+        }
+        DataType::Duration(unit) => format!("pl.Duration({})", py_str(time_unit(unit))),
+        #[cfg(feature = "decimal")]
+        DataType::Decimal(precision, scale) => {
+            let arg = |value: &Option<usize>| value.map_or("None".to_string(), |v| v.to_string());
+            format!("pl.Decimal({}, {})", arg(precision), arg(scale))
+        }
+        DataType::List(inner) => format!("pl.List({})", python_dtype(inner)?),
+        DataType::Struct(fields) => {
+            let fields = fields
+                .iter()
+                .map(|field| {
+                    let dtype = python_dtype(field.dtype())?;
+                    Some(format!("{}: {}", py_str(field.name()), dtype))
+                })
+                .collect::<Option<Vec<_>>>()?;
+            format!("pl.Struct({{{}}})", fields.join(", "))
+        }
+        _ => return None,
+    })
+}
+
+/// `pl.Schema({...})` expression of `schema`, with polars imported as `pl`
+pub fn python_schema(schema: &Schema) -> Result<String> {
+    let columns = schema
+        .iter()
+        .map(|(name, dtype)| {
+            let python = python_dtype(dtype).ok_or_else(|| ValidationError::TypeMismatch {
+                column_name: name.to_string(),
+                actual_type: format!("{:?}", dtype),
+                expected_type: "a dtype with a Python polars equivalent".to_string(),
+            })?;
+            Ok(format!("{}: {}", py_str(name), python))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(format!("pl.Schema({{{}}})", columns.join(", ")))
+}
@@ -0,0 +1,461 @@
+//! Data-quality rules checked against a frame in one pass
+//!
+//! Checks can be declared on fields (`#[polars(unique)]`,
+//! `#[polars(max_null_fraction = 0.1)]`, `#[polars(min = 0, max = 150)]`, plus
+//! the valid values of enum fields) and collected with the generated
+//! `Struct::quality_checks()`, built at runtime
+//! with the `QualityChecks` methods, or deserialized from JSON/YAML through
+//! serde:
+//!
+//! ```json
+//! {"checks": [
+//!     {"kind": "unique", "column": "id"},
+//!     {"kind": "range", "column": "age", "min": 0, "max": 150},
+//!     {"kind": "one_of", "column": "tier", "values": ["free", "pro"]},
+//!     {"kind": "references", "column": "country", "frame": "countries", "other_column": "code"}
+//! ]}
+//! ```
+//!
+//! Frames arriving in chunks are checked incrementally with
+//! `QualityChecks::streaming`.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use polars::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::suggest::missing_column;
+use crate::{Result, ValidateOptions, ValidationError};
+
+/// Row count column of the frame built by `QualityChecks::violations_lazy`
+const ROWS_COLUMN: &str = "__rows";
+
+/// A single data-quality rule on one column
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum QualityCheck {
+    /// At most `max` (from `0.0` to `1.0`) of the values may be null
+    MaxNullFraction { column: String, max: f64 },
+    /// Non-null values must not repeat
+    Unique { column: String },
+    /// Non-null values must lie within the inclusive bounds
+    Range {
+        column: String,
+        #[serde(default)]
+        min: Option<f64>,
+        #[serde(default)]
+        max: Option<f64>,
+    },
+    /// Non-null values must be one of `values`
+    OneOf { column: String, values: Vec<String> },
+    /// Non-null values must appear in `other_column` of the reference frame
+    /// registered as `frame`
+    References {
+        column: String,
+        frame: String,
+        other_column: String,
+    },
+}
+
+impl QualityCheck {
+    /// Column the check applies to
+    pub fn column(&self) -> &str {
+        match self {
+            QualityCheck::MaxNullFraction { column, .. }
+            | QualityCheck::Unique { column }
+            | QualityCheck::Range { column, .. }
+            | QualityCheck::OneOf { column, .. }
+            | QualityCheck::References { column, .. } => column,
+        }
+    }
+
+    /// Expression counting the rows violating a column-local check
+    fn violations_expr(&self) -> Option<Expr> {
+        let expr = match self {
+            QualityCheck::MaxNullFraction { column, .. } => col(column.as_str()).null_count(),
+            QualityCheck::Unique { column } => {
+                let column = col(column.as_str());
+                column.clone().count() - column.drop_nulls().n_unique()
+            }
+            QualityCheck::Range { column, min, max } => {
+                let value = col(column.as_str()).cast(DataType::Float64);
+                let below = min.map(|min| value.clone().lt(lit(min)));
+                let above = max.map(|max| value.gt(lit(max)));
+                let outside = match (below, above) {
+                    (Some(below), Some(above)) => below.or(above),
+                    (Some(outside), None) | (None, Some(outside)) => outside,
+                    (None, None) => lit(false),
+                };
+                outside.sum()
+            }
+            QualityCheck::OneOf { column, values } => {
+                let value = col(column.as_str());
+                let valid = values
+                    .iter()
+                    .fold(lit(false), |valid, v| valid.or(value.clone().eq(lit(v.as_str()))));
+                value.is_not_null().and(valid.not()).sum()
+            }
+            QualityCheck::References { .. } => return None,
+        };
+        Some(expr.cast(DataType::UInt64))
+    }
+}
+
+impl fmt::Display for QualityCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QualityCheck::MaxNullFraction { column, max } => {
+                write!(f, "{}: at most {:.1}% nulls", column, max * 100.0)
+            }
+            QualityCheck::Unique { column } => write!(f, "{}: unique", column),
+            QualityCheck::Range { column, min, max } => {
+                let bound = |b: &Option<f64>| b.map_or("..".to_string(), |b| b.to_string());
+                write!(f, "{}: within [{}, {}]", column, bound(min), bound(max))
+            }
+            QualityCheck::OneOf { column, values } => {
+                write!(f, "{}: one of [{}]", column, values.join(", "))
+            }
+            QualityCheck::References {
+                column,
+                frame,
+                other_column,
+            } => write!(f, "{}: references {}.{}", column, frame, other_column),
+        }
+    }
+}
+
+/// Collection of checks plus the reference frames used by `References`
+/// checks
+///
+/// Reference frames are not part of the serialized form; register them with
+/// `reference_frame` after loading a configuration.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct QualityChecks {
+    pub checks: Vec<QualityCheck>,
+    #[serde(skip)]
+    frames: HashMap<String, DataFrame>,
+}
+
+impl QualityChecks {
+    /// Empty collection
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add any check
+    pub fn check(mut self, check: QualityCheck) -> Self {
+        self.checks.push(check);
+        self
+    }
+
+    /// Append the checks (and reference frames) of another collection
+    pub fn extend(mut self, other: QualityChecks) -> Self {
+        self.checks.extend(other.checks);
+        self.frames.extend(other.frames);
+        self
+    }
+
+    /// Allow at most `max` (from `0.0` to `1.0`) nulls in `column`
+    pub fn max_null_fraction(self, column: &str, max: f64) -> Self {
+        self.check(QualityCheck::MaxNullFraction {
+            column: column.to_string(),
+            max,
+        })
+    }
+
+    /// Require the non-null values of `column` to be distinct
+    pub fn unique(self, column: &str) -> Self {
+        self.check(QualityCheck::Unique {
+            column: column.to_string(),
+        })
+    }
+
+    /// Require the values of `column` to lie within the inclusive bounds
+    pub fn range(self, column: &str, min: Option<f64>, max: Option<f64>) -> Self {
+        self.check(QualityCheck::Range {
+            column: column.to_string(),
+            min,
+            max,
+        })
+    }
+
+    /// Require the values of `column` to be one of `values`
+    pub fn one_of(self, column: &str, values: &[&str]) -> Self {
+        self.check(QualityCheck::OneOf {
+            column: column.to_string(),
+            values: values.iter().map(|v| v.to_string()).collect(),
+        })
+    }
+
+    /// Require the values of `column` to appear in `other_column` of the
+    /// reference frame registered as `frame`
+    pub fn references(self, column: &str, frame: &str, other_column: &str) -> Self {
+        self.check(QualityCheck::References {
+            column: column.to_string(),
+            frame: frame.to_string(),
+            other_column: other_column.to_string(),
+        })
+    }
+
+    /// Register the frame that `References` checks name as `name`
+    pub fn reference_frame(mut self, name: &str, frame: DataFrame) -> Self {
+        self.frames.insert(name.to_string(), frame);
+        self
+    }
+
+    /// Run every check against `df`
+    ///
+    /// All checks are evaluated together in one lazy query, see
+    /// `violations_lazy`.
+    pub fn run(&self, df: &DataFrame) -> Result<QualityReport> {
+        let counts = self.violations_lazy(df.clone().lazy())?.collect()?;
+        self.report(&counts)
+    }
+
+    /// Run every check against `df`, or against a random sample of it when
+    /// `opts.sample_rows` is set
+    ///
+    /// A sampled run is a smoke check for frames too large to scan: counts
+    /// refer to the sample, and duplicates split across the sample boundary
+    /// go unnoticed by `Unique` checks.
+    pub fn run_with(&self, df: &DataFrame, opts: &ValidateOptions) -> Result<QualityReport> {
+        let sample = opts.sample(df)?;
+        let mut report = self.run(&sample)?;
+        if sample.height() < df.height() {
+            report.sampled_from = Some(df.height());
+        }
+        Ok(report)
+    }
+
+    /// One-row lazy frame counting the violations of every check on `lf`
+    ///
+    /// Column-local checks become aggregations of a single `select`, and each
+    /// `References` check is a left join against its (deduplicated) reference
+    /// frame feeding that same `select`, so the whole suite runs as one query
+    /// with Polars' parallelism and fuses with whatever produced `lf`. Turn
+    /// the collected frame into a report with `report`.
+    pub fn violations_lazy(&self, mut lf: LazyFrame) -> Result<LazyFrame> {
+        let schema = lf.collect_schema()?;
+        for check in &self.checks {
+            if !schema.contains(check.column()) {
+                let columns = schema.iter_names().map(|name| name.as_str());
+                return Err(missing_column(check.column(), columns));
+            }
+        }
+
+        let mut counts = vec![len().cast(DataType::UInt64).alias(ROWS_COLUMN)];
+        for (i, check) in self.checks.iter().enumerate() {
+            let expr = match check {
+                QualityCheck::References {
+                    column,
+                    frame,
+                    other_column,
+                } => {
+                    let found = format!("__found_{}", i);
+                    lf = lf.join(
+                        self.reference_keys(frame, other_column, &found)?,
+                        [col(column.as_str())],
+                        [col(other_column.as_str())],
+                        JoinArgs::new(JoinType::Left),
+                    );
+                    col(column.as_str())
+                        .is_not_null()
+                        .and(col(found.as_str()).is_null())
+                        .sum()
+                        .cast(DataType::UInt64)
+                }
+                _ => check.violations_expr().expect("column-local check"),
+            };
+            counts.push(expr.alias(format!("{}", i)));
+        }
+
+        Ok(lf.select(counts))
+    }
+
+    /// Report from the collected output of `violations_lazy`
+    pub fn report(&self, counts: &DataFrame) -> Result<QualityReport> {
+        let count = |name: &str| -> Result<usize> {
+            Ok(counts
+                .column(name)?
+                .as_materialized_series()
+                .u64()?
+                .get(0)
+                .unwrap_or(0) as usize)
+        };
+
+        let rows = count(ROWS_COLUMN)?;
+        let results = self
+            .checks
+            .iter()
+            .enumerate()
+            .map(|(i, check)| Ok(CheckResult::new(check, count(&format!("{}", i))?, rows)))
+            .collect::<Result<_>>()?;
+
+        Ok(QualityReport {
+            rows,
+            sampled_from: None,
+            results,
+        })
+    }
+
+    /// Incremental run over a frame that arrives in chunks
+    ///
+    /// Feed every chunk to `StreamingChecks::push` and call `finish` for the
+    /// report of the whole stream. Only the running counts are kept, plus one
+    /// hash per distinct value of `Unique` columns.
+    pub fn streaming(&self) -> StreamingChecks<'_> {
+        StreamingChecks {
+            checks: self,
+            rows: 0,
+            violations: vec![0; self.checks.len()],
+            seen: vec![HashSet::new(); self.checks.len()],
+        }
+    }
+
+    /// Distinct values of `other_column` in the reference frame `frame`,
+    /// flagged with a `found` column of `true`
+    fn reference_keys(&self, frame: &str, other_column: &str, found: &str) -> Result<LazyFrame> {
+        let reference = self
+            .frames
+            .get(frame)
+            .ok_or_else(|| ValidationError::MissingReferenceFrame {
+                frame: frame.to_string(),
+            })?;
+
+        Ok(reference
+            .clone()
+            .lazy()
+            .select([col(other_column)])
+            .unique(None, UniqueKeepStrategy::Any)
+            .with_column(lit(true).alias(found)))
+    }
+}
+
+/// Running state of `QualityChecks::streaming`
+pub struct StreamingChecks<'a> {
+    checks: &'a QualityChecks,
+    rows: usize,
+    violations: Vec<usize>,
+    /// Hashes of the non-null values seen so far, for `Unique` checks
+    seen: Vec<HashSet<u64>>,
+}
+
+impl StreamingChecks<'_> {
+    /// Check the next chunk of the stream
+    ///
+    /// Counts of every check except `Unique` add up across chunks, so they
+    /// come from one lazy query per chunk as in `QualityChecks::run`.
+    /// Uniqueness is tracked across chunks by hashing the non-null values.
+    pub fn push(&mut self, chunk: &DataFrame) -> Result<()> {
+        let counts = self.checks.run(chunk)?;
+        self.rows += counts.rows;
+
+        for (i, result) in counts.results.into_iter().enumerate() {
+            match &result.check {
+                QualityCheck::Unique { column } => {
+                    let values = chunk.column(column)?.as_materialized_series().drop_nulls();
+                    for value in values.iter() {
+                        let mut hasher = DefaultHasher::new();
+                        value.hash(&mut hasher);
+                        if !self.seen[i].insert(hasher.finish()) {
+                            self.violations[i] += 1;
+                        }
+                    }
+                }
+                _ => self.violations[i] += result.violations,
+            }
+        }
+        Ok(())
+    }
+
+    /// Report over every chunk pushed so far
+    pub fn finish(self) -> QualityReport {
+        let results = self
+            .checks
+            .checks
+            .iter()
+            .zip(&self.violations)
+            .map(|(check, violations)| CheckResult::new(check, *violations, self.rows))
+            .collect();
+
+        QualityReport {
+            rows: self.rows,
+            sampled_from: None,
+            results,
+        }
+    }
+}
+
+/// Outcome of a single check
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CheckResult {
+    pub check: QualityCheck,
+    /// Offending rows: nulls, duplicates, out-of-range or unmatched values
+    pub violations: usize,
+    pub passed: bool,
+}
+
+impl CheckResult {
+    fn new(check: &QualityCheck, violations: usize, rows: usize) -> Self {
+        let passed = match check {
+            QualityCheck::MaxNullFraction { max, .. } => {
+                rows == 0 || violations as f64 / rows as f64 <= *max
+            }
+            _ => violations == 0,
+        };
+        CheckResult {
+            check: check.clone(),
+            violations,
+            passed,
+        }
+    }
+}
+
+/// Consolidated outcome of `QualityChecks::run`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QualityReport {
+    pub rows: usize,
+    /// Height of the full frame when the checks ran on a sample of `rows`
+    pub sampled_from: Option<usize>,
+    pub results: Vec<CheckResult>,
+}
+
+impl QualityReport {
+    /// Whether every check passed
+    pub fn passed(&self) -> bool {
+        self.results.iter().all(|r| r.passed)
+    }
+
+    /// Results of the checks that failed
+    pub fn failures(&self) -> impl Iterator<Item = &CheckResult> {
+        self.results.iter().filter(|r| !r.passed)
+    }
+}
+
+impl fmt::Display for QualityReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let failed = self.failures().count();
+        write!(
+            f,
+            "{} of {} checks passed on {} rows",
+            self.results.len() - failed,
+            self.results.len(),
+            self.rows
+        )?;
+        if let Some(height) = self.sampled_from {
+            write!(f, " (sampled from {})", height)?;
+        }
+        for result in &self.results {
+            let status = if result.passed { "ok" } else { "FAILED" };
+            write!(
+                f,
+                "\n[{}] {} ({} violations)",
+                status, result.check, result.violations
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+//! Referential integrity between schemas, behind the helpers generated for
+//! `#[polars(references(Other::field))]`
+
+use polars::prelude::*;
+
+use crate::keys::{key_value, SAMPLE_SIZE};
+use crate::{Result, ValidationError};
+
+/// Check that every non-null value of `column` in `df`, valid under `schema`,
+/// appears in `other_column` of `other`, valid under `other_schema`,
+/// reporting the orphaned rows and a sample of their values
+#[doc(hidden)]
+pub fn validate_reference(
+    df: &DataFrame,
+    schema: &Schema,
+    column: &str,
+    other: &DataFrame,
+    other_schema: &Schema,
+    other_column: &str,
+) -> Result<()> {
+    crate::validate::validate_schema(df, schema, &[])?;
+    crate::validate::validate_schema(other, other_schema, &[])?;
+    let dtype = schema.get(column).expect("column of the schema");
+    let other_dtype = other_schema
+        .get(other_column)
+        .expect("column of the referenced schema");
+    if dtype != other_dtype {
+        return Err(ValidationError::TypeMismatch {
+            column_name: column.to_string(),
+            actual_type: format!("{:?}", dtype),
+            expected_type: format!("{:?}", other_dtype),
+        });
+    }
+
+    let orphans = df
+        .clone()
+        .lazy()
+        .select([col(column)])
+        .filter(col(column).is_not_null())
+        .join(
+            other.clone().lazy().select([col(other_column)]),
+            [col(column)],
+            [col(other_column)],
+            JoinArgs::new(JoinType::Anti),
+        )
+        .collect()?;
+    if orphans.height() == 0 {
+        return Ok(());
+    }
+    let values = orphans.unique_stable(None, UniqueKeepStrategy::First, None)?;
+    let sample = (0..values.height().min(SAMPLE_SIZE))
+        .map(|row| key_value(&values, row))
+        .collect::<Result<_>>()?;
+    Err(ValidationError::OrphanedReferences {
+        column_name: column.to_string(),
+        referenced: other_column.to_string(),
+        rows: orphans.height(),
+        values: values.height(),
+        sample,
+    })
+}
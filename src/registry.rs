@@ -0,0 +1,125 @@
+//! Runtime lookup of derived schemas by name (`registry` feature)
+//!
+//! Schemas opt in with `#[polars(register)]`, or `#[polars(register =
+//! "name")]` to register under another name than the struct's, and can then
+//! be found from configuration strings:
+//!
+//! ```ignore
+//! #[derive(PolarsSchema)]
+//! #[polars(register)]
+//! struct UserProfile { /* ... */ }
+//!
+//! let schema = registry::get("UserProfile").expect("unknown table");
+//! schema.validate(&df)?;
+//! ```
+//!
+//! Registration happens at link time through `inventory`, so every schema of
+//! every linked crate is visible without explicit setup.
+
+use polars::prelude::*;
+
+use crate::{ColumnDType, Result, SchemaDescription};
+
+/// A derived schema registered with `#[polars(register)]`
+pub struct RegisteredSchema {
+    name: &'static str,
+    columns: &'static [&'static str],
+    dtypes: &'static [ColumnDType],
+    schema: fn() -> &'static Schema,
+    column_docs: fn() -> &'static [(&'static str, &'static str)],
+    description: fn() -> SchemaDescription,
+    validate: fn(&DataFrame) -> Result<()>,
+    validate_strict: fn(&DataFrame) -> Result<()>,
+}
+
+inventory::collect!(RegisteredSchema);
+
+impl RegisteredSchema {
+    #[doc(hidden)]
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new(
+        name: &'static str,
+        columns: &'static [&'static str],
+        dtypes: &'static [ColumnDType],
+        schema: fn() -> &'static Schema,
+        column_docs: fn() -> &'static [(&'static str, &'static str)],
+        description: fn() -> SchemaDescription,
+        validate: fn(&DataFrame) -> Result<()>,
+        validate_strict: fn(&DataFrame) -> Result<()>,
+    ) -> Self {
+        RegisteredSchema {
+            name,
+            columns,
+            dtypes,
+            schema,
+            column_docs,
+            description,
+            validate,
+            validate_strict,
+        }
+    }
+
+    /// Name the schema is registered under
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Column names in field order
+    pub fn columns(&self) -> &'static [&'static str] {
+        self.columns
+    }
+
+    /// Column dtypes in field order
+    pub fn dtypes(&self) -> &'static [ColumnDType] {
+        self.dtypes
+    }
+
+    /// Expected schema of the frame, as `Struct::polars_schema()`
+    pub fn schema(&self) -> &'static Schema {
+        (self.schema)()
+    }
+
+    /// Descriptions of the documented columns, as `Struct::column_docs()`
+    pub fn column_docs(&self) -> &'static [(&'static str, &'static str)] {
+        (self.column_docs)()
+    }
+
+    /// Columns with their dtype, nullability, doc and tags, under the
+    /// registered name, e.g. for a `--print-schema TABLE` flag
+    pub fn describe(&self) -> SchemaDescription {
+        SchemaDescription {
+            name: self.name.to_string(),
+            ..(self.description)()
+        }
+    }
+
+    /// Check `df` as `Struct::validate` does
+    pub fn validate(&self, df: &DataFrame) -> Result<()> {
+        (self.validate)(df)
+    }
+
+    /// Check `df` as `Struct::validate_strict` does
+    pub fn validate_strict(&self, df: &DataFrame) -> Result<()> {
+        (self.validate_strict)(df)
+    }
+}
+
+impl std::fmt::Debug for RegisteredSchema {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RegisteredSchema")
+            .field("name", &self.name)
+            .field("columns", &self.columns)
+            .field("dtypes", &self.dtypes)
+            .finish()
+    }
+}
+
+/// Registered schema named `name`
+pub fn get(name: &str) -> Option<&'static RegisteredSchema> {
+    all().find(|schema| schema.name == name)
+}
+
+/// Every registered schema, in no particular order
+pub fn all() -> impl Iterator<Item = &'static RegisteredSchema> {
+    inventory::iter::<RegisteredSchema>.into_iter()
+}
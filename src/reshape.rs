@@ -0,0 +1,73 @@
+//! Wide-to-long reshaping by column groups, behind the generated `melt`
+
+use polars::prelude::*;
+
+use crate::{Result, ValidationError};
+
+/// Name of the column holding the names of the unpivoted columns
+pub const MELT_VARIABLE: &str = "variable";
+
+/// Name of the column holding the values of the unpivoted columns
+pub const MELT_VALUE: &str = "value";
+
+/// Columns of `group` in `groups`, given as `(group, column)` pairs
+#[doc(hidden)]
+pub fn group_columns(groups: &[(&str, &'static str)], group: &str) -> Result<Vec<&'static str>> {
+    let columns: Vec<_> = groups
+        .iter()
+        .filter(|(name, _)| *name == group)
+        .map(|(_, column)| *column)
+        .collect();
+    if columns.is_empty() {
+        let mut known: Vec<String> = groups.iter().map(|(name, _)| name.to_string()).collect();
+        known.sort();
+        known.dedup();
+        return Err(ValidationError::UnknownGroup {
+            group: group.to_string(),
+            groups: known,
+        });
+    }
+    Ok(columns)
+}
+
+/// Long-format schema of unpivoting the `values` columns of `schema`: the
+/// `ids` columns, then `variable` (the column name) and `value`, of the
+/// supertype of the value dtypes
+pub fn melted_schema(schema: &Schema, ids: &[&str], values: &[&str]) -> Result<Schema> {
+    for column in ids.iter().chain(values) {
+        if !schema.contains(column) {
+            return Err(ValidationError::MissingColumn {
+                column_name: column.to_string(),
+                suggestions: Vec::new(),
+            });
+        }
+    }
+    // As Polars computes it, from a frame without rows
+    let melted = DataFrame::empty_with_schema(schema).unpivot2(unpivot_args(ids, values))?;
+    Ok(melted.schema().as_ref().clone())
+}
+
+fn unpivot_args(ids: &[&str], values: &[&str]) -> UnpivotArgsIR {
+    UnpivotArgsIR {
+        on: values.iter().map(|column| (*column).into()).collect(),
+        index: ids.iter().map(|column| (*column).into()).collect(),
+        variable_name: Some(MELT_VARIABLE.into()),
+        value_name: Some(MELT_VALUE.into()),
+    }
+}
+
+/// `df`, valid under `schema`, unpivoted to one row per `values` column and
+/// row, checked against `melted_schema`
+#[doc(hidden)]
+pub fn melt_columns(
+    df: &DataFrame,
+    schema: &Schema,
+    ids: &[&str],
+    values: &[&str],
+) -> Result<DataFrame> {
+    crate::validate::validate_schema(df, schema, &[])?;
+    let melted_schema = melted_schema(schema, ids, values)?;
+    let melted = df.unpivot2(unpivot_args(ids, values))?;
+    crate::validate::validate_schema(&melted, &melted_schema, &[])?;
+    Ok(melted)
+}
@@ -0,0 +1,87 @@
+//! Differences between a derived schema and a schema found elsewhere
+
+use std::fmt;
+
+use polars::prelude::*;
+use serde::Serialize;
+
+use crate::profile::serialize_dtype;
+
+/// Differences between an expected schema and an actual one, e.g. the schema
+/// of a table the derived struct is supposed to describe
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct SchemaDiff {
+    /// Expected columns absent from the actual schema
+    pub missing: Vec<String>,
+    /// Actual columns the expected schema does not have
+    pub unexpected: Vec<String>,
+    /// Columns present in both with different dtypes
+    pub mismatched: Vec<DTypeMismatch>,
+}
+
+/// A column whose actual dtype differs from the expected one
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DTypeMismatch {
+    pub column: String,
+    #[serde(serialize_with = "serialize_dtype")]
+    pub expected: DataType,
+    #[serde(serialize_with = "serialize_dtype")]
+    pub actual: DataType,
+}
+
+impl SchemaDiff {
+    /// Compare `actual` against `expected`, column by column
+    pub fn between(expected: &Schema, actual: &Schema) -> Self {
+        let mut diff = SchemaDiff::default();
+        for (name, dtype) in expected.iter() {
+            match actual.get(name) {
+                None => diff.missing.push(name.to_string()),
+                Some(actual) if actual != dtype => diff.mismatched.push(DTypeMismatch {
+                    column: name.to_string(),
+                    expected: dtype.clone(),
+                    actual: actual.clone(),
+                }),
+                Some(_) => {}
+            }
+        }
+        diff.unexpected = actual
+            .iter_names()
+            .filter(|name| !expected.contains(name))
+            .map(|name| name.to_string())
+            .collect();
+        diff
+    }
+
+    /// Whether both schemas have the same columns and dtypes
+    pub fn is_empty(&self) -> bool {
+        self.missing.is_empty() && self.unexpected.is_empty() && self.mismatched.is_empty()
+    }
+
+    /// Whether every expected column is present with its dtype, ignoring
+    /// unexpected columns as `validate` does
+    pub fn is_compatible(&self) -> bool {
+        self.missing.is_empty() && self.mismatched.is_empty()
+    }
+}
+
+impl fmt::Display for SchemaDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "no differences");
+        }
+        let mut parts = Vec::new();
+        if !self.missing.is_empty() {
+            parts.push(format!("missing {}", self.missing.join(", ")));
+        }
+        for mismatch in &self.mismatched {
+            parts.push(format!(
+                "{} is {}, expected {}",
+                mismatch.column, mismatch.actual, mismatch.expected
+            ));
+        }
+        if !self.unexpected.is_empty() {
+            parts.push(format!("unexpected {}", self.unexpected.join(", ")));
+        }
+        write!(f, "{}", parts.join("; "))
+    }
+}
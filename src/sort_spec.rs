@@ -0,0 +1,63 @@
+//! Sort specifications built from column constants
+
+use polars::prelude::*;
+
+/// Sort direction of a single column, keyed by a generated column constant
+/// such as `Struct::age`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortKey {
+    Asc(&'static str),
+    Desc(&'static str),
+}
+
+impl SortKey {
+    /// Name of the sorted column
+    pub fn column(&self) -> &'static str {
+        match self {
+            SortKey::Asc(column) | SortKey::Desc(column) => column,
+        }
+    }
+
+    /// Whether the column is sorted in descending order
+    pub fn is_descending(&self) -> bool {
+        matches!(self, SortKey::Desc(_))
+    }
+}
+
+/// Columns and options for `LazyFrame::sort` / `DataFrame::sort`, returned by
+/// the generated `Struct::sort_by(&[...])`
+#[derive(Debug, Clone)]
+pub struct SortSpec {
+    /// Sorted columns, in priority order
+    pub columns: Vec<&'static str>,
+    /// Per-column directions plus the remaining sort options
+    pub options: SortMultipleOptions,
+}
+
+impl SortSpec {
+    /// Sort by `keys`, the first key taking priority
+    pub fn new(keys: &[SortKey]) -> Self {
+        let descending: Vec<bool> = keys.iter().map(SortKey::is_descending).collect();
+        Self {
+            columns: keys.iter().map(SortKey::column).collect(),
+            options: SortMultipleOptions::default().with_order_descending_multi(descending),
+        }
+    }
+
+    /// Place nulls after all other values
+    pub fn nulls_last(mut self, nulls_last: bool) -> Self {
+        self.options = self.options.with_nulls_last(nulls_last);
+        self
+    }
+
+    /// Keep the original order of rows that compare equal
+    pub fn maintain_order(mut self, maintain_order: bool) -> Self {
+        self.options = self.options.with_maintain_order(maintain_order);
+        self
+    }
+
+    /// Sort a lazy frame by this specification
+    pub fn apply(self, lf: LazyFrame) -> LazyFrame {
+        lf.sort(self.columns, self.options)
+    }
+}
@@ -0,0 +1,50 @@
+//! Postgres statements driven by a derived schema
+
+use polars::prelude::*;
+
+use crate::{Result, ValidationError};
+
+/// Postgres column type of a dtype, `None` for dtypes without one
+pub fn pg_type(dtype: &DataType) -> Option<&'static str> {
+    Some(match dtype {
+        DataType::Boolean => "boolean",
+        DataType::Int8 | DataType::Int16 | DataType::UInt8 => "smallint",
+        DataType::Int32 | DataType::UInt16 => "integer",
+        DataType::Int64 | DataType::UInt32 => "bigint",
+        DataType::UInt64 | DataType::Int128 => "numeric",
+        #[cfg(feature = "decimal")]
+        DataType::Decimal(_, _) => "numeric",
+        DataType::Float32 => "real",
+        DataType::Float64 => "double precision",
+        DataType::String => "text",
+        DataType::Binary => "bytea",
+        DataType::Date => "date",
+        DataType::Datetime(_, None) => "timestamp",
+        DataType::Datetime(_, Some(_)) => "timestamptz",
+        DataType::Time => "time",
+        DataType::Duration(_) => "interval",
+        _ => return None,
+    })
+}
+
+/// `INSERT INTO table (...) SELECT * FROM UNNEST($1::type[], ...)`, inserting
+/// one row per element of the bound column arrays in a single statement
+pub fn insert_sql(table: &str, schema: &Schema) -> Result<String> {
+    let mut columns = Vec::with_capacity(schema.len());
+    let mut arrays = Vec::with_capacity(schema.len());
+    for (i, (name, dtype)) in schema.iter().enumerate() {
+        let pg_type = pg_type(dtype).ok_or_else(|| ValidationError::TypeMismatch {
+            column_name: name.to_string(),
+            actual_type: format!("{:?}", dtype),
+            expected_type: "a dtype with a Postgres array type".to_string(),
+        })?;
+        columns.push(format!("\"{}\"", name.replace('"', "\"\"")));
+        arrays.push(format!("${}::{}[]", i + 1, pg_type));
+    }
+    Ok(format!(
+        "INSERT INTO {} ({}) SELECT * FROM UNNEST({})",
+        table,
+        columns.join(", "),
+        arrays.join(", ")
+    ))
+}
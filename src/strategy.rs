@@ -0,0 +1,122 @@
+//! Proptest strategies for derived schemas (`proptest` feature)
+//!
+//! Used by the generated `Struct::df_strategy()`, which produces frames that
+//! pass `Struct::validate_strict`: every column has the schema dtype, `Option`
+//! fields may hold nulls and enum fields only hold their valid values.
+
+use std::fmt::Debug;
+use std::ops::RangeInclusive;
+
+use polars::prelude::*;
+use proptest::prelude::*;
+
+pub use proptest;
+
+use crate::{ToAnyValue, ValidatableEnum};
+
+/// Number of rows of `Struct::df_strategy()` frames
+pub const DEFAULT_ROWS: RangeInclusive<usize> = 0..=32;
+
+/// Trait for Rust types with a proptest strategy for their values
+pub trait ValueStrategy: ToAnyValue + Debug + Sized + 'static {
+    fn value_strategy() -> BoxedStrategy<Self>;
+}
+
+macro_rules! impl_value_strategy {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl ValueStrategy for $ty {
+                fn value_strategy() -> BoxedStrategy<Self> {
+                    any::<$ty>().boxed()
+                }
+            }
+        )*
+    };
+}
+
+impl_value_strategy!(i8, i16, i32, i64, i128, u8, u16, u32, u64, f32, f64, bool, String);
+
+impl<T: ValueStrategy> ValueStrategy for Option<T> {
+    fn value_strategy() -> BoxedStrategy<Self> {
+        proptest::option::of(T::value_strategy()).boxed()
+    }
+}
+
+impl<T: ValidatableEnum + Debug + 'static> ValueStrategy for T {
+    fn value_strategy() -> BoxedStrategy<Self> {
+        proptest::sample::select(T::valid_values())
+            .prop_map(|value| {
+                <T as ValidatableEnum>::from_str(value).expect("valid_values() entries must parse")
+            })
+            .boxed()
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_strategies {
+    use super::ValueStrategy;
+    use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+    use proptest::prelude::*;
+
+    /// 1900-01-01 and 2100-12-31 as Unix microseconds
+    const START: i64 = -2_208_988_800_000_000;
+    const END: i64 = 4_133_980_799_999_999;
+
+    impl ValueStrategy for DateTime<Utc> {
+        fn value_strategy() -> BoxedStrategy<Self> {
+            (START..=END)
+                .prop_map(|micros| DateTime::from_timestamp_micros(micros).unwrap_or_default())
+                .boxed()
+        }
+    }
+
+    impl ValueStrategy for NaiveDateTime {
+        fn value_strategy() -> BoxedStrategy<Self> {
+            DateTime::<Utc>::value_strategy()
+                .prop_map(|dt| dt.naive_utc())
+                .boxed()
+        }
+    }
+
+    impl ValueStrategy for NaiveDate {
+        fn value_strategy() -> BoxedStrategy<Self> {
+            DateTime::<Utc>::value_strategy()
+                .prop_map(|dt| dt.date_naive())
+                .boxed()
+        }
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl ValueStrategy for uuid::Uuid {
+    fn value_strategy() -> BoxedStrategy<Self> {
+        any::<[u8; 16]>().prop_map(uuid::Uuid::from_bytes).boxed()
+    }
+}
+
+/// Strategy for a column of `rows` values of `T`, converted to `dtype`
+#[doc(hidden)]
+pub fn column_strategy<T: ValueStrategy>(
+    name: &'static str,
+    dtype: DataType,
+    rows: usize,
+) -> BoxedStrategy<Column> {
+    proptest::collection::vec(T::value_strategy(), rows)
+        .prop_map(move |values| {
+            let values: Vec<_> = values.iter().map(ToAnyValue::to_any_value).collect();
+            crate::access::column_from_values(name, &values, &dtype)
+                .expect("generated values must convert to the column dtype")
+        })
+        .boxed()
+}
+
+/// Combine per-column strategies into frames
+#[doc(hidden)]
+pub fn frame_strategy(
+    rows: RangeInclusive<usize>,
+    columns: impl Fn(usize) -> Vec<BoxedStrategy<Column>> + 'static,
+) -> BoxedStrategy<DataFrame> {
+    rows.prop_flat_map(columns)
+        .prop_map(|columns| DataFrame::new(columns).expect("columns have equal lengths"))
+        .boxed()
+}
@@ -0,0 +1,138 @@
+//! Micro-batching of streamed rows into validated frames
+//!
+//! A `BatchCollector` buffers rows as they arrive, e.g. from a Kafka consumer,
+//! and hands back a validated frame once `max_rows` rows are buffered or the
+//! oldest row is `max_age` old. Batches are returned from the call that
+//! completes them, so the consumer loop decides when to write them out and
+//! when to read more; `time_until_flush` gives the timeout for the next read.
+
+use std::marker::PhantomData;
+use std::time::{Duration, Instant};
+
+use polars::prelude::*;
+
+use crate::{PolarsSchemaInfo, Result};
+
+/// Buffer of rows of type `R`, emitted as validated frames of schema `T`
+///
+/// With the generated `Struct::to_df`, rows are the structs themselves:
+///
+/// ```ignore
+/// let mut batches = BatchCollector::<Trade>::new(10_000, Trade::to_df)
+///     .max_age(Duration::from_secs(5));
+/// ```
+pub struct BatchCollector<T, R = T> {
+    max_rows: usize,
+    max_age: Option<Duration>,
+    convert: fn(&[R]) -> Result<DataFrame>,
+    pending: Vec<R>,
+    oldest: Option<Instant>,
+    schema: PhantomData<fn() -> T>,
+}
+
+impl<T: PolarsSchemaInfo, R> BatchCollector<T, R> {
+    /// Collector emitting a batch every `max_rows` rows (at least one),
+    /// built by `convert`
+    pub fn new(max_rows: usize, convert: fn(&[R]) -> Result<DataFrame>) -> Self {
+        BatchCollector {
+            max_rows: max_rows.max(1),
+            max_age: None,
+            convert,
+            pending: Vec::new(),
+            oldest: None,
+            schema: PhantomData,
+        }
+    }
+
+    /// Also emit a batch once its oldest row has been buffered for `max_age`,
+    /// checked by `push` and `poll`
+    pub fn max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    /// Number of buffered rows
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Whether no rows are buffered
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Buffer `row`, returning the batch it completes
+    pub fn push(&mut self, row: R) -> Result<Option<DataFrame>> {
+        self.oldest.get_or_insert_with(Instant::now);
+        self.pending.push(row);
+        if self.pending.len() >= self.max_rows || self.is_expired() {
+            self.flush()
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Buffer every row of `rows`, returning the batches they complete
+    pub fn extend<I: IntoIterator<Item = R>>(&mut self, rows: I) -> Result<Vec<DataFrame>> {
+        let mut batches = Vec::new();
+        for row in rows {
+            batches.extend(self.push(row)?);
+        }
+        Ok(batches)
+    }
+
+    /// Time left before the buffered rows are due, `None` when nothing is
+    /// buffered or no `max_age` is set
+    pub fn time_until_flush(&self) -> Option<Duration> {
+        let max_age = self.max_age?;
+        Some(max_age.saturating_sub(self.oldest?.elapsed()))
+    }
+
+    /// Emit the buffered rows if they are due, for consumer loops that wake
+    /// up without a new row
+    pub fn poll(&mut self) -> Result<Option<DataFrame>> {
+        if self.is_expired() {
+            self.flush()
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Emit the buffered rows, if any, e.g. on shutdown
+    ///
+    /// The buffer is emptied even when the batch fails to convert or validate.
+    pub fn flush(&mut self) -> Result<Option<DataFrame>> {
+        self.oldest = None;
+        if self.pending.is_empty() {
+            return Ok(None);
+        }
+        let rows = std::mem::take(&mut self.pending);
+        let df = (self.convert)(&rows)?;
+        T::validate(&df)?;
+        Ok(Some(df))
+    }
+
+    fn is_expired(&self) -> bool {
+        self.time_until_flush() == Some(Duration::ZERO)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: PolarsSchemaInfo> BatchCollector<T, serde_json::Map<String, serde_json::Value>> {
+    /// Collector of JSON row objects, read as `Struct::from_json_rows` does
+    pub fn json(max_rows: usize) -> Self {
+        Self::new(max_rows, json_batch::<T>)
+    }
+
+    /// Parse `payload`, a single JSON row object, and buffer it
+    pub fn push_json(&mut self, payload: &str) -> Result<Option<DataFrame>> {
+        self.push(serde_json::from_str(payload)?)
+    }
+}
+
+#[cfg(feature = "serde")]
+fn json_batch<T: PolarsSchemaInfo>(
+    rows: &[serde_json::Map<String, serde_json::Value>],
+) -> Result<DataFrame> {
+    crate::json::from_json_objects(rows, T::polars_schema(), T::json_formats())
+}
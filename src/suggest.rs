@@ -0,0 +1,65 @@
+//! Closest-name suggestions for misspelled columns
+
+use crate::ValidationError;
+
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Edit distance between `a` and `b`, counting single-character insertions,
+/// deletions and substitutions
+pub(crate) fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != *cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// The candidates close enough to `name` to be likely misspellings of it,
+/// closest first: at most a third of the name's length apart, and at least
+/// within two edits, ignoring case
+pub(crate) fn suggestions<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Vec<String> {
+    let max_distance = (name.chars().count() / 3).max(2);
+    let name = name.to_lowercase();
+    let mut close: Vec<(usize, &str)> = candidates
+        .into_iter()
+        .map(|candidate| (levenshtein(&name, &candidate.to_lowercase()), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .collect();
+    close.sort_by_key(|(distance, _)| *distance);
+    close
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, candidate)| candidate.to_string())
+        .collect()
+}
+
+/// `MissingColumn` error for `name`, suggesting the closest of `candidates`
+pub(crate) fn missing_column<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> ValidationError {
+    ValidationError::MissingColumn {
+        column_name: name.to_string(),
+        suggestions: suggestions(name, candidates),
+    }
+}
+
+/// Message suffix listing `suggestions`, empty when there are none
+pub(crate) fn did_you_mean(suggestions: &[String]) -> String {
+    let quoted: Vec<String> = suggestions.iter().map(|s| format!("`{s}`")).collect();
+    match quoted.as_slice() {
+        [] => String::new(),
+        [only] => format!(" (did you mean {only}?)"),
+        [init @ .., last] => format!(" (did you mean {} or {last}?)", init.join(", ")),
+    }
+}
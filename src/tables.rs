@@ -0,0 +1,99 @@
+//! Runtime side of the helpers generated for every schema
+//!
+//! The derives emit the columns of a schema once, as the static `COLUMNS` and
+//! `DTYPES` tables, and make helpers such as `all_types`, `cast_exprs` or
+//! `df` thin calls into these functions instead of expanding every column into
+//! every method body.
+
+use polars::prelude::*;
+
+use crate::column_expr::column_expr;
+use crate::ColumnDType;
+
+/// Resolved dtypes of a `DTYPES` table
+pub fn dtypes(dtypes: &[ColumnDType]) -> Vec<DataType> {
+    dtypes.iter().map(ColumnDType::dtype).collect()
+}
+
+/// Fields of `columns` with their `dtypes`
+pub fn fields(columns: &[&str], dtypes: &[ColumnDType]) -> Vec<Field> {
+    columns
+        .iter()
+        .zip(dtypes)
+        .map(|(column, dtype)| Field::new((*column).into(), dtype.dtype()))
+        .collect()
+}
+
+/// Schema of `columns` with their `dtypes`
+pub fn schema(columns: &[&str], dtypes: &[ColumnDType]) -> Schema {
+    fields(columns, dtypes).into_iter().collect()
+}
+
+/// Frame without rows of `columns` with their `dtypes`
+pub fn empty_frame(columns: &[&str], dtypes: &[ColumnDType]) -> PolarsResult<DataFrame> {
+    DataFrame::new(
+        columns
+            .iter()
+            .zip(dtypes)
+            .map(|(column, dtype)| Column::new_empty((*column).into(), &dtype.dtype()))
+            .collect(),
+    )
+}
+
+/// Lazy frame without rows of `columns` with their `dtypes`
+pub fn empty_lazy(columns: &[&str], dtypes: &[ColumnDType]) -> LazyFrame {
+    DataFrame::empty_with_schema(&schema(columns, dtypes)).lazy()
+}
+
+/// Column expression of every column
+pub fn column_exprs(columns: &[&'static str]) -> Vec<Expr> {
+    columns.iter().map(|column| column_expr(column)).collect()
+}
+
+/// Expression of every column cast to its dtype
+pub fn cast_exprs(columns: &[&'static str], dtypes: &[ColumnDType]) -> Vec<Expr> {
+    columns
+        .iter()
+        .zip(dtypes)
+        .map(|(column, dtype)| column_expr(column).cast(dtype.dtype()))
+        .collect()
+}
+
+/// `<column>_count` aggregation of every column
+pub fn count_exprs(columns: &[&'static str]) -> Vec<Expr> {
+    columns
+        .iter()
+        .map(|column| column_expr(column).count().alias(format!("{}_count", column)))
+        .collect()
+}
+
+/// `columns` without those in `except`
+pub fn columns_except(columns: &[&'static str], except: &[&str]) -> Vec<&'static str> {
+    columns
+        .iter()
+        .copied()
+        .filter(|column| !except.contains(column))
+        .collect()
+}
+
+/// Column expression of `name` if it is one of `columns`
+pub fn col_expr(columns: &[&'static str], name: &str) -> Option<Expr> {
+    columns
+        .iter()
+        .find(|column| **column == name)
+        .map(|column| column_expr(column))
+}
+
+/// Columns paired with `tag` among `(column, tag)` pairs
+pub fn columns_tagged(tags: &[(&'static str, &'static str)], tag: &str) -> Vec<&'static str> {
+    tags.iter()
+        .filter(|(_, column_tag)| *column_tag == tag)
+        .map(|(column, _)| *column)
+        .collect()
+}
+
+/// `df` grouped by the `keys` columns
+pub fn group_by<'a>(df: DataFrame, keys: impl IntoIterator<Item = &'a str>) -> LazyGroupBy {
+    let keys: Vec<Expr> = keys.into_iter().map(col).collect();
+    df.lazy().group_by(keys)
+}
@@ -0,0 +1,242 @@
+//! Frames known to match a derived schema
+//!
+//! A `TypedFrame<S>` can only be built by validating against `S`, so
+//! functions taking one need no checks of their own. Its operations keep the
+//! schema (`filter`, `sort`, `vstack`) or name the schema of their result
+//! (`select`, `join`, `into_schema`), which is validated in turn.
+
+use std::marker::PhantomData;
+
+use polars::prelude::*;
+
+use crate::{PolarsSchemaInfo, Result};
+
+fn schema_columns<S: PolarsSchemaInfo>() -> Vec<Expr> {
+    S::polars_schema()
+        .iter_names()
+        .map(|name| col(name.clone()))
+        .collect()
+}
+
+/// A DataFrame validated against the derived schema `S`
+///
+/// Built with the generated `Struct::typed(&df)` or `TypedFrame::new`.
+pub struct TypedFrame<S> {
+    df: DataFrame,
+    schema: PhantomData<fn() -> S>,
+}
+
+impl<S> Clone for TypedFrame<S> {
+    fn clone(&self) -> Self {
+        Self::unchecked(self.df.clone())
+    }
+}
+
+impl<S> std::fmt::Debug for TypedFrame<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.df.fmt(f)
+    }
+}
+
+impl<S> TypedFrame<S> {
+    fn unchecked(df: DataFrame) -> Self {
+        TypedFrame {
+            df,
+            schema: PhantomData,
+        }
+    }
+
+    /// The validated frame
+    pub fn df(&self) -> &DataFrame {
+        &self.df
+    }
+
+    /// The validated frame, giving up the schema guarantee
+    pub fn into_df(self) -> DataFrame {
+        self.df
+    }
+
+    /// Number of rows
+    pub fn height(&self) -> usize {
+        self.df.height()
+    }
+
+    /// Whether the frame has no rows
+    pub fn is_empty(&self) -> bool {
+        self.df.is_empty()
+    }
+}
+
+impl<S: PolarsSchemaInfo> TypedFrame<S> {
+    /// Validate `df` against `S`
+    pub fn new(df: DataFrame) -> Result<Self> {
+        S::validate(&df)?;
+        Ok(Self::unchecked(df))
+    }
+
+    /// Validate `df` against `S` only if `DEBUG_CHECKS` is set, for hot paths
+    /// that keep the checks in development and tests
+    pub fn debug_new(df: DataFrame) -> Result<Self> {
+        if crate::DEBUG_CHECKS {
+            S::validate(&df)?;
+        }
+        Ok(Self::unchecked(df))
+    }
+
+    /// Lazy query over the frame, still typed by `S`
+    pub fn lazy(self) -> TypedLazyFrame<S> {
+        TypedLazyFrame::unchecked(self.df.lazy())
+    }
+
+    /// Rows matching `predicate`
+    pub fn filter(&self, predicate: Expr) -> Result<Self> {
+        Ok(Self::unchecked(
+            self.df.clone().lazy().filter(predicate).collect()?,
+        ))
+    }
+
+    /// Rows sorted by the `by` columns
+    pub fn sort(&self, by: impl IntoVec<PlSmallStr>, options: SortMultipleOptions) -> Result<Self> {
+        Ok(Self::unchecked(self.df.sort(by, options)?))
+    }
+
+    /// Rows of `self` followed by those of `other`
+    pub fn vstack(&self, other: &Self) -> Result<Self> {
+        let columns = schema_columns::<S>();
+        let other = other.df.clone().lazy().select(&columns).collect()?;
+        let df = self.df.clone().lazy().select(columns).collect()?;
+        Ok(Self::unchecked(df.vstack(&other)?))
+    }
+
+    /// The columns of schema `T`, which must be present with their dtypes
+    pub fn select<T: PolarsSchemaInfo>(&self) -> Result<TypedFrame<T>> {
+        TypedFrame::new(self.df.select(T::polars_schema().iter_names().cloned())?)
+    }
+
+    /// Revalidate the frame, e.g. after columns were added, as schema `T`
+    pub fn into_schema<T: PolarsSchemaInfo>(self) -> Result<TypedFrame<T>> {
+        TypedFrame::new(self.df)
+    }
+
+    /// Join with `other` on the given columns, validating the result as
+    /// schema `O`
+    pub fn join<R: PolarsSchemaInfo, O: PolarsSchemaInfo>(
+        &self,
+        other: &TypedFrame<R>,
+        left_on: &[&str],
+        right_on: &[&str],
+        how: JoinType,
+    ) -> Result<TypedFrame<O>> {
+        self.clone()
+            .lazy()
+            .join::<R, O>(other.clone().lazy(), left_on, right_on, how)
+            .collect()
+    }
+}
+
+impl<S: PolarsSchemaInfo> TryFrom<&DataFrame> for TypedFrame<S> {
+    type Error = crate::ValidationError;
+
+    fn try_from(df: &DataFrame) -> Result<Self> {
+        Self::new(df.clone())
+    }
+}
+
+impl<S: PolarsSchemaInfo> TryFrom<DataFrame> for TypedFrame<S> {
+    type Error = crate::ValidationError;
+
+    fn try_from(df: DataFrame) -> Result<Self> {
+        Self::new(df)
+    }
+}
+
+impl<S> From<TypedFrame<S>> for DataFrame {
+    fn from(frame: TypedFrame<S>) -> Self {
+        frame.df
+    }
+}
+
+/// A LazyFrame whose result is expected to match the derived schema `S`,
+/// validated by `collect`
+pub struct TypedLazyFrame<S> {
+    lf: LazyFrame,
+    schema: PhantomData<fn() -> S>,
+}
+
+impl<S> Clone for TypedLazyFrame<S> {
+    fn clone(&self) -> Self {
+        Self::unchecked(self.lf.clone())
+    }
+}
+
+impl<S> TypedLazyFrame<S> {
+    fn unchecked(lf: LazyFrame) -> Self {
+        TypedLazyFrame {
+            lf,
+            schema: PhantomData,
+        }
+    }
+
+    /// The query, giving up the schema guarantee
+    pub fn into_lazy(self) -> LazyFrame {
+        self.lf
+    }
+}
+
+impl<S: PolarsSchemaInfo> TypedLazyFrame<S> {
+    /// Expect `lf` to produce frames of schema `S`
+    pub fn new(lf: LazyFrame) -> Self {
+        Self::unchecked(lf)
+    }
+
+    /// Rows matching `predicate`
+    pub fn filter(self, predicate: Expr) -> Self {
+        Self::unchecked(self.lf.filter(predicate))
+    }
+
+    /// Rows sorted by the `by` columns
+    pub fn sort(self, by: impl IntoVec<PlSmallStr>, options: SortMultipleOptions) -> Self {
+        Self::unchecked(self.lf.sort(by, options))
+    }
+
+    /// The columns of schema `T`
+    pub fn select<T: PolarsSchemaInfo>(self) -> TypedLazyFrame<T> {
+        TypedLazyFrame::unchecked(self.lf.select(schema_columns::<T>()))
+    }
+
+    /// Apply `f` to the query, expecting schema `T` from the result, e.g.
+    /// after adding columns
+    pub fn map<T: PolarsSchemaInfo>(
+        self,
+        f: impl FnOnce(LazyFrame) -> LazyFrame,
+    ) -> TypedLazyFrame<T> {
+        TypedLazyFrame::unchecked(f(self.lf))
+    }
+
+    /// Join with `other` on the given columns, expecting schema `O`
+    pub fn join<R: PolarsSchemaInfo, O: PolarsSchemaInfo>(
+        self,
+        other: TypedLazyFrame<R>,
+        left_on: &[&str],
+        right_on: &[&str],
+        how: JoinType,
+    ) -> TypedLazyFrame<O> {
+        let on = |columns: &[&str]| columns.iter().map(|c| col(*c)).collect::<Vec<_>>();
+        TypedLazyFrame::unchecked(self.lf.join(
+            other.lf,
+            on(left_on),
+            on(right_on),
+            JoinArgs::new(how),
+        ))
+    }
+
+    /// Run the query and validate the result against `S`
+    pub fn collect(self) -> Result<TypedFrame<S>> {
+        TypedFrame::new(self.lf.collect()?)
+    }
+
+    /// Run the query, validating the result only if `DEBUG_CHECKS` is set
+    pub fn debug_collect(self) -> Result<TypedFrame<S>> {
+        TypedFrame::debug_new(self.lf.collect()?)
+    }
+}
@@ -0,0 +1,377 @@
+//! Schema checks shared by the generated `validate` functions, and the
+//! options of the `validate_*_with` variants
+
+use std::borrow::Cow;
+
+use polars::prelude::*;
+
+use crate::dtype::dtype_eq_ignoring_time_unit;
+use crate::suggest::missing_column;
+use crate::{ColumnCast, MigrationReport, Result, ValidationError};
+
+/// Whether the debug-only checks run: `debug_validate`,
+/// `TypedFrame::debug_new` and pipelines with `Checks::DebugOnly`
+///
+/// Set in builds with debug assertions, and in all builds with the
+/// `release-checks` feature.
+pub const DEBUG_CHECKS: bool = cfg!(any(debug_assertions, feature = "release-checks"));
+
+/// Options for the `validate_*_with` functions
+///
+/// ```ignore
+/// let opts = ValidateOptions::default().sample_rows(100_000).seed(7);
+/// let report = User::validate_values_with(&df, &opts)?;
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValidateOptions {
+    /// Run value-level checks on a random sample of at most this many rows
+    /// instead of the whole frame
+    pub sample_rows: Option<usize>,
+    /// Seed of the sample, for reproducible runs
+    pub seed: Option<u64>,
+    /// Match column names ignoring case, e.g. `User_ID` as `user_id`
+    pub case_insensitive: bool,
+    /// Match column names ignoring leading and trailing whitespace
+    pub trim_whitespace: bool,
+    /// Match column names written in another naming convention, e.g.
+    /// `userId`, `UserId`, `USER_ID` or `user-id` as `user_id`
+    pub ignore_naming_convention: bool,
+    /// Accept columns of a narrower dtype whose values all fit the declared
+    /// one, e.g. `Int32` for `Int64` or `Float32` for `Float64`; `conform_with`
+    /// casts them to the declared dtype
+    pub allow_widening: bool,
+    /// Accept frames missing the columns of `Option` fields
+    pub allow_missing_optional: bool,
+}
+
+impl ValidateOptions {
+    /// Check a random sample of at most `n` rows
+    pub fn sample_rows(mut self, n: usize) -> Self {
+        self.sample_rows = Some(n);
+        self
+    }
+
+    /// Seed the sampling
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Match column names ignoring case
+    pub fn case_insensitive(mut self) -> Self {
+        self.case_insensitive = true;
+        self
+    }
+
+    /// Match column names ignoring surrounding whitespace
+    pub fn trim_whitespace(mut self) -> Self {
+        self.trim_whitespace = true;
+        self
+    }
+
+    /// Match column names ignoring their naming convention
+    pub fn ignore_naming_convention(mut self) -> Self {
+        self.ignore_naming_convention = true;
+        self
+    }
+
+    /// Accept narrower numeric dtypes that widen losslessly to the declared
+    /// one
+    pub fn allow_widening(mut self) -> Self {
+        self.allow_widening = true;
+        self
+    }
+
+    /// Accept frames without the columns of `Option` fields
+    pub fn allow_missing_optional(mut self) -> Self {
+        self.allow_missing_optional = true;
+        self
+    }
+
+    /// Whether column names are matched other than exactly
+    pub(crate) fn normalizes(&self) -> bool {
+        self.case_insensitive || self.trim_whitespace || self.ignore_naming_convention
+    }
+
+    /// `name` as compared under `case_insensitive`, `trim_whitespace` and
+    /// `ignore_naming_convention`
+    pub(crate) fn normalize(&self, name: &str) -> String {
+        if self.ignore_naming_convention {
+            return crate::naming::to_snake_case(name);
+        }
+        let name = if self.trim_whitespace {
+            name.trim()
+        } else {
+            name
+        };
+        if self.case_insensitive {
+            name.to_lowercase()
+        } else {
+            name.to_string()
+        }
+    }
+
+    /// `df` itself, or the sample of it selected by `sample_rows`
+    pub(crate) fn sample(&self, df: &DataFrame) -> Result<DataFrame> {
+        match self.sample_rows {
+            Some(n) if n < df.height() => Ok(df.sample_n_literal(n, false, false, self.seed)?),
+            _ => Ok(df.clone()),
+        }
+    }
+}
+
+/// Check `df` against the expected schema in a single pass over its fields,
+/// reporting the first missing column or dtype mismatch in schema order
+///
+/// Columns listed in `any_time_unit` accept `Datetime`/`Duration` dtypes of
+/// any time unit.
+#[doc(hidden)]
+pub fn validate_schema(df: &DataFrame, expected: &Schema, any_time_unit: &[&str]) -> Result<()> {
+    validate_schema_with(df, expected, any_time_unit, &[], &ValidateOptions::default())
+}
+
+/// `validate_schema`, also accepting the narrower dtypes of `allow_widening`
+/// and, under `allow_missing_optional`, missing columns that are `nullable`
+/// (one flag per column of `expected`)
+#[doc(hidden)]
+pub fn validate_schema_with(
+    df: &DataFrame,
+    expected: &Schema,
+    any_time_unit: &[&str],
+    nullable: &[bool],
+    opts: &ValidateOptions,
+) -> Result<()> {
+    let actual = df.schema();
+
+    for (i, (name, expected_type)) in expected.iter().enumerate() {
+        let Some(actual_type) = actual.get(name) else {
+            if opts.allow_missing_optional && nullable.get(i) == Some(&true) {
+                continue;
+            }
+            let unexpected = actual.iter_names().filter(|name| !expected.contains(name));
+            return Err(missing_column(name, unexpected.map(|name| name.as_str())));
+        };
+
+        let matches = if any_time_unit.contains(&name.as_str()) {
+            dtype_eq_ignoring_time_unit(actual_type, expected_type)
+        } else {
+            actual_type == expected_type
+                || (opts.allow_widening && widens_to(actual_type, expected_type))
+        };
+        if !matches {
+            return Err(ValidationError::TypeMismatch {
+                column_name: name.to_string(),
+                actual_type: format!("{:?}", actual_type),
+                expected_type: format!("{:?}", expected_type),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Signedness and bit width of an integer dtype
+fn integer_width(dtype: &DataType) -> Option<(bool, u8)> {
+    match dtype {
+        DataType::Int8 => Some((true, 8)),
+        DataType::Int16 => Some((true, 16)),
+        DataType::Int32 => Some((true, 32)),
+        DataType::Int64 => Some((true, 64)),
+        DataType::UInt8 => Some((false, 8)),
+        DataType::UInt16 => Some((false, 16)),
+        DataType::UInt32 => Some((false, 32)),
+        DataType::UInt64 => Some((false, 64)),
+        _ => None,
+    }
+}
+
+/// Whether every value of dtype `from` is exactly representable in `to`, a
+/// wider dtype of the same kind: `Int32` in `Int64`, `UInt8` in `UInt16` or
+/// `Int16`, `Float32` in `Float64`
+fn widens_to(from: &DataType, to: &DataType) -> bool {
+    match (integer_width(from), integer_width(to)) {
+        (Some((from_signed, from_bits)), Some((to_signed, to_bits))) => {
+            from_bits < to_bits && (from_signed == to_signed || to_signed)
+        }
+        _ => matches!((from, to), (DataType::Float32, DataType::Float64)),
+    }
+}
+
+/// `df`, valid under `opts`, brought to the `expected` dtypes: columns of a
+/// narrower dtype accepted by `allow_widening` are cast, and the `nullable`
+/// columns missing under `allow_missing_optional` are added as nulls, each
+/// change recorded in `report`
+#[doc(hidden)]
+pub fn conform_frame(
+    mut df: DataFrame,
+    expected: &Schema,
+    nullable: &[bool],
+    opts: &ValidateOptions,
+    report: &mut MigrationReport,
+) -> Result<DataFrame> {
+    let actual = df.schema().clone();
+    let mut casts = Vec::new();
+    for (i, (name, expected_type)) in expected.iter().enumerate() {
+        match actual.get(name) {
+            Some(actual_type) if opts.allow_widening && widens_to(actual_type, expected_type) => {
+                report.cast.push(ColumnCast {
+                    column: name.to_string(),
+                    from: actual_type.clone(),
+                    to: expected_type.clone(),
+                });
+                casts.push(col(name.clone()).cast(expected_type.clone()));
+            }
+            None if opts.allow_missing_optional && nullable.get(i) == Some(&true) => {
+                report.added.push(name.to_string());
+                df.with_column(Column::full_null(
+                    name.clone(),
+                    df.height(),
+                    expected_type,
+                ))?;
+            }
+            _ => {}
+        }
+    }
+    if casts.is_empty() {
+        return Ok(df);
+    }
+    Ok(df.lazy().with_columns(casts).collect()?)
+}
+
+/// `df` with every expected column missing from it replaced by a stand-in,
+/// renamed to the column, and a report of the renames
+///
+/// Stand-ins are the column's `(alias, column)` aliases and, with
+/// `case_insensitive`, `trim_whitespace` or `ignore_naming_convention`,
+/// columns whose normalized name equals that of the column or an alias. Columns of the schema are never
+/// used as stand-ins.
+#[doc(hidden)]
+pub fn match_columns<'a>(
+    df: &'a DataFrame,
+    expected: &Schema,
+    aliases: &[(&str, &str)],
+    opts: &ValidateOptions,
+) -> Result<(Cow<'a, DataFrame>, MigrationReport)> {
+    let actual = df.schema();
+    let mut renamed: Vec<(String, String)> = Vec::new();
+    for column in expected.iter_names() {
+        if actual.contains(column) {
+            continue;
+        }
+        let names: Vec<&str> = std::iter::once(column.as_str())
+            .chain(
+                aliases
+                    .iter()
+                    .filter(|(_, aliased)| aliased == column)
+                    .map(|(alias, _)| *alias),
+            )
+            .collect();
+        let available = |source: &&PlSmallStr| {
+            !expected.contains(source) && !renamed.iter().any(|(used, _)| used == source.as_str())
+        };
+        let exact = names.iter().find_map(|name| {
+            actual
+                .iter_names()
+                .filter(available)
+                .find(|source| source == name)
+        });
+        let normalized = || {
+            if !opts.normalizes() {
+                return None;
+            }
+            names.iter().find_map(|name| {
+                let name = opts.normalize(name);
+                actual
+                    .iter_names()
+                    .filter(available)
+                    .find(|source| opts.normalize(source) == name)
+            })
+        };
+        if let Some(source) = exact.or_else(normalized) {
+            renamed.push((source.to_string(), column.to_string()));
+        }
+    }
+    if renamed.is_empty() {
+        return Ok((Cow::Borrowed(df), MigrationReport::default()));
+    }
+
+    // Built anew, as `DataFrame::rename` keeps the cached schema
+    let columns = df
+        .get_columns()
+        .iter()
+        .map(|c| {
+            match renamed
+                .iter()
+                .find(|(source, _)| source == c.name().as_str())
+            {
+                Some((_, column)) => c.clone().with_name(column.into()),
+                None => c.clone(),
+            }
+        })
+        .collect();
+    let report = MigrationReport {
+        renamed,
+        ..Default::default()
+    };
+    Ok((Cow::Owned(DataFrame::new(columns)?), report))
+}
+
+/// Reject frames holding any of `columns` of the schema `schema`, or one of
+/// their `aliases`, matched as set in `opts`; the error names the columns found
+/// in frame order
+#[doc(hidden)]
+pub fn anti_validate(
+    df: &DataFrame,
+    schema: &str,
+    columns: &[&str],
+    aliases: &[(&str, &str)],
+    opts: &ValidateOptions,
+) -> Result<()> {
+    let forbidden: Vec<&str> = columns
+        .iter()
+        .copied()
+        .chain(aliases.iter().map(|(alias, _)| *alias))
+        .collect();
+    let normalized: Vec<String> = if opts.normalizes() {
+        forbidden.iter().map(|name| opts.normalize(name)).collect()
+    } else {
+        Vec::new()
+    };
+    let found: Vec<String> = df
+        .get_column_names()
+        .into_iter()
+        .filter(|name| {
+            forbidden.contains(&name.as_str())
+                || (opts.normalizes() && normalized.contains(&opts.normalize(name)))
+        })
+        .map(|name| name.to_string())
+        .collect();
+    if !found.is_empty() {
+        return Err(ValidationError::ForbiddenColumns {
+            schema: schema.to_string(),
+            columns: found,
+        });
+    }
+    Ok(())
+}
+
+/// Reject frames whose column set differs from the schema's
+#[doc(hidden)]
+pub fn validate_column_set(df: &DataFrame, expected: &Schema) -> Result<()> {
+    let extra = df
+        .get_column_names()
+        .into_iter()
+        .any(|name| !expected.contains(name));
+    if df.width() != expected.len() || extra {
+        return Err(ValidationError::ColumnCountMismatch {
+            expected: expected.iter_names().map(|s| s.to_string()).collect(),
+            actual: df
+                .get_column_names()
+                .into_iter()
+                .map(|s| s.to_string())
+                .collect(),
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+//! Schema versions declared with `#[polars(since = "2")]` and
+//! `#[polars(deprecated)]`, used by the generated `*_version` functions
+//!
+//! Versions start at 1. A field without `since` exists from version 1, and a
+//! field with `deprecated = "N"` is no longer required from version N on. A
+//! bare `deprecated` field is required by no version but still read when the
+//! column is there.
+
+use polars::prelude::*;
+
+use crate::migrate::missing_column;
+use crate::validate::validate_schema;
+use crate::Result;
+
+/// Version range of a generated schema column
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy)]
+pub struct VersionedColumn {
+    pub name: &'static str,
+    pub since: u32,
+    /// First version without the column, `Some(0)` for bare `deprecated`
+    pub until: Option<u32>,
+}
+
+impl VersionedColumn {
+    /// Whether frames of `version` must have this column
+    pub fn required_in(&self, version: u32) -> bool {
+        self.since <= version && self.until.is_none_or(|until| version < until)
+    }
+}
+
+/// Columns required by `version`, in field order
+#[doc(hidden)]
+pub fn columns_for_version(columns: &[VersionedColumn], version: u32) -> Vec<&'static str> {
+    columns
+        .iter()
+        .filter(|column| column.required_in(version))
+        .map(|column| column.name)
+        .collect()
+}
+
+/// The expected schema restricted to the columns of `version`
+fn version_schema(expected: &Schema, columns: &[VersionedColumn], version: u32) -> Schema {
+    let required = columns_for_version(columns, version);
+    expected
+        .iter()
+        .filter(|(name, _)| required.contains(&name.as_str()))
+        .map(|(name, dtype)| Field::new(name.clone(), dtype.clone()))
+        .collect()
+}
+
+/// Check `df` against the columns of `version` only
+#[doc(hidden)]
+pub fn validate_version(
+    df: &DataFrame,
+    expected: &Schema,
+    columns: &[VersionedColumn],
+    version: u32,
+    any_time_unit: &[&str],
+) -> Result<()> {
+    validate_schema(
+        df,
+        &version_schema(expected, columns, version),
+        any_time_unit,
+    )
+}
+
+/// Add the columns of `version` missing from `df`, filled with their default
+/// or with nulls of the declared dtype
+#[doc(hidden)]
+pub fn migrate_to_version(
+    df: &DataFrame,
+    expected: &Schema,
+    columns: &[VersionedColumn],
+    version: u32,
+    defaults: &[(&str, Expr)],
+) -> Result<DataFrame> {
+    let mut df = df.clone();
+    for (name, dtype) in version_schema(expected, columns, version).iter() {
+        if !df.schema().contains(name) {
+            df.with_column(missing_column(name, dtype, df.height(), defaults)?)?;
+        }
+    }
+    Ok(df)
+}
@@ -0,0 +1,75 @@
+//! Rolling and dynamic-window helpers of schemas with a
+//! `#[polars(time_index)]` column
+
+use polars::prelude::*;
+
+use crate::Result;
+
+/// Signature of `Expr::rolling_mean_by` and its siblings
+type RollingBy = fn(Expr, Expr, RollingOptionsDynamicWindow) -> Expr;
+
+/// Window of the `window` duration string (`"7d"`, `"1h30m"`, ...), closed on
+/// the right and computed from the first row on, as in Polars
+fn rolling_options(window: &str) -> Result<RollingOptionsDynamicWindow> {
+    Ok(RollingOptionsDynamicWindow {
+        window_size: Duration::try_parse(window)?,
+        min_periods: 1,
+        closed_window: ClosedWindow::Right,
+        fn_params: None,
+    })
+}
+
+/// `agg` of `column` over the `window` before each row, by the `time` column
+#[doc(hidden)]
+pub fn rolling_by(column: &str, time: &str, window: &str, agg: RollingBy) -> Result<Expr> {
+    Ok(agg(col(column), col(time), rolling_options(window)?))
+}
+
+/// `agg` over `window` of every numeric column other than `time`, aliasing
+/// each result as `<column>_rolling_<suffix>`
+#[doc(hidden)]
+pub fn numeric_rolling(
+    columns: &[&str],
+    dtypes: &[DataType],
+    time: &str,
+    window: &str,
+    suffix: &str,
+    agg: RollingBy,
+) -> Result<Vec<Expr>> {
+    let options = rolling_options(window)?;
+    Ok(columns
+        .iter()
+        .zip(dtypes)
+        .filter(|(column, dtype)| **column != time && dtype.is_primitive_numeric())
+        .map(|(column, _)| {
+            agg(col(*column), col(time), options.clone())
+                .alias(format!("{}_rolling_{}", column, suffix))
+        })
+        .collect())
+}
+
+/// `lf` sorted by `time` and grouped into consecutive windows of the `every`
+/// duration, also by the `by` columns
+#[doc(hidden)]
+pub fn group_by_every(lf: LazyFrame, time: &str, by: Vec<Expr>, every: &str) -> Result<LazyGroupBy> {
+    let every = Duration::try_parse(every)?;
+    let options = DynamicGroupOptions {
+        every,
+        period: every,
+        offset: Duration::try_parse("0ns")?,
+        ..Default::default()
+    };
+    Ok(group_by_dynamic(lf, time, by, options))
+}
+
+/// `lf` sorted by `time` and grouped by the windows of `options` over `time`, also by the `by` columns
+#[doc(hidden)]
+pub fn group_by_dynamic(
+    lf: LazyFrame,
+    time: &str,
+    by: Vec<Expr>,
+    options: DynamicGroupOptions,
+) -> LazyGroupBy {
+    lf.sort([time], Default::default())
+        .group_by_dynamic(col(time), by, options)
+}
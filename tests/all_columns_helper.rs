@@ -204,3 +204,70 @@ fn test_all_columns_real_world_example() {
     assert_eq!(summary.height(), 1); // Only Jane Smith has > 5 orders
     assert_eq!(summary.width(), 3);
 }
+
+#[test]
+fn test_const_column_metadata() {
+    const NAMES: &[&str] = TestSchema::COLUMNS;
+    const WIDTH: usize = TestSchema::WIDTH;
+    let widths: [usize; ColumnOnlySchema::WIDTH] = [0; 3];
+
+    assert_eq!(NAMES, TestSchema::all_columns().as_slice());
+    assert_eq!(WIDTH, 5);
+    assert_eq!(widths.len(), 3);
+    assert_eq!(ColumnOnlySchema::COLUMNS, ["product_id", "product_name", "price"]);
+
+    let dtypes: Vec<DataType> = TestSchema::DTYPES.iter().map(|d| d.dtype()).collect();
+    assert_eq!(dtypes, TestSchema::all_types());
+    assert_eq!(ColumnOnlySchema::DTYPES[2], DataType::Float64);
+}
+
+#[test]
+fn test_field_index_constants() {
+    assert_eq!(TestSchema::user_id_idx, 0);
+    assert_eq!(TestSchema::is_active_idx, 4);
+    assert_eq!(ColumnOnlySchema::price_idx, 2);
+
+    assert_eq!(TestSchema::field_index(TestSchema::age), Some(TestSchema::age_idx));
+    assert_eq!(TestSchema::field_index("missing"), None);
+
+    let df = df![
+        "user_id" => [7i64],
+        "username" => ["alice"],
+        "email" => ["alice@test.com"],
+        "age" => [25],
+        "is_active" => [true],
+    ]
+    .unwrap();
+    let row = df.get_row(0).unwrap();
+    assert_eq!(row.0[TestSchema::username_idx], AnyValue::String("alice"));
+    assert_eq!(TestSchema::COLUMNS[TestSchema::age_idx], TestSchema::age);
+}
+
+#[test]
+fn test_all_columns_except() {
+    assert_eq!(
+        TestSchema::all_columns_except(&[TestSchema::email, TestSchema::age]),
+        ["user_id", "username", "is_active"]
+    );
+    assert_eq!(TestSchema::all_columns_except(&[]), TestSchema::all_columns());
+    assert_eq!(
+        ColumnOnlySchema::all_cols_except(&[ColumnOnlySchema::price]),
+        [col("product_id"), col("product_name")]
+    );
+
+    let df = df![
+        "user_id" => [1i64],
+        "username" => ["alice"],
+        "email" => ["alice@test.com"],
+        "age" => [25],
+        "is_active" => [true],
+    ]
+    .unwrap();
+    let public = df
+        .lazy()
+        .select(TestSchema::all_cols_except(&[TestSchema::email]))
+        .collect()
+        .unwrap();
+    assert_eq!(public.width(), 4);
+    assert!(public.column("email").is_err());
+}
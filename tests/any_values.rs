@@ -0,0 +1,85 @@
+use polars_tools::*;
+
+#[derive(Debug, Clone, PartialEq, PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Trade {
+    id: i64,
+    symbol: String,
+    qty: i32,
+    price: Option<f64>,
+    tags: Vec<String>,
+}
+
+fn trades() -> Vec<Trade> {
+    vec![
+        Trade {
+            id: 1,
+            symbol: "ACME".to_string(),
+            qty: 10,
+            price: Some(9.5),
+            tags: vec!["open".to_string()],
+        },
+        Trade {
+            id: 2,
+            symbol: "INIT".to_string(),
+            qty: -4,
+            price: None,
+            tags: vec![],
+        },
+    ]
+}
+
+#[test]
+fn test_rows_of_get_row_round_trip() {
+    let rows = trades();
+    let df = Trade::to_df(&rows).unwrap();
+    for (index, trade) in rows.iter().enumerate() {
+        let row = df.get_row(index).unwrap();
+        assert_eq!(&Trade::from_any_values(&row.0).unwrap(), trade);
+        assert_eq!(trade.to_any_values(), row.0);
+    }
+}
+
+#[test]
+fn test_values_are_in_schema_dtypes() {
+    let values = trades()[1].to_any_values();
+    assert_eq!(values[0], AnyValue::Int64(2));
+    assert_eq!(values[2], AnyValue::Int32(-4));
+    assert_eq!(values[3], AnyValue::Null);
+}
+
+#[test]
+fn test_mismatched_value_names_its_position() {
+    let mut values = trades()[0].to_any_values();
+    values[2] = AnyValue::Int64(10);
+    let err = Trade::from_any_values(&values).unwrap_err();
+    assert_eq!(err.code(), "PT0024");
+    assert_eq!(
+        err.to_string(),
+        "Row value 2: Column 'qty' has type \"Int64\", expected \"Int32\""
+    );
+    let ValidationError::RowValue { position, source } = err else {
+        panic!("expected RowValue");
+    };
+    assert_eq!(position, 2);
+    assert!(matches!(*source, ValidationError::TypeMismatch { .. }));
+
+    values[2] = AnyValue::Int32(10);
+    values[1] = AnyValue::Null;
+    let err = Trade::from_any_values(&values).unwrap_err();
+    assert!(matches!(err, ValidationError::RowValue { position: 1, .. }));
+}
+
+#[test]
+fn test_row_of_the_wrong_width() {
+    let values = trades()[0].to_any_values();
+    let err = Trade::from_any_values(&values[..4]).unwrap_err();
+    assert!(matches!(
+        err,
+        ValidationError::RowWidthMismatch {
+            expected: 5,
+            actual: 4
+        }
+    ));
+    assert_eq!(err.to_string(), "Row has 4 values, expected 5");
+}
@@ -0,0 +1,82 @@
+#![allow(non_upper_case_globals)]
+use polars_tools::*;
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Product {
+    sku: String,
+    price: f64,
+    stock: i32,
+}
+
+fn products() -> DataFrame {
+    df![
+        "sku" => ["a", "b", "c"],
+        "price" => [1.5, 2.0, 3.25],
+        "stock" => [10i32, 0, 7],
+    ]
+    .unwrap()
+}
+
+#[test]
+fn test_assert_valid_passes() {
+    assert_valid!(Product, products());
+    assert_df_matches_schema!(Product, products());
+}
+
+#[test]
+#[should_panic(expected = "column `price`: expected f64, found str")]
+fn test_assert_valid_reports_dtype_difference() {
+    let df = df![
+        "sku" => ["a"],
+        "price" => ["1.5"],
+        "stock" => [1i32],
+    ]
+    .unwrap();
+    assert_valid!(Product, df);
+}
+
+#[test]
+fn test_schema_differences_lists_every_problem() {
+    let df = df!["price" => [1i32]].unwrap();
+    let report = assertions::schema_differences(
+        &df,
+        &Product::all_columns(),
+        &Product::all_types(),
+    )
+    .unwrap();
+
+    assert!(report.contains("missing column `sku`"));
+    assert!(report.contains("column `price`: expected f64, found i32"));
+    assert!(report.contains("missing column `stock`"));
+}
+
+#[test]
+fn test_assert_df_eq_ignores_extra_columns() {
+    let mut right = products();
+    right
+        .with_column(Series::new("note".into(), ["x", "y", "z"]))
+        .unwrap();
+
+    assert_df_eq_schema_subset!(Product, products(), right);
+}
+
+#[test]
+#[should_panic(expected = "row 1, column `stock`: left 0, right 5")]
+fn test_assert_df_eq_reports_first_differences() {
+    let right = Product::with_stock(products(), Series::new("stock".into(), [10i32, 5, 7])).unwrap();
+    assert_df_eq_schema_subset!(Product, products(), right);
+}
+
+#[test]
+fn test_frame_differences_row_count() {
+    let report = assertions::frame_differences(
+        &products(),
+        &products().head(Some(2)),
+        &Product::all_columns(),
+        &Product::all_types(),
+    )
+    .unwrap();
+
+    assert!(report.contains("row count: left has 3, right has 2"));
+}
@@ -0,0 +1,140 @@
+#![allow(non_upper_case_globals)]
+
+#[cfg(feature = "avro")]
+mod avro_io {
+    use polars_tools::*;
+    use serde_json::{json, Value};
+
+    #[derive(PolarsSchema)]
+    #[allow(dead_code, non_upper_case_globals)]
+    struct Click {
+        id: i64,
+        page: Option<String>,
+        weight: f32,
+        #[polars(dtype = DataType::Date)]
+        day: i32,
+        #[polars(dtype = DataType::Datetime(TimeUnit::Microseconds, None))]
+        at: Option<i64>,
+        tags: Vec<String>,
+    }
+
+    #[derive(PolarsSchema)]
+    #[allow(dead_code, non_upper_case_globals)]
+    struct Pair {
+        id: i64,
+        name: Option<String>,
+    }
+
+    fn clicks() -> DataFrame {
+        df![
+            "id" => [1i64, -300],
+            "page" => [Some("/home"), None],
+            "weight" => [0.5f32, 2.0],
+            "day" => [19_724i32, 19_725],
+            "at" => [Some(1_704_189_000_000_000i64), None],
+            "tags" => [Series::new("".into(), ["a", "b"]), Series::new_empty("".into(), &DataType::String)],
+        ]
+        .unwrap()
+        .lazy()
+        .with_columns([
+            col("day").cast(DataType::Date),
+            col("at").cast(DataType::Datetime(TimeUnit::Microseconds, None)),
+        ])
+        .collect()
+        .unwrap()
+    }
+
+    #[test]
+    fn test_avro_schema() {
+        let schema: Value = serde_json::from_str(&Click::avro_schema().unwrap()).unwrap();
+        assert_eq!(
+            schema,
+            json!({
+                "type": "record",
+                "name": "Click",
+                "fields": [
+                    {"name": "id", "type": "long"},
+                    {"name": "page", "type": ["null", "string"]},
+                    {"name": "weight", "type": "float"},
+                    {"name": "day", "type": {"type": "int", "logicalType": "date"}},
+                    {"name": "at", "type": ["null", {"type": "long", "logicalType": "timestamp-micros"}]},
+                    {"name": "tags", "type": {"type": "array", "items": ["null", "string"]}},
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let df = clicks();
+        let mut buf = Vec::new();
+        Click::write_avro(&mut buf, &df).unwrap();
+        let read = Click::read_avro(buf.as_slice()).unwrap();
+        assert!(read.equals_missing(&df));
+
+        let mut empty = Vec::new();
+        Click::write_avro(&mut empty, &Click::df().unwrap()).unwrap();
+        assert_eq!(Click::read_avro(empty.as_slice()).unwrap().height(), 0);
+    }
+
+    #[test]
+    fn test_binary_encoding() {
+        let df = df!["id" => [1i64], "name" => [Some("foo")]].unwrap();
+        let mut buf = Vec::new();
+        Pair::write_avro(&mut buf, &df).unwrap();
+        assert!(buf.starts_with(b"Obj\x01"));
+        // one row of 6 bytes: long 1, union branch 1, string "foo"
+        let block = &buf[buf.len() - 16 - 8..buf.len() - 16];
+        assert_eq!(block, &[0x02, 0x0c, 0x02, 0x02, 0x06, b'f', b'o', b'o']);
+    }
+
+    #[test]
+    fn test_schema_checked() {
+        let mut buf = Vec::new();
+        Click::write_avro(&mut buf, &clicks()).unwrap();
+        assert!(matches!(
+            Pair::read_avro(buf.as_slice()),
+            Err(ValidationError::Avro(_))
+        ));
+        assert!(matches!(
+            Click::read_avro(&b"not avro"[..]),
+            Err(ValidationError::Avro(_))
+        ));
+
+        let missing = df!["id" => [1i64]].unwrap();
+        assert!(matches!(
+            Pair::write_avro(&mut Vec::new(), &missing),
+            Err(ValidationError::MissingColumn { .. })
+        ));
+        let null_id = df!["id" => [None::<i64>], "name" => [Some("x")]].unwrap();
+        assert!(matches!(
+            Pair::write_avro(&mut Vec::new(), &null_id),
+            Err(ValidationError::InvalidValue { .. })
+        ));
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn test_decimal_round_trip() {
+        use rust_decimal::Decimal;
+
+        #[derive(PolarsSchema)]
+        #[allow(dead_code, non_upper_case_globals)]
+        struct Payment {
+            #[polars(precision = 18, scale = 2)]
+            amount: Decimal,
+        }
+
+        let df = df!["amount" => [1234i64, -5, 0]]
+            .unwrap()
+            .lazy()
+            .with_column(col("amount").cast(DataType::Decimal(Some(18), Some(2))))
+            .collect()
+            .unwrap();
+        let mut buf = Vec::new();
+        Payment::write_avro(&mut buf, &df).unwrap();
+        assert!(Payment::read_avro(buf.as_slice())
+            .unwrap()
+            .equals_missing(&df));
+    }
+}
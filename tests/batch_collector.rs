@@ -0,0 +1,96 @@
+use std::time::Duration;
+
+use polars_tools::stream::BatchCollector;
+use polars_tools::*;
+
+#[derive(Clone, Debug, PartialEq, PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Tick {
+    symbol: String,
+    price: f64,
+    size: Option<i64>,
+}
+
+fn tick(symbol: &str, price: f64) -> Tick {
+    Tick {
+        symbol: symbol.to_string(),
+        price,
+        size: None,
+    }
+}
+
+#[test]
+fn test_emits_every_max_rows() {
+    let mut batches = BatchCollector::<Tick>::new(2, Tick::to_df);
+
+    assert!(batches.push(tick("A", 1.0)).unwrap().is_none());
+    assert_eq!(batches.len(), 1);
+    let batch = batches.push(tick("B", 2.0)).unwrap().unwrap();
+    assert_eq!(batch.height(), 2);
+    assert!(batches.is_empty());
+
+    let emitted = batches.extend((0..5).map(|i| tick("C", i as f64))).unwrap();
+    assert_eq!(emitted.len(), 2);
+    assert_eq!(batches.len(), 1);
+    assert_eq!(batches.flush().unwrap().unwrap().height(), 1);
+    assert!(batches.flush().unwrap().is_none());
+}
+
+#[test]
+fn test_max_age() {
+    let mut batches = BatchCollector::<Tick>::new(100, Tick::to_df).max_age(Duration::ZERO);
+    assert_eq!(batches.time_until_flush(), None);
+    assert_eq!(batches.push(tick("A", 1.0)).unwrap().unwrap().height(), 1);
+
+    let mut batches =
+        BatchCollector::<Tick>::new(100, Tick::to_df).max_age(Duration::from_secs(3600));
+    assert!(batches.push(tick("A", 1.0)).unwrap().is_none());
+    assert!(batches.time_until_flush().unwrap() > Duration::from_secs(3500));
+    assert!(batches.poll().unwrap().is_none());
+    assert_eq!(batches.len(), 1);
+}
+
+#[test]
+fn test_batches_are_validated() {
+    fn wrong_dtype(rows: &[Tick]) -> Result<DataFrame> {
+        Ok(df!["symbol" => rows.iter().map(|r| r.symbol.clone()).collect::<Vec<_>>()]?)
+    }
+
+    let mut batches = BatchCollector::<Tick>::new(1, wrong_dtype);
+    assert!(matches!(
+        batches.push(tick("A", 1.0)),
+        Err(ValidationError::MissingColumn { .. })
+    ));
+    assert!(batches.is_empty());
+}
+
+#[cfg(feature = "serde")]
+mod json {
+    use super::*;
+
+    #[derive(PolarsSchema)]
+    #[allow(dead_code, non_upper_case_globals)]
+    struct Event {
+        id: i64,
+        #[polars(dtype = DataType::Date, format = "%d/%m/%Y")]
+        day: i32,
+    }
+
+    #[test]
+    fn test_json_payloads() {
+        let mut batches = BatchCollector::<Event, _>::json(2);
+        assert!(batches
+            .push_json(r#"{"id": 1, "day": "02/01/2024"}"#)
+            .unwrap()
+            .is_none());
+        let batch = batches
+            .push_json(r#"{"id": 2, "day": "03/01/2024", "extra": true}"#)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(batch.get_column_names(), ["id", "day"]);
+        assert_eq!(batch.column("day").unwrap().dtype(), &DataType::Date);
+        assert!(batches.push_json("not json").is_err());
+        assert!(batches.is_empty());
+    }
+}
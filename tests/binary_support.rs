@@ -0,0 +1,53 @@
+#![allow(non_upper_case_globals)]
+use polars_tools::*;
+
+#[derive(Debug, Clone)]
+#[allow(dead_code)]
+struct Digest([u8; 32]);
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Blob {
+    id: i64,
+    payload: Vec<u8>,
+    thumbnail: Option<Vec<u8>>,
+    #[polars(binary)]
+    digest: Digest,
+    sizes: Vec<u32>,
+}
+
+#[test]
+fn test_byte_buffers_map_to_binary() {
+    assert_eq!(Blob::payload_type, DataType::Binary);
+    assert_eq!(Blob::thumbnail_type, DataType::Binary);
+    assert_eq!(Blob::digest_type, DataType::Binary);
+    // Other vectors remain lists
+    assert_eq!(Blob::sizes_type, DataType::List(Box::new(DataType::UInt32)));
+}
+
+#[test]
+fn test_binary_empty_dataframe_and_validation() {
+    let empty_df = Blob::df().unwrap();
+    let schema = empty_df.schema();
+    assert_eq!(schema.get("payload"), Some(&DataType::Binary));
+    assert!(Blob::validate_strict(&empty_df).is_ok());
+
+    let df = DataFrame::new(vec![
+        Column::new("id".into(), [1i64]),
+        Column::new("payload".into(), [b"abc".as_slice()]),
+        Column::new("thumbnail".into(), [None::<&[u8]>]),
+        Column::new("digest".into(), [[0u8; 32].as_slice()]),
+        Column::new(
+            "sizes".into(),
+            Series::full_null("sizes".into(), 1, &Blob::sizes_type.dtype()),
+        ),
+    ])
+    .unwrap();
+    assert!(Blob::validate(&df).is_ok());
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn test_bytes_maps_to_binary() {
+    assert_eq!(<bytes::Bytes as PolarsDType>::dtype(), DataType::Binary);
+}
@@ -0,0 +1,94 @@
+use std::borrow::Cow;
+
+use polars_tools::*;
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct LogLine<'a> {
+    level: &'a str,
+    message: Cow<'a, str>,
+    source: Option<&'a str>,
+    code: i32,
+}
+
+#[derive(PolarsColumns)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Token<'src> {
+    text: &'src str,
+    offset: u32,
+}
+
+fn parse(input: &str) -> Vec<LogLine<'_>> {
+    input
+        .lines()
+        .map(|line| {
+            let mut parts = line.splitn(3, ' ');
+            let level = parts.next().unwrap();
+            let code = parts.next().unwrap().parse().unwrap();
+            let message = parts.next().unwrap_or_default();
+            LogLine {
+                level,
+                message: if message.contains('\t') {
+                    Cow::Owned(message.replace('\t', " "))
+                } else {
+                    Cow::Borrowed(message)
+                },
+                source: (level == "ERROR").then_some("app"),
+                code,
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn test_borrowed_string_dtypes() {
+    assert_eq!(LogLine::level_type, DataType::String);
+    assert_eq!(LogLine::message_type, DataType::String);
+    assert_eq!(LogLine::source_type, DataType::String);
+    assert_eq!(Token::text_type, DataType::String);
+    assert_eq!(Token::column_names(), ["text", "offset"]);
+}
+
+#[test]
+fn test_borrowed_rows_to_df() {
+    let input = String::from("INFO 0 started\nERROR 2 disk\tfull");
+    let rows = parse(&input);
+    let df = LogLine::to_df(&rows).unwrap();
+
+    LogLine::validate(&df).unwrap();
+    assert_eq!(df.height(), 2);
+    let messages: Vec<_> = df.column("message").unwrap().str().unwrap().into_iter().collect();
+    assert_eq!(messages, [Some("started"), Some("disk full")]);
+    let sources: Vec<_> = df.column("source").unwrap().str().unwrap().into_iter().collect();
+    assert_eq!(sources, [None, Some("app")]);
+}
+
+#[test]
+fn test_borrowed_filters() {
+    let rows = parse("INFO 0 started\nERROR 2 disk full");
+    let errors = LogLine::to_df(&rows)
+        .unwrap()
+        .lazy()
+        .filter(LogLine::filter().level().eq("ERROR").build())
+        .collect()
+        .unwrap();
+    assert_eq!(errors.height(), 1);
+}
+
+#[test]
+fn test_strict_types_accept_borrowed_strings() {
+    #[derive(PolarsSchema)]
+    #[polars(strict_types)]
+    #[allow(dead_code, non_upper_case_globals)]
+    struct Tag<'a> {
+        name: &'a str,
+        label: Option<Cow<'a, str>>,
+    }
+
+    assert_eq!(Tag::label_type, DataType::String);
+    let rows = [Tag {
+        name: "a",
+        label: None,
+    }];
+    Tag::validate(&Tag::to_df(&rows).unwrap()).unwrap();
+}
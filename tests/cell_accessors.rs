@@ -0,0 +1,98 @@
+#![allow(non_upper_case_globals)]
+use polars_tools::*;
+
+#[derive(Debug, Clone, PartialEq, PolarsNewtype)]
+struct AccountId(i64);
+
+#[derive(Debug, Clone, PartialEq)]
+enum Tier {
+    Free,
+    Pro,
+}
+
+impl ValidatableEnum for Tier {
+    fn valid_values() -> Vec<&'static str> {
+        vec!["Free", "Pro"]
+    }
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "Free" => Ok(Tier::Free),
+            "Pro" => Ok(Tier::Pro),
+            _ => Err(ValidationError::InvalidEnumValue {
+                field: "Tier".to_string(),
+                value: value.to_string(),
+                valid_values: Self::valid_values().into_iter().map(|s| s.to_string()).collect(),
+            }),
+        }
+    }
+
+    fn to_str(&self) -> &'static str {
+        match self {
+            Tier::Free => "Free",
+            Tier::Pro => "Pro",
+        }
+    }
+}
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Account {
+    id: AccountId,
+    name: String,
+    age: Option<i32>,
+    tier: Tier,
+}
+
+fn accounts() -> DataFrame {
+    df![
+        "id" => [10i64, 11],
+        "name" => ["Alice", "Bob"],
+        "age" => [Some(30i32), None],
+        "tier" => ["Pro", "Gold"],
+    ]
+    .unwrap()
+}
+
+#[test]
+fn test_cell_accessors_read_values() {
+    let df = accounts();
+
+    assert_eq!(Account::name_at(&df, 1).unwrap(), Some("Bob".to_string()));
+    assert_eq!(Account::age_at(&df, 0).unwrap(), Some(30));
+    assert_eq!(Account::id_at(&df, 0).unwrap(), Some(AccountId(10)));
+    assert_eq!(Account::tier_at(&df, 0).unwrap(), Some(Tier::Pro));
+}
+
+#[test]
+fn test_cell_accessor_null_is_none() {
+    assert_eq!(Account::age_at(&accounts(), 1).unwrap(), None);
+}
+
+#[test]
+fn test_cell_accessor_out_of_bounds() {
+    match Account::name_at(&accounts(), 5) {
+        Err(ValidationError::RowOutOfBounds { row, height }) => {
+            assert_eq!(row, 5);
+            assert_eq!(height, 2);
+        }
+        other => panic!("Expected RowOutOfBounds, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_cell_accessor_invalid_enum_value() {
+    match Account::tier_at(&accounts(), 1) {
+        Err(ValidationError::InvalidEnumValue { value, .. }) => assert_eq!(value, "Gold"),
+        other => panic!("Expected InvalidEnumValue, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_cell_accessor_wrong_dtype() {
+    let df = df!["age" => ["thirty"]].unwrap();
+    assert!(matches!(
+        Account::age_at(&df, 0),
+        Err(ValidationError::TypeMismatch { .. })
+    ));
+}
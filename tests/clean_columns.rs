@@ -0,0 +1,124 @@
+use polars_tools::*;
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Patient {
+    #[polars(trim, uppercase)]
+    ward: String,
+    #[polars(trim, lowercase)]
+    email: Option<String>,
+    #[polars(clip(0, 120), min = 0, max = 120)]
+    age: i32,
+    #[polars(clip(min = 0.0))]
+    weight: Option<f64>,
+    #[polars(clip(max = 10))]
+    pain: i64,
+    name: String,
+}
+
+fn patients() -> DataFrame {
+    df![
+        "ward" => [" icu", "er "],
+        "email" => [Some(" Ada@X.io "), None],
+        "age" => [130i32, -1],
+        "weight" => [Some(-5.0), Some(70.5)],
+        "pain" => [12i64, 3],
+        "name" => [" Ada ", "Grace"],
+    ]
+    .unwrap()
+}
+
+#[test]
+fn test_clean() {
+    assert!(!Patient::validate_values(&patients()).unwrap().passed());
+
+    let df = Patient::clean(&patients()).unwrap();
+    assert!(Patient::validate_strict(&df).is_ok());
+    assert!(Patient::validate_values(&df).unwrap().passed());
+
+    let ward = df.column(Patient::ward).unwrap().str().unwrap();
+    assert_eq!(ward.into_iter().collect::<Vec<_>>(), [Some("ICU"), Some("ER")]);
+    let email = df.column(Patient::email).unwrap().str().unwrap();
+    assert_eq!(email.into_iter().collect::<Vec<_>>(), [Some("ada@x.io"), None]);
+    let age = df.column(Patient::age).unwrap().i32().unwrap();
+    assert_eq!(age.into_iter().collect::<Vec<_>>(), [Some(120), Some(0)]);
+    let weight = df.column(Patient::weight).unwrap().f64().unwrap();
+    assert_eq!(weight.into_iter().collect::<Vec<_>>(), [Some(0.0), Some(70.5)]);
+    let pain = df.column(Patient::pain).unwrap().i64().unwrap();
+    assert_eq!(pain.into_iter().collect::<Vec<_>>(), [Some(10), Some(3)]);
+    assert_eq!(df.column(Patient::name).unwrap().str().unwrap().get(0), Some(" Ada "));
+}
+
+#[test]
+fn test_clean_lazy() {
+    assert_eq!(Patient::clean_exprs().len(), 5);
+    let df = Patient::clean_lazy(patients().lazy())
+        .filter(Patient::expr.age().eq(lit(120)))
+        .collect()
+        .unwrap();
+    assert_eq!(df.height(), 1);
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Priority {
+    Low,
+    High,
+}
+
+impl ValidatableEnum for Priority {
+    fn valid_values() -> Vec<&'static str> {
+        vec!["low", "high"]
+    }
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "low" => Ok(Priority::Low),
+            "high" => Ok(Priority::High),
+            _ => Err(ValidationError::InvalidEnumValue {
+                field: "Priority".to_string(),
+                value: value.to_string(),
+                valid_values: Self::valid_values().into_iter().map(|s| s.to_string()).collect(),
+            }),
+        }
+    }
+
+    fn to_str(&self) -> &'static str {
+        match self {
+            Priority::Low => "low",
+            Priority::High => "high",
+        }
+    }
+}
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Ticket {
+    id: i64,
+    #[polars(strip, lowercase, map_values("hi" => "high", "lo" => "low"))]
+    priority: Priority,
+}
+
+fn tickets() -> DataFrame {
+    df![
+        "id" => [1i64, 2, 3, 4],
+        "priority" => ["HIGH", " high ", "High", "Lo"],
+    ]
+    .unwrap()
+}
+
+#[test]
+fn test_normalize_enum_values() {
+    assert!(!Ticket::validate_values(&tickets()).unwrap().passed());
+    assert_eq!(Ticket::normalize_exprs().len(), 1);
+
+    let df = Ticket::clean(&tickets()).unwrap();
+    assert!(Ticket::validate_values(&df).unwrap().passed());
+    let priority = df.column(Ticket::priority).unwrap().str().unwrap();
+    assert_eq!(
+        priority.into_iter().collect::<Vec<_>>(),
+        [Some("high"), Some("high"), Some("high"), Some("low")]
+    );
+
+    let (conformed, _) = Ticket::conform(&tickets()).unwrap();
+    assert!(conformed.equals(&df));
+}
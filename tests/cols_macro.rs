@@ -0,0 +1,45 @@
+use polars_tools::*;
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct User {
+    user_id: i64,
+    email: Option<String>,
+    age: i32,
+}
+
+mod models {
+    use polars_tools::*;
+
+    #[derive(PolarsColumns)]
+    #[allow(dead_code, non_upper_case_globals)]
+    pub struct Visit {
+        pub page: String,
+        pub seconds: f64,
+    }
+}
+
+fn users() -> DataFrame {
+    df![
+        "user_id" => [1i64, 2],
+        "email" => [Some("a@x.io"), None],
+        "age" => [30i32, 41],
+    ]
+    .unwrap()
+}
+
+#[test]
+fn test_cols_macro() {
+    assert_eq!(cols!(User: user_id, email), [col("user_id"), col("email")]);
+    assert_eq!(cols!(models::Visit: seconds,), [col("seconds")]);
+}
+
+#[test]
+fn test_cols_macro_select() {
+    let df = users()
+        .lazy()
+        .select(cols!(User: email, user_id))
+        .collect()
+        .unwrap();
+    assert_eq!(df.get_column_names(), ["email", "user_id"]);
+}
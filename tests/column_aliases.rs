@@ -0,0 +1,88 @@
+use polars_tools::*;
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Shipment {
+    id: i64,
+    #[polars(alias = "dest", alias = "destination_code")]
+    destination: String,
+    weight_kg: f64,
+}
+
+fn legacy() -> DataFrame {
+    df![
+        "id" => [1i64, 2],
+        "destination_code" => ["BER", "LIS"],
+        "weight_kg" => [1.5, 2.0],
+    ]
+    .unwrap()
+}
+
+#[test]
+fn test_validate_accepts_aliases() {
+    assert!(Shipment::validate(&legacy()).is_ok());
+    assert!(Shipment::validate_strict(&legacy()).is_ok());
+
+    let wrong_dtype = df![
+        "id" => [1i64],
+        "dest" => [7i32],
+        "weight_kg" => [1.0],
+    ]
+    .unwrap();
+    assert!(matches!(
+        Shipment::validate(&wrong_dtype),
+        Err(ValidationError::TypeMismatch { column_name, .. }) if column_name == "destination"
+    ));
+}
+
+#[test]
+fn test_conform_renames() {
+    let (df, report) = Shipment::conform(&legacy()).unwrap();
+
+    assert_eq!(df.get_column_names(), ["id", "destination", "weight_kg"]);
+    assert_eq!(
+        report.renamed,
+        [("destination_code".to_string(), "destination".to_string())]
+    );
+    assert_eq!(
+        report.to_string(),
+        "renamed destination_code -> destination"
+    );
+
+    let (_, report) = Shipment::conform(&df).unwrap();
+    assert!(report.is_empty());
+}
+
+#[test]
+fn test_canonical_name_wins() {
+    let both = legacy()
+        .hstack(&[Column::new("destination".into(), ["MAD", "OSL"])])
+        .unwrap();
+    let (df, report) = Shipment::conform(&both).unwrap();
+
+    assert!(report.is_empty());
+    assert_eq!(
+        df.column("destination").unwrap().str().unwrap().get(0),
+        Some("MAD")
+    );
+    assert!(matches!(
+        Shipment::validate_strict(&both),
+        Err(ValidationError::ColumnCountMismatch { .. })
+    ));
+}
+
+#[test]
+fn test_rows_and_accessors_read_aliases() {
+    let df = legacy();
+    let rows = Shipment::from_df(&df).unwrap();
+    assert_eq!(rows[1].destination, "LIS");
+
+    assert_eq!(
+        Shipment::destination_series(&df).unwrap().get(0),
+        Some("BER")
+    );
+    assert_eq!(
+        Shipment::destination_at(&df, 1).unwrap().as_deref(),
+        Some("LIS")
+    );
+}
@@ -0,0 +1,51 @@
+#![allow(non_upper_case_globals)]
+
+use polars_tools::*;
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Customer {
+    /// Surrogate key
+    id: i64,
+    /// Display name, as entered at sign-up; may be
+    /// changed by the customer later.
+    ///
+    /// Not unique.
+    name: String,
+    /// Ignored in favour of the attribute
+    #[polars(doc = "ISO 3166-1 alpha-2 country code")]
+    country: String,
+    balance: f64,
+}
+
+#[test]
+fn test_column_docs() {
+    assert_eq!(
+        Customer::column_docs(),
+        [
+            ("id", "Surrogate key"),
+            (
+                "name",
+                "Display name, as entered at sign-up; may be changed by the customer later.\n\n\
+                 Not unique."
+            ),
+            ("country", "ISO 3166-1 alpha-2 country code"),
+        ]
+    );
+}
+
+#[cfg(feature = "avro")]
+mod avro_docs {
+    use super::*;
+    use serde_json::Value;
+
+    #[test]
+    fn test_avro_field_docs() {
+        let schema: Value = serde_json::from_str(&Customer::avro_schema().unwrap()).unwrap();
+        let fields = schema["fields"].as_array().unwrap();
+
+        assert_eq!(fields[0]["doc"], "Surrogate key");
+        assert_eq!(fields[2]["doc"], "ISO 3166-1 alpha-2 country code");
+        assert!(fields[3].get("doc").is_none());
+    }
+}
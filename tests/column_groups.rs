@@ -0,0 +1,94 @@
+use polars_tools::*;
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Sale {
+    #[polars(group = "keys")]
+    store_id: i64,
+    #[polars(group = "keys", group = "dims")]
+    day: String,
+    #[polars(group = "measures")]
+    units: i64,
+    #[polars(group = "measures")]
+    revenue: f64,
+    note: Option<String>,
+}
+
+#[derive(PolarsColumns)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Visit {
+    #[polars(group = "keys")]
+    user_id: i64,
+    page: String,
+}
+
+fn sales() -> DataFrame {
+    df![
+        "store_id" => [1i64, 1, 2],
+        "day" => ["mon", "mon", "tue"],
+        "units" => [3i64, 4, 5],
+        "revenue" => [9.0, 12.0, 15.0],
+        "note" => [None::<&str>, None, Some("promo")],
+    ]
+    .unwrap()
+}
+
+#[test]
+fn test_group_columns() {
+    assert_eq!(Sale::keys_columns(), ["store_id", "day"]);
+    assert_eq!(Sale::dims_columns(), ["day"]);
+    assert_eq!(Sale::measures_columns(), ["units", "revenue"]);
+    assert_eq!(Visit::keys_columns(), ["user_id"]);
+}
+
+#[test]
+fn test_group_cols() {
+    let totals = sales()
+        .lazy()
+        .group_by(Sale::keys_cols())
+        .agg(
+            Sale::measures_cols()
+                .into_iter()
+                .map(|c| c.sum())
+                .collect::<Vec<_>>(),
+        )
+        .sort(Sale::keys_columns(), Default::default())
+        .collect()
+        .unwrap();
+
+    assert_eq!(
+        totals.get_column_names(),
+        ["store_id", "day", "units", "revenue"]
+    );
+    assert_eq!(totals.height(), 2);
+}
+
+#[test]
+fn test_cast_exprs() {
+    let raw = df![
+        "store_id" => [1i32, 2],
+        "day" => ["mon", "tue"],
+        "units" => [3i32, 4],
+        "revenue" => [9i64, 12],
+        "note" => [None::<&str>, None],
+    ]
+    .unwrap();
+    assert!(Sale::validate(&raw).is_err());
+
+    let df = raw
+        .clone()
+        .lazy()
+        .with_columns(Sale::cast_exprs())
+        .collect()
+        .unwrap();
+    assert!(Sale::validate_strict(&df).is_ok());
+
+    let df = raw
+        .lazy()
+        .with_columns(Sale::measures_cast_exprs())
+        .collect()
+        .unwrap();
+    assert_eq!(df.column(Sale::units).unwrap().dtype(), &DataType::Int64);
+    assert_eq!(df.column(Sale::revenue).unwrap().dtype(), &DataType::Float64);
+    assert_eq!(df.column(Sale::store_id).unwrap().dtype(), &DataType::Int32);
+}
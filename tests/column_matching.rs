@@ -0,0 +1,67 @@
+use polars_tools::*;
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct User {
+    user_id: i64,
+    #[polars(alias = "mail")]
+    email: String,
+}
+
+fn spreadsheet() -> DataFrame {
+    df![
+        "User_ID" => [1i64, 2],
+        " MAIL " => ["a@x.io", "b@x.io"],
+    ]
+    .unwrap()
+}
+
+#[test]
+fn test_exact_matching_by_default() {
+    assert!(matches!(
+        User::validate(&spreadsheet()),
+        Err(ValidationError::MissingColumn { column_name, .. }) if column_name == "user_id"
+    ));
+    let opts = ValidateOptions::default().case_insensitive();
+    assert!(matches!(
+        User::validate_with(&spreadsheet(), &opts),
+        Err(ValidationError::MissingColumn { column_name, .. }) if column_name == "email"
+    ));
+}
+
+#[test]
+fn test_conform_with_normalized_names() {
+    let opts = ValidateOptions::default()
+        .case_insensitive()
+        .trim_whitespace();
+    assert!(User::validate_with(&spreadsheet(), &opts).is_ok());
+
+    let (df, report) = User::conform_with(&spreadsheet(), &opts).unwrap();
+    assert_eq!(df.get_column_names(), ["user_id", "email"]);
+    assert_eq!(
+        report.renamed,
+        [
+            ("User_ID".to_string(), "user_id".to_string()),
+            (" MAIL ".to_string(), "email".to_string()),
+        ]
+    );
+    assert!(User::validate_strict(&df).is_ok());
+}
+
+#[test]
+fn test_schema_columns_are_not_stand_ins() {
+    let df = df![
+        "user_id" => [1i64],
+        "USER_ID" => [2i64],
+        "email" => ["a@x.io"],
+    ]
+    .unwrap();
+    let opts = ValidateOptions::default().case_insensitive();
+    let (conformed, report) = User::conform_with(&df, &opts).unwrap();
+
+    assert!(report.is_empty());
+    assert_eq!(
+        conformed.get_column_names(),
+        ["user_id", "USER_ID", "email"]
+    );
+}
@@ -0,0 +1,66 @@
+use polars_tools::*;
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Account {
+    account_id: i64,
+    http_status: i32,
+    #[polars(alias = "mail_address")]
+    email: String,
+    address2_line: Option<String>,
+}
+
+fn vendor() -> DataFrame {
+    df![
+        "AccountId" => [1i64, 2],
+        "HTTPStatus" => [200i32, 404],
+        "mail-address" => ["a@x.io", "b@x.io"],
+        "ADDRESS2_LINE" => [Some("Flat 2"), None],
+        "sourceSystem" => ["crm", "crm"],
+    ]
+    .unwrap()
+}
+
+#[test]
+fn test_rename_to_snake() {
+    let (df, report) = Account::rename_to_snake(&vendor()).unwrap();
+    assert_eq!(
+        df.get_column_names(),
+        ["account_id", "http_status", "email", "address2_line", "sourceSystem"]
+    );
+    assert_eq!(
+        report.renamed,
+        [
+            ("AccountId".to_string(), "account_id".to_string()),
+            ("HTTPStatus".to_string(), "http_status".to_string()),
+            ("mail-address".to_string(), "email".to_string()),
+            ("ADDRESS2_LINE".to_string(), "address2_line".to_string()),
+        ]
+    );
+    assert!(Account::validate(&df).is_ok());
+
+    let (same, report) = Account::rename_to_snake(&df).unwrap();
+    assert!(report.is_empty());
+    assert_eq!(same.get_column_names(), df.get_column_names());
+}
+
+#[test]
+fn test_validate_ignoring_naming_convention() {
+    assert!(Account::validate(&vendor()).is_err());
+    let opts = ValidateOptions::default().ignore_naming_convention();
+    assert!(Account::validate_with(&vendor(), &opts).is_ok());
+}
+
+#[test]
+fn test_rename_to_camel() {
+    let (snake, _) = Account::rename_to_snake(&vendor()).unwrap();
+    let (df, report) = Account::rename_to_camel(&snake).unwrap();
+    assert_eq!(
+        df.get_column_names(),
+        ["accountId", "httpStatus", "email", "address2Line", "sourceSystem"]
+    );
+    assert_eq!(report.renamed.len(), 3);
+
+    let (back, _) = Account::rename_to_snake(&df).unwrap();
+    assert!(Account::validate(&back).is_ok());
+}
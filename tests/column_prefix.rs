@@ -0,0 +1,86 @@
+use polars_tools::*;
+use serde::Serialize;
+
+#[derive(Debug, PartialEq, PolarsSchema)]
+#[polars(prefix = "user_")]
+#[allow(dead_code, non_upper_case_globals)]
+struct User {
+    id: i64,
+    #[polars(alias = "user_login")]
+    name: String,
+}
+
+#[derive(Debug, PartialEq, PolarsSchema)]
+#[polars(prefix = "org_")]
+#[allow(dead_code, non_upper_case_globals)]
+struct Org {
+    id: i64,
+    plan: Option<String>,
+}
+
+#[derive(Serialize, PolarsColumns)]
+#[polars(prefix = "acct_", use_serde_rename)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Account {
+    #[serde(rename = "accountId")]
+    account_id: i64,
+}
+
+fn wide() -> DataFrame {
+    df![
+        "user_id" => [1i64, 2],
+        "user_name" => ["ada", "bob"],
+        "org_id" => [10i64, 20],
+        "org_plan" => [Some("pro"), None],
+    ]
+    .unwrap()
+}
+
+#[test]
+fn test_prefix_names_columns_not_constants() {
+    assert_eq!(User::COLUMNS, ["user_id", "user_name"]);
+    assert_eq!(User::id, "user_id");
+    assert_eq!(User::expr.name(), col("user_name"));
+    assert_eq!(
+        Org::polars_schema().get("org_plan"),
+        Some(&DataType::String)
+    );
+    assert_eq!(Account::COLUMNS, ["acct_accountId"]);
+    assert_eq!(Account::account_id, "acct_accountId");
+}
+
+#[test]
+fn test_entities_of_one_wide_table() {
+    let df = wide();
+    User::validate(&df).unwrap();
+    Org::validate(&df).unwrap();
+
+    let users = User::from_df(&df).unwrap();
+    assert_eq!(
+        users[1],
+        User {
+            id: 2,
+            name: "bob".to_string()
+        }
+    );
+    let orgs = Org::from_df(&df).unwrap();
+    assert_eq!(orgs[0].plan.as_deref(), Some("pro"));
+
+    let orgs = df.select(Org::all_columns()).unwrap();
+    assert_eq!(orgs.get_column_names(), ["org_id", "org_plan"]);
+}
+
+#[test]
+fn test_aliases_are_not_prefixed() {
+    let legacy = df![
+        "user_id" => [1i64],
+        "user_login" => ["ada"],
+    ]
+    .unwrap();
+    let (df, report) = User::conform(&legacy).unwrap();
+    assert_eq!(df.get_column_names(), ["user_id", "user_name"]);
+    assert_eq!(
+        report.renamed,
+        [("user_login".to_string(), "user_name".to_string())]
+    );
+}
@@ -0,0 +1,62 @@
+#![allow(non_upper_case_globals)]
+use polars_tools::*;
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Person {
+    name: String,
+    age: i32,
+}
+
+fn people() -> DataFrame {
+    df![
+        "name" => ["Alice", "Bob"],
+        "age" => [30i32, 25],
+    ]
+    .unwrap()
+}
+
+#[test]
+fn test_with_column_replaces_values() {
+    let df = Person::with_age(people(), Series::new("ignored".into(), [31i32, 26])).unwrap();
+
+    assert_eq!(df.width(), 2);
+    assert_eq!(Person::age_at(&df, 0).unwrap(), Some(31));
+    assert!(Person::validate(&df).is_ok());
+}
+
+#[test]
+fn test_with_column_adds_missing_column() {
+    let df = df!["name" => ["Alice", "Bob"]].unwrap();
+    let df = Person::with_age(df, Series::new("age".into(), [30i32, 25])).unwrap();
+
+    assert_eq!(df.get_column_names(), &["name", "age"]);
+}
+
+#[test]
+fn test_with_column_rejects_wrong_dtype() {
+    match Person::with_age(people(), Series::new("age".into(), ["thirty", "twenty"])) {
+        Err(ValidationError::TypeMismatch {
+            column_name,
+            actual_type,
+            ..
+        }) => {
+            assert_eq!(column_name, "age");
+            assert!(actual_type.contains("String"));
+        }
+        other => panic!("Expected TypeMismatch, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_with_column_rejects_wrong_length() {
+    match Person::with_name(people(), Series::new("name".into(), ["Alice"])) {
+        Err(ValidationError::LengthMismatch {
+            expected, actual, ..
+        }) => {
+            assert_eq!(expected, 2);
+            assert_eq!(actual, 1);
+        }
+        other => panic!("Expected LengthMismatch, got {:?}", other),
+    }
+}
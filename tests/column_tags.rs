@@ -0,0 +1,57 @@
+use polars_tools::*;
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Patient {
+    id: i64,
+    #[polars(tag = "pii")]
+    name: String,
+    #[polars(tag = "pii", tag = "contact")]
+    email: Option<String>,
+    #[polars(tag = "clinical")]
+    diagnosis: String,
+}
+
+fn patients() -> DataFrame {
+    df![
+        "id" => [1i64, 2],
+        "name" => ["Ada", "Grace"],
+        "email" => [Some("ada@x.io"), None],
+        "diagnosis" => ["A01", "B02"],
+    ]
+    .unwrap()
+}
+
+#[test]
+fn test_columns_tagged() {
+    assert_eq!(Patient::columns_tagged("pii"), ["name", "email"]);
+    assert_eq!(Patient::columns_tagged("contact"), ["email"]);
+    assert!(Patient::columns_tagged("unknown").is_empty());
+}
+
+#[test]
+fn test_drop_tagged() {
+    let df = Patient::drop_tagged(&patients(), "pii");
+    assert_eq!(df.get_column_names(), ["id", "diagnosis"]);
+
+    let partial = patients().drop("email").unwrap();
+    let df = Patient::drop_tagged(&partial, "pii");
+    assert_eq!(df.get_column_names(), ["id", "diagnosis"]);
+}
+
+#[test]
+fn test_tagged_expressions() {
+    let clinical = patients()
+        .lazy()
+        .select(Patient::cols_tagged("clinical"))
+        .collect()
+        .unwrap();
+    assert_eq!(clinical.get_column_names(), ["diagnosis"]);
+
+    let public = patients()
+        .lazy()
+        .select(Patient::cols_untagged("pii"))
+        .collect()
+        .unwrap();
+    assert_eq!(public.get_column_names(), ["id", "diagnosis"]);
+}
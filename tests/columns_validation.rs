@@ -0,0 +1,71 @@
+#![allow(non_upper_case_globals)]
+use polars_tools::*;
+
+#[derive(PolarsColumns)]
+#[allow(dead_code)]
+struct Reading {
+    sensor: String,
+    #[polars(alias = "temp")]
+    celsius: f64,
+    #[polars(dtype = DataType::Datetime(TimeUnit::Nanoseconds, None), any_time_unit)]
+    at: i64,
+}
+
+fn readings() -> DataFrame {
+    df![
+        "sensor" => ["a", "b"],
+        "temp" => [20.5, 21.0],
+        "at" => [1i64, 2],
+    ]
+    .unwrap()
+    .lazy()
+    .with_column(col("at").cast(DataType::Datetime(TimeUnit::Milliseconds, None)))
+    .collect()
+    .unwrap()
+}
+
+#[test]
+fn test_columns_derive_validates() {
+    let df = readings();
+    Reading::validate(&df).unwrap();
+    Reading::validate_cached(&df).unwrap();
+    Reading::debug_validate(&df).unwrap();
+    assert_eq!(
+        Reading::polars_schema().get("celsius"),
+        Some(&DataType::Float64)
+    );
+
+    let err = Reading::validate(&df.drop("sensor").unwrap()).unwrap_err();
+    assert!(matches!(err, ValidationError::MissingColumn { .. }));
+
+    let mut wrong = df.clone();
+    wrong
+        .with_column(Column::new("sensor".into(), [1i32, 2]))
+        .unwrap();
+    assert!(matches!(
+        Reading::validate(&wrong),
+        Err(ValidationError::TypeMismatch { .. })
+    ));
+}
+
+#[test]
+fn test_columns_derive_validate_strict() {
+    let df = readings();
+    Reading::validate_strict(&df).unwrap();
+
+    let mut extra = df.clone();
+    extra.with_column(Column::new("note".into(), ["x", "y"])).unwrap();
+    Reading::validate(&extra).unwrap();
+    assert!(Reading::validate_strict(&extra).is_err());
+}
+
+#[test]
+fn test_columns_derive_validate_with() {
+    let df = readings()
+        .lazy()
+        .rename(["sensor"], ["Sensor"], true)
+        .collect()
+        .unwrap();
+    assert!(Reading::validate(&df).is_err());
+    Reading::validate_with(&df, &ValidateOptions::default().case_insensitive()).unwrap();
+}
@@ -0,0 +1,99 @@
+use polars_tools::*;
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Order {
+    id: i64,
+    customer_id: i64,
+    amount: f64,
+}
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Customer {
+    customer_id: i64,
+    country: String,
+}
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Audit {
+    loaded_at: i64,
+}
+
+combine!(Order, Customer, on = [customer_id]);
+combine!(pub OrderAudit = Order, Audit);
+
+fn orders() -> DataFrame {
+    df![
+        "id" => [1i64, 2, 3],
+        "customer_id" => [10i64, 20, 10],
+        "amount" => [5.0, 50.0, 500.0],
+    ]
+    .unwrap()
+}
+
+fn customers() -> DataFrame {
+    df!["customer_id" => [10i64, 20], "country" => ["NL", "DE"]].unwrap()
+}
+
+#[test]
+fn test_combined_columns() {
+    const COLUMNS: &[&str] = OrderCustomer::COLUMNS;
+    assert_eq!(COLUMNS, ["id", "customer_id", "amount", "country"]);
+    assert_eq!(OrderCustomer::WIDTH, 4);
+    assert_eq!(OrderCustomer::KEYS, ["customer_id"]);
+    assert_eq!(OrderCustomer::field_index(Customer::country), Some(3));
+    assert_eq!(OrderCustomer::all_cols()[1], col("customer_id"));
+    assert_eq!(OrderCustomer::cast_exprs().len(), OrderCustomer::WIDTH);
+    assert_eq!(
+        OrderCustomer::all_types(),
+        [DataType::Int64, DataType::Int64, DataType::Float64, DataType::String]
+    );
+
+    assert_eq!(OrderAudit::COLUMNS, ["id", "customer_id", "amount", "loaded_at"]);
+    let empty = OrderAudit::df();
+    assert!(OrderAudit::validate_strict(&empty).is_ok());
+    assert_eq!(OrderAudit::empty_lazy().collect().unwrap().width(), 4);
+}
+
+#[test]
+fn test_combined_validation() {
+    let joined = orders()
+        .lazy()
+        .join(
+            customers().lazy(),
+            [col("customer_id")],
+            [col("customer_id")],
+            JoinArgs::new(JoinType::Inner),
+        )
+        .collect()
+        .unwrap();
+    assert!(OrderCustomer::validate_strict(&joined).is_ok());
+    assert!(OrderCustomer::typed(&joined).is_ok());
+
+    let missing = joined.drop(Customer::country).unwrap();
+    assert!(matches!(
+        OrderCustomer::validate(&missing),
+        Err(ValidationError::MissingColumn { .. })
+    ));
+    assert!(OrderAudit::validate(&joined).is_err());
+}
+
+#[test]
+fn test_typed_join_into_combined_schema() {
+    let orders = Order::typed(&orders()).unwrap();
+    let customers = Customer::typed(&customers()).unwrap();
+    let joined: TypedFrame<OrderCustomer> = orders
+        .join(
+            &customers,
+            &[Order::customer_id],
+            &[Customer::customer_id],
+            JoinType::Left,
+        )
+        .unwrap();
+    assert_eq!(joined.height(), 3);
+
+    let back: TypedFrame<Customer> = joined.select().unwrap();
+    assert_eq!(back.df().width(), 2);
+}
@@ -0,0 +1,67 @@
+use polars_tools::*;
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Trade {
+    symbol: String,
+    #[polars(alias = "qty")]
+    quantity: i64,
+}
+
+fn batch(symbols: &[&str], quantities: &[i64]) -> DataFrame {
+    df!["symbol" => symbols, "quantity" => quantities].unwrap()
+}
+
+#[test]
+fn test_concat_valid_frames() {
+    let with_extra = batch(&["c"], &[3])
+        .lazy()
+        .with_column(lit("x").alias("venue"))
+        .select([col("venue"), col("quantity"), col("symbol")])
+        .collect()
+        .unwrap();
+    let df = Trade::concat([batch(&["a", "b"], &[1, 2]), with_extra]).unwrap();
+    assert_eq!(df.height(), 3);
+    assert_eq!(df.get_column_names(), ["symbol", "quantity"]);
+
+    let empty = Trade::concat([]).unwrap();
+    assert!(Trade::validate_strict(&empty).is_ok());
+}
+
+#[test]
+fn test_concat_reports_the_failing_input() {
+    let missing = batch(&["c"], &[3]).drop("quantity").unwrap();
+    let err = Trade::concat([batch(&["a"], &[1]), batch(&["b"], &[2]), missing]).unwrap_err();
+    match &err {
+        ValidationError::InputFrame { index, source } => {
+            assert_eq!(*index, 2);
+            assert!(matches!(**source, ValidationError::MissingColumn { .. }));
+        }
+        other => panic!("Expected InputFrame, got {:?}", other),
+    }
+    assert_eq!(err.code(), "PT0016");
+    assert!(err
+        .to_string()
+        .starts_with("Input frame 2: Missing required column"));
+    assert!(matches!(
+        PolarsError::from(err),
+        PolarsError::Context { .. }
+    ));
+}
+
+#[test]
+fn test_concat_conforms_inputs() {
+    let aliased = df!["symbol" => ["b"], "qty" => [2i64]].unwrap();
+    let upper = df!["SYMBOL" => ["c"], "quantity" => [3i64]].unwrap();
+
+    let df = Trade::concat([batch(&["a"], &[1]), aliased.clone()]).unwrap();
+    assert_eq!(df.column("quantity").unwrap().i64().unwrap().sum(), Some(3));
+    assert!(Trade::concat([upper.clone()]).is_err());
+
+    let opts = ConcatOptions::default()
+        .match_columns(ValidateOptions::default().case_insensitive())
+        .rechunk();
+    let df = Trade::concat_with([batch(&["a"], &[1]), aliased, upper], &opts).unwrap();
+    assert_eq!(df.column("quantity").unwrap().i64().unwrap().sum(), Some(6));
+    assert_eq!(df.first_col_n_chunks(), 1);
+}
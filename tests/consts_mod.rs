@@ -0,0 +1,46 @@
+use polars_tools::*;
+
+#[derive(PolarsSchema)]
+#[polars(consts_mod = "cols")]
+#[allow(dead_code, non_upper_case_globals)]
+struct UserProfile {
+    id: i64,
+    expr: String,
+    df: String,
+    columns: i32,
+}
+
+#[derive(PolarsColumns)]
+#[polars(consts_mod = "names")]
+#[allow(dead_code, non_upper_case_globals)]
+struct Query {
+    filter: String,
+    validate: bool,
+}
+
+#[test]
+fn test_consts_in_module() {
+    assert_eq!(user_profile::cols::id, "id");
+    assert_eq!(user_profile::cols::expr, "expr");
+    assert_eq!(user_profile::cols::df, "df");
+    assert_eq!(UserProfile::COLUMNS, ["id", "expr", "df", "columns"]);
+
+    assert_eq!(query::names::filter, "filter");
+    assert_eq!(Query::column_names(), ["filter", "validate"]);
+}
+
+#[test]
+fn test_generated_items_keep_their_names() {
+    let df = df![
+        user_profile::cols::id => [1i64],
+        user_profile::cols::expr => ["a + b"],
+        user_profile::cols::df => ["frame"],
+        user_profile::cols::columns => [3i32],
+    ]
+    .unwrap();
+
+    UserProfile::validate(&df).unwrap();
+    assert_eq!(UserProfile::df().unwrap().width(), 4);
+    assert_eq!(UserProfile::expr.expr(), col("expr"));
+    assert_eq!(cols!(UserProfile: df, columns), [col("df"), col("columns")]);
+}
@@ -0,0 +1,59 @@
+//! Derives used through a facade crate re-exporting polars-tools
+
+mod facade {
+    pub use polars_tools as tools;
+}
+
+mod models {
+    use crate::facade::tools::{PolarsNewtype, PolarsSchema};
+
+    #[derive(Debug, Clone, PartialEq, PolarsNewtype)]
+    #[polars(crate = "crate::facade::tools")]
+    pub struct Cents(pub i64);
+
+    #[derive(PolarsSchema)]
+    #[polars(crate = "crate::facade::tools")]
+    #[allow(dead_code, non_upper_case_globals)]
+    pub struct Order {
+        pub id: i64,
+        #[polars(dtype = polars::prelude::DataType::Int64)]
+        pub total: i64,
+        pub note: Option<String>,
+    }
+}
+
+use facade::tools::{df, DataFrame, IntoLazy, NamedFrom, PolarsDType, Series};
+use models::{Cents, Order};
+
+fn orders() -> DataFrame {
+    df![
+        "id" => [1i64, 2],
+        "total" => [250i64, 1000],
+        "note" => [Some("gift"), None],
+    ]
+    .unwrap()
+}
+
+#[test]
+fn test_derives_through_facade() {
+    Order::validate(&orders()).unwrap();
+    assert_eq!(Order::COLUMNS, ["id", "total", "note"]);
+
+    let rows = Order::from_df(&orders()).unwrap();
+    assert_eq!(rows[1].total, 1000);
+    assert!(Order::to_df(&rows).unwrap().equals_missing(&orders()));
+
+    let big = orders()
+        .lazy()
+        .filter(Order::expr.total().gt(facade::tools::lit(500i64)))
+        .collect()
+        .unwrap();
+    assert_eq!(big.height(), 1);
+}
+
+#[test]
+fn test_newtype_through_facade() {
+    assert_eq!(Cents::dtype(), facade::tools::DataType::Int64);
+    let series = Series::new("total".into(), [Cents(1).0]);
+    assert_eq!(series.dtype(), &Cents::dtype());
+}
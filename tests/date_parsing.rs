@@ -0,0 +1,149 @@
+use polars_tools::*;
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Invoice {
+    id: i64,
+    #[polars(dtype = DataType::Date, parse_date = "%d/%m/%Y")]
+    issued: i32,
+    #[polars(
+        dtype = DataType::Datetime(TimeUnit::Milliseconds, None),
+        parse_date = "%Y-%m-%d %H:%M"
+    )]
+    paid_at: Option<i64>,
+}
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Event {
+    #[polars(
+        dtype = DataType::Datetime(TimeUnit::Microseconds, None),
+        parse_date = "%Y-%m-%dT%H:%M:%S",
+        parse_date = "%d/%m/%Y %H:%M",
+        parse_date = "epoch_s"
+    )]
+    at: i64,
+    #[polars(dtype = DataType::Date, parse_date = "epoch_ms")]
+    day: Option<i32>,
+}
+
+fn csv_like() -> DataFrame {
+    df![
+        "id" => [1i64, 2, 3],
+        "issued" => ["31/01/2024", "01/02/2024", "15/02/2024"],
+        "paid_at" => [Some("2024-02-01 09:30"), None, Some("2024-03-01 17:00")],
+    ]
+    .unwrap()
+}
+
+#[test]
+fn test_conform_parses_string_columns() {
+    assert!(Invoice::validate(&csv_like()).is_err());
+    let (df, report) = Invoice::conform(&csv_like()).unwrap();
+
+    Invoice::validate(&df).unwrap();
+    assert_eq!(
+        df.column("issued").unwrap().get(0).unwrap(),
+        AnyValue::Date(19753)
+    );
+    assert_eq!(df.column("paid_at").unwrap().null_count(), 1);
+    let parsed: Vec<_> = report
+        .cast
+        .iter()
+        .map(|cast| cast.column.as_str())
+        .collect();
+    assert_eq!(parsed, ["issued", "paid_at"]);
+    assert_eq!(report.cast[0].from, DataType::String);
+    assert_eq!(report.cast[0].to, DataType::Date);
+}
+
+#[test]
+fn test_unparseable_values_are_sampled() {
+    let df = df![
+        "id" => [1i64, 2, 3, 4],
+        "issued" => ["31/01/2024", "2024-02-01", "soon", "2024-02-01"],
+        "paid_at" => [None::<&str>, None, None, None],
+    ]
+    .unwrap();
+    let err = Invoice::parse_dates(&df).unwrap_err();
+
+    assert_eq!(err.code(), "PT0022");
+    assert_eq!(
+        err.to_string(),
+        r#"Column 'issued' has 3 values matching none of the formats ["%d/%m/%Y"], e.g. "2024-02-01", "soon""#
+    );
+    assert!(Invoice::conform(&df).is_err());
+}
+
+#[test]
+fn test_temporal_columns_are_left_alone() {
+    let parsed = Invoice::parse_dates(&csv_like()).unwrap();
+    let (again, report) = Invoice::conform(&parsed).unwrap();
+    assert!(report.is_empty());
+    assert!(again.equals_missing(&parsed));
+}
+
+#[test]
+fn test_formats_are_tried_in_order() {
+    let df = df![
+        "at" => ["2024-02-01T09:00:00", "01/02/2024 09:00", "1706778000", "1706778000"],
+        "day" => [Some("1706778000000"), None, None, None],
+    ]
+    .unwrap();
+    let (df, report) = Event::conform(&df).unwrap();
+
+    let at = df.column("at").unwrap().datetime().unwrap().clone();
+    assert!(at.into_no_null_iter().all(|us| us == 1_706_778_000_000_000));
+    assert_eq!(
+        df.column("day").unwrap().get(0).unwrap(),
+        AnyValue::Date(19754)
+    );
+    assert_eq!(
+        report.parsed[0],
+        ColumnParse {
+            column: "at".to_string(),
+            formats: vec![
+                ("%Y-%m-%dT%H:%M:%S".to_string(), 1),
+                ("%d/%m/%Y %H:%M".to_string(), 1),
+                ("epoch_s".to_string(), 2),
+            ],
+        }
+    );
+    assert_eq!(
+        report.to_string(),
+        "cast at: str -> datetime[μs]\n\
+         cast day: str -> date\n\
+         parsed at: 1 as %Y-%m-%dT%H:%M:%S, 1 as %d/%m/%Y %H:%M, 2 as epoch_s\n\
+         parsed day: 1 as epoch_ms"
+    );
+}
+
+#[test]
+fn test_values_matching_no_format() {
+    let df = df![
+        "at" => [Some("1706778000"), None, Some("yesterday")],
+        "day" => [None::<&str>, None, None],
+    ]
+    .unwrap();
+    let err = Event::parse_dates(&df).unwrap_err();
+    assert!(matches!(
+        err,
+        ValidationError::UnparseableValues { rows: 1, ref formats, ref sample, .. }
+            if formats.len() == 3 && sample == &["\"yesterday\""]
+    ));
+}
+
+#[test]
+fn test_epoch_seconds_overflowing_milliseconds_are_unparsed() {
+    let df = df![
+        "at" => ["1706778000", "9300000000000000"],
+        "day" => [None::<&str>, None],
+    ]
+    .unwrap();
+    let err = Event::parse_dates(&df).unwrap_err();
+    assert!(matches!(
+        err,
+        ValidationError::UnparseableValues { rows: 1, ref sample, .. }
+            if sample == &["\"9300000000000000\""]
+    ));
+}
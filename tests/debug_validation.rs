@@ -0,0 +1,43 @@
+use polars_tools::*;
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Reading {
+    sensor: String,
+    value: f64,
+}
+
+fn readings() -> DataFrame {
+    df!["sensor" => ["a", "b"], "value" => [1.5, 2.5]].unwrap()
+}
+
+#[test]
+fn test_debug_checks_follow_the_build() {
+    assert_eq!(
+        DEBUG_CHECKS,
+        cfg!(any(debug_assertions, feature = "release-checks"))
+    );
+}
+
+#[test]
+fn test_debug_validate() {
+    assert!(Reading::debug_validate(&readings()).is_ok());
+
+    let bad = readings().drop("value").unwrap();
+    assert_eq!(Reading::debug_validate(&bad).is_err(), DEBUG_CHECKS);
+}
+
+#[test]
+fn test_debug_typed_frames() {
+    assert!(TypedFrame::<Reading>::debug_new(readings()).is_ok());
+
+    let bad = readings().drop("value").unwrap();
+    assert_eq!(TypedFrame::<Reading>::debug_new(bad).is_err(), DEBUG_CHECKS);
+
+    let renamed = Reading::typed(&readings())
+        .unwrap()
+        .lazy()
+        .map::<Reading>(|lf| lf.rename(["value"], ["reading"], true))
+        .debug_collect();
+    assert_eq!(renamed.is_err(), DEBUG_CHECKS);
+}
@@ -0,0 +1,66 @@
+#![allow(non_upper_case_globals)]
+#[cfg(feature = "decimal")]
+mod decimal {
+    use polars_tools::*;
+    use rust_decimal::Decimal;
+
+    #[derive(PolarsSchema)]
+    #[allow(dead_code, non_upper_case_globals)]
+    struct Ledger {
+        id: i64,
+        balance: Decimal,
+        #[polars(precision = 18, scale = 2)]
+        amount: Decimal,
+        #[polars(scale = 4)]
+        fee: Option<Decimal>,
+    }
+
+    #[test]
+    fn test_decimal_type_mapping() {
+        assert_eq!(
+            Ledger::balance_type,
+            DataType::Decimal(Some(DEFAULT_DECIMAL_PRECISION), Some(DEFAULT_DECIMAL_SCALE))
+        );
+        assert_eq!(Ledger::amount_type, DataType::Decimal(Some(18), Some(2)));
+        assert_eq!(
+            Ledger::fee_type,
+            DataType::Decimal(Some(DEFAULT_DECIMAL_PRECISION), Some(4))
+        );
+    }
+
+    #[test]
+    fn test_decimal_empty_dataframe_validates() {
+        let empty_df = Ledger::df().unwrap();
+        let schema = empty_df.schema();
+        assert_eq!(schema.get("amount"), Some(&DataType::Decimal(Some(18), Some(2))));
+
+        assert!(Ledger::validate_strict(&empty_df).is_ok());
+    }
+
+    #[test]
+    fn test_decimal_scale_mismatch() {
+        let df = DataFrame::new(vec![
+            Column::new("id".into(), Series::new_empty("id".into(), &DataType::Int64)),
+            Column::new(
+                "balance".into(),
+                Series::new_empty("balance".into(), &Ledger::balance_type.dtype()),
+            ),
+            Column::new(
+                "amount".into(),
+                Series::new_empty("amount".into(), &DataType::Decimal(Some(18), Some(4))),
+            ),
+            Column::new(
+                "fee".into(),
+                Series::new_empty("fee".into(), &Ledger::fee_type.dtype()),
+            ),
+        ])
+        .unwrap();
+
+        match Ledger::validate(&df) {
+            Err(ValidationError::TypeMismatch { column_name, .. }) => {
+                assert_eq!(column_name, "amount");
+            }
+            other => panic!("Expected TypeMismatch, got {:?}", other),
+        }
+    }
+}
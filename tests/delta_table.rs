@@ -0,0 +1,181 @@
+#![allow(non_upper_case_globals)]
+
+#[cfg(feature = "delta")]
+mod delta_table {
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    use polars_tools::delta::DeltaTable;
+    use polars_tools::*;
+    use serde_json::json;
+
+    #[derive(PolarsSchema)]
+    #[allow(dead_code, non_upper_case_globals)]
+    struct Event {
+        id: i64,
+        kind: String,
+        #[polars(dtype = DataType::Date)]
+        day: i32,
+    }
+
+    #[derive(PolarsSchema)]
+    #[allow(dead_code, non_upper_case_globals)]
+    struct Metric {
+        id: i32,
+        value: f64,
+    }
+
+    const SCHEMA: &str = r#"{"type":"struct","fields":[
+        {"name":"id","type":"long","nullable":false,"metadata":{}},
+        {"name":"kind","type":"string","nullable":false,"metadata":{}},
+        {"name":"day","type":"date","nullable":true,"metadata":{}},
+        {"name":"extra","type":"double","nullable":true,"metadata":{}}]}"#;
+
+    /// A table partitioned by `day` in a fresh directory, with one commit per
+    /// entry of `commits`
+    fn table(name: &str, commits: &[Vec<serde_json::Value>]) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("polars_tools_delta_{}", name));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("_delta_log")).unwrap();
+        for (version, actions) in commits.iter().enumerate() {
+            let lines: Vec<String> = actions.iter().map(|a| a.to_string()).collect();
+            fs::write(
+                root.join("_delta_log")
+                    .join(format!("{:020}.json", version)),
+                lines.join("\n"),
+            )
+            .unwrap();
+        }
+        root
+    }
+
+    fn metadata() -> serde_json::Value {
+        json!({"metaData": {
+            "id": "table",
+            "format": {"provider": "parquet", "options": {}},
+            "schemaString": SCHEMA,
+            "partitionColumns": ["day"],
+            "configuration": {},
+        }})
+    }
+
+    fn add(path: &str, day: &str) -> serde_json::Value {
+        json!({"add": {"path": path, "partitionValues": {"day": day}, "size": 1, "dataChange": true}})
+    }
+
+    fn events() -> Vec<Vec<serde_json::Value>> {
+        vec![
+            vec![
+                json!({"protocol": {"minReaderVersion": 1, "minWriterVersion": 2}}),
+                metadata(),
+                add("day=2024-01-01/part-0.parquet", "2024-01-01"),
+            ],
+            vec![
+                add("day=2024-01-02/part%201.parquet", "2024-01-02"),
+                add("day=2024-01-02/part-2.parquet", "2024-01-02"),
+            ],
+            vec![json!({"remove": {"path": "day=2024-01-02/part-2.parquet", "dataChange": true}})],
+        ]
+    }
+
+    /// Stands in for a Parquet reader; the data files hold every column but
+    /// the partition column `day`
+    fn scan(path: &Path) -> PolarsResult<LazyFrame> {
+        let name = path.file_name().unwrap().to_str().unwrap();
+        let df = match name {
+            "part-0.parquet" => df!["id" => [1i64, 2], "kind" => ["a", "b"], "extra" => [0.5, 1.5]],
+            "part 1.parquet" => df!["id" => [3i64], "kind" => ["c"], "extra" => [2.5]],
+            other => panic!("scanned a removed file {}", other),
+        }?;
+        Ok(df.lazy())
+    }
+
+    #[test]
+    fn test_open_replays_log() {
+        let root = table("replay", &events());
+        let table = DeltaTable::open(root.to_str().unwrap()).unwrap();
+
+        assert_eq!(table.version(), 2);
+        assert_eq!(table.partition_columns(), ["day"]);
+        assert_eq!(table.schema().get("id"), Some(&DataType::Int64));
+        assert_eq!(table.schema().get("day"), Some(&DataType::Date));
+        let paths: Vec<&str> = table.files().iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            [
+                "day=2024-01-01/part-0.parquet",
+                "day=2024-01-02/part%201.parquet"
+            ]
+        );
+        assert_eq!(
+            table.files()[0].partition_values,
+            [("day".to_string(), Some("2024-01-01".to_string()))]
+        );
+    }
+
+    #[test]
+    fn test_scan_delta() {
+        let root = table("scan", &events());
+        let uri = format!("file://{}", root.display());
+        let df = Event::scan_delta(&uri, scan).unwrap().collect().unwrap();
+
+        assert_eq!(df.get_column_names(), ["id", "kind", "day"]);
+        assert_eq!(df.column("day").unwrap().dtype(), &DataType::Date);
+        let days = df.column("day").unwrap().cast(&DataType::Int32).unwrap();
+        let days: Vec<Option<i32>> = days.i32().unwrap().into_iter().collect();
+        assert_eq!(days, [Some(19_723), Some(19_723), Some(19_724)]);
+        Event::validate(&df).unwrap();
+    }
+
+    #[test]
+    fn test_scan_delta_schema_mismatch() {
+        let root = table("mismatch", &events());
+        let Err(ValidationError::SchemaMismatch(diff)) =
+            Metric::scan_delta(root.to_str().unwrap(), scan)
+        else {
+            panic!("expected a schema mismatch");
+        };
+        assert_eq!(diff.missing, ["value"]);
+        assert_eq!(diff.unexpected, ["kind", "day", "extra"]);
+        assert_eq!(
+            diff.mismatched,
+            [DTypeMismatch {
+                column: "id".to_string(),
+                expected: DataType::Int32,
+                actual: DataType::Int64,
+            }]
+        );
+        assert!(!diff.is_compatible());
+        assert_eq!(
+            diff.to_string(),
+            "missing value; id is i64, expected i32; unexpected kind, day, extra"
+        );
+    }
+
+    #[test]
+    fn test_empty_table() {
+        let root = table("empty", &[vec![metadata()]]);
+        let df = Event::scan_delta(root.to_str().unwrap(), scan)
+            .unwrap()
+            .collect()
+            .unwrap();
+        assert_eq!(df.height(), 0);
+        assert_eq!(df.get_column_names(), ["id", "kind", "day"]);
+    }
+
+    #[test]
+    fn test_unsupported_tables() {
+        let err = DeltaTable::open("s3://bucket/table").unwrap_err();
+        assert!(matches!(err, ValidationError::Delta(_)));
+
+        let root = table("missing", &[]);
+        let err = DeltaTable::open(root.to_str().unwrap()).unwrap_err();
+        assert!(err.to_string().contains("no Delta log"));
+
+        let mut mapped = metadata();
+        mapped["metaData"]["configuration"] = json!({"delta.columnMapping.mode": "name"});
+        let root = table("mapped", &[vec![mapped]]);
+        let err = DeltaTable::open(root.to_str().unwrap()).unwrap_err();
+        assert!(err.to_string().contains("column mapping"));
+    }
+}
@@ -0,0 +1,60 @@
+use polars_tools::*;
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Payment {
+    #[polars(tag = "key")]
+    id: i64,
+    /// Amount in cents
+    amount: i64,
+    #[polars(tag = "pii", tag = "contact", doc = "Payer address")]
+    email: Option<String>,
+}
+
+#[test]
+fn test_schema_description() {
+    let description = Payment::schema_description();
+    assert_eq!(description.name, "Payment");
+    assert_eq!(description.columns.len(), 3);
+
+    let email = &description.columns[2];
+    assert_eq!(email.name, "email");
+    assert_eq!(email.dtype, DataType::String);
+    assert!(email.nullable);
+    assert_eq!(email.doc.as_deref(), Some("Payer address"));
+    assert_eq!(email.tags, ["pii", "contact"]);
+
+    let id = &description.columns[0];
+    assert!(!id.nullable);
+    assert_eq!(id.doc, None);
+}
+
+#[test]
+fn test_describe_schema() {
+    assert_eq!(
+        Payment::describe_schema(),
+        "Payment (3 columns)\n\
+         column  dtype  nullable  doc              tags\n\
+         id      i64    no                         key\n\
+         amount  i64    no        Amount in cents\n\
+         email   str    yes       Payer address    pii, contact"
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_describe_schema_json() {
+    let json: serde_json::Value =
+        serde_json::from_str(&Payment::describe_schema_json().unwrap()).unwrap();
+    assert_eq!(json["name"], "Payment");
+    assert_eq!(
+        json["columns"][2],
+        serde_json::json!({
+            "name": "email",
+            "dtype": "str",
+            "nullable": true,
+            "doc": "Payer address",
+            "tags": ["pii", "contact"],
+        })
+    );
+}
@@ -0,0 +1,75 @@
+#![allow(non_upper_case_globals)]
+use polars_tools::*;
+
+// Custom type taught to the derive through PolarsDType
+#[derive(Debug, Clone, PartialEq)]
+struct Cents(i64);
+
+impl PolarsDType for Cents {
+    fn dtype() -> DataType {
+        DataType::Int64
+    }
+}
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Invoice {
+    id: i64,
+    amount: Cents,
+    tags: Vec<String>,
+    scores: Option<Vec<f64>>,
+    note: Option<String>,
+}
+
+#[test]
+fn test_primitive_dtypes() {
+    assert_eq!(<i8 as PolarsDType>::dtype(), DataType::Int8);
+    assert_eq!(<u64 as PolarsDType>::dtype(), DataType::UInt64);
+    assert_eq!(<f32 as PolarsDType>::dtype(), DataType::Float32);
+    assert_eq!(<bool as PolarsDType>::dtype(), DataType::Boolean);
+    assert_eq!(<String as PolarsDType>::dtype(), DataType::String);
+}
+
+#[test]
+fn test_option_dtype_and_nullability() {
+    assert_eq!(<Option<i32> as PolarsDType>::dtype(), DataType::Int32);
+    const { assert!(<Option<i32> as PolarsDType>::NULLABLE) };
+    const { assert!(!<i32 as PolarsDType>::NULLABLE) };
+}
+
+#[test]
+fn test_vec_maps_to_list() {
+    assert_eq!(
+        <Vec<i32> as PolarsDType>::dtype(),
+        DataType::List(Box::new(DataType::Int32))
+    );
+}
+
+#[test]
+fn test_custom_type_used_by_derive() {
+    assert_eq!(Invoice::amount_type, DataType::Int64);
+    assert_eq!(Invoice::tags_type, DataType::List(Box::new(DataType::String)));
+    assert_eq!(Invoice::scores_type, DataType::List(Box::new(DataType::Float64)));
+    assert_eq!(Invoice::note_type, DataType::String);
+}
+
+#[test]
+fn test_type_constant_converts_to_datatype() {
+    let dtype: DataType = Invoice::id_type.into();
+    assert_eq!(dtype, DataType::Int64);
+    assert_eq!(Invoice::id_type.dtype(), DataType::Int64);
+}
+
+#[test]
+fn test_custom_type_empty_dataframe_and_validation() {
+    let empty_df = Invoice::df().unwrap();
+    let schema = empty_df.schema();
+    assert_eq!(schema.get("amount"), Some(&DataType::Int64));
+    assert_eq!(
+        schema.get("tags"),
+        Some(&DataType::List(Box::new(DataType::String)))
+    );
+
+    assert!(Invoice::validate(&empty_df).is_ok());
+    assert!(Invoice::validate_strict(&empty_df).is_ok());
+}
@@ -0,0 +1,76 @@
+#![allow(non_upper_case_globals)]
+use polars_tools::*;
+use std::time::Duration;
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Job {
+    id: i64,
+    runtime: Duration,
+    #[polars(time_unit = "ms")]
+    timeout: Option<Duration>,
+    #[polars(time_unit = "ns", any_time_unit)]
+    latency: Duration,
+}
+
+fn job_frame(latency_unit: TimeUnit, timeout_unit: TimeUnit) -> DataFrame {
+    DataFrame::new(vec![
+        Column::new("id".into(), Series::new_empty("id".into(), &DataType::Int64)),
+        Column::new(
+            "runtime".into(),
+            Series::new_empty("runtime".into(), &DataType::Duration(TimeUnit::Microseconds)),
+        ),
+        Column::new(
+            "timeout".into(),
+            Series::new_empty("timeout".into(), &DataType::Duration(timeout_unit)),
+        ),
+        Column::new(
+            "latency".into(),
+            Series::new_empty("latency".into(), &DataType::Duration(latency_unit)),
+        ),
+    ])
+    .unwrap()
+}
+
+#[test]
+fn test_duration_type_mapping() {
+    assert_eq!(Job::runtime_type, DataType::Duration(TimeUnit::Microseconds));
+    assert_eq!(Job::timeout_type, DataType::Duration(TimeUnit::Milliseconds));
+    assert_eq!(Job::latency_type, DataType::Duration(TimeUnit::Nanoseconds));
+}
+
+#[test]
+fn test_duration_empty_dataframe() {
+    let empty_df = Job::df().unwrap();
+    assert_eq!(
+        empty_df.schema().get("timeout"),
+        Some(&DataType::Duration(TimeUnit::Milliseconds))
+    );
+    assert!(Job::validate_strict(&empty_df).is_ok());
+}
+
+#[test]
+fn test_any_time_unit_accepts_other_units() {
+    let df = job_frame(TimeUnit::Microseconds, TimeUnit::Milliseconds);
+    assert!(Job::validate(&df).is_ok());
+}
+
+#[test]
+fn test_time_unit_mismatch_without_any_time_unit() {
+    let df = job_frame(TimeUnit::Nanoseconds, TimeUnit::Microseconds);
+    match Job::validate(&df) {
+        Err(ValidationError::TypeMismatch { column_name, .. }) => {
+            assert_eq!(column_name, "timeout");
+        }
+        other => panic!("Expected TypeMismatch, got {:?}", other),
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_chrono_duration_mapping() {
+    assert_eq!(
+        <chrono::Duration as PolarsDType>::dtype(),
+        DataType::Duration(TimeUnit::Microseconds)
+    );
+}
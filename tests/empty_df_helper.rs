@@ -265,3 +265,24 @@ fn test_empty_dataframe_with_chrono_types() {
     assert!(ChronoTypes::validate(&empty_df).is_ok());
     assert!(ChronoTypes::validate_strict(&empty_df).is_ok());
 }
+
+#[test]
+fn test_empty_lazy_frame() {
+    let mut lf = User::empty_lazy();
+    assert_eq!(lf.collect_schema().unwrap().as_ref(), User::polars_schema());
+    assert_eq!(Product::lazy().collect().unwrap().height(), 0);
+
+    let batches = [
+        df!["id" => [1i64], "name" => ["a"], "age" => [30], "active" => [true]].unwrap(),
+        df!["id" => [2i64], "name" => ["b"], "age" => [40], "active" => [false]].unwrap(),
+    ];
+    let all = batches
+        .into_iter()
+        .fold(User::empty_lazy(), |acc, df| {
+            concat([acc, df.lazy()], UnionArgs::default()).unwrap()
+        })
+        .collect()
+        .unwrap();
+    assert_eq!(all.height(), 2);
+    assert!(User::validate_strict(&all).is_ok());
+}
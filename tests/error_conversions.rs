@@ -0,0 +1,77 @@
+use std::io::Write;
+
+use polars_tools::*;
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Order {
+    id: i64,
+    amount: f64,
+}
+
+fn total(df: &DataFrame) -> PolarsResult<f64> {
+    Order::validate(df)?;
+    Ok(df.column("amount")?.f64()?.sum().unwrap_or(0.0))
+}
+
+fn export(df: &DataFrame, out: &mut impl Write) -> std::result::Result<usize, Error> {
+    Order::validate(df)?;
+    let ids = df.column("id")?.i64()?;
+    writeln!(out, "{}", ids.len())?;
+    Ok(ids.len())
+}
+
+fn orders() -> DataFrame {
+    df!["id" => [1i64, 2], "amount" => [1.5, 2.5]].unwrap()
+}
+
+#[test]
+fn test_validation_error_into_polars_error() {
+    assert_eq!(total(&orders()).unwrap(), 4.0);
+
+    let err = total(&df!["id" => [1i64]].unwrap()).unwrap_err();
+    assert!(matches!(err, PolarsError::ColumnNotFound(_)));
+    assert!(err.to_string().contains("Missing required column: amount"));
+
+    let err = total(&df!["id" => [1i64], "amount" => [1i32]].unwrap()).unwrap_err();
+    assert!(matches!(err, PolarsError::SchemaMismatch(_)));
+
+    let err = PolarsError::from(ValidationError::RowOutOfBounds { row: 2, height: 1 });
+    assert!(matches!(err, PolarsError::OutOfBounds(_)));
+
+    let inner = PolarsError::NoData("empty".into());
+    let err = PolarsError::from(ValidationError::Polars(inner));
+    assert!(matches!(err, PolarsError::NoData(_)));
+}
+
+#[test]
+fn test_combined_error() {
+    let mut out = Vec::new();
+    assert_eq!(export(&orders(), &mut out).unwrap(), 2);
+    assert_eq!(out, b"2\n");
+
+    let err = export(&df!["id" => [1i64]].unwrap(), &mut out).unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Validation(ValidationError::MissingColumn { .. })
+    ));
+
+    struct Closed;
+    impl Write for Closed {
+        fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+            Err(std::io::ErrorKind::BrokenPipe.into())
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+    assert!(matches!(export(&orders(), &mut Closed), Err(Error::Io(_))));
+
+    // polars errors keep their kind, even when wrapped by validation
+    let wrapped = ValidationError::Polars(PolarsError::NoData("empty".into()));
+    assert!(matches!(Error::from(wrapped), Error::Polars(_)));
+    let err = PolarsError::from(Error::from(ValidationError::UnexpectedColumn {
+        column_name: "x".to_string(),
+    }));
+    assert!(matches!(err, PolarsError::SchemaMismatch(_)));
+}
@@ -0,0 +1,95 @@
+use polars_tools::expectations::{ExpectationSuite, SuiteRun};
+use polars_tools::quality::QualityCheck;
+use polars_tools::*;
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Order {
+    #[polars(unique)]
+    id: i64,
+    #[polars(min = 0, since = "2")]
+    amount: f64,
+}
+
+fn orders(ids: [i64; 3], amounts: [f64; 3]) -> DataFrame {
+    df!["id" => ids, "amount" => amounts].unwrap()
+}
+
+#[test]
+fn test_generated_suite() {
+    let suite = Order::expectation_suite();
+    assert_eq!(suite.name, "Order");
+    assert_eq!(suite.version, 2);
+    assert_eq!(suite.checks.checks, Order::quality_checks().checks);
+
+    let run = suite.run(&orders([1, 2, 3], [1.0, 2.0, 3.0])).unwrap();
+    assert!(run.passed());
+    assert_eq!((run.suite.as_str(), run.version), ("Order", 2));
+}
+
+#[test]
+fn test_suite_json_round_trip() {
+    let suite = Order::expectation_suite().check(QualityCheck::MaxNullFraction {
+        column: "amount".to_string(),
+        max: 0.1,
+    });
+    let json = serde_json::to_value(&suite).unwrap();
+    assert_eq!(json["name"], "Order");
+    assert_eq!(json["version"], 2);
+    assert_eq!(json["checks"][0]["kind"], "unique");
+
+    let loaded: ExpectationSuite = serde_json::from_value(json).unwrap();
+    assert_eq!(loaded.checks.checks, suite.checks.checks);
+    assert_eq!(loaded.version, 2);
+}
+
+#[test]
+fn test_compare_runs() {
+    let previous = Order::expectation_suite()
+        .checks(quality::QualityChecks::new().max_null_fraction("id", 0.0))
+        .run(&orders([1, 1, 2], [1.0, 2.0, 3.0]))
+        .unwrap();
+    // Stored and reloaded, as between two pipeline runs
+    let previous: SuiteRun =
+        serde_json::from_str(&serde_json::to_string(&previous).unwrap()).unwrap();
+
+    let mut suite = Order::expectation_suite();
+    suite.version = 3;
+    let suite = suite.checks(quality::QualityChecks::new().range("id", None, Some(2.0)));
+    let run = suite.run(&orders([1, 2, 3], [1.0, -2.0, 3.0])).unwrap();
+
+    let delta = run.compare(&previous);
+    assert!(delta.regressed());
+    assert_eq!(delta.newly_failing.len(), 1);
+    assert_eq!(delta.newly_failing[0].check.column(), "amount");
+    assert_eq!(delta.newly_failing[0].violations, 1);
+    assert_eq!(delta.newly_passing.len(), 1);
+    assert_eq!(delta.newly_passing[0].previous_violations, 1);
+    assert!(delta.still_failing.is_empty());
+    assert_eq!(delta.added.len(), 1);
+    assert!(!delta.added[0].passed);
+    assert_eq!(delta.removed.len(), 1);
+    assert_eq!(
+        delta.to_string(),
+        "Order v3 against v2: 1 newly failing, 1 newly passing, 0 still failing\n\
+         [newly failing] amount: within [0, ..] (0 -> 1 violations)\n\
+         [newly passing] id: unique (1 -> 0 violations)\n\
+         [added, FAILED] id: within [.., 2] (1 violations)\n\
+         [removed] id: at most 0.0% nulls"
+    );
+
+    let unchanged = run.compare(&run);
+    assert!(!unchanged.regressed());
+    assert_eq!(unchanged.still_failing.len(), 2);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_to_json() {
+    let suite = Order::expectation_suite();
+    let loaded = ExpectationSuite::from_json(&suite.to_json().unwrap()).unwrap();
+    assert_eq!(loaded.checks.checks, suite.checks.checks);
+
+    let run = suite.run(&orders([1, 2, 3], [1.0, 2.0, 3.0])).unwrap();
+    assert_eq!(SuiteRun::from_json(&run.to_json().unwrap()).unwrap(), run);
+}
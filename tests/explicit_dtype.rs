@@ -0,0 +1,26 @@
+#![allow(non_upper_case_globals)]
+use polars_tools::*;
+
+// Types that are rejected without an explicit dtype
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Overrides {
+    id: i64,
+    #[polars(dtype = DataType::Int32)]
+    tri_state: Option<Option<i32>>,
+    #[polars(dtype = DataType::List(Box::new(DataType::Float64)))]
+    point: (f64, f64),
+}
+
+#[test]
+fn test_explicit_dtype_attribute() {
+    assert_eq!(Overrides::tri_state_type, DataType::Int32);
+    assert_eq!(Overrides::point_type, DataType::List(Box::new(DataType::Float64)));
+}
+
+#[test]
+fn test_explicit_dtype_validation() {
+    let empty_df = Overrides::df().unwrap();
+    assert_eq!(empty_df.schema().get("tri_state"), Some(&DataType::Int32));
+    assert!(Overrides::validate_strict(&empty_df).is_ok());
+}
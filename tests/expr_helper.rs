@@ -0,0 +1,44 @@
+use polars_tools::*;
+
+#[derive(PolarsColumns)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Payment {
+    amount: f64,
+    status: String,
+}
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Refund {
+    amount: f64,
+}
+
+fn completed(exprs: ExprForPayment) -> Expr {
+    exprs.status().eq(lit("completed"))
+}
+
+fn all_exprs<T: PolarsExprs>() -> T::Exprs {
+    T::Exprs::default()
+}
+
+#[test]
+fn test_expr_helper_as_value() {
+    let df = df!["amount" => [1.0, 2.0], "status" => ["completed", "pending"]].unwrap();
+    let out = df
+        .lazy()
+        .filter(completed(Payment::exprs()))
+        .select([Payment::expr.amount()])
+        .collect()
+        .unwrap();
+    assert_eq!(out.height(), 1);
+
+    let stored = [Payment::expr, Payment::exprs()];
+    assert_eq!(stored[1].amount(), col("amount"));
+}
+
+#[test]
+fn test_generic_exprs() {
+    let payment: <Payment as PolarsExprs>::Exprs = all_exprs::<Payment>();
+    assert_eq!(payment.all_cols(), [col("amount"), col("status")]);
+    assert_eq!(all_exprs::<Refund>().amount(), col("amount"));
+}
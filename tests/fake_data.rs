@@ -0,0 +1,104 @@
+#![allow(non_upper_case_globals)]
+
+#[cfg(feature = "fake")]
+mod fake {
+    use polars_tools::fake::rand::rngs::StdRng;
+    use polars_tools::fake::rand::SeedableRng;
+    use polars_tools::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Plan {
+        Free,
+        Pro,
+    }
+
+    impl ValidatableEnum for Plan {
+        fn valid_values() -> Vec<&'static str> {
+            vec!["Free", "Pro"]
+        }
+
+        fn from_str(value: &str) -> Result<Self> {
+            match value {
+                "Free" => Ok(Plan::Free),
+                "Pro" => Ok(Plan::Pro),
+                _ => Err(ValidationError::InvalidEnumValue {
+                    field: "Plan".to_string(),
+                    value: value.to_string(),
+                    valid_values: Self::valid_values().into_iter().map(|s| s.to_string()).collect(),
+                }),
+            }
+        }
+
+        fn to_str(&self) -> &'static str {
+            match self {
+                Plan::Free => "Free",
+                Plan::Pro => "Pro",
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, PolarsSchema)]
+    #[allow(dead_code, non_upper_case_globals)]
+    struct Subscriber {
+        name: String,
+        #[polars(min = 18, max = 99)]
+        age: i32,
+        score: Option<f64>,
+        plan: Plan,
+    }
+
+    #[test]
+    fn test_fake_df_matches_schema() {
+        let df = Subscriber::fake_df(50).unwrap();
+
+        assert_eq!(df.height(), 50);
+        assert!(Subscriber::validate_strict(&df).is_ok());
+    }
+
+    #[test]
+    fn test_fake_rows_respect_bounds_and_enum_values() {
+        let rows = Subscriber::fake_rows(200);
+
+        assert!(rows.iter().all(|r| (18..=99).contains(&r.age)));
+        assert!(rows.iter().any(|r| r.plan == Plan::Pro));
+        assert!(rows.iter().any(|r| r.plan == Plan::Free));
+        assert!(rows.iter().any(|r| r.score.is_none()));
+    }
+
+    #[test]
+    fn test_seeded_generation_is_reproducible() {
+        let first = Subscriber::fake_rows_with(&mut StdRng::seed_from_u64(7), 10);
+        let second = Subscriber::fake_rows_with(&mut StdRng::seed_from_u64(7), 10);
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_fake_df_values_follow_rows() {
+        let rows = Subscriber::fake_rows_with(&mut StdRng::seed_from_u64(3), 5);
+        let df = Subscriber::fake_df_with(&mut StdRng::seed_from_u64(3), 5).unwrap();
+
+        assert_eq!(Subscriber::age_at(&df, 4).unwrap(), Some(rows[4].age));
+        assert_eq!(Subscriber::plan_at(&df, 0).unwrap(), Some(rows[0].plan.clone()));
+    }
+
+    #[derive(Debug, Clone, PartialEq, PolarsSchema)]
+    #[allow(dead_code, non_upper_case_globals)]
+    struct Device {
+        #[polars(min = 1, max = 9)]
+        id: i64,
+        #[polars(default = 0)]
+        retries: u32,
+        #[polars(default = "eu")]
+        region: String,
+    }
+
+    #[test]
+    fn test_fake_rows_use_defaults() {
+        let rows = Device::fake_rows(20);
+
+        assert!(rows.iter().all(|r| r.retries == 0));
+        assert!(rows.iter().all(|r| r.region == "eu"));
+        assert!(Device::validate(&Device::fake_df(5).unwrap()).is_ok());
+    }
+}
@@ -0,0 +1,69 @@
+use polars_tools::*;
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Reading {
+    sensor: String,
+    #[polars(fill_null = "zero")]
+    errors: Option<i64>,
+    #[polars(fill_null = "mean")]
+    temperature: Option<f64>,
+    #[polars(fill_null = "forward")]
+    status: Option<String>,
+    #[polars(fill_null = "unknown")]
+    location: Option<String>,
+    #[polars(fill_null = -1)]
+    battery: Option<i32>,
+    note: Option<String>,
+}
+
+#[derive(PolarsColumns)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Plain {
+    value: Option<f64>,
+}
+
+fn readings() -> DataFrame {
+    df![
+        "sensor" => ["a", "b", "c"],
+        "errors" => [Some(2i64), None, Some(1)],
+        "temperature" => [Some(10.0), None, Some(20.0)],
+        "status" => [Some("ok"), None, Some("down")],
+        "location" => [None, Some("roof"), None],
+        "battery" => [Some(80i32), None, Some(60)],
+        "note" => [None::<&str>, None, None],
+    ]
+    .unwrap()
+}
+
+#[test]
+fn test_fill_nulls() {
+    let df = Reading::fill_nulls(&readings()).unwrap();
+    assert!(Reading::validate_strict(&df).is_ok());
+    assert_eq!(df.column(Reading::errors).unwrap().i64().unwrap().get(1), Some(0));
+    assert_eq!(
+        df.column(Reading::temperature).unwrap().f64().unwrap().get(1),
+        Some(15.0)
+    );
+    assert_eq!(df.column(Reading::status).unwrap().str().unwrap().get(1), Some("ok"));
+    assert_eq!(
+        df.column(Reading::location).unwrap().str().unwrap().get(0),
+        Some("unknown")
+    );
+    assert_eq!(df.column(Reading::battery).unwrap().i32().unwrap().get(1), Some(-1));
+    assert_eq!(df.column(Reading::note).unwrap().null_count(), 3);
+}
+
+#[test]
+fn test_fill_nulls_lazy() {
+    assert_eq!(Reading::fill_null_exprs().len(), 5);
+    let df = Reading::fill_nulls_lazy(readings().lazy())
+        .filter(Reading::expr.errors().eq(lit(0i64)))
+        .collect()
+        .unwrap();
+    assert_eq!(df.height(), 1);
+
+    assert!(Plain::fill_null_exprs().is_empty());
+    let plain = df!["value" => [None::<f64>]].unwrap();
+    assert_eq!(Plain::fill_nulls(&plain).unwrap(), plain);
+}
@@ -0,0 +1,78 @@
+#![allow(non_upper_case_globals)]
+use polars_tools::*;
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Customer {
+    name: String,
+    age: i64,
+    is_active: bool,
+    score: Option<f64>,
+}
+
+fn customers() -> DataFrame {
+    df![
+        "name" => ["Alice", "Bob", "Carol", "Dave"],
+        "age" => [34i64, 25, 41, 19],
+        "is_active" => [true, false, true, true],
+        "score" => [Some(7.5), None, Some(3.0), Some(9.0)],
+    ]
+    .unwrap()
+}
+
+fn names(predicate: Expr) -> Vec<String> {
+    let df = customers().lazy().filter(predicate).collect().unwrap();
+    df.column("name")
+        .unwrap()
+        .str()
+        .unwrap()
+        .into_no_null_iter()
+        .map(str::to_string)
+        .collect()
+}
+
+#[test]
+fn test_conditions_are_anded() {
+    let predicate = Customer::filter().age().gt(30).is_active().eq(true).build();
+    assert_eq!(names(predicate), ["Alice", "Carol"]);
+}
+
+#[test]
+fn test_or_composition() {
+    let predicate = Customer::filter()
+        .age()
+        .lt(20)
+        .or()
+        .name()
+        .eq("Bob")
+        .build();
+    assert_eq!(names(predicate), ["Bob", "Dave"]);
+}
+
+#[test]
+fn test_or_then_and_is_left_associative() {
+    let predicate = Customer::filter()
+        .age()
+        .lt(20)
+        .or()
+        .age()
+        .gt(40)
+        .score()
+        .gt_eq(5.0f64)
+        .build();
+    assert_eq!(names(predicate), ["Dave"]);
+}
+
+#[test]
+fn test_null_checks() {
+    assert_eq!(names(Customer::filter().score().is_null().build()), ["Bob"]);
+    assert_eq!(
+        names(Customer::filter().score().is_not_null().build()).len(),
+        3
+    );
+}
+
+#[test]
+fn test_empty_filter_keeps_all_rows() {
+    assert_eq!(names(Customer::filter().build()).len(), 4);
+}
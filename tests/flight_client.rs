@@ -0,0 +1,100 @@
+#![allow(non_upper_case_globals)]
+
+#[cfg(feature = "flight")]
+mod flight_client {
+    use std::collections::HashMap;
+
+    use polars_tools::flight::{decode_flight, encode_flight, FlightClient, FlightData};
+    use polars_tools::*;
+
+    #[derive(PolarsSchema)]
+    #[allow(dead_code, non_upper_case_globals)]
+    struct Trade {
+        id: i64,
+        symbol: String,
+        price: Option<f64>,
+        tags: Vec<String>,
+    }
+
+    /// Serves back whatever was put, keyed by the ticket of the last put
+    #[derive(Default)]
+    struct MemoryClient {
+        streams: HashMap<Vec<u8>, Vec<FlightData>>,
+    }
+
+    impl FlightClient for MemoryClient {
+        fn do_get(&mut self, ticket: &[u8]) -> Result<Vec<FlightData>> {
+            self.streams
+                .get(ticket)
+                .cloned()
+                .ok_or_else(|| ValidationError::Flight("unknown ticket".to_string()))
+        }
+
+        fn do_put(&mut self, data: Vec<FlightData>) -> Result<()> {
+            self.streams.insert(b"trades".to_vec(), data);
+            Ok(())
+        }
+    }
+
+    fn trades() -> DataFrame {
+        df![
+            "id" => [1i64, 2, 3],
+            "symbol" => ["AAPL", "MSFT", "GOOG"],
+            "price" => [Some(190.5), None, Some(140.25)],
+            "tags" => [
+                Series::new("".into(), ["tech"]),
+                Series::new_empty("".into(), &DataType::String),
+                Series::new("".into(), ["tech", "ads"]),
+            ],
+            "desk" => ["a", "b", "c"],
+        ]
+        .unwrap()
+    }
+
+    #[test]
+    fn test_put_then_get() {
+        let mut client = MemoryClient::default();
+        Trade::do_put(&mut client, &trades()).unwrap();
+        let df = Trade::do_get(&mut client, b"trades").unwrap();
+
+        assert_eq!(df.get_column_names(), ["id", "symbol", "price", "tags"]);
+        assert!(df.equals_missing(&trades().drop("desk").unwrap()));
+    }
+
+    #[test]
+    fn test_messages_schema_first() {
+        let data = encode_flight(&trades(), Trade::polars_schema()).unwrap();
+
+        assert!(data.len() >= 2);
+        assert!(data[0].data_body.is_empty());
+        assert!(data[1..]
+            .iter()
+            .all(|message| !message.data_body.is_empty()));
+        let df = decode_flight(&data).unwrap();
+        assert_eq!(df.height(), 3);
+    }
+
+    #[test]
+    fn test_do_put_validates() {
+        let mut client = MemoryClient::default();
+        let df = trades().drop("price").unwrap();
+        assert!(Trade::do_put(&mut client, &df).is_err());
+        assert!(client.streams.is_empty());
+    }
+
+    #[test]
+    fn test_do_get_validates() {
+        #[derive(PolarsSchema)]
+        #[allow(dead_code, non_upper_case_globals)]
+        struct Quote {
+            id: i64,
+            bid: f64,
+        }
+
+        let mut client = MemoryClient::default();
+        Trade::do_put(&mut client, &trades()).unwrap();
+        let err = Quote::do_get(&mut client, b"trades").unwrap_err();
+        assert!(matches!(err, ValidationError::MissingColumn { .. }));
+        assert!(Trade::do_get(&mut client, b"other").is_err());
+    }
+}
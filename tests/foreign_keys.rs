@@ -0,0 +1,114 @@
+use polars_tools::*;
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Customer {
+    #[polars(key)]
+    id: i64,
+    name: String,
+}
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Order {
+    id: i64,
+    #[polars(references(Customer::id))]
+    customer_id: Option<i64>,
+    total: f64,
+}
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Country {
+    code: String,
+}
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Shipment {
+    #[polars(references(Customer::id))]
+    customer_id: i64,
+    #[polars(references(Country::code))]
+    country: String,
+}
+
+fn customers() -> DataFrame {
+    df![
+        "id" => [1i64, 2, 3],
+        "name" => ["ada", "bob", "cy"],
+    ]
+    .unwrap()
+}
+
+#[test]
+fn test_valid_references() {
+    let orders = df![
+        "id" => [10i64, 11, 12],
+        "customer_id" => [Some(1i64), None, Some(3)],
+        "total" => [1.0, 2.0, 3.0],
+    ]
+    .unwrap();
+    Order::validate_references(&orders, &customers()).unwrap();
+    Order::validate_customer_id_references(&orders, &customers()).unwrap();
+}
+
+#[test]
+fn test_orphaned_references() {
+    let orders = df![
+        "id" => [10i64, 11, 12, 13, 14],
+        "customer_id" => [Some(1i64), Some(7), Some(9), Some(7), None],
+        "total" => [1.0, 2.0, 3.0, 4.0, 5.0],
+    ]
+    .unwrap();
+    let err = Order::validate_references(&orders, &customers()).unwrap_err();
+    assert_eq!(err.code(), "PT0019");
+    match &err {
+        ValidationError::OrphanedReferences {
+            column_name,
+            referenced,
+            rows,
+            values,
+            sample,
+        } => {
+            assert_eq!(column_name, "customer_id");
+            assert_eq!(referenced, "id");
+            assert_eq!(*rows, 3);
+            assert_eq!(*values, 2);
+            assert_eq!(sample, &["7", "9"]);
+        }
+        other => panic!("unexpected error: {other}"),
+    }
+    assert_eq!(
+        err.to_string(),
+        "Column 'customer_id' has 3 rows with 2 values missing from the referenced column 'id', e.g. 7, 9"
+    );
+}
+
+#[test]
+fn test_references_validate_both_frames() {
+    let orders = df![
+        "id" => [10i64],
+        "customer_id" => [1i64],
+        "total" => [1.0],
+    ]
+    .unwrap();
+    let unnamed = customers().drop("name").unwrap();
+    let err = Order::validate_references(&orders, &unnamed).unwrap_err();
+    assert!(matches!(err, ValidationError::MissingColumn { .. }));
+}
+
+#[test]
+fn test_references_to_several_schemas() {
+    let shipments = df![
+        "customer_id" => [1i64, 2],
+        "country" => ["nl", "xx"],
+    ]
+    .unwrap();
+    let countries = df!["code" => ["nl", "de"]].unwrap();
+    Shipment::validate_customer_id_references(&shipments, &customers()).unwrap();
+    let err = Shipment::validate_country_references(&shipments, &countries).unwrap_err();
+    assert!(matches!(
+        err,
+        ValidationError::OrphanedReferences { ref sample, .. } if sample == &["\"xx\""]
+    ));
+}
@@ -0,0 +1,70 @@
+use polars_tools::*;
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Visit {
+    page: String,
+    ms: i64,
+    referrer: Option<String>,
+}
+
+fn visits(rows: i64) -> DataFrame {
+    let pages: Vec<String> = (0..rows).map(|i| format!("/page/{}", i)).collect();
+    let referrers: Vec<Option<&str>> = (0..rows)
+        .map(|i| if i % 2 == 0 { Some("search") } else { None })
+        .collect();
+    df![
+        "session" => (0..rows).collect::<Vec<_>>(),
+        "page" => pages,
+        "ms" => (0..rows).map(|i| i * 100).collect::<Vec<_>>(),
+        "referrer" => referrers,
+    ]
+    .unwrap()
+}
+
+#[test]
+fn test_preview_first_and_last_rows() {
+    assert_eq!(
+        Visit::preview(&visits(10), 2).unwrap(),
+        "Visit: 10 rows, first and last 2\n\
+         page     ms   referrer\n\
+         str      i64  str\n\
+         /page/0  0    search\n\
+         /page/1  100  null\n\
+         …        …    …\n\
+         /page/8  800  search\n\
+         /page/9  900  null"
+    );
+}
+
+#[test]
+fn test_preview_short_frame() {
+    assert_eq!(
+        Visit::preview(&visits(3), 2).unwrap(),
+        "Visit: 3 rows\n\
+         page     ms   referrer\n\
+         str      i64  str\n\
+         /page/0  0    search\n\
+         /page/1  100  null\n\
+         /page/2  200  search"
+    );
+}
+
+#[test]
+fn test_preview_truncates_long_cells() {
+    let mut df = visits(1);
+    df.with_column(Column::new("page".into(), ["/".repeat(40)]))
+        .unwrap();
+    let preview = Visit::preview(&df, 5).unwrap();
+    let cell = preview.lines().nth(3).unwrap().split("  ").next().unwrap();
+    assert_eq!(cell, format!("{}…", "/".repeat(31)));
+}
+
+#[test]
+fn test_preview_validates() {
+    let df = visits(2).drop("ms").unwrap();
+    assert!(matches!(
+        Visit::preview(&df, 2),
+        Err(ValidationError::MissingColumn { .. })
+    ));
+}
@@ -0,0 +1,64 @@
+#![allow(non_upper_case_globals)]
+use polars_tools::*;
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Sale {
+    country: String,
+    units: i64,
+    price: f64,
+    note: Option<String>,
+}
+
+fn sales() -> DataFrame {
+    df![
+        "country" => ["DE", "FR", "DE", "FR"],
+        "units" => [1i64, 2, 3, 4],
+        "price" => [10.0, 20.0, 30.0, 40.0],
+        "note" => [Some("a"), None, None, Some("d")],
+    ]
+    .unwrap()
+}
+
+fn grouped(aggs: Vec<Expr>) -> DataFrame {
+    Sale::group_by(sales(), [Sale::country])
+        .agg(aggs)
+        .sort([Sale::country], SortMultipleOptions::default())
+        .collect()
+        .unwrap()
+}
+
+#[test]
+fn test_numeric_sums() {
+    let df = grouped(Sale::agg_numeric_sums());
+
+    assert_eq!(df.get_column_names(), &["country", "units_sum", "price_sum"]);
+    let units: Vec<_> = df.column("units_sum").unwrap().i64().unwrap().into_no_null_iter().collect();
+    assert_eq!(units, [4, 6]);
+}
+
+#[test]
+fn test_numeric_means() {
+    let df = grouped(Sale::agg_numeric_means());
+
+    let prices: Vec<_> = df.column("price_mean").unwrap().f64().unwrap().into_no_null_iter().collect();
+    assert_eq!(prices, [20.0, 30.0]);
+}
+
+#[test]
+fn test_counts_cover_every_field() {
+    let df = grouped(Sale::agg_counts());
+
+    assert_eq!(df.width(), 5);
+    let notes: Vec<_> = df.column("note_count").unwrap().u32().unwrap().into_no_null_iter().collect();
+    assert_eq!(notes, [1, 1]);
+}
+
+#[test]
+fn test_presets_combine_with_custom_aggregations() {
+    let mut aggs = Sale::agg_numeric_sums();
+    aggs.push(Sale::price_col().max().alias("max_price"));
+    let df = grouped(aggs);
+
+    assert_eq!(df.width(), 4);
+}
@@ -0,0 +1,108 @@
+#![allow(non_upper_case_globals)]
+
+#[cfg(feature = "serde")]
+mod json_rows {
+    use polars_tools::*;
+    use serde_json::{json, Value};
+
+    #[derive(PolarsSchema)]
+    #[allow(dead_code, non_upper_case_globals)]
+    struct Event {
+        id: i64,
+        name: Option<String>,
+        score: f64,
+        #[polars(dtype = DataType::Date, format = "%d/%m/%Y")]
+        day: i32,
+        #[polars(dtype = DataType::Datetime(TimeUnit::Milliseconds, None))]
+        at: i64,
+        tags: Vec<String>,
+    }
+
+    const ROWS: &str = r#"[
+        {"id": 1, "name": "launch", "score": 0.5, "day": "02/01/2024",
+         "at": "2024-01-02T10:30:00", "tags": ["a", "b"]},
+        {"id": 2, "score": 2, "day": "03/01/2024",
+         "at": "2024-01-03T00:00:00.250", "tags": [], "ignored": true}
+    ]"#;
+
+    #[test]
+    fn test_from_json_rows() {
+        let df = Event::from_json_rows(ROWS).unwrap();
+        assert_eq!(df.shape(), (2, 6));
+        assert_eq!(&**df.schema(), Event::polars_schema());
+        assert_eq!(df.column("name").unwrap().str().unwrap().get(1), None);
+        assert_eq!(df.column("score").unwrap().f64().unwrap().get(1), Some(2.0));
+        let day = df.column("day").unwrap().cast(&DataType::String).unwrap();
+        assert_eq!(day.str().unwrap().get(0), Some("2024-01-02"));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let df = Event::from_json_rows(ROWS).unwrap();
+        let json = Event::to_json_rows(&df).unwrap();
+        let rows: Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            rows[0],
+            json!({
+                "id": 1,
+                "name": "launch",
+                "score": 0.5,
+                "day": "02/01/2024",
+                "at": "2024-01-02T10:30:00",
+                "tags": ["a", "b"],
+            })
+        );
+        assert_eq!(rows[1]["name"], Value::Null);
+        assert_eq!(rows[1]["at"], "2024-01-03T00:00:00.250");
+        assert!(Event::from_json_rows(&json).unwrap().equals_missing(&df));
+    }
+
+    #[test]
+    fn test_invalid_rows() {
+        assert!(matches!(
+            Event::from_json_rows("{\"id\": 1}"),
+            Err(ValidationError::Json(_))
+        ));
+        let wrong_type = r#"[{"id": "x", "score": 1.0, "day": "02/01/2024",
+            "at": "2024-01-02T10:30:00", "tags": []}]"#;
+        assert!(Event::from_json_rows(wrong_type).is_err());
+        let bad_date = r#"[{"id": 1, "score": 1.0, "day": "2024-01-02",
+            "at": "2024-01-02T10:30:00", "tags": []}]"#;
+        assert!(Event::from_json_rows(bad_date).is_err());
+    }
+
+    #[test]
+    fn test_to_json_rows_validates() {
+        let df = df!["id" => [1i64]].unwrap();
+        assert!(matches!(
+            Event::to_json_rows(&df),
+            Err(ValidationError::MissingColumn { .. })
+        ));
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    #[test]
+    fn test_zoned_datetimes() {
+        #[derive(PolarsSchema)]
+        #[allow(dead_code, non_upper_case_globals)]
+        struct Login {
+            #[polars(
+                dtype = DataType::Datetime(TimeUnit::Milliseconds, Some("Europe/Berlin".into())),
+                format = "%Y-%m-%dT%H:%M:%S%:z"
+            )]
+            local: i64,
+            #[polars(dtype = DataType::Datetime(TimeUnit::Milliseconds, Some("Europe/Berlin".into())))]
+            utc: i64,
+        }
+
+        let df = Login::from_json_rows(
+            r#"[{"local": "2024-01-02T10:30:00+00:00", "utc": "2024-01-02T10:30:00"}]"#,
+        )
+        .unwrap();
+        let rows: Value = serde_json::from_str(&Login::to_json_rows(&df).unwrap()).unwrap();
+        assert_eq!(
+            rows,
+            json!([{"local": "2024-01-02T11:30:00+01:00", "utc": "2024-01-02T10:30:00"}])
+        );
+    }
+}
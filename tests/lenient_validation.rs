@@ -0,0 +1,61 @@
+use polars_tools::*;
+
+#[derive(Debug, PartialEq, PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Contact {
+    id: i64,
+    email: Option<String>,
+    score: Option<f64>,
+}
+
+#[derive(PolarsColumns)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Visit {
+    url: String,
+    referrer: Option<String>,
+}
+
+fn sparse() -> DataFrame {
+    df!["id" => [1i64, 2], "score" => [Some(0.5), None]].unwrap()
+}
+
+#[test]
+fn test_validate_lenient_allows_absent_options() {
+    assert!(Contact::validate(&sparse()).is_err());
+    Contact::validate_lenient(&sparse()).unwrap();
+
+    let err = Contact::validate_lenient(&sparse().drop("id").unwrap()).unwrap_err();
+    assert!(
+        matches!(err, ValidationError::MissingColumn { column_name, .. } if column_name == "id")
+    );
+
+    Visit::validate_lenient(&df!["url" => ["/"]].unwrap()).unwrap();
+    assert!(Visit::validate_lenient(&df!["referrer" => ["/"]].unwrap()).is_err());
+}
+
+#[test]
+fn test_present_options_are_still_checked() {
+    let df = sparse()
+        .hstack(&[Column::new("email".into(), [1i64, 2])])
+        .unwrap();
+    let err = Contact::validate_lenient(&df).unwrap_err();
+    assert!(matches!(err, ValidationError::TypeMismatch { .. }));
+}
+
+#[test]
+fn test_conform_lenient_adds_null_columns() {
+    let (df, report) = Contact::conform_lenient(&sparse()).unwrap();
+
+    Contact::validate(&df).unwrap();
+    assert_eq!(report.added, ["email"]);
+    assert_eq!(df.column("email").unwrap().dtype(), &DataType::String);
+    assert_eq!(df.column("email").unwrap().null_count(), 2);
+    assert_eq!(
+        Contact::from_df(&df).unwrap()[0],
+        Contact {
+            id: 1,
+            email: None,
+            score: Some(0.5)
+        }
+    );
+}
@@ -0,0 +1,135 @@
+use polars_tools::*;
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Order {
+    id: i64,
+    qty: i32,
+    unit_price: f64,
+    coupon: Option<String>,
+}
+
+#[derive(Debug, PartialEq, PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Invoice {
+    order_id: i64,
+    total: f64,
+    discounted: bool,
+}
+
+fn orders() -> DataFrame {
+    df![
+        "id" => [1i64, 2, 3],
+        "qty" => [2i32, 1, 5],
+        "unit_price" => [10.0, 4.5, 2.0],
+        "coupon" => [None, Some("HALF"), None],
+    ]
+    .unwrap()
+}
+
+fn invoice(order: Order) -> Invoice {
+    let total = order.qty as f64 * order.unit_price;
+    Invoice {
+        order_id: order.id,
+        total: if order.coupon.is_some() { total / 2.0 } else { total },
+        discounted: order.coupon.is_some(),
+    }
+}
+
+#[test]
+fn test_map_rows_builds_the_output_schema() {
+    let df = Order::map_rows(&orders(), invoice).unwrap();
+    Invoice::validate(&df).unwrap();
+    assert_eq!(
+        Invoice::from_df(&df).unwrap(),
+        [
+            Invoice {
+                order_id: 1,
+                total: 20.0,
+                discounted: false
+            },
+            Invoice {
+                order_id: 2,
+                total: 2.25,
+                discounted: true
+            },
+            Invoice {
+                order_id: 3,
+                total: 10.0,
+                discounted: false
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_map_rows_validates_the_input() {
+    let df = orders().drop("qty").unwrap();
+    let err = Order::map_rows(&df, invoice).unwrap_err();
+    assert!(matches!(err, ValidationError::MissingColumn { .. }));
+}
+
+#[test]
+fn test_map_rows_of_an_empty_frame() {
+    let df = Order::map_rows(&orders().head(Some(0)), invoice).unwrap();
+    assert_eq!(df.height(), 0);
+    assert_eq!(df.schema().as_ref(), Invoice::polars_schema());
+}
+
+fn to_df_generic<T: PolarsRows>(rows: &[T]) -> Result<DataFrame> {
+    T::to_df(rows)
+}
+
+#[test]
+fn test_polars_rows_is_generic() {
+    let mut seen = 0;
+    let df = Order::map_rows(&orders(), |order| {
+        seen += 1;
+        invoice(order)
+    })
+    .unwrap();
+    assert_eq!(seen, 3);
+    let invoices = <Invoice as PolarsRows>::from_df(&df).unwrap();
+    assert!(to_df_generic(&invoices).unwrap().equals(&df));
+}
+
+#[cfg(feature = "rayon")]
+mod par_map_rows {
+    use super::*;
+
+    fn many_orders(n: i64) -> DataFrame {
+        df![
+            "id" => (0..n).collect::<Vec<_>>(),
+            "qty" => (0..n).map(|i| (i % 7) as i32).collect::<Vec<_>>(),
+            "unit_price" => (0..n).map(|i| i as f64 / 4.0).collect::<Vec<_>>(),
+            "coupon" => (0..n).map(|i| (i % 3 == 0).then_some("THIRD")).collect::<Vec<_>>(),
+        ]
+        .unwrap()
+    }
+
+    #[test]
+    fn test_par_map_rows_matches_map_rows() {
+        let orders = many_orders(10_001);
+        let parallel = Order::par_map_rows(&orders, invoice).unwrap();
+        let sequential = Order::map_rows(&orders, invoice).unwrap();
+        assert!(parallel.equals(&sequential));
+        assert_eq!(parallel.get_columns()[0].n_chunks(), 1);
+    }
+
+    #[test]
+    fn test_par_map_rows_of_small_and_empty_frames() {
+        let df = Order::par_map_rows(&orders(), invoice).unwrap();
+        assert_eq!(Invoice::from_df(&df).unwrap()[1].total, 2.25);
+
+        let df = Order::par_map_rows(&orders().head(Some(0)), invoice).unwrap();
+        assert_eq!(df.height(), 0);
+        assert_eq!(df.schema().as_ref(), Invoice::polars_schema());
+    }
+
+    #[test]
+    fn test_par_map_rows_validates_the_input() {
+        let df = orders().drop("coupon").unwrap();
+        let err = Order::par_map_rows(&df, invoice).unwrap_err();
+        assert!(matches!(err, ValidationError::MissingColumn { .. }));
+    }
+}
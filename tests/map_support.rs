@@ -0,0 +1,53 @@
+#![allow(non_upper_case_globals)]
+use polars_tools::*;
+use std::collections::{BTreeMap, HashMap};
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct FeatureRow {
+    entity_id: i64,
+    features: HashMap<String, f64>,
+    labels: Option<BTreeMap<String, String>>,
+    #[polars(struct_keys("open", "close"))]
+    prices: HashMap<String, f64>,
+}
+
+fn entries(key: DataType, value: DataType) -> DataType {
+    DataType::List(Box::new(DataType::Struct(vec![
+        Field::new("key".into(), key),
+        Field::new("value".into(), value),
+    ])))
+}
+
+#[test]
+fn test_map_fields_become_key_value_lists() {
+    assert_eq!(
+        FeatureRow::features_type,
+        entries(DataType::String, DataType::Float64)
+    );
+    assert_eq!(
+        FeatureRow::labels_type,
+        entries(DataType::String, DataType::String)
+    );
+}
+
+#[test]
+fn test_struct_keys_attribute() {
+    assert_eq!(
+        FeatureRow::prices_type,
+        DataType::Struct(vec![
+            Field::new("open".into(), DataType::Float64),
+            Field::new("close".into(), DataType::Float64),
+        ])
+    );
+}
+
+#[test]
+fn test_map_fields_empty_dataframe() {
+    let empty_df = FeatureRow::df().unwrap();
+    assert_eq!(
+        empty_df.schema().get("features"),
+        Some(&entries(DataType::String, DataType::Float64))
+    );
+    assert!(FeatureRow::validate_strict(&empty_df).is_ok());
+}
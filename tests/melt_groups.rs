@@ -0,0 +1,65 @@
+use polars_tools::*;
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Reading {
+    #[polars(group = "keys", tag = "id")]
+    station: String,
+    #[polars(group = "keys", tag = "id")]
+    day: i32,
+    #[polars(group = "measures", tag = "metric")]
+    temperature: f64,
+    #[polars(group = "measures", tag = "metric")]
+    rainfall: f32,
+    note: Option<String>,
+}
+
+fn readings() -> DataFrame {
+    df![
+        "station" => ["ams", "rtm"],
+        "day" => [1i32, 1],
+        "temperature" => [11.5, 12.0],
+        "rainfall" => [0.5f32, 0.0],
+        "note" => [None::<&str>, Some("windy")],
+    ]
+    .unwrap()
+}
+
+#[test]
+fn test_melt_groups() {
+    let df = Reading::melt(&readings(), "keys", "measures").unwrap();
+    assert_eq!(df.get_column_names(), ["station", "day", "variable", "value"]);
+    assert_eq!(df.height(), 4);
+    assert_eq!(df.column(MELT_VALUE).unwrap().dtype(), &DataType::Float64);
+
+    let schema = Reading::melt_schema("keys", "measures").unwrap();
+    assert_eq!(df.schema().as_ref(), &schema);
+
+    let variables = df.column(MELT_VARIABLE).unwrap().str().unwrap();
+    assert_eq!(
+        variables.into_iter().collect::<Vec<_>>(),
+        [Some("temperature"), Some("temperature"), Some("rainfall"), Some("rainfall")]
+    );
+}
+
+#[test]
+fn test_melt_by_tags() {
+    let df = Reading::melt_columns(
+        &readings(),
+        &Reading::columns_tagged("id"),
+        &Reading::columns_tagged("metric"),
+    )
+    .unwrap();
+    assert_eq!(df.width(), 4);
+}
+
+#[test]
+fn test_melt_unknown_group() {
+    let err = Reading::melt(&readings(), "keys", "metrics").unwrap_err();
+    assert_eq!(err.code(), "PT0017");
+    assert!(matches!(
+        err,
+        ValidationError::UnknownGroup { ref group, ref groups }
+            if group == "metrics" && groups == &["keys", "measures"]
+    ));
+}
@@ -0,0 +1,123 @@
+#![allow(non_upper_case_globals)]
+use polars_tools::*;
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct CustomerV1 {
+    id: i32,
+    full_name: String,
+    fax: Option<String>,
+}
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct CustomerV2 {
+    id: i64,
+    #[polars(renamed_from = "full_name")]
+    name: String,
+    email: Option<String>,
+}
+
+fn v1() -> DataFrame {
+    df![
+        "id" => [1i32, 2],
+        "full_name" => ["Ada", "Grace"],
+        "fax" => [None::<&str>, Some("555")],
+    ]
+    .unwrap()
+}
+
+#[test]
+fn test_migrate_from_old_schema() {
+    let (df, report) = CustomerV2::migrate_from::<CustomerV1>(&v1()).unwrap();
+
+    assert!(CustomerV2::validate_strict(&df).is_ok());
+    assert_eq!(df.get_column_names(), ["id", "name", "email"]);
+    assert_eq!(
+        df.column(CustomerV2::name).unwrap().str().unwrap().get(1),
+        Some("Grace")
+    );
+
+    assert_eq!(
+        report.renamed,
+        [("full_name".to_string(), "name".to_string())]
+    );
+    assert_eq!(
+        report.cast,
+        [ColumnCast {
+            column: "id".to_string(),
+            from: DataType::Int32,
+            to: DataType::Int64,
+        }]
+    );
+    assert_eq!(report.added, ["email"]);
+    assert_eq!(report.dropped, ["fax"]);
+    assert_eq!(
+        report.to_string(),
+        "renamed full_name -> name\ncast id: i32 -> i64\nadded email\ndropped fax"
+    );
+}
+
+#[test]
+fn test_migrate_to_same_schema_is_empty() {
+    let (df, report) = CustomerV1::migrate_from::<CustomerV1>(&v1()).unwrap();
+
+    assert!(report.is_empty());
+    assert_eq!(report.to_string(), "no changes");
+    assert!(df.equals_missing(&v1()));
+}
+
+#[test]
+fn test_migrate_rejects_frame_invalid_under_old_schema() {
+    let df = df!["id" => [1i32]].unwrap();
+    let result = CustomerV2::migrate_from::<CustomerV1>(&df);
+
+    assert!(matches!(result, Err(ValidationError::MissingColumn { .. })));
+}
+
+fn default_region() -> &'static str {
+    "eu"
+}
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct CustomerV3 {
+    id: i64,
+    #[polars(renamed_from = "full_name")]
+    name: String,
+    #[polars(default = 3)]
+    tier: u32,
+    #[polars(default = "unknown")]
+    email: Option<String>,
+    #[polars(default_fn = "default_region")]
+    region: String,
+}
+
+#[test]
+fn test_migrate_fills_defaults() {
+    let (df, report) = CustomerV3::migrate_from::<CustomerV1>(&v1()).unwrap();
+
+    assert!(CustomerV3::validate_strict(&df).is_ok());
+    assert_eq!(report.added, ["tier", "email", "region"]);
+    assert_eq!(
+        df.column(CustomerV3::tier).unwrap().u32().unwrap().to_vec(),
+        [Some(3), Some(3)]
+    );
+    assert_eq!(
+        df.column(CustomerV3::email).unwrap().str().unwrap().get(1),
+        Some("unknown")
+    );
+    assert_eq!(
+        df.column(CustomerV3::region).unwrap().str().unwrap().get(0),
+        Some("eu")
+    );
+}
+
+#[test]
+fn test_column_defaults() {
+    let defaults = <CustomerV3 as PolarsSchemaInfo>::column_defaults();
+    let columns: Vec<_> = defaults.iter().map(|(column, _)| *column).collect();
+
+    assert_eq!(columns, ["tier", "email", "region"]);
+    assert!(<CustomerV1 as PolarsSchemaInfo>::column_defaults().is_empty());
+}
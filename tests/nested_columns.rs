@@ -0,0 +1,67 @@
+use polars_tools::*;
+use std::collections::HashMap;
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Listing {
+    id: i64,
+    tags: Vec<String>,
+    #[polars(struct_keys("open", "close"))]
+    prices: HashMap<String, f64>,
+    photo: Vec<u8>,
+}
+
+fn listings() -> DataFrame {
+    let tags = Series::new(
+        "tags".into(),
+        [
+            Series::new("".into(), ["garden", "quiet"]),
+            Series::new("".into(), ["central"]),
+        ],
+    );
+    let open = Series::new("open".into(), [1.5, 2.0]);
+    let close = Series::new("close".into(), [1.75, 1.9]);
+    let prices = StructChunked::from_series("prices".into(), 2, [open, close].iter())
+        .unwrap()
+        .into_series();
+    let photo = Series::new("photo".into(), [&b"\x89PNG"[..], &b""[..]]);
+    DataFrame::new(vec![
+        Series::new("id".into(), [1i64, 2]).into(),
+        tags.into(),
+        prices.into(),
+        photo.into(),
+    ])
+    .unwrap()
+}
+
+#[test]
+fn test_explode_list_field() {
+    assert!(Listing::validate_strict(&listings()).is_ok());
+    let df = Listing::explode_tags(&listings()).unwrap();
+    assert_eq!(df.height(), 3);
+    assert_eq!(df.column(Listing::tags).unwrap().dtype(), &DataType::String);
+
+    let schema = exploded_schema(Listing::polars_schema(), Listing::tags).unwrap();
+    assert!(has_schema(&df, &schema));
+    assert!(exploded_schema(Listing::polars_schema(), Listing::id).is_err());
+}
+
+#[test]
+fn test_unnest_struct_field() {
+    let df = Listing::unnest_prices(&listings()).unwrap();
+    assert_eq!(df.get_column_names(), ["id", "tags", "open", "close", "photo"]);
+    assert_eq!(df.column("close").unwrap().f64().unwrap().get(1), Some(1.9));
+
+    let schema = unnested_schema(Listing::polars_schema(), Listing::prices).unwrap();
+    assert!(has_schema(&df, &schema));
+
+    let invalid = listings().drop(Listing::tags).unwrap();
+    assert!(matches!(
+        Listing::unnest_prices(&invalid),
+        Err(ValidationError::MissingColumn { .. })
+    ));
+}
+
+fn has_schema(df: &DataFrame, schema: &Schema) -> bool {
+    df.schema().as_ref() == schema
+}
@@ -0,0 +1,68 @@
+#![allow(non_upper_case_globals)]
+use polars_tools::*;
+
+// Wrapper deriving the marker
+#[derive(Debug, Clone, PolarsNewtype)]
+struct UserId(i64);
+
+// Wrapper without the marker, exposed through Deref
+#[derive(Debug, Clone)]
+struct Score(f64);
+
+impl std::ops::Deref for Score {
+    type Target = f64;
+
+    fn deref(&self) -> &f64 {
+        &self.0
+    }
+}
+
+// Wrapper with neither, still falls back to String
+#[derive(Debug, Clone)]
+#[allow(dead_code)]
+struct Opaque(i32);
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Player {
+    id: UserId,
+    referrer: Option<UserId>,
+    #[polars(transparent)]
+    score: Score,
+    #[polars(transparent)]
+    best_score: Option<Score>,
+    opaque: Opaque,
+}
+
+#[test]
+fn test_newtype_marker_uses_inner_dtype() {
+    assert_eq!(<UserId as PolarsDType>::dtype(), DataType::Int64);
+    const { assert!(!<UserId as PolarsDType>::NULLABLE) };
+    assert_eq!(Player::id_type, DataType::Int64);
+    assert_eq!(Player::referrer_type, DataType::Int64);
+}
+
+#[test]
+fn test_transparent_attribute_uses_deref_target() {
+    assert_eq!(Player::score_type, DataType::Float64);
+    assert_eq!(Player::best_score_type, DataType::Float64);
+}
+
+#[test]
+fn test_unmarked_wrapper_falls_back_to_string() {
+    assert_eq!(Player::opaque_type, DataType::String);
+}
+
+#[test]
+fn test_newtype_validation() {
+    let df = df![
+        "id" => [1i64, 2],
+        "referrer" => [None, Some(1i64)],
+        "score" => [10.5f64, 7.0],
+        "best_score" => [Some(12.0f64), None],
+        "opaque" => ["a", "b"],
+    ]
+    .unwrap();
+
+    assert!(Player::validate_strict(&df).is_ok());
+}
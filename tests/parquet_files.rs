@@ -0,0 +1,111 @@
+#![allow(non_upper_case_globals)]
+
+#[cfg(feature = "parquet")]
+mod parquet_files {
+    use std::io::Cursor;
+
+    use polars_tools::parquet::{read_schema_metadata, SchemaMetadata};
+    use polars_tools::*;
+
+    #[derive(PolarsSchema)]
+    #[allow(dead_code, non_upper_case_globals)]
+    struct Reading {
+        /// Sensor identifier
+        sensor: String,
+        #[polars(since = "2", doc = "Degrees Celsius")]
+        celsius: f64,
+        at: i64,
+    }
+
+    fn readings() -> DataFrame {
+        df![
+            "sensor" => ["a", "b"],
+            "celsius" => [20.5, 21.0],
+            "at" => [1i64, 2],
+        ]
+        .unwrap()
+    }
+
+    fn plain_parquet(mut df: DataFrame) -> Vec<u8> {
+        let mut file = Vec::new();
+        ParquetWriter::new(&mut file).finish(&mut df).unwrap();
+        file
+    }
+
+    #[test]
+    fn test_schema_metadata_round_trip() {
+        let mut file = Vec::new();
+        Reading::write_parquet(&mut file, &readings()).unwrap();
+
+        let metadata = read_schema_metadata(Cursor::new(&file)).unwrap();
+        assert_eq!(metadata, Reading::parquet_schema_metadata());
+        assert_eq!(metadata.name.as_deref(), Some("Reading"));
+        assert_eq!(metadata.version, Some(2));
+        assert_eq!(metadata.fingerprint, Some(Reading::schema_fingerprint()));
+        assert_eq!(
+            metadata.column_docs,
+            [
+                ("sensor".to_string(), "Sensor identifier".to_string()),
+                ("celsius".to_string(), "Degrees Celsius".to_string()),
+            ]
+        );
+
+        let df = Reading::read_parquet(Cursor::new(&file)).unwrap();
+        assert!(df.equals(&readings()));
+    }
+
+    #[test]
+    fn test_read_parquet_without_metadata() {
+        let mut df = readings();
+        df.with_column(Column::new("extra".into(), [1i32, 2])).unwrap();
+        let file = plain_parquet(df);
+        assert_eq!(
+            read_schema_metadata(Cursor::new(&file)).unwrap(),
+            SchemaMetadata::default()
+        );
+
+        let diff = Reading::compare_parquet(Cursor::new(&file)).unwrap();
+        assert!(diff.is_compatible());
+        assert_eq!(diff.unexpected, ["extra"]);
+
+        let df = Reading::read_parquet(Cursor::new(&file)).unwrap();
+        assert_eq!(df.get_column_names(), ["sensor", "celsius", "at"]);
+    }
+
+    #[test]
+    fn test_read_parquet_schema_mismatch() {
+        let mut df = readings();
+        df.with_column(Column::new("at".into(), [1i32, 2])).unwrap();
+        let file = plain_parquet(df.drop("celsius").unwrap());
+
+        let err = Reading::read_parquet(Cursor::new(&file)).unwrap_err();
+        let ValidationError::SchemaMismatch(diff) = err else {
+            panic!("unexpected error: {err}");
+        };
+        assert_eq!(diff.missing, ["celsius"]);
+        assert_eq!(diff.mismatched.len(), 1);
+        assert_eq!(diff.mismatched[0].column, "at");
+        assert_eq!(diff.mismatched[0].actual, DataType::Int32);
+        assert_eq!(diff, Reading::compare_parquet(Cursor::new(&file)).unwrap());
+    }
+
+    #[test]
+    fn test_metadata_key_values() {
+        let metadata = SchemaMetadata {
+            name: Some("Reading".to_string()),
+            version: Some(3),
+            fingerprint: None,
+            column_docs: vec![("at".to_string(), "Unix seconds".to_string())],
+        };
+        let pairs = metadata.to_key_values();
+        assert_eq!(
+            pairs,
+            [
+                ("polars_tools.schema_name".to_string(), "Reading".to_string()),
+                ("polars_tools.schema_version".to_string(), "3".to_string()),
+                ("polars_tools.doc.at".to_string(), "Unix seconds".to_string()),
+            ]
+        );
+        assert_eq!(SchemaMetadata::from_key_values(&pairs), metadata);
+    }
+}
@@ -0,0 +1,91 @@
+use polars_tools::pipeline::{Boundary, Checks, Pipeline, Transform};
+use polars_tools::*;
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct RawOrder {
+    id: i64,
+    amount: f64,
+}
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct PricedOrder {
+    id: i64,
+    amount: f64,
+    amount_with_tax: f64,
+}
+
+struct AddTax;
+
+impl Transform<RawOrder, PricedOrder> for AddTax {
+    fn name(&self) -> String {
+        "add_tax".to_string()
+    }
+
+    fn apply(&self, df: DataFrame) -> Result<DataFrame> {
+        Ok(df
+            .lazy()
+            .with_column((col(RawOrder::amount) * lit(1.2)).alias(PricedOrder::amount_with_tax))
+            .collect()?)
+    }
+}
+
+fn orders() -> DataFrame {
+    df!["id" => [1i64, 2, 3], "amount" => [10.0, -5.0, 20.0]].unwrap()
+}
+
+fn positive(df: DataFrame) -> Result<DataFrame> {
+    Ok(df.lazy().filter(col("amount").gt(lit(0.0))).collect()?)
+}
+
+#[test]
+fn test_run_through_stages() {
+    let pipeline = Pipeline::<RawOrder>::new().then(positive).then(AddTax);
+    assert_eq!(pipeline.stage_names()[1], "add_tax");
+
+    let df = pipeline.run(orders()).unwrap();
+    assert_eq!(df.height(), 2);
+    assert_eq!(
+        df.column("amount_with_tax").unwrap().f64().unwrap().sum(),
+        Some(36.0)
+    );
+}
+
+#[test]
+fn test_errors_name_the_stage() {
+    let pipeline = Pipeline::<RawOrder>::new().then(positive).then(AddTax);
+    let err = pipeline.run(orders().drop("amount").unwrap()).unwrap_err();
+    assert_eq!((err.index, err.boundary), (0, Boundary::Input));
+
+    let broken = Pipeline::<RawOrder>::new()
+        .then(AddTax)
+        .then::<PricedOrder>(|df: DataFrame| Ok(df.drop("amount_with_tax")?));
+    let err = broken.run(orders()).unwrap_err();
+    assert_eq!((err.index, err.boundary), (1, Boundary::Output));
+    assert!(matches!(*err.source, ValidationError::MissingColumn { .. }));
+    assert!(err.to_string().starts_with("stage 1 `"));
+
+    let failing =
+        Pipeline::<RawOrder>::new().then::<RawOrder>(|df: DataFrame| Ok(df.drop("nope")?));
+    let err = failing.run(orders()).unwrap_err();
+    assert_eq!(err.boundary, Boundary::Apply);
+    assert!(matches!(*err.source, ValidationError::Polars(_)));
+}
+
+#[test]
+fn test_check_modes() {
+    let broken = || {
+        Pipeline::<RawOrder>::new()
+            .then::<RawOrder>(|df: DataFrame| -> Result<DataFrame> { Ok(df.drop("amount")?) })
+    };
+    assert!(broken().checks(Checks::Never).run(orders()).is_ok());
+    assert_eq!(
+        broken().checks(Checks::DebugOnly).run(orders()).is_err(),
+        cfg!(debug_assertions)
+    );
+
+    let sampled = broken().checks(Checks::Sampled(3));
+    let failures: Vec<bool> = (0..6).map(|_| sampled.run(orders()).is_err()).collect();
+    assert_eq!(failures, [true, false, false, true, false, false]);
+}
@@ -0,0 +1,80 @@
+#![allow(non_upper_case_globals)]
+
+#[cfg(feature = "pretty-errors")]
+mod pretty_errors {
+    use polars_tools::diagnostic::Diagnostic;
+    use polars_tools::*;
+
+    #[derive(PolarsSchema)]
+    #[allow(dead_code, non_upper_case_globals)]
+    struct User {
+        id: i64,
+        user_id: String,
+        score: f64,
+    }
+
+    #[test]
+    fn test_suggests_misspelled_column() {
+        let df = df!["id" => [1i64], "usr_id" => ["a"], "score" => [1.0]].unwrap();
+        let diagnostic = User::diagnose(&df).unwrap();
+
+        assert_eq!(
+            diagnostic.help.as_deref(),
+            Some("did you mean column `usr_id`?")
+        );
+        assert_eq!(
+            diagnostic.to_string(),
+            "error: Missing required column: user_id (did you mean `usr_id`?)\n  \
+             help: did you mean column `usr_id`?\n\n    \
+             column   expected  actual\n  \
+             ✓ id       i64       i64\n  \
+             ✗ user_id  str       missing\n  \
+             ✓ score    f64       f64\n  \
+             + usr_id   -         str\n"
+        );
+    }
+
+    #[test]
+    fn test_type_mismatch() {
+        let df = df!["id" => [1i64], "user_id" => ["a"], "score" => ["high"]].unwrap();
+        let diagnostic = User::diagnose(&df).unwrap();
+
+        assert_eq!(
+            diagnostic.help.as_deref(),
+            Some("cast column `score` to Float64 before validating")
+        );
+        assert!(!diagnostic.columns[2].is_ok());
+        assert!(diagnostic.to_string().contains("✗ score    f64       str"));
+    }
+
+    #[test]
+    fn test_unrelated_missing_column() {
+        let df = df!["id" => [1i64], "email" => ["a"], "score" => [1.0]].unwrap();
+        let diagnostic = User::diagnose(&df).unwrap();
+        assert_eq!(
+            diagnostic.help.as_deref(),
+            Some("add column `user_id` to the frame")
+        );
+    }
+
+    #[test]
+    fn test_colors_with_alternate() {
+        let df = df!["id" => [1i64]].unwrap();
+        let diagnostic = User::diagnose(&df).unwrap();
+
+        assert!(!diagnostic.to_string().contains('\x1b'));
+        let colored = format!("{diagnostic:#}");
+        assert!(colored.contains("\x1b[31m✗ user_id"));
+        assert!(colored.contains("\x1b[32m✓ id"));
+    }
+
+    #[test]
+    fn test_valid_frame() {
+        let df = df!["id" => [1i64], "user_id" => ["a"], "score" => [1.0]].unwrap();
+        assert!(User::diagnose(&df).is_none());
+
+        let error = ValidationError::RowOutOfBounds { row: 3, height: 1 };
+        let diagnostic = Diagnostic::new(&error, User::polars_schema(), &Schema::default());
+        assert_eq!(diagnostic.help, None);
+    }
+}
@@ -0,0 +1,117 @@
+use polars_tools::*;
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Account {
+    #[polars(key)]
+    region: String,
+    #[polars(key)]
+    id: i64,
+    balance: f64,
+    updated_at: i64,
+}
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Owner {
+    region: String,
+    id: i64,
+    name: String,
+}
+
+fn accounts() -> DataFrame {
+    df![
+        "region" => ["eu", "eu", "us", "eu", "us"],
+        "id" => [1i64, 2, 1, 1, 1],
+        "balance" => [10.0, 20.0, 30.0, 15.0, 35.0],
+        "updated_at" => [3i64, 1, 2, 1, 5],
+    ]
+    .unwrap()
+}
+
+#[test]
+fn test_key_columns() {
+    assert_eq!(Account::key_columns(), ["region", "id"]);
+}
+
+#[test]
+fn test_assert_unique_keys() {
+    let err = Account::assert_unique_keys(&accounts()).unwrap_err();
+    assert_eq!(err.code(), "PT0018");
+    match &err {
+        ValidationError::DuplicateKeys {
+            columns,
+            duplicates,
+            sample,
+        } => {
+            assert_eq!(columns, &["region", "id"]);
+            assert_eq!(*duplicates, 2);
+            assert_eq!(sample, &[r#"("eu", 1)"#, r#"("us", 1)"#]);
+        }
+        other => panic!("unexpected error: {other}"),
+    }
+    assert_eq!(
+        err.to_string(),
+        r#"2 values of the key ["region", "id"] appear more than once, e.g. ("eu", 1), ("us", 1)"#
+    );
+
+    let deduped = Account::dedupe(&accounts()).unwrap();
+    Account::assert_unique_keys(&deduped).unwrap();
+}
+
+#[test]
+fn test_dedupe_keeps_first() {
+    let df = Account::dedupe(&accounts()).unwrap();
+    assert_eq!(df.height(), 3);
+    let balances: Vec<_> = df.column("balance").unwrap().f64().unwrap().into_iter().collect();
+    assert_eq!(balances, [Some(10.0), Some(20.0), Some(30.0)]);
+}
+
+#[test]
+fn test_dedupe_keep_last() {
+    let df = Account::dedupe_keep_last(&accounts(), &[Account::updated_at]).unwrap();
+    assert_eq!(df.height(), 3);
+    let latest = df
+        .lazy()
+        .sort([Account::region, Account::id], Default::default())
+        .collect()
+        .unwrap();
+    let balances: Vec<_> = latest.column("balance").unwrap().f64().unwrap().into_iter().collect();
+    assert_eq!(balances, [Some(10.0), Some(20.0), Some(35.0)]);
+}
+
+#[test]
+fn test_join_on_key() {
+    let owners = df![
+        "region" => ["eu", "us"],
+        "id" => [2i64, 1],
+        "name" => ["ada", "bob"],
+    ]
+    .unwrap();
+    let accounts = Account::dedupe(&accounts()).unwrap();
+    let df = Account::join_on_key::<Owner>(&accounts, &owners, JoinType::Inner).unwrap();
+    assert_eq!(df.height(), 2);
+    assert_eq!(
+        df.get_column_names(),
+        ["region", "id", "balance", "updated_at", "name"]
+    );
+
+    let invalid = owners.drop("name").unwrap();
+    assert!(Account::join_on_key::<Owner>(&accounts, &invalid, JoinType::Left).is_err());
+}
+
+#[test]
+fn test_join_on_missing_key() {
+    #[derive(PolarsSchema)]
+    #[allow(dead_code, non_upper_case_globals)]
+    struct Region {
+        region: String,
+    }
+
+    let regions = df!["region" => ["eu"]].unwrap();
+    let err = Account::join_on_key::<Region>(&accounts(), &regions, JoinType::Inner).unwrap_err();
+    assert!(matches!(
+        err,
+        ValidationError::MissingColumn { ref column_name, .. } if column_name == "id"
+    ));
+}
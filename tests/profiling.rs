@@ -0,0 +1,114 @@
+#![allow(non_upper_case_globals)]
+use polars_tools::*;
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Order {
+    city: String,
+    amount: Option<f64>,
+    quantity: i32,
+    paid: bool,
+}
+
+fn orders() -> DataFrame {
+    df![
+        "city" => ["Berlin", "Paris", "Berlin", "Rome"],
+        "amount" => [Some(10.0), None, Some(30.0), Some(20.0)],
+        "quantity" => [1i32, 2, 2, 5],
+        "paid" => [true, false, true, true],
+    ]
+    .unwrap()
+}
+
+#[test]
+fn test_numeric_profile() {
+    let report = Order::profile(&orders()).unwrap();
+    let amount = report.column("amount").unwrap();
+
+    assert_eq!(report.rows, 4);
+    assert_eq!(amount.null_count, 1);
+    assert_eq!(amount.null_fraction, 0.25);
+    assert_eq!(
+        amount.stats,
+        ColumnStats::Numeric {
+            min: Some(10.0),
+            max: Some(30.0),
+            mean: Some(20.0),
+        }
+    );
+    assert_eq!(report.column("quantity").unwrap().n_unique, Some(3));
+}
+
+#[test]
+fn test_text_profile_top_values() {
+    let report = Order::profile(&orders()).unwrap();
+
+    match &report.column("city").unwrap().stats {
+        ColumnStats::Text { top_values } => {
+            assert_eq!(top_values[0], ("Berlin".to_string(), 2));
+            assert_eq!(top_values.len(), 3);
+        }
+        other => panic!("Expected text stats, got {:?}", other),
+    }
+    assert_eq!(report.column("paid").unwrap().stats, ColumnStats::Other);
+}
+
+#[test]
+fn test_profile_missing_column() {
+    let df = orders().drop("paid").unwrap();
+    assert!(matches!(
+        Order::profile(&df),
+        Err(ValidationError::MissingColumn { .. })
+    ));
+}
+
+#[test]
+fn test_profile_display() {
+    let text = Order::profile(&orders()).unwrap().to_string();
+
+    assert!(text.starts_with("4 rows, 4 columns"));
+    assert!(text.contains("amount (f64): nulls 1 (25.0%)"));
+    assert!(text.contains("top Berlin (2)"));
+}
+
+#[test]
+fn test_profile_json() {
+    let report = Order::profile(&orders()).unwrap();
+    let json = serde_json::to_value(&report).unwrap();
+
+    assert_eq!(json["rows"], 4);
+    assert_eq!(json["columns"][1]["stats"]["kind"], "numeric");
+    assert_eq!(json["columns"][1]["stats"]["mean"], 20.0);
+}
+
+#[cfg(feature = "chrono")]
+mod temporal {
+    use chrono::NaiveDate;
+    use polars_tools::*;
+
+    #[derive(Debug, PolarsSchema)]
+    #[allow(dead_code, non_upper_case_globals)]
+    struct Event {
+        day: NaiveDate,
+    }
+
+    #[test]
+    fn test_date_range() {
+        let df = df![
+            "day" => [
+                NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 1, 15).unwrap(),
+            ],
+        ]
+        .unwrap();
+        let report = Event::profile(&df).unwrap();
+
+        assert_eq!(
+            report.column("day").unwrap().stats,
+            ColumnStats::Temporal {
+                min: Some("2024-01-15".to_string()),
+                max: Some("2024-03-01".to_string()),
+            }
+        );
+    }
+}
@@ -0,0 +1,66 @@
+#![allow(non_upper_case_globals)]
+
+#[cfg(feature = "proptest")]
+mod strategies {
+    use polars_tools::strategy::proptest::prelude::*;
+    use polars_tools::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Side {
+        Buy,
+        Sell,
+    }
+
+    impl ValidatableEnum for Side {
+        fn valid_values() -> Vec<&'static str> {
+            vec!["Buy", "Sell"]
+        }
+
+        fn from_str(value: &str) -> Result<Self> {
+            match value {
+                "Buy" => Ok(Side::Buy),
+                "Sell" => Ok(Side::Sell),
+                _ => Err(ValidationError::InvalidEnumValue {
+                    field: "Side".to_string(),
+                    value: value.to_string(),
+                    valid_values: Self::valid_values().into_iter().map(|s| s.to_string()).collect(),
+                }),
+            }
+        }
+
+        fn to_str(&self) -> &'static str {
+            match self {
+                Side::Buy => "Buy",
+                Side::Sell => "Sell",
+            }
+        }
+    }
+
+    #[derive(Debug, PolarsSchema)]
+    #[allow(dead_code, non_upper_case_globals)]
+    struct Trade {
+        symbol: String,
+        quantity: i64,
+        price: Option<f64>,
+        side: Side,
+    }
+
+    proptest! {
+        #[test]
+        fn generated_frames_are_schema_valid(df in Trade::df_strategy()) {
+            prop_assert!(Trade::validate_strict(&df).is_ok());
+        }
+
+        #[test]
+        fn enum_columns_hold_valid_values(df in Trade::df_strategy_with_rows(1..=20)) {
+            for row in 0..df.height() {
+                prop_assert!(Trade::side_at(&df, row).is_ok());
+            }
+        }
+
+        #[test]
+        fn row_counts_follow_range(df in Trade::df_strategy_with_rows(5..=8)) {
+            prop_assert!((5..=8).contains(&df.height()));
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use polars_tools::*;
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Order {
+    id: u32,
+    customer: String,
+    total: Option<f64>,
+    items: Vec<String>,
+    #[polars(dtype = DataType::Datetime(TimeUnit::Microseconds, Some("UTC".into())))]
+    placed_at: i64,
+    #[polars(dtype = DataType::Duration(TimeUnit::Milliseconds))]
+    wait: i64,
+}
+
+#[test]
+fn test_python_schema() {
+    assert_eq!(
+        Order::python_schema().unwrap(),
+        "pl.Schema({\"id\": pl.UInt32, \"customer\": pl.String, \"total\": pl.Float64, \
+         \"items\": pl.List(pl.String), \"placed_at\": pl.Datetime(\"us\", \"UTC\"), \
+         \"wait\": pl.Duration(\"ms\")})"
+    );
+}
+
+#[test]
+fn test_python_dtype() {
+    let point = DataType::Struct(vec![
+        Field::new("x".into(), DataType::Float32),
+        Field::new("say \"hi\"".into(), DataType::Boolean),
+    ]);
+    assert_eq!(
+        python::python_dtype(&point).unwrap(),
+        "pl.Struct({\"x\": pl.Float32, \"say \\\"hi\\\"\": pl.Boolean})"
+    );
+    assert_eq!(
+        python::python_dtype(&DataType::Unknown(Default::default())),
+        None
+    );
+}
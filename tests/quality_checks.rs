@@ -0,0 +1,296 @@
+#![allow(non_upper_case_globals)]
+use polars_tools::quality::{QualityCheck, QualityChecks};
+use polars_tools::*;
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Member {
+    #[polars(unique)]
+    id: i64,
+    #[polars(min = 0, max = 150)]
+    age: i32,
+    #[polars(max_null_fraction = 0.25)]
+    email: Option<String>,
+    country: String,
+}
+
+fn members() -> DataFrame {
+    df![
+        "id" => [1i64, 2, 2, 4],
+        "age" => [34i32, 200, 28, -1],
+        "email" => [Some("a@x.io"), None, None, Some("d@x.io")],
+        "country" => ["DE", "FR", "XX", "DE"],
+    ]
+    .unwrap()
+}
+
+fn countries() -> DataFrame {
+    df!["code" => ["DE", "FR", "IT"]].unwrap()
+}
+
+#[test]
+fn test_attribute_checks() {
+    let checks = Member::quality_checks();
+
+    assert_eq!(
+        checks.checks,
+        vec![
+            QualityCheck::Unique {
+                column: "id".to_string()
+            },
+            QualityCheck::Range {
+                column: "age".to_string(),
+                min: Some(0.0),
+                max: Some(150.0),
+            },
+            QualityCheck::MaxNullFraction {
+                column: "email".to_string(),
+                max: 0.25,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_run_reports_violations() {
+    let report = Member::quality_checks().run(&members()).unwrap();
+
+    assert!(!report.passed());
+    let violations: Vec<_> = report.results.iter().map(|r| r.violations).collect();
+    assert_eq!(violations, [1, 2, 2]);
+    assert_eq!(report.failures().count(), 3);
+}
+
+#[test]
+fn test_passing_frame() {
+    let df = df![
+        "id" => [1i64, 2],
+        "age" => [30i32, 40],
+        "email" => [Some("a@x.io"), Some("b@x.io")],
+        "country" => ["DE", "FR"],
+    ]
+    .unwrap();
+
+    assert!(Member::quality_checks().run(&df).unwrap().passed());
+}
+
+#[test]
+fn test_referential_check() {
+    let report = QualityChecks::new()
+        .references(Member::country, "countries", "code")
+        .reference_frame("countries", countries())
+        .run(&members())
+        .unwrap();
+
+    assert_eq!(report.results[0].violations, 1);
+    assert!(!report.passed());
+}
+
+#[test]
+fn test_unregistered_reference_frame() {
+    let result = QualityChecks::new()
+        .references(Member::country, "countries", "code")
+        .run(&members());
+
+    assert!(matches!(
+        result,
+        Err(ValidationError::MissingReferenceFrame { .. })
+    ));
+}
+
+#[test]
+fn test_checks_loaded_from_json() {
+    let config = r#"{"checks": [
+        {"kind": "unique", "column": "country"},
+        {"kind": "range", "column": "age", "min": 18}
+    ]}"#;
+    let runtime: QualityChecks = serde_json::from_str(config).unwrap();
+    let report = Member::quality_checks().extend(runtime).run(&members()).unwrap();
+
+    assert_eq!(report.results.len(), 5);
+    assert_eq!(report.results[3].violations, 1);
+    assert_eq!(report.results[4].violations, 1);
+}
+
+#[test]
+fn test_missing_column() {
+    let result = QualityChecks::new().unique("nope").run(&members());
+    assert!(matches!(result, Err(ValidationError::MissingColumn { .. })));
+}
+
+#[test]
+fn test_report_display() {
+    let text = Member::quality_checks().run(&members()).unwrap().to_string();
+
+    assert!(text.starts_with("0 of 3 checks passed on 4 rows"));
+    assert!(text.contains("[FAILED] id: unique (1 violations)"));
+}
+
+#[test]
+fn test_validate_values_lazy_fuses_with_query() {
+    let lf = members()
+        .lazy()
+        .filter(col(Member::country).neq(lit("XX")));
+    let counts = Member::validate_values_lazy(lf).unwrap().collect().unwrap();
+    assert_eq!(counts.height(), 1);
+
+    let report = Member::quality_checks().report(&counts).unwrap();
+    assert_eq!(report.rows, 3);
+    let violations: Vec<_> = report.results.iter().map(|r| r.violations).collect();
+    assert_eq!(violations, [0, 2, 1]);
+}
+
+#[test]
+fn test_violations_lazy_includes_references() {
+    let checks = Member::quality_checks()
+        .references(Member::country, "countries", "code")
+        .reference_frame("countries", countries());
+    let counts = checks
+        .violations_lazy(members().lazy())
+        .unwrap()
+        .collect()
+        .unwrap();
+    let report = checks.report(&counts).unwrap();
+
+    assert_eq!(report, checks.run(&members()).unwrap());
+    assert_eq!(report.results[3].violations, 1);
+}
+
+#[test]
+fn test_validate_values_lazy_missing_column() {
+    let lf = members().lazy().drop([Member::age]);
+    let result = Member::validate_values_lazy(lf);
+    assert!(matches!(result, Err(ValidationError::MissingColumn { .. })));
+}
+
+#[test]
+fn test_validate_values_with_sample() {
+    let df = df![
+        "id" => (0..1000i64).collect::<Vec<_>>(),
+        "age" => (0..1000i32).map(|i| i % 200).collect::<Vec<_>>(),
+        "email" => (0..1000).map(|i| Some(format!("{}@x.io", i))).collect::<Vec<_>>(),
+        "country" => vec!["DE"; 1000],
+    ]
+    .unwrap();
+    let opts = ValidateOptions::default().sample_rows(100).seed(7);
+
+    let report = Member::validate_values_with(&df, &opts).unwrap();
+    assert_eq!(report.rows, 100);
+    assert_eq!(report.sampled_from, Some(1000));
+    assert!(report.to_string().contains("on 100 rows (sampled from 1000)"));
+    assert_eq!(report, Member::validate_values_with(&df, &opts).unwrap());
+
+    let full = Member::validate_values(&df).unwrap();
+    assert_eq!(full.rows, 1000);
+    assert_eq!(full.sampled_from, None);
+    assert_eq!(full.results[1].violations, 245);
+}
+
+#[test]
+fn test_sample_larger_than_frame_checks_everything() {
+    let opts = ValidateOptions::default().sample_rows(10);
+    let report = Member::validate_values_with(&members(), &opts).unwrap();
+
+    assert_eq!(report, Member::validate_values(&members()).unwrap());
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Tier {
+    Free,
+    Pro,
+}
+
+impl ValidatableEnum for Tier {
+    fn valid_values() -> Vec<&'static str> {
+        vec!["Free", "Pro"]
+    }
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "Free" => Ok(Tier::Free),
+            "Pro" => Ok(Tier::Pro),
+            _ => Err(ValidationError::InvalidEnumValue {
+                field: "Tier".to_string(),
+                value: value.to_string(),
+                valid_values: Self::valid_values().into_iter().map(|s| s.to_string()).collect(),
+            }),
+        }
+    }
+
+    fn to_str(&self) -> &'static str {
+        match self {
+            Tier::Free => "Free",
+            Tier::Pro => "Pro",
+        }
+    }
+}
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Account {
+    #[polars(unique)]
+    id: i64,
+    tier: Tier,
+    previous_tier: Option<Tier>,
+}
+
+fn accounts(ids: &[i64], tiers: &[&str]) -> DataFrame {
+    df![
+        "id" => ids,
+        "tier" => tiers,
+        "previous_tier" => vec![None::<&str>; ids.len()],
+    ]
+    .unwrap()
+}
+
+#[test]
+fn test_enum_fields_add_one_of_checks() {
+    let checks = Account::quality_checks();
+    assert_eq!(
+        checks.checks[1..],
+        [
+            QualityCheck::OneOf {
+                column: "tier".to_string(),
+                values: vec!["Free".to_string(), "Pro".to_string()],
+            },
+            QualityCheck::OneOf {
+                column: "previous_tier".to_string(),
+                values: vec!["Free".to_string(), "Pro".to_string()],
+            },
+        ]
+    );
+
+    let report = checks.run(&accounts(&[1, 2, 3], &["Free", "Gold", "Pro"])).unwrap();
+    assert_eq!(report.results[1].violations, 1);
+    assert!(report.to_string().contains("[FAILED] tier: one of [Free, Pro] (1 violations)"));
+}
+
+#[test]
+fn test_validate_streaming_accumulates_across_chunks() {
+    let chunks = vec![
+        accounts(&[1, 2], &["Free", "Pro"]),
+        accounts(&[3, 1], &["Gold", "Free"]),
+        accounts(&[2, 4], &["Gold", "Pro"]),
+    ];
+
+    let report = Account::validate_streaming(chunks.clone()).unwrap();
+    let whole = chunks
+        .iter()
+        .skip(1)
+        .fold(chunks[0].clone(), |acc, chunk| acc.vstack(chunk).unwrap());
+    assert_eq!(report, Account::validate_values(&whole).unwrap());
+    assert_eq!(report.rows, 6);
+    assert_eq!(report.results[0].violations, 2);
+    assert_eq!(report.results[1].violations, 2);
+}
+
+#[test]
+fn test_validate_streaming_rejects_bad_chunk() {
+    let chunks = vec![
+        accounts(&[1], &["Free"]),
+        df!["id" => [2i64], "tier" => ["Pro"]].unwrap(),
+    ];
+
+    let result = Account::validate_streaming(chunks);
+    assert!(matches!(result, Err(ValidationError::MissingColumn { .. })));
+}
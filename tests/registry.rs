@@ -0,0 +1,91 @@
+#![allow(non_upper_case_globals)]
+
+#[cfg(feature = "registry")]
+mod registry_lookup {
+    use polars_tools::*;
+
+    #[derive(PolarsSchema)]
+    #[polars(register)]
+    #[allow(dead_code, non_upper_case_globals)]
+    struct UserProfile {
+        user_id: i64,
+        /// Contact address, if the user gave one
+        email: Option<String>,
+    }
+
+    #[derive(PolarsSchema)]
+    #[polars(register = "orders")]
+    #[allow(dead_code, non_upper_case_globals)]
+    struct Order {
+        order_id: i64,
+        amount: f64,
+    }
+
+    #[derive(PolarsSchema)]
+    #[allow(dead_code, non_upper_case_globals)]
+    struct Unregistered {
+        id: i64,
+    }
+
+    fn users() -> DataFrame {
+        df![
+            "user_id" => [1i64, 2],
+            "email" => [Some("a@x.io"), None],
+        ]
+        .unwrap()
+    }
+
+    #[test]
+    fn test_lookup_by_name() {
+        let schema = registry::get("UserProfile").unwrap();
+
+        assert_eq!(schema.name(), "UserProfile");
+        assert_eq!(schema.columns(), UserProfile::COLUMNS);
+        assert_eq!(schema.dtypes()[1], DataType::String);
+        assert_eq!(schema.schema(), UserProfile::polars_schema());
+        assert_eq!(
+            schema.column_docs(),
+            [("email", "Contact address, if the user gave one")]
+        );
+        assert!(schema.validate(&users()).is_ok());
+        assert!(schema.validate_strict(&users()).is_ok());
+    }
+
+    #[test]
+    fn test_registered_validation_errors() {
+        let schema = registry::get("orders").unwrap();
+
+        assert!(matches!(
+            schema.validate(&users()),
+            Err(ValidationError::MissingColumn { .. })
+        ));
+
+        let wide = users()
+            .hstack(&[Column::new("extra".into(), [1i32, 2])])
+            .unwrap();
+        let users = registry::get("UserProfile").unwrap();
+        assert!(users.validate(&wide).is_ok());
+        assert!(matches!(
+            users.validate_strict(&wide),
+            Err(ValidationError::ColumnCountMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_describe_registered_schema() {
+        let description = registry::get("orders").unwrap().describe();
+        assert_eq!(description.name, "orders");
+        assert_eq!(description.columns, Order::schema_description().columns);
+        assert!(description.to_string().starts_with("orders (2 columns)"));
+    }
+
+    #[test]
+    fn test_only_opted_in_schemas_are_registered() {
+        assert!(registry::get("Order").is_none());
+        assert!(registry::get("Unregistered").is_none());
+
+        let mut names: Vec<_> = registry::all().map(|schema| schema.name()).collect();
+        names.sort();
+        assert_eq!(names, ["UserProfile", "orders"]);
+    }
+}
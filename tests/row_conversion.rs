@@ -0,0 +1,134 @@
+use polars_tools::*;
+
+/// Stand-in for the `prost-types` well-known messages
+mod prost_types {
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Timestamp {
+        pub seconds: i64,
+        pub nanos: i32,
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Duration {
+        pub seconds: i64,
+        pub nanos: i32,
+    }
+}
+
+/// Shaped like a prost-generated message with
+/// `type_attribute(".", "#[derive(polars_tools::PolarsSchema)]")`
+#[derive(Clone, Debug, PartialEq, PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+pub struct ClickEvent {
+    pub id: i64,
+    pub user: ::std::string::String,
+    pub referrer: ::core::option::Option<::std::string::String>,
+    pub tags: ::std::vec::Vec<::std::string::String>,
+    pub payload: ::std::vec::Vec<u8>,
+    pub kind: i32,
+    pub at: ::core::option::Option<prost_types::Timestamp>,
+    pub dwell: ::core::option::Option<prost_types::Duration>,
+}
+
+#[derive(Clone, Debug, PartialEq, PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Reading {
+    sensor: String,
+    value: f64,
+    flagged: Option<bool>,
+}
+
+fn events() -> Vec<ClickEvent> {
+    vec![
+        ClickEvent {
+            id: 1,
+            user: "ada".to_string(),
+            referrer: Some("search".to_string()),
+            tags: vec!["a".to_string(), "b".to_string()],
+            payload: vec![0, 255],
+            kind: 2,
+            at: Some(prost_types::Timestamp {
+                seconds: 1_704_189_000,
+                nanos: 250,
+            }),
+            dwell: Some(prost_types::Duration {
+                seconds: -1,
+                nanos: -500_000_000,
+            }),
+        },
+        ClickEvent {
+            id: 2,
+            user: "bob".to_string(),
+            referrer: None,
+            tags: vec![],
+            payload: vec![],
+            kind: 0,
+            at: Some(prost_types::Timestamp {
+                seconds: -1,
+                nanos: 500_000_000,
+            }),
+            dwell: None,
+        },
+    ]
+}
+
+#[test]
+fn test_plain_struct_round_trip() {
+    let rows = vec![
+        Reading {
+            sensor: "t1".to_string(),
+            value: 20.5,
+            flagged: None,
+        },
+        Reading {
+            sensor: "t2".to_string(),
+            value: -3.0,
+            flagged: Some(true),
+        },
+    ];
+    let df = Reading::to_df(&rows).unwrap();
+    Reading::validate(&df).unwrap();
+    assert_eq!(df.height(), 2);
+    assert_eq!(Reading::from_df(&df).unwrap(), rows);
+}
+
+#[test]
+fn test_prost_message_dtypes() {
+    assert_eq!(
+        ClickEvent::at_type,
+        DataType::Datetime(TimeUnit::Nanoseconds, None)
+    );
+    assert_eq!(
+        ClickEvent::dwell_type,
+        DataType::Duration(TimeUnit::Nanoseconds)
+    );
+    assert_eq!(ClickEvent::payload_type, DataType::Binary);
+    assert_eq!(
+        ClickEvent::tags_type,
+        DataType::List(Box::new(DataType::String))
+    );
+}
+
+#[test]
+fn test_prost_message_round_trip() {
+    let df = ClickEvent::to_df(&events()).unwrap();
+    ClickEvent::validate(&df).unwrap();
+    let at = df.column("at").unwrap().datetime().unwrap();
+    assert_eq!(at.get(0), Some(1_704_189_000_000_000_250));
+    assert_eq!(at.get(1), Some(-500_000_000));
+    assert_eq!(ClickEvent::from_df(&df).unwrap(), events());
+}
+
+#[test]
+fn test_from_df_rejects_nulls_in_required_fields() {
+    let df = df![
+        "sensor" => [Some("t1"), None],
+        "value" => [1.0, 2.0],
+        "flagged" => [None::<bool>, None],
+    ]
+    .unwrap();
+    assert!(matches!(
+        Reading::from_df(&df),
+        Err(ValidationError::InvalidValue { column_name, .. }) if column_name == "sensor"
+    ));
+}
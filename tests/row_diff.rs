@@ -0,0 +1,74 @@
+#![allow(non_upper_case_globals)]
+use polars_tools::*;
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Account {
+    id: i64,
+    owner: String,
+    balance: Option<f64>,
+}
+
+fn before() -> DataFrame {
+    df![
+        "id" => [1i64, 2, 3, 4],
+        "owner" => ["ann", "bob", "cid", "dee"],
+        "balance" => [Some(10.0), None, Some(30.0), Some(40.0)],
+        "loaded_at" => ["t0", "t0", "t0", "t0"],
+    ]
+    .unwrap()
+}
+
+fn after() -> DataFrame {
+    df![
+        "id" => [1i64, 2, 3, 5],
+        "owner" => ["ann", "bob", "CID", "eve"],
+        "balance" => [Some(10.0), None, Some(35.0), Some(50.0)],
+        "loaded_at" => ["t1", "t1", "t1", "t1"],
+    ]
+    .unwrap()
+}
+
+#[test]
+fn test_diff_rows_classifies_rows() {
+    let diff = Account::diff_rows(&before(), &after(), &[Account::id]).unwrap();
+
+    assert_eq!(diff.added.height(), 1);
+    assert_eq!(diff.removed.height(), 1);
+    assert_eq!(diff.changed.height(), 1);
+    assert_eq!(Account::id_at(&diff.added, 0).unwrap(), Some(5));
+    assert_eq!(Account::id_at(&diff.removed, 0).unwrap(), Some(4));
+    assert_eq!(Account::id_at(&diff.changed, 0).unwrap(), Some(3));
+}
+
+#[test]
+fn test_diff_rows_column_counts_ignore_non_schema_columns() {
+    let diff = Account::diff_rows(&before(), &after(), &[Account::id]).unwrap();
+
+    assert_eq!(
+        diff.column_changes,
+        vec![("owner".to_string(), 1), ("balance".to_string(), 1)]
+    );
+    assert_eq!(diff.changes(Account::balance), 1);
+    assert_eq!(
+        diff.changed.get_column_names(),
+        &["id", "owner", "owner_right", "balance", "balance_right"]
+    );
+}
+
+#[test]
+fn test_identical_frames() {
+    let diff = Account::diff_rows(&before(), &before(), &[Account::id]).unwrap();
+
+    assert!(diff.is_empty());
+    assert_eq!(diff.to_string(), "0 added, 0 removed, 0 changed");
+}
+
+#[test]
+fn test_missing_key_column() {
+    let right = after().drop("id").unwrap();
+    assert!(matches!(
+        Account::diff_rows(&before(), &right, &[Account::id]),
+        Err(ValidationError::MissingColumn { .. })
+    ));
+}
@@ -0,0 +1,51 @@
+use polars_tools::*;
+
+#[derive(PolarsSchema)]
+#[polars(prefix = "geo_")]
+#[allow(dead_code, non_upper_case_globals)]
+struct Location {
+    lat: f64,
+    lon: f64,
+    #[polars(dtype = DataType::Int32)]
+    zoom: i64,
+    label: Option<String>,
+}
+
+#[derive(PolarsColumns)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Tag {
+    key: String,
+}
+
+#[test]
+fn test_fields_follow_the_schema() {
+    let fields = Location::fields();
+    let names: Vec<_> = fields.iter().map(|field| field.name().as_str()).collect();
+    assert_eq!(names, ["geo_lat", "geo_lon", "geo_zoom", "geo_label"]);
+    assert_eq!(fields[2].dtype(), &DataType::Int32);
+    assert_eq!(
+        fields.into_iter().collect::<Schema>(),
+        *Location::polars_schema()
+    );
+    assert_eq!(Tag::fields(), [Field::new("key".into(), DataType::String)]);
+}
+
+#[test]
+fn test_fields_build_struct_dtypes() {
+    let df = df![
+        "geo_lat" => [52.37],
+        "geo_lon" => [4.89],
+        "geo_zoom" => [12i32],
+        "geo_label" => [Some("Amsterdam")],
+    ]
+    .unwrap();
+    let packed = df
+        .lazy()
+        .select([as_struct(Location::all_cols()).alias("location")])
+        .collect()
+        .unwrap();
+    assert_eq!(
+        packed.column("location").unwrap().dtype(),
+        &DataType::Struct(Location::fields())
+    );
+}
@@ -0,0 +1,96 @@
+use polars_tools::*;
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Event {
+    id: i64,
+    name: String,
+    score: Option<f64>,
+}
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct EventRetyped {
+    id: i32,
+    name: String,
+    score: Option<f64>,
+}
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct EventReordered {
+    name: String,
+    id: i64,
+    score: Option<f64>,
+}
+
+#[test]
+fn test_schema_hash_is_stable() {
+    assert_eq!(Event::schema_hash(), 0x0b97_1b2c_5abd_79ed);
+    assert_eq!(Event::schema_fingerprint(), "0b971b2c5abd79ed");
+    assert_eq!(Event::schema_hash(), schema_hash(Event::polars_schema()));
+}
+
+#[test]
+fn test_schema_hash_covers_names_dtypes_and_order() {
+    assert_ne!(Event::schema_hash(), EventRetyped::schema_hash());
+    assert_ne!(Event::schema_hash(), EventReordered::schema_hash());
+
+    let mut renamed = Event::polars_schema().clone();
+    renamed.rename("score", "points".into());
+    assert_ne!(schema_hash(&renamed), Event::schema_hash());
+}
+
+#[cfg(feature = "parquet")]
+mod parquet_files {
+    use std::io::Cursor;
+
+    use super::*;
+
+    fn events() -> DataFrame {
+        df![
+            "id" => [1i64, 2],
+            "name" => ["a", "b"],
+            "score" => [Some(0.5), None],
+            "extra" => [true, false],
+        ]
+        .unwrap()
+    }
+
+    #[test]
+    fn test_check_parquet_fingerprint() {
+        let mut file = Vec::new();
+        Event::write_parquet(&mut file, &events()).unwrap();
+        Event::check_parquet_fingerprint(Cursor::new(&file)).unwrap();
+
+        let err = EventRetyped::check_parquet_fingerprint(Cursor::new(&file)).unwrap_err();
+        assert_eq!(err.code(), "PT0020");
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Schema fingerprint mismatch: expected {}, found {}",
+                EventRetyped::schema_fingerprint(),
+                Event::schema_fingerprint()
+            )
+        );
+
+        let metadata = polars_tools::parquet::parquet_metadata(Cursor::new(&file)).unwrap();
+        assert!(metadata.contains(&(
+            polars_tools::parquet::FINGERPRINT_KEY.to_string(),
+            Event::schema_fingerprint()
+        )));
+        let df = ParquetReader::new(Cursor::new(&file)).finish().unwrap();
+        assert_eq!(df.get_column_names(), ["id", "name", "score"]);
+    }
+
+    #[test]
+    fn test_check_parquet_without_fingerprint() {
+        let mut file = Vec::new();
+        ParquetWriter::new(&mut file).finish(&mut events()).unwrap();
+        let err = Event::check_parquet_fingerprint(Cursor::new(&file)).unwrap_err();
+        assert!(matches!(
+            err,
+            ValidationError::FingerprintMismatch { actual: None, .. }
+        ));
+    }
+}
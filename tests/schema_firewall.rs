@@ -0,0 +1,69 @@
+use polars_tools::*;
+
+#[derive(PolarsColumns)]
+#[allow(dead_code, non_upper_case_globals)]
+struct RawPii {
+    email: String,
+    #[polars(alias = "ssn")]
+    social_security_number: String,
+}
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Export {
+    id: i64,
+    country: String,
+}
+
+fn export() -> DataFrame {
+    df![
+        "id" => [1i64, 2],
+        "country" => ["NL", "PT"],
+    ]
+    .unwrap()
+}
+
+#[test]
+fn test_clean_frame_passes() {
+    RawPii::anti_validate(&export()).unwrap();
+    assert!(Export::anti_validate(&export()).is_err());
+}
+
+#[test]
+fn test_forbidden_columns_are_listed() {
+    let leaky = export()
+        .hstack(&[
+            Column::new("ssn".into(), ["123", "456"]),
+            Column::new("email".into(), ["a@x.io", "b@x.io"]),
+        ])
+        .unwrap();
+    let err = RawPii::anti_validate(&leaky).unwrap_err();
+
+    assert_eq!(err.code(), "PT0021");
+    assert_eq!(
+        err.to_string(),
+        r#"Frame holds columns of RawPii: ["ssn", "email"]"#
+    );
+    match err {
+        ValidationError::ForbiddenColumns { schema, columns } => {
+            assert_eq!(schema, "RawPii");
+            assert_eq!(columns, ["ssn", "email"]);
+        }
+        err => panic!("unexpected error: {err}"),
+    }
+}
+
+#[test]
+fn test_anti_validate_with_matches_names() {
+    let shouty = export()
+        .hstack(&[Column::new("EMAIL".into(), ["a@x.io", "b@x.io"])])
+        .unwrap();
+    RawPii::anti_validate(&shouty).unwrap();
+
+    let opts = ValidateOptions::default().case_insensitive();
+    let err = RawPii::anti_validate_with(&shouty, &opts).unwrap_err();
+    assert!(matches!(
+        err,
+        ValidationError::ForbiddenColumns { columns, .. } if columns == ["EMAIL"]
+    ));
+}
@@ -0,0 +1,77 @@
+use polars_tools::*;
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct UserProfile {
+    user_id: i64,
+    email: Option<String>,
+    age: i32,
+    country: String,
+}
+
+#[derive(PolarsProjection)]
+#[polars(of = "UserProfile")]
+#[allow(dead_code, non_upper_case_globals)]
+struct UserContact {
+    user_id: i64,
+    #[polars(alias = "mail")]
+    email: Option<String>,
+}
+
+mod read_models {
+    use polars_tools::*;
+
+    #[derive(PolarsProjection)]
+    #[polars(of = "super::UserProfile")]
+    #[allow(dead_code, non_upper_case_globals)]
+    pub struct UserAge {
+        pub age: i32,
+    }
+}
+
+fn profiles() -> DataFrame {
+    df![
+        "user_id" => [1i64, 2],
+        "email" => [Some("a@x.io"), None],
+        "age" => [30i32, 41],
+        "country" => ["NL", "DE"],
+    ]
+    .unwrap()
+}
+
+#[test]
+fn test_projection_schema() {
+    assert_eq!(UserContact::COLUMNS, ["user_id", "email"]);
+    assert!(UserContact::validate(&profiles()).is_ok());
+    assert!(UserContact::validate_strict(&profiles()).is_err());
+}
+
+#[test]
+fn test_select_from_parent_frames() {
+    let df = UserContact::select_from(&profiles()).unwrap();
+    assert_eq!(df.get_column_names(), ["user_id", "email"]);
+    assert!(UserContact::validate_strict(&df).is_ok());
+
+    let legacy = df!["user_id" => [1i64], "mail" => ["a@x.io"], "age" => [30i32]].unwrap();
+    let df = UserContact::select_from(&legacy).unwrap();
+    assert_eq!(df.get_column_names(), ["user_id", "email"]);
+
+    let missing = profiles().drop("age").unwrap();
+    assert!(matches!(
+        read_models::UserAge::select_from(&missing),
+        Err(ValidationError::MissingColumn { .. })
+    ));
+
+    let df = read_models::UserAge::select_from_lazy(profiles().lazy())
+        .collect()
+        .unwrap();
+    assert_eq!(df.get_column_names(), ["age"]);
+}
+
+#[test]
+fn test_from_typed_parent() {
+    let profiles = UserProfile::typed(&profiles()).unwrap();
+    let ages = read_models::UserAge::from_parent(&profiles).unwrap();
+    assert_eq!(ages.df().width(), 1);
+    assert_eq!(ages.height(), 2);
+}
@@ -213,3 +213,25 @@ fn test_column_order_independence() {
     assert!(BasicSchema::validate(&df).is_ok());
     assert!(BasicSchema::validate_strict(&df).is_ok());
 }
+
+#[test]
+fn test_polars_schema_is_built_once() {
+    let schema = BasicSchema::polars_schema();
+    assert!(std::ptr::eq(schema, BasicSchema::polars_schema()));
+    assert_eq!(
+        schema.iter_names().map(|s| s.as_str()).collect::<Vec<_>>(),
+        vec!["id", "name", "active"]
+    );
+    assert_eq!(schema.get("id"), Some(&DataType::Int32));
+
+    let df = df! {
+        "id" => [1i32],
+        "name" => ["a"],
+        "active" => [true],
+    }
+    .unwrap();
+    for _ in 0..3 {
+        assert!(BasicSchema::validate(&df).is_ok());
+        assert!(BasicSchema::validate_strict(&df).is_ok());
+    }
+}
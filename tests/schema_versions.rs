@@ -0,0 +1,86 @@
+#![allow(non_upper_case_globals)]
+use polars_tools::*;
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Event {
+    id: i64,
+    #[polars(deprecated = "3")]
+    legacy_code: String,
+    #[polars(since = "2")]
+    source: Option<String>,
+    #[polars(since = 3)]
+    score: Option<f64>,
+    #[polars(deprecated)]
+    note: Option<String>,
+}
+
+fn v1() -> DataFrame {
+    df![
+        "id" => [1i64, 2],
+        "legacy_code" => ["a", "b"],
+    ]
+    .unwrap()
+}
+
+#[test]
+fn test_columns_for_version() {
+    assert_eq!(Event::SCHEMA_VERSION, 3);
+    assert_eq!(Event::columns_for_version(1), ["id", "legacy_code"]);
+    assert_eq!(
+        Event::columns_for_version(2),
+        ["id", "legacy_code", "source"]
+    );
+    assert_eq!(Event::columns_for_version(3), ["id", "source", "score"]);
+}
+
+#[test]
+fn test_validate_version() {
+    assert!(Event::validate_version(&v1(), 1).is_ok());
+    assert!(matches!(
+        Event::validate_version(&v1(), 2),
+        Err(ValidationError::MissingColumn { column_name, .. }) if column_name == "source"
+    ));
+
+    let bad = df!["id" => ["1"], "legacy_code" => ["a"]].unwrap();
+    assert!(matches!(
+        Event::validate_version(&bad, 1),
+        Err(ValidationError::TypeMismatch { .. })
+    ));
+}
+
+#[test]
+fn test_migrate_to_version() {
+    let migrated = Event::migrate_to_version(&v1(), 3).unwrap();
+
+    assert!(Event::validate_version(&migrated, 3).is_ok());
+    assert_eq!(
+        migrated.get_column_names(),
+        ["id", "legacy_code", "source", "score"]
+    );
+    assert_eq!(
+        migrated.column(Event::score).unwrap().dtype(),
+        &DataType::Float64
+    );
+    assert_eq!(migrated.column(Event::source).unwrap().null_count(), 2);
+}
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Metric {
+    id: i64,
+    #[polars(since = "2", default = 1.5)]
+    weight: f64,
+}
+
+#[test]
+fn test_migrate_to_version_uses_defaults() {
+    let df = df!["id" => [1i64, 2, 3]].unwrap();
+    let migrated = Metric::migrate_to_version(&df, 2).unwrap();
+
+    assert!(Metric::validate_version(&migrated, 2).is_ok());
+    assert_eq!(
+        migrated.column(Metric::weight).unwrap().f64().unwrap().to_vec(),
+        [Some(1.5); 3]
+    );
+}
@@ -0,0 +1,56 @@
+use polars_tools::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PolarsSchema)]
+#[polars(use_serde_rename)]
+#[allow(dead_code, non_upper_case_globals)]
+struct ApiUser {
+    #[serde(rename = "userId")]
+    user_id: i64,
+    #[serde(rename = "displayName", default)]
+    #[polars(trim)]
+    display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    email: Option<String>,
+}
+
+#[derive(Serialize, PolarsColumns)]
+#[allow(dead_code, non_upper_case_globals)]
+struct ApiUserRef {
+    #[serde(rename = "userId")]
+    user_id: i64,
+}
+
+fn users() -> DataFrame {
+    df![
+        "userId" => [1i64, 2],
+        "displayName" => [Some(" Ada "), None],
+        "email" => [Some("ada@x.io"), None],
+    ]
+    .unwrap()
+}
+
+#[test]
+fn test_serde_rename_columns() {
+    assert_eq!(ApiUser::COLUMNS, ["userId", "displayName", "email"]);
+    assert_eq!(ApiUser::user_id, "userId");
+    assert_eq!(ApiUser::field_index("displayName"), Some(1));
+    assert!(ApiUser::validate_strict(&users()).is_ok());
+
+    let df = ApiUser::clean(&users()).unwrap();
+    assert_eq!(
+        df.column(ApiUser::display_name).unwrap().str().unwrap().get(0),
+        Some("Ada")
+    );
+    let ids = users()
+        .lazy()
+        .select([ApiUser::expr.user_id()])
+        .collect()
+        .unwrap();
+    assert_eq!(ids.get_column_names(), ["userId"]);
+}
+
+#[test]
+fn test_serde_rename_is_opt_in() {
+    assert_eq!(ApiUserRef::COLUMNS, ["user_id"]);
+}
@@ -0,0 +1,49 @@
+#![allow(non_upper_case_globals)]
+use polars_tools::*;
+
+#[derive(PolarsSchema)]
+#[polars(skip_expr, skip_df, skip_consts, skip_col_fns)]
+#[allow(dead_code)]
+struct Slim {
+    id: i64,
+    // Free to use without `consts_mod`: `skip_df` leaves out `Slim::df()`
+    df: String,
+    lazy: Option<f64>,
+}
+
+#[derive(PolarsColumns)]
+#[polars(skip_expr)]
+#[allow(dead_code)]
+struct Point {
+    // Free to use: `skip_expr` leaves out `Point::expr`
+    expr: String,
+    x: f64,
+}
+
+#[test]
+fn test_skipped_blocks_leave_other_helpers() {
+    assert_eq!(Slim::COLUMNS, &["id", "df", "lazy"]);
+    assert_eq!(Slim::df, "df");
+    assert_eq!(Slim::lazy, "lazy");
+    assert_eq!(Slim::type_at(1), Some(DataType::String));
+    assert_eq!(Slim::DTYPES[2], DataType::Float64);
+    assert_eq!(Slim::all_cols().len(), 3);
+
+    let df = df![
+        "id" => [1i64, 2],
+        "df" => ["a", "b"],
+        "lazy" => [Some(1.0), None],
+    ]
+    .unwrap();
+    Slim::validate(&df).unwrap();
+    assert_eq!(Slim::from_df(&df).unwrap().len(), 2);
+}
+
+#[test]
+fn test_skip_expr_keeps_field_items() {
+    assert_eq!(Point::expr, "expr");
+    assert_eq!(Point::x_idx, 1);
+    assert_eq!(Point::x_type, DataType::Float64);
+    assert_eq!(Point::x_col(), col("x"));
+    assert_eq!(Point::df().unwrap().width(), 2);
+}
@@ -0,0 +1,62 @@
+#![allow(non_upper_case_globals)]
+use polars_tools::*;
+
+#[derive(Debug, PolarsColumns)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Player {
+    name: String,
+    team: String,
+    score: Option<i32>,
+}
+
+fn players() -> DataFrame {
+    df![
+        "name" => ["Alice", "Bob", "Carol", "Dave"],
+        "team" => ["red", "blue", "red", "blue"],
+        "score" => [Some(10i32), Some(30), None, Some(20)],
+    ]
+    .unwrap()
+}
+
+fn names(df: &DataFrame) -> Vec<Option<&str>> {
+    df.column("name").unwrap().str().unwrap().into_iter().collect()
+}
+
+#[test]
+fn test_sort_spec_columns_and_directions() {
+    let spec = Player::sort_by(&[Asc(Player::team), Desc(Player::score)]);
+
+    assert_eq!(spec.columns, vec!["team", "score"]);
+    assert_eq!(spec.options.descending, vec![false, true]);
+}
+
+#[test]
+fn test_sort_spec_applies_to_lazy_frame() {
+    let spec = Player::sort_by(&[Asc(Player::team), Desc(Player::score)]).nulls_last(true);
+    let df = spec.apply(players().lazy()).collect().unwrap();
+
+    assert_eq!(
+        names(&df),
+        [Some("Bob"), Some("Dave"), Some("Alice"), Some("Carol")]
+    );
+}
+
+#[test]
+fn test_sort_spec_with_lazy_frame_sort() {
+    let spec = Player::sort_by(&[Desc(Player::score)]).nulls_last(true);
+    let df = players()
+        .lazy()
+        .sort(spec.columns, spec.options)
+        .collect()
+        .unwrap();
+
+    assert_eq!(names(&df)[0], Some("Bob"));
+    assert_eq!(names(&df)[3], Some("Carol"));
+}
+
+#[test]
+fn test_sort_key_accessors() {
+    assert_eq!(Desc(Player::score).column(), "score");
+    assert!(Desc(Player::score).is_descending());
+    assert!(!Asc(Player::name).is_descending());
+}
@@ -0,0 +1,75 @@
+#![allow(non_upper_case_globals)]
+
+#[cfg(feature = "sql")]
+mod sql_context {
+    use polars::sql::SQLContext;
+    use polars_tools::*;
+
+    #[derive(PolarsSchema)]
+    #[allow(dead_code, non_upper_case_globals)]
+    struct OrderLine {
+        order_id: i64,
+        sku: String,
+        quantity: i64,
+    }
+
+    #[derive(PolarsSchema)]
+    #[polars(table = "totals")]
+    #[allow(dead_code, non_upper_case_globals)]
+    struct OrderTotal {
+        order_id: i64,
+        quantity: i64,
+    }
+
+    fn lines() -> DataFrame {
+        df![
+            "order_id" => [1i64, 1, 2],
+            "sku" => ["a", "b", "a"],
+            "quantity" => [2i64, 3, 1],
+        ]
+        .unwrap()
+    }
+
+    #[test]
+    fn test_table_names() {
+        assert_eq!(OrderLine::TABLE_NAME, "order_line");
+        assert_eq!(OrderTotal::TABLE_NAME, "totals");
+    }
+
+    #[test]
+    fn test_register_and_query() {
+        let mut ctx = SQLContext::new();
+        OrderLine::register(&mut ctx, &lines()).unwrap();
+        let totals = OrderTotal::query(
+            &mut ctx,
+            "SELECT order_id, SUM(quantity) AS quantity FROM order_line \
+             GROUP BY order_id ORDER BY order_id",
+        )
+        .unwrap();
+        assert_eq!(
+            totals.column("quantity").unwrap().i64().unwrap().to_vec(),
+            [Some(5), Some(1)]
+        );
+    }
+
+    #[test]
+    fn test_register_validates() {
+        let mut ctx = SQLContext::new();
+        let bad = lines().drop("sku").unwrap();
+        assert!(matches!(
+            OrderLine::register(&mut ctx, &bad),
+            Err(ValidationError::MissingColumn { .. })
+        ));
+        assert!(ctx.get_tables().is_empty());
+    }
+
+    #[test]
+    fn test_query_validates_result() {
+        let mut ctx = SQLContext::new();
+        OrderLine::register(&mut ctx, &lines()).unwrap();
+        assert!(matches!(
+            OrderTotal::query(&mut ctx, "SELECT order_id, sku AS quantity FROM order_line"),
+            Err(ValidationError::TypeMismatch { .. })
+        ));
+    }
+}
@@ -0,0 +1,47 @@
+use polars_tools::*;
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Account {
+    id: i64,
+    owner: String,
+    balance: Option<f64>,
+    active: bool,
+    #[polars(dtype = DataType::Datetime(TimeUnit::Microseconds, None))]
+    opened_at: i64,
+}
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Tagged {
+    id: i64,
+    tags: Vec<String>,
+}
+
+#[test]
+fn test_insert_sql() {
+    assert_eq!(
+        Account::insert_sql("accounts").unwrap(),
+        "INSERT INTO accounts (\"id\", \"owner\", \"balance\", \"active\", \"opened_at\") \
+         SELECT * FROM UNNEST($1::bigint[], $2::text[], $3::double precision[], \
+         $4::boolean[], $5::timestamp[])"
+    );
+}
+
+#[test]
+fn test_insert_sql_rejects_list_columns() {
+    assert!(matches!(
+        Tagged::insert_sql("tagged"),
+        Err(ValidationError::TypeMismatch { column_name, .. }) if column_name == "tags"
+    ));
+}
+
+#[test]
+fn test_pg_type() {
+    assert_eq!(sql::pg_type(&DataType::UInt32), Some("bigint"));
+    assert_eq!(sql::pg_type(&DataType::Binary), Some("bytea"));
+    assert_eq!(
+        sql::pg_type(&DataType::List(Box::new(DataType::Int64))),
+        None
+    );
+}
@@ -0,0 +1,85 @@
+#![allow(non_upper_case_globals)]
+use polars_tools::*;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Level {
+    Low,
+    High,
+}
+
+impl ValidatableEnum for Level {
+    fn valid_values() -> Vec<&'static str> {
+        vec!["Low", "High"]
+    }
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "Low" => Ok(Level::Low),
+            "High" => Ok(Level::High),
+            _ => Err(ValidationError::InvalidEnumValue {
+                field: "Level".to_string(),
+                value: value.to_string(),
+                valid_values: Self::valid_values().into_iter().map(|s| s.to_string()).collect(),
+            }),
+        }
+    }
+
+    fn to_str(&self) -> &'static str {
+        match self {
+            Level::Low => "Low",
+            Level::High => "High",
+        }
+    }
+}
+
+// Type without a PolarsDType impl
+#[derive(Debug, Clone)]
+#[allow(dead_code)]
+struct Label(String);
+
+// Strict mode accepts known types, ValidatableEnum types, and explicitly annotated fields
+#[derive(PolarsSchema)]
+#[polars(strict_types)]
+#[allow(dead_code, non_upper_case_globals)]
+struct StrictRecord {
+    id: i64,
+    level: Level,
+    maybe_level: Option<Level>,
+    #[polars(as_string)]
+    label: Label,
+}
+
+#[derive(PolarsColumns)]
+#[allow(dead_code, non_upper_case_globals)]
+struct MixedRecord {
+    #[polars(not_enum)]
+    id: i64,
+    label: Label,
+}
+
+#[test]
+fn test_strict_types_known_fields() {
+    assert_eq!(StrictRecord::id_type, DataType::Int64);
+    assert_eq!(StrictRecord::level_type, DataType::String);
+    assert_eq!(StrictRecord::maybe_level_type, DataType::String);
+    assert_eq!(StrictRecord::label_type, DataType::String);
+}
+
+#[test]
+fn test_not_enum_field_with_heuristic_elsewhere() {
+    assert_eq!(MixedRecord::id_type, DataType::Int64);
+    assert_eq!(MixedRecord::label_type, DataType::String);
+}
+
+#[test]
+fn test_strict_record_validation() {
+    let df = df![
+        "id" => [1i64],
+        "level" => ["Low"],
+        "maybe_level" => [None::<&str>],
+        "label" => ["x"],
+    ]
+    .unwrap();
+
+    assert!(StrictRecord::validate_strict(&df).is_ok());
+}
@@ -0,0 +1,101 @@
+use polars_tools::*;
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Quote {
+    ticker: String,
+    #[polars(time_index, dtype = DataType::Date)]
+    day: i32,
+    price: f64,
+    volume: i64,
+}
+
+fn quotes() -> DataFrame {
+    let df = df![
+        "ticker" => ["abc", "abc", "xyz", "abc", "xyz"],
+        "day" => [0i32, 1, 2, 8, 9],
+        "price" => [1.0, 3.0, 10.0, 5.0, 20.0],
+        "volume" => [10i64, 20, 30, 40, 50],
+    ]
+    .unwrap();
+    df.lazy()
+        .with_column(col("day").cast(DataType::Date))
+        .collect()
+        .unwrap()
+}
+
+#[test]
+fn test_time_index() {
+    assert_eq!(Quote::time_index(), "day");
+}
+
+#[test]
+fn test_rolling_mean() {
+    let df = quotes()
+        .lazy()
+        .select([Quote::rolling_mean(Quote::price, "7d").unwrap()])
+        .collect()
+        .unwrap();
+    let means: Vec<_> = df.column("price").unwrap().f64().unwrap().into_iter().collect();
+    // The window of day 8 covers days 2 to 8, the one of day 9 days 3 to 9
+    assert_eq!(
+        means,
+        [Some(1.0), Some(2.0), Some(14.0 / 3.0), Some(7.5), Some(12.5)]
+    );
+
+    let df = quotes()
+        .lazy()
+        .select([
+            Quote::rolling_sum(Quote::volume, "2d").unwrap(),
+            Quote::rolling_max(Quote::price, "2d").unwrap().alias("max"),
+            Quote::rolling_min(Quote::price, "2d").unwrap().alias("min"),
+        ])
+        .collect()
+        .unwrap();
+    let sums: Vec<_> = df.column("volume").unwrap().i64().unwrap().into_iter().collect();
+    assert_eq!(sums, [Some(10), Some(30), Some(50), Some(40), Some(90)]);
+    let maxima: Vec<_> = df.column("max").unwrap().f64().unwrap().into_iter().collect();
+    assert_eq!(maxima, [Some(1.0), Some(3.0), Some(10.0), Some(5.0), Some(20.0)]);
+}
+
+#[test]
+fn test_rolling_means() {
+    let exprs = Quote::rolling_means("7d").unwrap();
+    let df = quotes().lazy().select(exprs).collect().unwrap();
+    assert_eq!(df.get_column_names(), ["price_rolling_mean", "volume_rolling_mean"]);
+}
+
+#[test]
+fn test_rolling_invalid_window() {
+    assert!(Quote::rolling_mean(Quote::price, "seven days").is_err());
+    assert!(Quote::rolling_means("7x").is_err());
+}
+
+#[test]
+fn test_group_by_dynamic() {
+    let df = Quote::group_by_dynamic(quotes(), [Quote::ticker], "1w")
+        .unwrap()
+        .agg([col(Quote::price).mean(), col(Quote::volume).sum()])
+        .sort([Quote::ticker, Quote::day], Default::default())
+        .collect()
+        .unwrap();
+    assert_eq!(df.get_column_names(), ["ticker", "day", "price", "volume"]);
+    let volumes: Vec<_> = df.column("volume").unwrap().i64().unwrap().into_iter().collect();
+    assert_eq!(volumes, [Some(30), Some(40), Some(30), Some(50)]);
+}
+
+#[test]
+fn test_group_by_dynamic_with() {
+    let options = DynamicGroupOptions {
+        every: Duration::parse("1w"),
+        period: Duration::parse("2w"),
+        offset: Duration::parse("0d"),
+        ..Default::default()
+    };
+    let df = Quote::group_by_dynamic_with(quotes(), [], options)
+        .agg([col(Quote::volume).sum()])
+        .collect()
+        .unwrap();
+    let volumes: Vec<_> = df.column("volume").unwrap().i64().unwrap().into_iter().collect();
+    assert_eq!(volumes[0], Some(150));
+}
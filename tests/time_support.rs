@@ -0,0 +1,38 @@
+#![allow(non_upper_case_globals)]
+#[cfg(feature = "time")]
+mod time_types {
+    use polars_tools::*;
+
+    #[derive(PolarsSchema)]
+    #[allow(dead_code, non_upper_case_globals)]
+    struct Shift {
+        day: time::Date,
+        starts: time::Time,
+        created_at: time::OffsetDateTime,
+        scheduled: Option<time::PrimitiveDateTime>,
+        #[polars(time_unit = "ms")]
+        length: time::Duration,
+    }
+
+    #[test]
+    fn test_time_crate_type_mapping() {
+        assert_eq!(Shift::day_type, DataType::Date);
+        assert_eq!(Shift::starts_type, DataType::Time);
+        assert_eq!(
+            Shift::created_at_type,
+            DataType::Datetime(TimeUnit::Microseconds, Some(PlSmallStr::from_static("UTC")))
+        );
+        assert_eq!(
+            Shift::scheduled_type,
+            DataType::Datetime(TimeUnit::Microseconds, None)
+        );
+        assert_eq!(Shift::length_type, DataType::Duration(TimeUnit::Milliseconds));
+    }
+
+    #[test]
+    fn test_time_crate_empty_dataframe_validates() {
+        let empty_df = Shift::df().unwrap();
+        assert_eq!(empty_df.schema().get("day"), Some(&DataType::Date));
+        assert!(Shift::validate_strict(&empty_df).is_ok());
+    }
+}
@@ -0,0 +1,58 @@
+#![allow(non_upper_case_globals)]
+#[cfg(feature = "chrono")]
+mod timezones {
+    use chrono::{DateTime, FixedOffset, NaiveDateTime, Utc};
+    use polars_tools::*;
+
+    #[derive(PolarsSchema)]
+    #[allow(dead_code, non_upper_case_globals)]
+    struct Event {
+        id: i64,
+        created_at: DateTime<Utc>,
+        #[polars(time_unit = "ns")]
+        ingested_at: DateTime<Utc>,
+        #[polars(tz = "Europe/Berlin", time_unit = "ms")]
+        local_time: DateTime<Utc>,
+        received_at: DateTime<FixedOffset>,
+        #[polars(time_unit = "ns")]
+        naive: Option<NaiveDateTime>,
+    }
+
+    fn tz(name: &'static str) -> Option<PlSmallStr> {
+        Some(PlSmallStr::from_static(name))
+    }
+
+    #[test]
+    fn test_datetime_time_unit_and_tz_attributes() {
+        assert_eq!(
+            Event::created_at_type,
+            DataType::Datetime(TimeUnit::Microseconds, tz("UTC"))
+        );
+        assert_eq!(
+            Event::ingested_at_type,
+            DataType::Datetime(TimeUnit::Nanoseconds, tz("UTC"))
+        );
+        assert_eq!(
+            Event::local_time_type,
+            DataType::Datetime(TimeUnit::Milliseconds, tz("Europe/Berlin"))
+        );
+        assert_eq!(
+            Event::received_at_type,
+            DataType::Datetime(TimeUnit::Microseconds, tz("UTC"))
+        );
+        assert_eq!(
+            Event::naive_type,
+            DataType::Datetime(TimeUnit::Nanoseconds, None)
+        );
+    }
+
+    #[test]
+    fn test_nanosecond_frame_validates() {
+        let empty_df = Event::df().unwrap();
+        assert_eq!(
+            empty_df.schema().get("ingested_at"),
+            Some(&DataType::Datetime(TimeUnit::Nanoseconds, tz("UTC")))
+        );
+        assert!(Event::validate_strict(&empty_df).is_ok());
+    }
+}
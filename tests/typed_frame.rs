@@ -0,0 +1,151 @@
+use polars_tools::*;
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Order {
+    id: i64,
+    customer_id: i64,
+    amount: f64,
+}
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Customer {
+    customer_id: i64,
+    country: String,
+}
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct OrderCountry {
+    id: i64,
+    amount: f64,
+    country: String,
+}
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Amount {
+    amount: f64,
+}
+
+fn orders() -> DataFrame {
+    df![
+        "id" => [1i64, 2, 3],
+        "customer_id" => [10i64, 20, 10],
+        "amount" => [5.0, 50.0, 500.0],
+        "note" => ["a", "b", "c"],
+    ]
+    .unwrap()
+}
+
+fn customers() -> TypedFrame<Customer> {
+    Customer::typed(&df!["customer_id" => [10i64, 20], "country" => ["NL", "DE"]].unwrap()).unwrap()
+}
+
+fn total(orders: &TypedFrame<Order>) -> f64 {
+    orders
+        .df()
+        .column(Order::amount)
+        .unwrap()
+        .f64()
+        .unwrap()
+        .sum()
+        .unwrap()
+}
+
+#[test]
+fn test_construction_validates() {
+    let typed = Order::typed(&orders()).unwrap();
+    assert_eq!(total(&typed), 555.0);
+
+    let missing = orders().drop("amount").unwrap();
+    assert!(Order::typed(&missing).is_err());
+    assert!(TypedFrame::<Order>::try_from(&missing).is_err());
+    assert!(TypedFrame::<Order>::try_from(orders()).is_ok());
+}
+
+#[test]
+fn test_schema_preserving_operations() {
+    let orders = Order::typed(&orders()).unwrap();
+    let big = orders.filter(Order::expr.amount().gt(lit(10.0))).unwrap();
+    assert_eq!(big.height(), 2);
+
+    let sorted = orders
+        .sort(
+            [Order::amount],
+            SortMultipleOptions::default().with_order_descending(true),
+        )
+        .unwrap();
+    assert_eq!(
+        sorted.df().column("id").unwrap().i64().unwrap().get(0),
+        Some(3)
+    );
+
+    let both = big.vstack(&orders).unwrap();
+    assert_eq!(both.height(), 5);
+    assert_eq!(
+        both.df().get_column_names(),
+        ["id", "customer_id", "amount"]
+    );
+}
+
+#[test]
+fn test_select_and_join() {
+    let orders = Order::typed(&orders()).unwrap();
+    let amounts: TypedFrame<Amount> = orders.select().unwrap();
+    assert_eq!(amounts.df().width(), 1);
+
+    let joined: TypedFrame<OrderCountry> = orders
+        .join(
+            &customers(),
+            &["customer_id"],
+            &["customer_id"],
+            JoinType::Inner,
+        )
+        .unwrap();
+    assert_eq!(joined.height(), 3);
+
+    assert!(orders.clone().into_schema::<Customer>().is_err());
+}
+
+#[test]
+fn test_lazy_frames() {
+    let nl = Order::typed(&orders())
+        .unwrap()
+        .lazy()
+        .join::<Customer, OrderCountry>(
+            customers().lazy(),
+            &["customer_id"],
+            &["customer_id"],
+            JoinType::Left,
+        )
+        .filter(OrderCountry::expr.country().eq(lit("NL")))
+        .select::<OrderCountry>()
+        .collect()
+        .unwrap();
+    assert_eq!(nl.height(), 2);
+
+    let doubled = Order::typed(&orders())
+        .unwrap()
+        .lazy()
+        .map::<Amount>(|lf| lf.select([(col("amount") * lit(2.0)).alias("amount")]))
+        .collect()
+        .unwrap();
+    assert_eq!(
+        DataFrame::from(doubled)
+            .column("amount")
+            .unwrap()
+            .f64()
+            .unwrap()
+            .sum(),
+        Some(1110.0)
+    );
+
+    let wrong = Order::typed(&orders())
+        .unwrap()
+        .lazy()
+        .map::<Amount>(|lf| lf.select([col("id").alias("amount")]))
+        .collect();
+    assert!(matches!(wrong, Err(ValidationError::TypeMismatch { .. })));
+}
@@ -0,0 +1,71 @@
+#![allow(non_upper_case_globals)]
+use polars_tools::*;
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Person {
+    id: i64,
+    name: String,
+    age: i32,
+    nickname: Option<String>,
+    score: f64,
+    tags: Vec<String>,
+}
+
+fn people() -> DataFrame {
+    df![
+        "id" => [1i64, 2],
+        "name" => ["Alice", "Bob"],
+        "age" => [30i32, 41],
+        "nickname" => [Some("Al"), None],
+        "score" => [1.5f64, 2.5],
+        "tags" => [Series::new("".into(), ["a"]), Series::new("".into(), ["b", "c"])],
+    ]
+    .unwrap()
+}
+
+#[test]
+fn test_typed_getters_return_chunked_arrays() {
+    let df = people();
+
+    let ages: &Int32Chunked = Person::age_series(&df).unwrap();
+    assert_eq!(ages.get(1), Some(41));
+
+    let names: &StringChunked = Person::name_series(&df).unwrap();
+    assert_eq!(names.get(0), Some("Alice"));
+
+    let nicknames = Person::nickname_series(&df).unwrap();
+    assert_eq!(nicknames.get(1), None);
+
+    let tags: &ListChunked = Person::tags_series(&df).unwrap();
+    assert_eq!(tags.len(), 2);
+}
+
+#[test]
+fn test_typed_getter_missing_column() {
+    let df = people().drop("age").unwrap();
+    match Person::age_series(&df) {
+        Err(ValidationError::MissingColumn { column_name, .. }) => assert_eq!(column_name, "age"),
+        other => panic!("Expected MissingColumn, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_typed_getter_wrong_dtype() {
+    let df = df![
+        "age" => ["thirty"],
+    ]
+    .unwrap();
+    match Person::age_series(&df) {
+        Err(ValidationError::TypeMismatch {
+            column_name,
+            actual_type,
+            expected_type,
+        }) => {
+            assert_eq!(column_name, "age");
+            assert_eq!(actual_type, "String");
+            assert_eq!(expected_type, "Int32");
+        }
+        other => panic!("Expected TypeMismatch, got {:?}", other),
+    }
+}
@@ -0,0 +1,103 @@
+#![allow(non_upper_case_globals)]
+use polars_tools::*;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Status {
+    Active,
+    Inactive,
+}
+
+impl ValidatableEnum for Status {
+    fn valid_values() -> Vec<&'static str> {
+        vec!["Active", "Inactive"]
+    }
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "Active" => Ok(Status::Active),
+            "Inactive" => Ok(Status::Inactive),
+            _ => Err(ValidationError::InvalidEnumValue {
+                field: "Status".to_string(),
+                value: value.to_string(),
+                valid_values: Self::valid_values().into_iter().map(|s| s.to_string()).collect(),
+            }),
+        }
+    }
+
+    fn to_str(&self) -> &'static str {
+        match self {
+            Status::Active => "Active",
+            Status::Inactive => "Inactive",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, PolarsNewtype)]
+struct UserId(u32);
+
+#[derive(Debug, PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct User {
+    id: UserId,
+    name: String,
+    age: i64,
+    score: Option<f32>,
+    status: Status,
+}
+
+fn users() -> DataFrame {
+    df![
+        "id" => [1u32, 2, 3],
+        "name" => ["Alice", "Bob", "Carol"],
+        "age" => [30i64, 25, 41],
+        "score" => [Some(1.5f32), None, Some(3.0)],
+        "status" => ["Active", "Inactive", "Active"],
+    ]
+    .unwrap()
+}
+
+fn filtered(predicate: Expr) -> DataFrame {
+    users().lazy().filter(predicate).collect().unwrap()
+}
+
+#[test]
+fn test_literal_is_cast_to_column_dtype() {
+    let df = users()
+        .lazy()
+        .select([User::lit_age(30).alias("lit")])
+        .collect()
+        .unwrap();
+
+    assert_eq!(df.column("lit").unwrap().dtype(), &DataType::Int64);
+}
+
+#[test]
+fn test_literals_in_filters() {
+    assert_eq!(filtered(User::age_col().gt(User::lit_age(28))).height(), 2);
+    assert_eq!(filtered(User::name_col().eq(User::lit_name("Bob"))).height(), 1);
+    assert_eq!(filtered(User::score_col().gt(User::lit_score(2.0f32))).height(), 1);
+}
+
+#[test]
+fn test_enum_literal() {
+    let df = filtered(User::status_col().eq(User::lit_status(Status::Active)));
+    assert_eq!(df.height(), 2);
+}
+
+#[test]
+fn test_newtype_literal() {
+    let df = filtered(User::id_col().eq(User::lit_id(UserId(3))));
+    assert_eq!(User::name_at(&df, 0).unwrap(), Some("Carol".to_string()));
+}
+
+#[test]
+fn test_null_literal() {
+    let df = users()
+        .lazy()
+        .select([User::lit_score(None::<f32>).alias("lit")])
+        .collect()
+        .unwrap();
+
+    assert_eq!(df.column("lit").unwrap().dtype(), &DataType::Float32);
+    assert_eq!(df.column("lit").unwrap().null_count(), 1);
+}
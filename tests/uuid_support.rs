@@ -0,0 +1,38 @@
+#![allow(non_upper_case_globals)]
+#[cfg(feature = "uuid")]
+mod uuid_fields {
+    use polars_tools::*;
+    use uuid::Uuid;
+
+    #[derive(PolarsSchema)]
+    #[allow(dead_code, non_upper_case_globals)]
+    struct Account {
+        id: Uuid,
+        parent_id: Option<Uuid>,
+        #[polars(binary)]
+        raw_id: Uuid,
+        name: String,
+    }
+
+    #[test]
+    fn test_uuid_type_mapping() {
+        assert_eq!(Account::id_type, DataType::String);
+        assert_eq!(Account::parent_id_type, DataType::String);
+        assert_eq!(Account::raw_id_type, DataType::Binary);
+    }
+
+    #[test]
+    fn test_uuid_validation() {
+        let id = Uuid::nil().to_string();
+        let df = DataFrame::new(vec![
+            Column::new("id".into(), [id.as_str()]),
+            Column::new("parent_id".into(), [None::<&str>]),
+            Column::new("raw_id".into(), [Uuid::nil().as_bytes().as_slice()]),
+            Column::new("name".into(), ["root"]),
+        ])
+        .unwrap();
+
+        assert!(Account::validate_strict(&df).is_ok());
+        assert!(Account::validate_strict(&Account::df().unwrap()).is_ok());
+    }
+}
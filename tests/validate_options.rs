@@ -0,0 +1,84 @@
+use polars_tools::*;
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Trade {
+    id: i64,
+    qty: u64,
+    price: f64,
+    venue: Option<String>,
+}
+
+#[derive(PolarsColumns)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Tick {
+    seq: i64,
+    note: Option<String>,
+}
+
+fn narrow() -> DataFrame {
+    df![
+        "id" => [1i32, 2],
+        "qty" => [10u32, 20],
+        "price" => [1.5f32, 2.25],
+        "venue" => [Some("XAMS"), None],
+    ]
+    .unwrap()
+}
+
+#[test]
+fn test_widening_is_opt_in() {
+    let err = Trade::validate(&narrow()).unwrap_err();
+    assert!(
+        matches!(err, ValidationError::TypeMismatch { column_name, .. } if column_name == "id")
+    );
+
+    let opts = ValidateOptions::default().allow_widening();
+    Trade::validate_with(&narrow(), &opts).unwrap();
+}
+
+#[test]
+fn test_only_lossless_widening_is_accepted() {
+    let opts = ValidateOptions::default().allow_widening();
+    let signed_qty = narrow()
+        .with_column(Column::new("qty".into(), [10i32, 20]))
+        .unwrap()
+        .clone();
+    assert!(Trade::validate_with(&signed_qty, &opts).is_err());
+
+    let wide_id = df!["seq" => [1u32], "note" => [Some("a")]].unwrap();
+    Tick::validate_with(&wide_id, &opts).unwrap();
+    let float_seq = df!["seq" => [1.0f32], "note" => [Some("a")]].unwrap();
+    assert!(Tick::validate_with(&float_seq, &opts).is_err());
+}
+
+#[test]
+fn test_conform_with_casts_widened_columns() {
+    let opts = ValidateOptions::default().allow_widening();
+    let (df, report) = Trade::conform_with(&narrow(), &opts).unwrap();
+
+    Trade::validate(&df).unwrap();
+    let cast: Vec<_> = report
+        .cast
+        .iter()
+        .map(|cast| cast.column.as_str())
+        .collect();
+    assert_eq!(cast, ["id", "qty", "price"]);
+    assert_eq!(report.cast[0].from, DataType::Int32);
+    assert_eq!(report.cast[0].to, DataType::Int64);
+}
+
+#[test]
+fn test_missing_optional_columns() {
+    let df = narrow().drop("venue").unwrap();
+    let opts = ValidateOptions::default()
+        .allow_widening()
+        .allow_missing_optional();
+    assert!(Trade::validate_with(&df, &ValidateOptions::default().allow_widening()).is_err());
+    Trade::validate_with(&df, &opts).unwrap();
+
+    let err = Trade::validate_with(&df.drop("price").unwrap(), &opts).unwrap_err();
+    assert!(matches!(err, ValidationError::MissingColumn { .. }));
+
+    Tick::validate_with(&df!["seq" => [1i64]].unwrap(), &opts).unwrap();
+}
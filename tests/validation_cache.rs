@@ -0,0 +1,62 @@
+use polars_tools::*;
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Trade {
+    symbol: String,
+    #[polars(alias = "qty")]
+    quantity: i64,
+}
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Quote {
+    symbol: String,
+    quantity: i64,
+}
+
+fn trades() -> DataFrame {
+    df!["symbol" => ["a", "b"], "qty" => [1i64, 2]].unwrap()
+}
+
+#[test]
+fn test_validate_cached_same_frame() {
+    let df = trades();
+    Trade::validate_cached(&df).unwrap();
+    Trade::validate_cached(&df).unwrap();
+    // Clones share the column data
+    Trade::validate_cached(&df.clone()).unwrap();
+    clear_validation_cache();
+    Trade::validate_cached(&df).unwrap();
+}
+
+#[test]
+fn test_validate_cached_per_schema() {
+    let df = trades();
+    Trade::validate_cached(&df).unwrap();
+    // Quote has no `qty` alias, so the same frame fails it
+    assert!(matches!(
+        Quote::validate_cached(&df),
+        Err(ValidationError::MissingColumn { .. })
+    ));
+}
+
+#[test]
+fn test_validate_cached_changed_frame() {
+    let mut df = trades();
+    Trade::validate_cached(&df).unwrap();
+
+    df.with_column(Column::new("qty".into(), [1.5f64, 2.5]))
+        .unwrap();
+    assert!(matches!(
+        Trade::validate_cached(&df),
+        Err(ValidationError::TypeMismatch { .. })
+    ));
+
+    let renamed = trades()
+        .lazy()
+        .rename(["qty"], ["count"], true)
+        .collect()
+        .unwrap();
+    assert!(Trade::validate_cached(&renamed).is_err());
+}
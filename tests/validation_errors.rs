@@ -25,7 +25,7 @@ fn test_missing_column_error() {
 
     let err = result.unwrap_err();
     match err {
-        ValidationError::MissingColumn { column_name } => {
+        ValidationError::MissingColumn { column_name, .. } => {
             assert_eq!(column_name, "age");
         }
         _ => panic!("Expected MissingColumn error, got: {:?}", err),
@@ -218,9 +218,19 @@ fn test_boolean_and_string_types() {
 fn test_error_display_formatting() {
     let missing_err = ValidationError::MissingColumn {
         column_name: "test_column".to_string(),
+        suggestions: vec![],
     };
     let error_msg = format!("{}", missing_err);
-    assert!(error_msg.contains("Missing required column: test_column"));
+    assert_eq!(error_msg, "Missing required column: test_column");
+
+    let missing_err = ValidationError::MissingColumn {
+        column_name: "test_column".to_string(),
+        suggestions: vec!["test_colum".to_string(), "Test_Column".to_string()],
+    };
+    assert_eq!(
+        missing_err.to_string(),
+        "Missing required column: test_column (did you mean `test_colum` or `Test_Column`?)"
+    );
 
     let type_err = ValidationError::TypeMismatch {
         column_name: "age".to_string(),
@@ -233,6 +243,42 @@ fn test_error_display_formatting() {
     assert!(type_msg.contains("Int32"));
 }
 
+#[test]
+fn test_missing_column_suggestions() {
+    let df = df![
+        "id" => [1i64],
+        "Name" => ["a"],
+        "agee" => [1i32],
+        "actv" => [true],
+    ]
+    .unwrap();
+
+    match TestSchema::validate(&df).unwrap_err() {
+        ValidationError::MissingColumn {
+            column_name,
+            suggestions,
+        } => {
+            assert_eq!(column_name, "name");
+            assert_eq!(suggestions, ["Name"]);
+        }
+        err => panic!("Expected MissingColumn error, got: {:?}", err),
+    }
+
+    let df = df!["id" => [1i64], "name" => ["a"], "agee" => [1i32]].unwrap();
+    let err = TestSchema::validate(&df).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Missing required column: age (did you mean `agee`?)"
+    );
+
+    // columns of the schema and unrelated names are never suggested
+    let df = df!["id" => [1i64], "name" => ["a"], "weight" => [1i32]].unwrap();
+    assert!(matches!(
+        TestSchema::validate(&df),
+        Err(ValidationError::MissingColumn { suggestions, .. }) if suggestions.is_empty()
+    ));
+}
+
 #[test]
 fn test_multiple_validation_errors() {
     // Test that validation stops at first error (as expected)
@@ -280,3 +326,46 @@ fn test_chrono_type_validation() {
 
     assert!(ChronoTypes::validate(&df).is_ok());
 }
+
+#[test]
+fn test_error_codes() {
+    let df = df!["id" => [1i64], "name" => ["a"], "agee" => [1i32]].unwrap();
+    let missing = TestSchema::validate(&df).unwrap_err();
+    assert_eq!(missing.code(), "PT0001");
+    assert_eq!(missing.kind(), "MissingColumn");
+
+    let row = ValidationError::RowOutOfBounds { row: 3, height: 1 };
+    assert_eq!(row.code(), "PT0006");
+    let polars = ValidationError::from(PolarsError::NoData("empty".into()));
+    assert_eq!(polars.code(), "PT0009");
+}
+
+#[test]
+fn test_error_serialization() {
+    let df = df!["id" => [1i64], "name" => ["a"], "agee" => [1i32]].unwrap();
+    let missing = TestSchema::validate(&df).unwrap_err();
+    assert_eq!(
+        serde_json::to_value(&missing).unwrap(),
+        serde_json::json!({
+            "code": "PT0001",
+            "kind": "MissingColumn",
+            "message": "Missing required column: age (did you mean `agee`?)",
+            "column": "age",
+            "suggestions": ["agee"],
+        })
+    );
+
+    let strict = ValidationError::ColumnCountMismatch {
+        expected: ["b", "a"].map(String::from).into(),
+        actual: ["c", "a", "b"].map(String::from).into(),
+    };
+    let value = serde_json::to_value(&strict).unwrap();
+    assert_eq!(value["code"], "PT0003");
+    assert_eq!(value["expected"], serde_json::json!(["a", "b"]));
+    assert_eq!(value["actual"], serde_json::json!(["a", "b", "c"]));
+
+    let polars = ValidationError::from(PolarsError::NoData("empty".into()));
+    let value = serde_json::to_value(&polars).unwrap();
+    assert_eq!(value["kind"], "Polars");
+    assert_eq!(value.as_object().unwrap().len(), 3);
+}
@@ -0,0 +1,47 @@
+#![allow(non_upper_case_globals)]
+use polars_tools::*;
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Counters {
+    id: i64,
+    total: i128,
+    delta: Option<i128>,
+}
+
+#[test]
+fn test_i128_type_mapping() {
+    assert_eq!(Counters::total_type, DataType::Int128);
+    assert_eq!(Counters::delta_type, DataType::Int128);
+    assert_eq!(
+        Counters::all_types(),
+        vec![DataType::Int64, DataType::Int128, DataType::Int128]
+    );
+}
+
+#[test]
+fn test_i128_empty_dataframe_and_validation() {
+    let empty_df = Counters::df().unwrap();
+    assert_eq!(empty_df.schema().get("total"), Some(&DataType::Int128));
+    assert!(Counters::validate_strict(&empty_df).is_ok());
+
+    let wrong = DataFrame::new(vec![
+        Column::new("id".into(), [1i64]),
+        Column::new("total".into(), [1i64]),
+        Column::new("delta".into(), Series::full_null("delta".into(), 1, &DataType::Int128)),
+    ])
+    .unwrap();
+    match Counters::validate(&wrong) {
+        Err(ValidationError::TypeMismatch { column_name, .. }) => assert_eq!(column_name, "total"),
+        other => panic!("Expected TypeMismatch, got {:?}", other),
+    }
+}
+
+#[cfg(feature = "decimal")]
+#[test]
+fn test_u128_maps_to_integral_decimal() {
+    assert_eq!(
+        <u128 as PolarsDType>::dtype(),
+        DataType::Decimal(Some(DEFAULT_DECIMAL_PRECISION), Some(0))
+    );
+}
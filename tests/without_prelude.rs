@@ -0,0 +1,50 @@
+//! Derives used with polars imported directly rather than through the
+//! re-exported prelude, as with `default-features = false`
+
+use polars::prelude::{col, df, lit, DataFrame, DataType, IntoLazy};
+use polars_tools::{PolarsColumns, PolarsSchema, ValidationError};
+
+#[derive(PolarsSchema)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Reading {
+    sensor: String,
+    value: f64,
+    #[polars(dtype = DataType::Date)]
+    day: i32,
+}
+
+#[derive(PolarsColumns)]
+#[allow(dead_code, non_upper_case_globals)]
+struct Sensor {
+    sensor: String,
+}
+
+fn readings() -> DataFrame {
+    df![
+        "sensor" => ["a", "b"],
+        "value" => [1.5, 2.5],
+        "day" => [19_000i32, 19_001],
+    ]
+    .unwrap()
+    .lazy()
+    .with_column(col("day").cast(DataType::Date))
+    .collect()
+    .unwrap()
+}
+
+#[test]
+fn test_derives_without_prelude() {
+    Reading::validate(&readings()).unwrap();
+    assert!(matches!(
+        Reading::validate(&readings().drop("value").unwrap()),
+        Err(ValidationError::MissingColumn { .. })
+    ));
+
+    let a = readings()
+        .lazy()
+        .filter(Sensor::expr.sensor().eq(lit("a")))
+        .collect()
+        .unwrap();
+    assert_eq!(a.height(), 1);
+    assert_eq!(Reading::df().unwrap().schema(), readings().schema());
+}